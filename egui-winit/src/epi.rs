@@ -196,6 +196,8 @@ pub struct EpiIntegration {
     pub app: Box<dyn epi::App>,
     /// When set, it is time to quit
     quit: bool,
+    /// From the last [`Self::update`], see [`Self::take_snapshot_requests`].
+    snapshot_requests: Vec<egui::output::SnapshotRequest>,
 }
 
 impl EpiIntegration {
@@ -229,6 +231,7 @@ impl EpiIntegration {
             egui_winit: crate::State::new(window),
             app,
             quit: false,
+            snapshot_requests: Default::default(),
         };
 
         slf.setup(window);
@@ -269,23 +272,33 @@ impl EpiIntegration {
         self.egui_winit.on_event(&self.egui_ctx, event);
     }
 
-    /// Returns `needs_repaint` and shapes to paint.
+    /// Returns when to repaint (`None` if there is no need to until there is new input),
+    /// and shapes to paint.
+    ///
+    /// The repaint delay is [`egui::Output::repaint_after`]: pass it to e.g.
+    /// [`winit::event_loop::ControlFlow::WaitUntil`] instead of repainting right away.
     pub fn update(
         &mut self,
         window: &winit::window::Window,
     ) -> (
-        bool,
+        Option<std::time::Duration>,
         epi::backend::TexAllocationData,
         Vec<egui::epaint::ClippedShape>,
     ) {
         let frame_start = std::time::Instant::now();
 
         let raw_input = self.egui_winit.take_egui_input(window);
-        let (egui_output, shapes) = self.egui_ctx.run(raw_input, |egui_ctx| {
+        let (mut egui_output, shapes) = self.egui_ctx.run(raw_input, |egui_ctx| {
             self.app.update(egui_ctx, &self.frame);
         });
 
-        let needs_repaint = egui_output.needs_repaint;
+        let repaint_after = egui_output
+            .needs_repaint
+            .then(|| std::time::Duration::from_secs_f32(egui_output.repaint_after));
+        self.snapshot_requests = std::mem::take(&mut egui_output.snapshot_requests);
+        self.quit |= egui_output
+            .window_commands
+            .contains(&egui::output::WindowCommand::Close);
         self.egui_winit
             .handle_output(window, &self.egui_ctx, egui_output);
 
@@ -297,7 +310,19 @@ impl EpiIntegration {
         let frame_time = (std::time::Instant::now() - frame_start).as_secs_f64() as f32;
         self.frame.lock().info.cpu_usage = Some(frame_time);
 
-        (needs_repaint, tex_allocation_data, shapes)
+        (repaint_after, tex_allocation_data, shapes)
+    }
+
+    /// The [`egui::output::SnapshotRequest`]s of the last [`Self::update`].
+    ///
+    /// Capture the requested pixels after painting, and pass them to [`Self::on_snapshot`].
+    pub fn take_snapshot_requests(&mut self) -> Vec<egui::output::SnapshotRequest> {
+        std::mem::take(&mut self.snapshot_requests)
+    }
+
+    /// Call this with the pixels captured for a [`egui::output::SnapshotRequest`].
+    pub fn on_snapshot(&mut self, id: egui::Id, snapshot: egui::WidgetSnapshot) {
+        self.egui_winit.on_snapshot(id, snapshot);
     }
 
     pub fn maybe_autosave(&mut self, window: &winit::window::Window) {
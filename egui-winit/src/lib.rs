@@ -103,6 +103,23 @@ pub fn screen_size_in_pixels(window: &winit::window::Window) -> egui::Vec2 {
     egui::vec2(size.width as f32, size.height as f32)
 }
 
+/// Whether the operating system is in dark or light mode, if winit can tell.
+///
+/// Only Windows can be asked. Elsewhere this is `None`, and the theme is only known
+/// once a [`winit::event::WindowEvent::ThemeChanged`] arrives.
+pub fn system_theme(window: &winit::window::Window) -> Option<egui::Theme> {
+    #[cfg(target_os = "windows")]
+    {
+        use winit::platform::windows::WindowExtWindows as _;
+        Some(translate_theme(window.theme()))
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = window;
+        None
+    }
+}
+
 /// Handles the integration between egui and winit.
 pub struct State {
     start_time: std::time::Instant,
@@ -131,7 +148,9 @@ pub struct State {
 impl State {
     /// Initialize with the native `pixels_per_point` (dpi scaling).
     pub fn new(window: &winit::window::Window) -> Self {
-        Self::from_pixels_per_point(native_pixels_per_point(window))
+        let mut state = Self::from_pixels_per_point(native_pixels_per_point(window));
+        state.egui_input.system_theme = system_theme(window);
+        state
     }
 
     /// Initialize with a given dpi scaling.
@@ -194,6 +213,16 @@ impl State {
         self.egui_input.take()
     }
 
+    /// Call this with the pixels captured for a [`egui::output::SnapshotRequest`].
+    ///
+    /// egui receives them as an [`egui::Event::Snapshot`] next frame.
+    pub fn on_snapshot(&mut self, id: egui::Id, snapshot: egui::WidgetSnapshot) {
+        self.egui_input.events.push(egui::Event::Snapshot {
+            id,
+            snapshot: std::sync::Arc::new(snapshot),
+        });
+    }
+
     /// Call this when there is a new event.
     ///
     /// The result can be found in [`Self::egui_input`] and be extracted with [`Self::take_egui_input`].
@@ -289,6 +318,10 @@ impl State {
                 });
                 false
             }
+            WindowEvent::ThemeChanged(theme) => {
+                self.egui_input.system_theme = Some(translate_theme(*theme));
+                false
+            }
             WindowEvent::ModifiersChanged(state) => {
                 self.egui_input.modifiers.alt = state.alt();
                 self.egui_input.modifiers.ctrl = state.ctrl();
@@ -444,14 +477,14 @@ impl State {
     }
 
     fn on_mouse_wheel(&mut self, delta: winit::event::MouseScrollDelta) {
-        let mut delta = match delta {
+        let (unit, mut delta) = match delta {
             winit::event::MouseScrollDelta::LineDelta(x, y) => {
-                let points_per_scroll_line = 50.0; // Scroll speed decided by consensus: https://github.com/emilk/egui/issues/461
-                egui::vec2(x, y) * points_per_scroll_line
-            }
-            winit::event::MouseScrollDelta::PixelDelta(delta) => {
-                egui::vec2(delta.x as f32, delta.y as f32) / self.pixels_per_point()
+                (egui::MouseWheelUnit::Line, egui::vec2(x, y))
             }
+            winit::event::MouseScrollDelta::PixelDelta(delta) => (
+                egui::MouseWheelUnit::Point,
+                egui::vec2(delta.x as f32, delta.y as f32) / self.pixels_per_point(),
+            ),
         };
         if cfg!(target_os = "macos") {
             // This is still buggy in winit despite
@@ -464,10 +497,21 @@ impl State {
 
         if self.egui_input.modifiers.ctrl || self.egui_input.modifiers.command {
             // Treat as zoom instead:
-            let factor = (delta.y / 200.0).exp();
+            let points = match unit {
+                egui::MouseWheelUnit::Line => {
+                    let points_per_scroll_line = 50.0; // Scroll speed decided by consensus: https://github.com/emilk/egui/issues/461
+                    delta.y * points_per_scroll_line
+                }
+                _ => delta.y,
+            };
+            let factor = (points / 200.0).exp();
             self.egui_input.events.push(egui::Event::Zoom(factor));
         } else {
-            self.egui_input.events.push(egui::Event::Scroll(delta));
+            self.egui_input.events.push(egui::Event::MouseWheel {
+                unit,
+                delta,
+                modifiers: self.egui_input.modifiers,
+            });
         }
     }
 
@@ -484,9 +528,12 @@ impl State {
                     self.egui_input.events.push(egui::Event::Copy);
                 } else if is_paste_command(self.egui_input.modifiers, keycode) {
                     if let Some(contents) = self.clipboard.get() {
+                        let text = contents.replace("\r\n", "\n");
                         self.egui_input
                             .events
-                            .push(egui::Event::Text(contents.replace("\r\n", "\n")));
+                            .push(egui::Event::Paste(egui::ClipboardData::Text(text.clone())));
+                        // For widgets that don't know about `Event::Paste` yet:
+                        self.egui_input.events.push(egui::Event::Text(text));
                     }
                 }
             }
@@ -523,16 +570,41 @@ impl State {
 
         self.set_cursor_icon(window, output.cursor_icon);
 
-        if let Some(open) = output.open_url {
+        for open in output.open_urls {
             open_url(&open.url);
         }
 
-        if !output.copied_text.is_empty() {
-            self.clipboard.set(output.copied_text);
+        // Only the text is copied: `copypasta` can't copy HTML or images.
+        if let Some(text) = output.copied.as_ref().and_then(|copied| copied.text()) {
+            self.clipboard.set(text.to_owned());
         }
 
-        if let Some(egui::Pos2 { x, y }) = output.text_cursor_pos {
+        if let Some(text_input) = output.text_input {
+            // Put the IME candidate window just below the text cursor:
+            let egui::Pos2 { x, y } = text_input.cursor_rect.left_bottom();
             window.set_ime_position(winit::dpi::LogicalPosition { x, y });
+        } else if let Some(egui::Pos2 { x, y }) = output.text_cursor_pos {
+            window.set_ime_position(winit::dpi::LogicalPosition { x, y });
+        }
+
+        for command in output.window_commands {
+            match command {
+                egui::output::WindowCommand::StartDrag => {
+                    if let Err(err) = window.drag_window() {
+                        eprintln!("Failed to drag window: {}", err);
+                    }
+                }
+                egui::output::WindowCommand::StartResize(_) => {
+                    // Not supported by winit yet.
+                }
+                egui::output::WindowCommand::Minimize => window.set_minimized(true),
+                egui::output::WindowCommand::ToggleMaximized => {
+                    window.set_maximized(!window.is_maximized());
+                }
+                egui::output::WindowCommand::Close => {
+                    // Handled by the app loop, see `EpiIntegration::update`.
+                }
+            }
         }
     }
 
@@ -606,10 +678,21 @@ fn translate_mouse_button(button: winit::event::MouseButton) -> Option<egui::Poi
         winit::event::MouseButton::Left => Some(egui::PointerButton::Primary),
         winit::event::MouseButton::Right => Some(egui::PointerButton::Secondary),
         winit::event::MouseButton::Middle => Some(egui::PointerButton::Middle),
+        // The back/forward buttons are numbered differently on different platforms:
+        // 1/2 on Windows, 3/4 on Mac and 8/9 on X11.
+        winit::event::MouseButton::Other(1 | 3 | 8) => Some(egui::PointerButton::Extra1),
+        winit::event::MouseButton::Other(2 | 4 | 9) => Some(egui::PointerButton::Extra2),
         winit::event::MouseButton::Other(_) => None,
     }
 }
 
+fn translate_theme(theme: winit::window::Theme) -> egui::Theme {
+    match theme {
+        winit::window::Theme::Dark => egui::Theme::Dark,
+        winit::window::Theme::Light => egui::Theme::Light,
+    }
+}
+
 fn translate_virtual_key_code(key: winit::event::VirtualKeyCode) -> Option<egui::Key> {
     use egui::Key;
     use winit::event::VirtualKeyCode;
@@ -633,6 +716,11 @@ fn translate_virtual_key_code(key: winit::event::VirtualKeyCode) -> Option<egui:
         VirtualKeyCode::PageUp => Key::PageUp,
         VirtualKeyCode::PageDown => Key::PageDown,
 
+        VirtualKeyCode::Minus | VirtualKeyCode::NumpadSubtract => Key::Minus,
+        VirtualKeyCode::Equals | VirtualKeyCode::Plus | VirtualKeyCode::NumpadAdd => {
+            Key::PlusEquals
+        }
+
         VirtualKeyCode::Key0 | VirtualKeyCode::Numpad0 => Key::Num0,
         VirtualKeyCode::Key1 | VirtualKeyCode::Numpad1 => Key::Num1,
         VirtualKeyCode::Key2 | VirtualKeyCode::Numpad2 => Key::Num2,
@@ -671,6 +759,19 @@ fn translate_virtual_key_code(key: winit::event::VirtualKeyCode) -> Option<egui:
         VirtualKeyCode::Y => Key::Y,
         VirtualKeyCode::Z => Key::Z,
 
+        VirtualKeyCode::F1 => Key::F1,
+        VirtualKeyCode::F2 => Key::F2,
+        VirtualKeyCode::F3 => Key::F3,
+        VirtualKeyCode::F4 => Key::F4,
+        VirtualKeyCode::F5 => Key::F5,
+        VirtualKeyCode::F6 => Key::F6,
+        VirtualKeyCode::F7 => Key::F7,
+        VirtualKeyCode::F8 => Key::F8,
+        VirtualKeyCode::F9 => Key::F9,
+        VirtualKeyCode::F10 => Key::F10,
+        VirtualKeyCode::F11 => Key::F11,
+        VirtualKeyCode::F12 => Key::F12,
+
         _ => {
             return None;
         }
@@ -700,10 +801,23 @@ fn translate_cursor(cursor_icon: egui::CursorIcon) -> Option<winit::window::Curs
         egui::CursorIcon::ResizeNeSw => Some(winit::window::CursorIcon::NeswResize),
         egui::CursorIcon::ResizeNwSe => Some(winit::window::CursorIcon::NwseResize),
         egui::CursorIcon::ResizeVertical => Some(winit::window::CursorIcon::NsResize),
+        egui::CursorIcon::ResizeEast => Some(winit::window::CursorIcon::EResize),
+        egui::CursorIcon::ResizeSouthEast => Some(winit::window::CursorIcon::SeResize),
+        egui::CursorIcon::ResizeSouth => Some(winit::window::CursorIcon::SResize),
+        egui::CursorIcon::ResizeSouthWest => Some(winit::window::CursorIcon::SwResize),
+        egui::CursorIcon::ResizeWest => Some(winit::window::CursorIcon::WResize),
+        egui::CursorIcon::ResizeNorthWest => Some(winit::window::CursorIcon::NwResize),
+        egui::CursorIcon::ResizeNorth => Some(winit::window::CursorIcon::NResize),
+        egui::CursorIcon::ResizeNorthEast => Some(winit::window::CursorIcon::NeResize),
+        egui::CursorIcon::ResizeColumn => Some(winit::window::CursorIcon::ColResize),
+        egui::CursorIcon::ResizeRow => Some(winit::window::CursorIcon::RowResize),
         egui::CursorIcon::Text => Some(winit::window::CursorIcon::Text),
         egui::CursorIcon::VerticalText => Some(winit::window::CursorIcon::VerticalText),
         egui::CursorIcon::Wait => Some(winit::window::CursorIcon::Wait),
         egui::CursorIcon::ZoomIn => Some(winit::window::CursorIcon::ZoomIn),
         egui::CursorIcon::ZoomOut => Some(winit::window::CursorIcon::ZoomOut),
+
+        // winit does not support custom cursor images:
+        egui::CursorIcon::Custom(..) => Some(winit::window::CursorIcon::Default),
     }
 }
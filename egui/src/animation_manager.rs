@@ -1,8 +1,13 @@
-use crate::{emath::remap_clamp, Id, IdMap, InputState};
+use crate::{
+    emath::{lerp, remap_clamp},
+    Easing, Id, IdMap, InputState,
+};
 
 #[derive(Clone, Default)]
 pub(crate) struct AnimationManager {
     bools: IdMap<BoolAnim>,
+    values: IdMap<ValueAnim>,
+    springs: IdMap<SpringAnim>,
 }
 
 #[derive(Clone, Debug)]
@@ -12,15 +17,132 @@ struct BoolAnim {
     toggle_time: f64,
 }
 
+/// The value of a [`ValueAnim`]: up to four numbers, e.g. the components of a [`crate::Vec2`] or [`crate::Rgba`].
+pub(crate) type Values = [f32; 4];
+
+#[derive(Clone, Debug)]
+struct ValueAnim {
+    from_value: Values,
+    to_value: Values,
+    /// when did `to_value` last change?
+    toggle_time: f64,
+}
+
+impl ValueAnim {
+    /// How far we have come from `from_value` to `to_value`, in the range [0, 1].
+    fn progress(&self, input: &InputState, animation_time: f32) -> f32 {
+        // Extrapolate forwards, so that we don't return the old value on the frame the target changes:
+        let time_since_toggle = (input.time - self.toggle_time) as f32 + input.predicted_dt;
+        if animation_time > 0.0 {
+            (time_since_toggle / animation_time).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    fn value(&self, t: f32) -> Values {
+        let mut value = self.from_value;
+        for (value, to) in value.iter_mut().zip(self.to_value) {
+            *value = lerp(*value..=to, t);
+        }
+        value
+    }
+}
+
+/// The physics of a spring animation, see [`crate::Context::animate_value_with_spring`].
+///
+/// Unlike an animation with a fixed duration, a spring keeps its velocity when the target changes,
+/// so it never restarts or jerks when the target moves in the middle of an animation.
+///
+/// ```
+/// let snappy = egui::Spring::new(0.2);
+/// let bouncy = egui::Spring::new(0.5).damping_ratio(0.4);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Spring {
+    /// How many seconds one swing of the spring would take without any damping.
+    /// Smaller is snappier.
+    pub response: f32,
+
+    /// `1.0` is critically damped: it reaches the target as fast as possible without overshooting.
+    /// Smaller values make the spring overshoot and bounce around the target.
+    pub damping_ratio: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::new(0.3)
+    }
+}
+
+impl Spring {
+    /// A critically damped spring, which takes about `response` seconds to reach its target.
+    pub fn new(response: f32) -> Self {
+        Self {
+            response,
+            damping_ratio: 1.0,
+        }
+    }
+
+    /// Smaller than `1.0` makes the spring bounce. Clamped to `[0, 1]`.
+    pub fn damping_ratio(mut self, damping_ratio: f32) -> Self {
+        self.damping_ratio = damping_ratio;
+        self
+    }
+
+    /// Move a spring that is `offset` from its target for `dt` seconds.
+    ///
+    /// Uses the exact solution of the spring equation, so it is stable for any `dt`.
+    /// Returns the new offset and velocity.
+    fn step(self, offset: f32, velocity: f32, dt: f32) -> (f32, f32) {
+        if self.response <= 0.0 {
+            return (0.0, 0.0);
+        }
+        let omega = std::f32::consts::TAU / self.response;
+        let damping_ratio = self.damping_ratio.clamp(0.0, 1.0);
+
+        if damping_ratio > 0.999 {
+            // Critically damped:
+            let c2 = velocity + omega * offset;
+            let decay = (-omega * dt).exp();
+            let new_offset = (offset + c2 * dt) * decay;
+            let new_velocity = (c2 - omega * (offset + c2 * dt)) * decay;
+            (new_offset, new_velocity)
+        } else {
+            // Underdamped:
+            let omega_d = omega * (1.0 - damping_ratio * damping_ratio).sqrt();
+            let c2 = (velocity + damping_ratio * omega * offset) / omega_d;
+            let decay = (-damping_ratio * omega * dt).exp();
+            let (sin, cos) = (omega_d * dt).sin_cos();
+            let new_offset = decay * (offset * cos + c2 * sin);
+            let new_velocity =
+                decay * (omega_d * (c2 * cos - offset * sin)) - damping_ratio * omega * new_offset;
+            (new_offset, new_velocity)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct SpringAnim {
+    value: Values,
+    velocity: Values,
+    /// The time `value` and `velocity` are at.
+    time: f64,
+}
+
 impl AnimationManager {
-    /// See `Context::animate_bool` for documentation
+    /// See `Context::animate_bool` for documentation.
+    ///
+    /// Returns how "on" the value is, and whether it is still animating.
     pub fn animate_bool(
         &mut self,
         input: &InputState,
         animation_time: f32,
+        easing: Easing,
         id: Id,
         value: bool,
-    ) -> f32 {
+    ) -> (f32, bool) {
         match self.bools.get_mut(&id) {
             None => {
                 self.bools.insert(
@@ -30,11 +152,7 @@ impl AnimationManager {
                         toggle_time: -f64::INFINITY, // long time ago
                     },
                 );
-                if value {
-                    1.0
-                } else {
-                    0.0
-                }
+                (if value { 1.0 } else { 0.0 }, false)
             }
             Some(anim) => {
                 if anim.value != value {
@@ -48,12 +166,97 @@ impl AnimationManager {
                 // so we extrapolate forwards:
                 let time_since_toggle = time_since_toggle + input.predicted_dt;
 
-                if value {
+                let progress = if animation_time > 0.0 {
                     remap_clamp(time_since_toggle, 0.0..=animation_time, 0.0..=1.0)
                 } else {
-                    remap_clamp(time_since_toggle, 0.0..=animation_time, 1.0..=0.0)
+                    1.0
+                };
+                let eased = easing.ease(progress);
+                let animated_value = if value { eased } else { 1.0 - eased };
+                (animated_value, progress < 1.0)
+            }
+        }
+    }
+
+    /// See `Context::animate_value_with_time` for documentation.
+    ///
+    /// Returns the current value, and whether it is still moving towards `value`.
+    pub fn animate_value(
+        &mut self,
+        input: &InputState,
+        animation_time: f32,
+        easing: Easing,
+        id: Id,
+        value: Values,
+    ) -> (Values, bool) {
+        match self.values.get_mut(&id) {
+            None => {
+                self.values.insert(
+                    id,
+                    ValueAnim {
+                        from_value: value,
+                        to_value: value,
+                        toggle_time: -f64::INFINITY, // long time ago
+                    },
+                );
+                (value, false)
+            }
+            Some(anim) => {
+                if anim.to_value != value {
+                    // Continue smoothly from wherever we are now:
+                    anim.from_value = anim.value(easing.ease(anim.progress(input, animation_time)));
+                    anim.to_value = value;
+                    anim.toggle_time = input.time;
                 }
+
+                let progress = anim.progress(input, animation_time);
+                (anim.value(easing.ease(progress)), progress < 1.0)
+            }
+        }
+    }
+
+    /// See `Context::animate_value_with_spring` for documentation.
+    ///
+    /// Returns the current value, and whether it is still moving.
+    /// With `instant` the value jumps straight to `target`.
+    pub fn animate_spring(
+        &mut self,
+        input: &InputState,
+        spring: Spring,
+        instant: bool,
+        id: Id,
+        target: Values,
+    ) -> (Values, bool) {
+        /// Closer than this to the target, and slower than this, counts as being at rest.
+        const EPSILON: f32 = 1e-3;
+
+        // Extrapolate forwards, just like the other animations:
+        let time = input.time + input.predicted_dt as f64;
+        let anim = self.springs.entry(id).or_insert(SpringAnim {
+            value: target,
+            velocity: [0.0; 4],
+            time,
+        });
+        let dt = (time - anim.time).max(0.0) as f32;
+        anim.time = time;
+
+        let mut is_moving = false;
+        for ((value, velocity), target) in anim.value.iter_mut().zip(&mut anim.velocity).zip(target)
+        {
+            let (offset, new_velocity) = if instant {
+                (0.0, 0.0)
+            } else {
+                spring.step(*value - target, *velocity, dt)
+            };
+            if offset.abs() < EPSILON && new_velocity.abs() < EPSILON {
+                *value = target;
+                *velocity = 0.0;
+            } else {
+                *value = target + offset;
+                *velocity = new_velocity;
+                is_moving = true;
             }
         }
+        (anim.value, is_moving)
     }
 }
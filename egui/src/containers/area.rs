@@ -7,7 +7,7 @@ use std::{fmt::Debug, hash::Hash};
 use crate::*;
 
 /// State that is persisted between frames.
-// TODO: this is not currently stored in `memory().data`, but maybe it should be?
+// TODO: this is not currently stored in `memory().data_persisted`, but maybe it should be?
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub(crate) struct State {
@@ -20,6 +20,10 @@ pub(crate) struct State {
     /// If false, clicks goes straight through to what is behind us.
     /// Good for tooltips etc.
     pub interactable: bool,
+
+    /// If true, [`Context::layer_id_at`] ignores this area, see [`Area::input_transparent`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub input_transparent: bool,
 }
 
 impl State {
@@ -46,6 +50,7 @@ pub struct Area {
     pub(crate) id: Id,
     movable: bool,
     interactable: bool,
+    input_transparent: bool,
     enabled: bool,
     order: Order,
     default_pos: Option<Pos2>,
@@ -55,11 +60,13 @@ pub struct Area {
 }
 
 impl Area {
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn new(id_source: impl Hash) -> Self {
         Self {
             id: Id::new(id_source),
             movable: true,
             interactable: true,
+            input_transparent: false,
             enabled: true,
             order: Order::Middle,
             default_pos: None,
@@ -110,6 +117,24 @@ impl Area {
         self
     }
 
+    /// If true, the pointer goes straight through this area:
+    /// [`Context::layer_id_at`] skips it, so hovering and clicks go to whatever is behind it.
+    /// This includes whatever is outside of egui (see [`Context::wants_pointer_input`]),
+    /// e.g. when egui shows a HUD on top of a game viewport.
+    ///
+    /// The widgets in the area can then not be interacted with, and the area can not be moved.
+    ///
+    /// See also [`Context::block_input_in`].
+    /// Default: `false`.
+    pub fn input_transparent(mut self, input_transparent: bool) -> Self {
+        self.input_transparent = input_transparent;
+        if input_transparent {
+            self.interactable = false;
+            self.movable = false;
+        }
+        self
+    }
+
     /// `order(Order::Foreground)` for an Area that should always be on top
     pub fn order(mut self, order: Order) -> Self {
         self.order = order;
@@ -186,12 +211,55 @@ impl Area {
         InnerResponse { inner, response }
     }
 
+    /// Like [`Self::show`], but the area fades and slides in when `is_open` turns `true`,
+    /// and out when it turns `false`.
+    ///
+    /// While fading out `add_contents` is still called, but `None` is returned.
+    /// The area isn't painted where it is while it slides, so until it is fully open
+    /// the pointer goes straight through it (see [`Self::input_transparent`]).
+    /// With [`Context::reduce_motion`] the area appears and disappears at once.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let show_hint = true;
+    /// egui::Area::new("hint").show_animated(ctx, show_hint, |ui| {
+    ///     ui.label("Drag to move");
+    /// });
+    /// # });
+    /// ```
+    pub fn show_animated<R>(
+        self,
+        ctx: &CtxRef,
+        is_open: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let openness = self.openness(ctx, is_open);
+        if openness <= 0.0 {
+            return None;
+        }
+        let layer_id = self.layer();
+        let area = if openness >= 1.0 {
+            self
+        } else {
+            self.input_transparent(true)
+        };
+        let response = area.show(ctx, add_contents);
+        open_close_transition(ctx, layer_id, openness);
+        is_open.then(|| response)
+    }
+
+    /// How far the open/close transition of [`Self::show_animated`] has come: `0` is closed, `1` is open.
+    pub(crate) fn openness(&self, ctx: &CtxRef, is_open: bool) -> f32 {
+        ctx.animate_bool(self.id.with("open_close_transition"), is_open)
+    }
+
     pub(crate) fn begin(self, ctx: &CtxRef) -> Prepared {
         let Area {
             id,
             movable,
             order,
             interactable,
+            input_transparent,
             enabled,
             default_pos,
             new_pos,
@@ -210,8 +278,10 @@ impl Area {
             pos: default_pos.unwrap_or_else(|| automatic_area_position(ctx)),
             size: Vec2::ZERO,
             interactable,
+            input_transparent,
         });
         state.pos = new_pos.unwrap_or(state.pos);
+        state.input_transparent = input_transparent;
 
         if let Some((anchor, offset)) = anchor {
             if is_new {
@@ -263,6 +333,17 @@ impl Area {
     }
 }
 
+/// Fade and slide what an area has painted this frame, for the open/close transition of [`Area::show_animated`].
+pub(crate) fn open_close_transition(ctx: &CtxRef, layer_id: LayerId, openness: f32) {
+    /// How far below its position a closed area is, in points.
+    const SLIDE_DISTANCE: f32 = 8.0;
+
+    if openness < 1.0 {
+        ctx.multiply_layer_opacity(layer_id, openness.max(0.0));
+        ctx.translate_layer(layer_id, vec2(0.0, (1.0 - openness) * SLIDE_DISTANCE));
+    }
+}
+
 impl Prepared {
     pub(crate) fn state(&self) -> &State {
         &self.state
@@ -343,6 +424,7 @@ impl Prepared {
             state.rect(),
             sense,
             enabled,
+            None,
         );
 
         if move_response.dragged() && movable {
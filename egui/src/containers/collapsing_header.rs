@@ -7,7 +7,7 @@ use epaint::{Shape, TextStyle};
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub(crate) struct State {
-    open: bool,
+    pub(crate) open: bool,
 
     /// Height of the region when open. Used for animations
     open_height: Option<f32>,
@@ -24,11 +24,11 @@ impl Default for State {
 
 impl State {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
-        ctx.memory().data.get_persisted(id)
+        ctx.memory().data_persisted.get_persisted(id)
     }
 
     pub fn store(self, ctx: &Context, id: Id) {
-        ctx.memory().data.insert_persisted(id, self);
+        ctx.memory().data_persisted.insert_persisted(id, self);
     }
 
     pub fn from_memory_with_default_open(ctx: &Context, id: Id, default_open: bool) -> Self {
@@ -150,8 +150,15 @@ pub struct CollapsingHeader {
     selectable: bool,
     selected: bool,
     show_background: bool,
+    icon: Option<IconPainter>,
 }
 
+/// Paints the open/close icon of a [`CollapsingHeader`], see [`CollapsingHeader::icon`].
+type IconPainter = Box<dyn FnOnce(&mut Ui, f32, &Response)>;
+
+/// Custom contents of the header of a [`CollapsingHeader`], see [`CollapsingHeader::show_with_header`].
+type HeaderContents<'c> = Box<dyn FnOnce(&mut Ui) + 'c>;
+
 impl CollapsingHeader {
     /// The `CollapsingHeader` starts out collapsed unless you call `default_open`.
     ///
@@ -159,6 +166,7 @@ impl CollapsingHeader {
     /// If the label is unique and static this is fine,
     /// but if it changes or there are several `CollapsingHeader` with the same title
     /// you need to provide a unique id source with [`Self::id_source`].
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn new(text: impl Into<WidgetText>) -> Self {
         let text = text.into();
         let id_source = Id::new(text.text());
@@ -171,6 +179,7 @@ impl CollapsingHeader {
             selectable: false,
             selected: false,
             show_background: false,
+            icon: None,
         }
     }
 
@@ -193,6 +202,7 @@ impl CollapsingHeader {
 
     /// Explicitly set the source of the `Id` of this widget, instead of using title label.
     /// This is useful if the title label is dynamic or not unique.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn id_source(mut self, id_source: impl Hash) -> Self {
         self.id_source = Id::new(id_source);
         self
@@ -245,6 +255,30 @@ impl CollapsingHeader {
         self.show_background = show_background;
         self
     }
+
+    /// Paint a custom icon instead of the default triangle.
+    ///
+    /// The function is called with the [`Ui`], the openness (0 for closed, 1 for open, and in between while animating),
+    /// and a [`Response`] whose `rect` is where the icon should go.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::CollapsingHeader::new("Plus and minus")
+    ///     .icon(|ui, openness, response| {
+    ///         let stroke = ui.style().interact(response).fg_stroke;
+    ///         let rect = response.rect.shrink(2.0);
+    ///         ui.painter().line_segment([rect.left_center(), rect.right_center()], stroke);
+    ///         // The vertical line of the plus shrinks away while opening:
+    ///         let half_height = egui::vec2(0.0, (1.0 - openness) * rect.height() / 2.0);
+    ///         ui.painter().line_segment([rect.center() - half_height, rect.center() + half_height], stroke);
+    ///     })
+    ///     .show(ui, |ui| ui.label("Contents"));
+    /// # });
+    /// ```
+    pub fn icon(mut self, icon_fn: impl FnOnce(&mut Ui, f32, &Response) + 'static) -> Self {
+        self.icon = Some(Box::new(icon_fn));
+        self
+    }
 }
 
 struct Prepared {
@@ -254,7 +288,8 @@ struct Prepared {
 }
 
 impl CollapsingHeader {
-    fn begin(self, ui: &mut Ui) -> Prepared {
+    /// Show the header: either the text, or `add_header` if given.
+    fn begin(self, ui: &mut Ui, add_header: Option<HeaderContents<'_>>) -> Prepared {
         assert!(
             ui.layout().main_dir().is_vertical(),
             "Horizontal collapsing is unimplemented"
@@ -265,37 +300,55 @@ impl CollapsingHeader {
             open,
             id_source,
             enabled: _,
-            selectable: _,
-            selected: _,
-            show_background: _,
+            selectable,
+            selected,
+            show_background,
+            icon,
         } = self;
 
-        // TODO: horizontal layout, with icon and text as labels. Insert background behind using Frame.
-
-        let id = ui.make_persistent_id(id_source);
+        let id = ui.make_persistent_id(id_source).derived_from(id_source);
+        let label = text.text().to_owned();
         let button_padding = ui.spacing().button_padding;
-
+        let indent = ui.spacing().indent;
         let available = ui.available_rect_before_wrap();
-        let text_pos = available.min + vec2(ui.spacing().indent, 0.0);
-        let wrap_width = available.right() - text_pos.x;
-        let wrap = Some(false);
-        let text = text.into_galley(ui, wrap, wrap_width, TextStyle::Button);
-        let text_max_x = text_pos.x + text.size().x;
-
-        let mut desired_width = text_max_x + button_padding.x - available.left();
-        if ui.visuals().collapsing_header_frame {
-            desired_width = desired_width.max(available.width()); // fill full width
-        }
 
-        let mut desired_size = vec2(desired_width, text.size().y + 2.0 * button_padding.y);
-        desired_size = desired_size.at_least(ui.spacing().interact_size);
-        let (_, rect) = ui.allocate_space(desired_size);
+        // The background goes behind the custom header contents, which are added before we know their size:
+        let background = ui.painter().add(Shape::Noop);
+
+        let (rect, galley) = if let Some(add_header) = add_header {
+            let header_rect = Rect::from_min_size(
+                available.min + vec2(indent, 0.0),
+                vec2(available.width() - indent, ui.spacing().interact_size.y),
+            );
+            let mut header_ui = ui.child_ui(header_rect, Layout::left_to_right());
+            add_header(&mut header_ui);
+
+            let mut rect = Rect::from_min_max(available.min, header_ui.min_rect().max);
+            rect.set_height(rect.height().at_least(ui.spacing().interact_size.y));
+            if ui.visuals().collapsing_header_frame {
+                rect.max.x = rect.max.x.max(available.right()); // fill full width
+            }
+            ui.advance_cursor_after_rect(rect);
+            (rect, None)
+        } else {
+            let text_pos = available.min + vec2(indent, 0.0);
+            let wrap_width = available.right() - text_pos.x;
+            let galley = text.into_galley(ui, Some(false), wrap_width, TextStyle::Button);
+            let text_max_x = text_pos.x + galley.size().x;
+
+            let mut desired_width = text_max_x + button_padding.x - available.left();
+            if ui.visuals().collapsing_header_frame {
+                desired_width = desired_width.max(available.width()); // fill full width
+            }
+
+            let mut desired_size = vec2(desired_width, galley.size().y + 2.0 * button_padding.y);
+            desired_size = desired_size.at_least(ui.spacing().interact_size);
+            let (_, rect) = ui.allocate_space(desired_size);
+            (rect, Some(galley))
+        };
 
+        // Interact after the custom header contents, so that e.g. buttons in the header get the clicks:
         let mut header_response = ui.interact(rect, id, Sense::click());
-        let text_pos = pos2(
-            text_pos.x,
-            header_response.rect.center().y - text.size().y / 2.0,
-        );
 
         let mut state = State::from_memory_with_default_open(ui.ctx(), id, default_open);
         if let Some(open) = open {
@@ -308,38 +361,35 @@ impl CollapsingHeader {
             header_response.mark_changed();
         }
 
-        header_response
-            .widget_info(|| WidgetInfo::labeled(WidgetType::CollapsingHeader, text.text()));
+        header_response.widget_info(|| WidgetInfo::labeled(WidgetType::CollapsingHeader, &label));
 
         if ui.is_rect_visible(rect) {
-            let visuals = ui
-                .style()
-                .interact_selectable(&header_response, self.selected);
+            let visuals = ui.style().interact_selectable(&header_response, selected);
 
-            if ui.visuals().collapsing_header_frame || self.show_background {
-                ui.painter().add(epaint::RectShape {
+            let mut background_shapes = vec![];
+            if ui.visuals().collapsing_header_frame || show_background {
+                background_shapes.push(Shape::Rect(epaint::RectShape {
                     rect: header_response.rect.expand(visuals.expansion),
                     corner_radius: visuals.corner_radius,
                     fill: visuals.bg_fill,
                     stroke: visuals.bg_stroke,
-                    // stroke: Default::default(),
-                });
+                }));
             }
 
-            if self.selected
-                || self.selectable && (header_response.hovered() || header_response.has_focus())
+            if selected || selectable && (header_response.hovered() || header_response.has_focus())
             {
                 let rect = rect.expand(visuals.expansion);
 
                 let corner_radius = 2.0;
-                ui.painter()
-                    .rect(rect, corner_radius, visuals.bg_fill, visuals.bg_stroke);
+                background_shapes.push(Shape::rect_filled(rect, corner_radius, visuals.bg_fill));
+                background_shapes.push(Shape::rect_stroke(rect, corner_radius, visuals.bg_stroke));
             }
+            ui.painter().set(background, Shape::Vec(background_shapes));
 
             {
                 let (mut icon_rect, _) = ui.spacing().icon_rectangles(header_response.rect);
                 icon_rect.set_center(pos2(
-                    header_response.rect.left() + ui.spacing().indent / 2.0,
+                    header_response.rect.left() + indent / 2.0,
                     header_response.rect.center().y,
                 ));
                 let icon_response = Response {
@@ -347,10 +397,20 @@ impl CollapsingHeader {
                     ..header_response.clone()
                 };
                 let openness = state.openness(ui.ctx(), id);
-                paint_icon(ui, openness, &icon_response);
+                if let Some(icon) = icon {
+                    icon(ui, openness, &icon_response);
+                } else {
+                    paint_icon(ui, openness, &icon_response);
+                }
             }
 
-            text.paint_with_visuals(ui.painter(), text_pos, &visuals);
+            if let Some(galley) = galley {
+                let text_pos = pos2(
+                    rect.left() + indent,
+                    header_response.rect.center().y - galley.size().y / 2.0,
+                );
+                galley.paint_with_visuals(ui.painter(), text_pos, &visuals);
+            }
         }
 
         Prepared {
@@ -366,12 +426,52 @@ impl CollapsingHeader {
         ui: &mut Ui,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> CollapsingResponse<R> {
-        self.show_dyn(ui, Box::new(add_contents))
+        self.show_dyn(ui, None, Box::new(add_contents))
+    }
+
+    /// Like [`Self::show`], but with custom contents in the header instead of the text,
+    /// e.g. an icon, a [`crate::Badge`], or buttons aligned to the right.
+    ///
+    /// Clicking the header outside of any interactive widgets in it opens and closes the body.
+    /// The text given to [`Self::new`] is still used for the [`Id`] (unless you set [`Self::id_source`])
+    /// and for accessibility.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut items = vec!["Apple".to_owned()];
+    /// egui::CollapsingHeader::new("Items")
+    ///     .default_open(true)
+    ///     .show_with_header(
+    ///         ui,
+    ///         |ui| {
+    ///             ui.label("Items");
+    ///             ui.with_layout(egui::Layout::right_to_left(), |ui| {
+    ///                 if ui.small_button("➕").clicked() {
+    ///                     // add an item…
+    ///                 }
+    ///             });
+    ///         },
+    ///         |ui| {
+    ///             for item in &items {
+    ///                 ui.label(item);
+    ///             }
+    ///         },
+    ///     );
+    /// # });
+    /// ```
+    pub fn show_with_header<R>(
+        self,
+        ui: &mut Ui,
+        add_header: impl FnOnce(&mut Ui),
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        self.show_dyn(ui, Some(Box::new(add_header)), Box::new(add_contents))
     }
 
     fn show_dyn<'c, R>(
         self,
         ui: &mut Ui,
+        add_header: Option<HeaderContents<'c>>,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> CollapsingResponse<R> {
         // Make sure contents are bellow header,
@@ -383,7 +483,7 @@ impl CollapsingHeader {
                 id,
                 header_response,
                 mut state,
-            } = self.begin(ui);
+            } = self.begin(ui, add_header);
 
             let ret_response = state.add_contents(ui, id, |ui| {
                 ui.indent(id, |ui| {
@@ -393,6 +493,7 @@ impl CollapsingHeader {
                 })
                 .inner
             });
+            let openness = state.openness(ui.ctx(), id);
             state.store(ui.ctx(), id);
 
             if let Some(ret_response) = ret_response {
@@ -400,12 +501,14 @@ impl CollapsingHeader {
                     header_response,
                     body_response: Some(ret_response.response),
                     body_returned: Some(ret_response.inner),
+                    openness,
                 }
             } else {
                 CollapsingResponse {
                     header_response,
                     body_response: None,
                     body_returned: None,
+                    openness,
                 }
             }
         })
@@ -420,4 +523,137 @@ pub struct CollapsingResponse<R> {
     pub body_response: Option<Response>,
     /// None iff collapsed.
     pub body_returned: Option<R>,
+
+    /// How open the body is: 0 for closed, 1 for fully open, and in between while animating.
+    pub openness: f32,
+}
+
+impl<R> CollapsingResponse<R> {
+    /// Is the body shown in full, i.e. open and not animating?
+    pub fn fully_open(&self) -> bool {
+        self.openness >= 1.0
+    }
+
+    /// Is the body hidden, i.e. closed and not animating?
+    pub fn fully_closed(&self) -> bool {
+        self.openness <= 0.0
+    }
+
+    /// Is the body opening or closing?
+    pub fn is_animating(&self) -> bool {
+        0.0 < self.openness && self.openness < 1.0
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_set_collapsing_open() {
+    use crate::RawInput;
+
+    let mut ctx = CtxRef::default();
+    let mut frame_nr = 0;
+    let mut frame = |ctx: &mut CtxRef| {
+        frame_nr += 1;
+        let input = RawInput {
+            time: Some(0.05 * frame_nr as f64),
+            ..Default::default()
+        };
+        let mut responses = vec![];
+        let _ = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                for name in ["a", "b"] {
+                    let response = ui.collapsing(name, |ui| ui.label("contents"));
+                    responses.push((response.header_response.id, response.openness));
+                }
+            });
+        });
+        responses
+    };
+
+    let responses = frame(&mut ctx);
+    assert!(responses.iter().all(|(_, openness)| *openness == 0.0));
+    let ids: Vec<Id> = responses.iter().map(|(id, _)| *id).collect();
+    ctx.set_collapsing_open(ids.clone(), true);
+    assert_eq!(ctx.is_collapsing_open(ids[0]), Some(true));
+
+    assert!(
+        frame(&mut ctx)
+            .iter()
+            .all(|(_, openness)| 0.0 < *openness && *openness < 1.0),
+        "animating"
+    );
+    for _ in 0..10 {
+        frame(&mut ctx);
+    }
+    assert!(frame(&mut ctx).iter().all(|(_, openness)| *openness == 1.0));
+
+    let last_id = ids[1];
+    ctx.set_collapsing_open(ids, false);
+    assert_eq!(ctx.is_collapsing_open(last_id), Some(false));
+}
+
+#[cfg(test)]
+#[test]
+fn test_custom_header() {
+    use crate::test::Harness;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    struct State {
+        button_rect: Rect,
+        header_rect: Rect,
+        button_clicks: usize,
+        header_clicks: usize,
+        open: bool,
+    }
+
+    let icon_painted = Arc::new(AtomicBool::new(false));
+    let painted = icon_painted.clone();
+    let state = State {
+        button_rect: Rect::NOTHING,
+        header_rect: Rect::NOTHING,
+        button_clicks: 0,
+        header_clicks: 0,
+        open: false,
+    };
+    let mut harness = Harness::with_state(state, move |ctx, state| {
+        let painted = painted.clone();
+        CentralPanel::default().show(ctx, |ui| {
+            let response = CollapsingHeader::new("Items")
+                .icon(move |_, _, _| painted.store(true, Ordering::Relaxed))
+                .show_with_header(
+                    ui,
+                    |ui| {
+                        ui.label("Items");
+                        let button = ui.button("Add");
+                        state.button_rect = button.rect;
+                        state.button_clicks += button.clicked() as usize;
+                    },
+                    |ui| ui.label("Contents"),
+                );
+            state.header_rect = response.header_response.rect;
+            state.header_clicks += response.header_response.clicked() as usize;
+            state.open = response.body_response.is_some();
+        });
+    });
+
+    assert!(harness
+        .state()
+        .header_rect
+        .contains_rect(harness.state().button_rect));
+    assert!(icon_painted.load(Ordering::Relaxed));
+
+    harness.click("Add");
+    assert_eq!(
+        harness.state().button_clicks,
+        1,
+        "the button in the header gets the click"
+    );
+    assert_eq!(harness.state().header_clicks, 0);
+    assert!(!harness.state().open);
+
+    let header_rect = harness.state().header_rect;
+    harness.click_at(header_rect.left_center() + vec2(2.0, 0.0));
+    assert_eq!(harness.state().header_clicks, 1);
+    assert!(harness.state().open);
 }
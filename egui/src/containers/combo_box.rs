@@ -28,6 +28,7 @@ pub struct ComboBox {
 
 impl ComboBox {
     /// Label shown next to the combo box
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn from_label(label: impl Into<WidgetText>) -> Self {
         let label = label.into();
         Self {
@@ -39,6 +40,7 @@ impl ComboBox {
     }
 
     /// Without label.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn from_id_source(id_source: impl std::hash::Hash) -> Self {
         Self {
             id_source: Id::new(id_source),
@@ -83,7 +85,7 @@ impl ComboBox {
             width,
         } = self;
 
-        let button_id = ui.make_persistent_id(id_source);
+        let button_id = ui.make_persistent_id(id_source).derived_from(id_source);
 
         ui.horizontal(|ui| {
             if let Some(width) = width {
@@ -196,6 +198,7 @@ fn combo_box_dyn<'c, R>(
         ScrollArea::vertical()
             .max_height(ui.spacing().combo_height)
             .show(ui, menu_contents)
+            .inner
     });
 
     InnerResponse {
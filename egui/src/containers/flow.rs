@@ -0,0 +1,284 @@
+//! See [`Flow`].
+
+use crate::*;
+
+/// Not persisted: this is just a layout cache, recomputed each frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct State {
+    /// The size of each item last frame.
+    item_sizes: Vec<Vec2>,
+}
+
+impl State {
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.memory().data_temp.get_temp(id)
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.memory().data_temp.insert_temp(id, self);
+    }
+}
+
+/// A row of items, as laid out by [`layout_rows`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Row {
+    /// Index of the first item in the row.
+    start: usize,
+    /// One past the index of the last item in the row.
+    end: usize,
+    width: f32,
+    height: f32,
+}
+
+/// Break items of the given sizes into rows no wider than `max_width` (if possible).
+fn layout_rows(item_sizes: &[Vec2], max_width: f32, spacing: Vec2) -> Vec<Row> {
+    let mut rows: Vec<Row> = vec![];
+    for (i, size) in item_sizes.iter().enumerate() {
+        match rows.last_mut() {
+            Some(row) if row.width + spacing.x + size.x <= max_width => {
+                row.end = i + 1;
+                row.width += spacing.x + size.x;
+                row.height = row.height.max(size.y);
+            }
+            _ => rows.push(Row {
+                start: i,
+                end: i + 1,
+                width: size.x,
+                height: size.y,
+            }),
+        }
+    }
+    rows
+}
+
+// ----------------------------------------------------------------------------
+
+/// Places arbitrary widgets left to right, wrapping to a new row when there is no more room.
+///
+/// Unlike [`Ui::horizontal_wrapped`], each row is as high as its tallest item,
+/// with the other items aligned within it (see [`Self::cross_align`]),
+/// and rows can be centered or right-aligned (see [`Self::align`] and [`Self::last_row_align`]).
+///
+/// This is useful for tag clouds, toolbars and galleries.
+///
+/// The layout is based on the size of the items in the previous frame,
+/// so there may be a frame delay when items change size.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Flow::new("tags")
+///     .align(egui::Align::Center)
+///     .show(ui, |flow| {
+///         for tag in ["rust", "gui", "immediate mode", "egui"] {
+///             flow.add(egui::Button::new(tag));
+///         }
+///     });
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use = "You should call .show()"]
+pub struct Flow {
+    id_source: Id,
+    spacing: Option<Vec2>,
+    align: Align,
+    last_row_align: Option<Align>,
+    cross_align: Align,
+}
+
+impl Flow {
+    /// `id_source` must be unique within the parent [`Ui`].
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            spacing: None,
+            align: Align::Min,
+            last_row_align: None,
+            cross_align: Align::Center,
+        }
+    }
+
+    /// Spacing between items (x) and between rows (y).
+    ///
+    /// Default: [`crate::style::Spacing::item_spacing`].
+    pub fn spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+
+    /// How to align each row horizontally. Default: [`Align::Min`] (left).
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// How to align the last row horizontally. Default: same as [`Self::align`].
+    pub fn last_row_align(mut self, align: Align) -> Self {
+        self.last_row_align = Some(align);
+        self
+    }
+
+    /// How to align items vertically within a row that is taller than they are.
+    ///
+    /// Default: [`Align::Center`].
+    pub fn cross_align(mut self, cross_align: Align) -> Self {
+        self.cross_align = cross_align;
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut FlowUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        self.show_dyn(ui, Box::new(add_contents))
+    }
+
+    fn show_dyn<'c, R>(
+        self,
+        ui: &mut Ui,
+        add_contents: Box<dyn FnOnce(&mut FlowUi<'_>) -> R + 'c>,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_source,
+            spacing,
+            align,
+            last_row_align,
+            cross_align,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source).derived_from(id_source);
+        let spacing = spacing.unwrap_or(ui.spacing().item_spacing);
+        let max_rect = ui.available_rect_before_wrap();
+        let prev_state = State::load(ui.ctx(), id).unwrap_or_default();
+
+        // Where to put each item, based on the sizes from last frame:
+        let rows = layout_rows(&prev_state.item_sizes, max_rect.width(), spacing);
+        let mut placements = Vec::with_capacity(prev_state.item_sizes.len());
+        let mut y = max_rect.top();
+        for (row_index, row) in rows.iter().enumerate() {
+            let row_align = if row_index + 1 == rows.len() {
+                last_row_align.unwrap_or(align)
+            } else {
+                align
+            };
+            let extra_width = (max_rect.width() - row.width).at_least(0.0);
+            let mut x = max_rect.left();
+            if extra_width.is_finite() {
+                x += row_align.to_factor() * extra_width;
+            }
+            for size in &prev_state.item_sizes[row.start..row.end] {
+                let y_offset = cross_align.to_factor() * (row.height - size.y);
+                placements.push(pos2(x, y + y_offset));
+                x += size.x + spacing.x;
+            }
+            y += row.height + spacing.y;
+        }
+
+        let mut flow_ui = FlowUi {
+            ui,
+            id,
+            max_rect,
+            spacing,
+            placements,
+            item_sizes: vec![],
+            last_item_pos: None,
+            content_rect: Rect::from_min_size(max_rect.min, Vec2::ZERO),
+        };
+        let inner = add_contents(&mut flow_ui);
+        let FlowUi {
+            item_sizes,
+            content_rect,
+            ..
+        } = flow_ui;
+
+        let mut rect = content_rect;
+        if align != Align::Min || last_row_align.map_or(false, |align| align != Align::Min) {
+            // Claim the whole width, so that we don't shrink the parent when centering rows:
+            rect.max.x = rect.max.x.max(max_rect.right());
+        }
+
+        let state = State { item_sizes };
+        if state != prev_state {
+            ui.ctx().request_repaint();
+        }
+        state.store(ui.ctx(), id);
+
+        let response = ui.allocate_rect(rect, Sense::hover());
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Add items to a [`Flow`] with this.
+pub struct FlowUi<'a> {
+    ui: &'a mut Ui,
+    id: Id,
+    max_rect: Rect,
+    spacing: Vec2,
+    /// Where to put each item, from last frame's layout.
+    placements: Vec<Pos2>,
+    /// The size of each item added so far.
+    item_sizes: Vec<Vec2>,
+    last_item_pos: Option<Pos2>,
+    /// Bounding rectangle of everything added so far.
+    content_rect: Rect,
+}
+
+impl<'a> FlowUi<'a> {
+    /// Add a widget as an item of the flow.
+    pub fn add(&mut self, widget: impl Widget) -> Response {
+        self.item(|ui| ui.add(widget)).inner
+    }
+
+    /// Add a piece of ui as an item of the flow.
+    ///
+    /// Everything added here will be kept together on one row.
+    pub fn item<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let index = self.item_sizes.len();
+        let pos = self.placements.get(index).copied().unwrap_or_else(|| {
+            // A new item: put it at the end of the last row for now.
+            match self.last_item_pos {
+                Some(last_pos) => pos2(self.content_rect.right() + self.spacing.x, last_pos.y),
+                None => self.max_rect.min,
+            }
+        });
+
+        let max_rect = Rect::from_min_max(pos, pos2(self.max_rect.right().max(pos.x), pos.y));
+        let mut child_ui = self.ui.child_ui_with_id_source(
+            max_rect,
+            Layout::left_to_right().with_cross_align(Align::Min),
+            self.id.with(index),
+        );
+        let inner = add_contents(&mut child_ui);
+        let rect = child_ui.min_rect();
+
+        self.item_sizes.push(rect.size());
+        self.last_item_pos = Some(pos);
+        self.content_rect = self.content_rect.union(rect);
+        InnerResponse::new(inner, self.ui.interact(rect, child_ui.id(), Sense::hover()))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_layout_rows() {
+    let sizes = [vec2(40.0, 10.0), vec2(40.0, 20.0), vec2(40.0, 10.0)];
+    let rows = layout_rows(&sizes, 100.0, vec2(10.0, 5.0));
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                start: 0,
+                end: 2,
+                width: 90.0,
+                height: 20.0
+            },
+            Row {
+                start: 2,
+                end: 3,
+                width: 40.0,
+                height: 10.0
+            },
+        ]
+    );
+}
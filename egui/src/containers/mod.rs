@@ -5,9 +5,12 @@
 pub(crate) mod area;
 pub(crate) mod collapsing_header;
 mod combo_box;
+pub(crate) mod flow;
 pub(crate) mod frame;
 pub mod panel;
 pub mod popup;
+pub(crate) mod radial_menu;
+pub(crate) mod resizable_columns;
 pub(crate) mod resize;
 pub(crate) mod scroll_area;
 pub(crate) mod window;
@@ -16,10 +19,13 @@ pub use {
     area::Area,
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
+    flow::{Flow, FlowUi},
     frame::Frame,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
     popup::*,
+    radial_menu::{RadialMenu, RadialMenuItemResponse, RadialMenuUi},
+    resizable_columns::ResizableColumns,
     resize::Resize,
-    scroll_area::ScrollArea,
+    scroll_area::{ScrollArea, ScrollAreaOutput, ScrollBarVisibility},
     window::Window,
 };
@@ -25,11 +25,11 @@ struct PanelState {
 
 impl PanelState {
     fn load(ctx: &Context, bar_id: Id) -> Option<Self> {
-        ctx.memory().data.get_persisted(bar_id)
+        ctx.memory().data_persisted.get_persisted(bar_id)
     }
 
     fn store(self, ctx: &Context, bar_id: Id) {
-        ctx.memory().data.insert_persisted(bar_id, self);
+        ctx.memory().data_persisted.insert_persisted(bar_id, self);
     }
 }
 
@@ -93,16 +93,19 @@ pub struct SidePanel {
 
 impl SidePanel {
     /// `id_source`: Something unique, e.g. `"my_left_panel"`.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn left(id_source: impl std::hash::Hash) -> Self {
         Self::new(Side::Left, id_source)
     }
 
     /// `id_source`: Something unique, e.g. `"my_right_panel"`.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn right(id_source: impl std::hash::Hash) -> Self {
         Self::new(Side::Right, id_source)
     }
 
     /// `id_source`: Something unique, e.g. `"my_panel"`.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn new(side: Side, id_source: impl std::hash::Hash) -> Self {
         Self {
             side,
@@ -369,16 +372,19 @@ pub struct TopBottomPanel {
 
 impl TopBottomPanel {
     /// `id_source`: Something unique, e.g. `"my_top_panel"`.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn top(id_source: impl std::hash::Hash) -> Self {
         Self::new(TopBottomSide::Top, id_source)
     }
 
     /// `id_source`: Something unique, e.g. `"my_bottom_panel"`.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn bottom(id_source: impl std::hash::Hash) -> Self {
         Self::new(TopBottomSide::Bottom, id_source)
     }
 
     /// `id_source`: Something unique, e.g. `"my_panel"`.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn new(side: TopBottomSide, id_source: impl std::hash::Hash) -> Self {
         Self {
             side,
@@ -13,11 +13,11 @@ pub(crate) struct MonoState {
 
 impl MonoState {
     fn load(ctx: &Context) -> Option<Self> {
-        ctx.memory().data.get_temp(Id::null())
+        ctx.memory().data_temp.get_temp(Id::null())
     }
 
     fn store(self, ctx: &Context) {
-        ctx.memory().data.insert_temp(Id::null(), self);
+        ctx.memory().data_temp.insert_temp(Id::null(), self);
     }
 
     fn tooltip_size(&self, id: Id, index: usize) -> Option<Vec2> {
@@ -47,6 +47,179 @@ impl MonoState {
     }
 }
 
+/// When to show the tooltips of [`Response::on_hover_ui`], and which one is pinned.
+///
+/// See [`crate::style::Interaction::tooltip_delay`] and [`crate::style::Interaction::pin_tooltip_modifiers`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct TooltipTiming {
+    /// The widget that is hovered, since when, and the last frame it was hovered.
+    hovered: Option<(Id, f64, u64)>,
+
+    /// The widget whose tooltip is showing, the last frame it was shown,
+    /// and the last time the widget was hovered.
+    shown: Option<(Id, u64, f64)>,
+
+    /// The widget whose tooltip is pinned, and the region (widget and tooltip)
+    /// the pointer must stay in to keep it pinned.
+    pinned: Option<(Id, Rect)>,
+}
+
+impl TooltipTiming {
+    fn load(ctx: &Context) -> Self {
+        ctx.memory()
+            .data_temp
+            .get_temp(Id::null())
+            .unwrap_or_default()
+    }
+
+    fn store(self, ctx: &Context) {
+        ctx.memory().data_temp.insert_temp(Id::null(), self);
+    }
+
+    /// Should the tooltip of this widget be shown this frame?
+    ///
+    /// Returns `None` if not, otherwise whether it is pinned.
+    /// `hovered` is whether the pointer is (still) over the widget.
+    pub(crate) fn update(
+        ctx: &Context,
+        widget_id: Id,
+        widget_rect: Rect,
+        hovered: bool,
+    ) -> Option<bool> {
+        let old_timing = Self::load(ctx);
+        let mut timing = old_timing;
+        let visible = timing.update_dyn(ctx, widget_id, widget_rect, hovered);
+        if timing != old_timing {
+            timing.store(ctx);
+        }
+        visible
+    }
+
+    fn update_dyn(
+        &mut self,
+        ctx: &Context,
+        widget_id: Id,
+        widget_rect: Rect,
+        hovered: bool,
+    ) -> Option<bool> {
+        let interaction = ctx.style().interaction.clone();
+        let input = ctx.input();
+        let now = input.time;
+        let frame_nr = ctx.memory().frame_nr;
+        let pin_modifiers_down = interaction
+            .pin_tooltip_modifiers
+            .map_or(false, |modifiers| input.modifiers.matches(modifiers));
+
+        if let Some((pinned_id, region)) = self.pinned {
+            if pinned_id == widget_id {
+                let pointer_in_region = input
+                    .pointer
+                    .hover_pos()
+                    .map_or(false, |pos| region.expand(8.0).contains(pos));
+                if pointer_in_region || pin_modifiers_down {
+                    self.shown = Some((widget_id, frame_nr, now));
+                    return Some(true);
+                }
+                self.pinned = None;
+            }
+        }
+
+        if hovered {
+            let hover_start = match self.hovered {
+                Some((id, start, last_frame)) if id == widget_id && last_frame + 1 >= frame_nr => {
+                    start
+                }
+                _ => now,
+            };
+            self.hovered = Some((widget_id, hover_start, frame_nr));
+
+            // No delay when moving from one tooltip to the next:
+            let other_tooltip_showing = self.shown.map_or(false, |(id, last_frame, _)| {
+                id != widget_id && last_frame + 1 >= frame_nr
+            });
+            let waited = now - hover_start >= interaction.tooltip_delay as f64;
+            if !waited && !other_tooltip_showing {
+                ctx.request_repaint();
+                return None;
+            }
+
+            self.shown = Some((widget_id, frame_nr, now));
+            if pin_modifiers_down {
+                self.pinned = Some((widget_id, widget_rect));
+                return Some(true);
+            }
+            Some(false)
+        } else {
+            match self.shown {
+                Some((id, last_frame, last_hovered))
+                    if id == widget_id
+                        && last_frame + 1 >= frame_nr
+                        && now - last_hovered < interaction.tooltip_hide_delay as f64 =>
+                {
+                    self.shown = Some((widget_id, frame_nr, last_hovered));
+                    ctx.request_repaint();
+                    Some(false)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    /// The tooltip of the pinned widget was shown, covering `tooltip_rect`.
+    pub(crate) fn pinned_tooltip_shown(ctx: &Context, widget_id: Id, tooltip_rect: Rect) {
+        let mut timing = Self::load(ctx);
+        if let Some((pinned_id, region)) = &mut timing.pinned {
+            if *pinned_id == widget_id {
+                *region = region.union(tooltip_rect);
+                timing.store(ctx);
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Where to place a tooltip next to the pointer, see [`Response::on_hover_ui_at_pointer_placed`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TooltipPlacement {
+    /// Which side of the pointer to prefer, e.g. [`Align2::RIGHT_BOTTOM`] for below and to the right.
+    ///
+    /// If the tooltip doesn't fit on the screen there, it is placed on the opposite side.
+    pub side: Align2,
+
+    /// Distance from the pointer, in points.
+    pub offset: Vec2,
+}
+
+impl Default for TooltipPlacement {
+    fn default() -> Self {
+        Self {
+            side: Align2::RIGHT_BOTTOM,
+            offset: Vec2::splat(16.0),
+        }
+    }
+}
+
+impl TooltipPlacement {
+    /// The top left corner of a tooltip of the given size.
+    fn position(&self, pointer_pos: Pos2, size: Vec2, screen_rect: Rect) -> Pos2 {
+        let mut pos = Pos2::ZERO;
+        for d in 0..2 {
+            let before = pointer_pos[d] - self.offset[d] - size[d];
+            let after = pointer_pos[d] + self.offset[d];
+            let centered = pointer_pos[d] - 0.5 * size[d];
+            pos[d] = match self.side[d] {
+                Align::Min if before < screen_rect.min[d] => after,
+                Align::Min => before,
+                Align::Center => centered,
+                Align::Max if after + size[d] > screen_rect.max[d] => before,
+                Align::Max => after,
+            };
+        }
+        pos
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Show a tooltip at the current pointer position (if any).
@@ -92,12 +265,43 @@ pub fn show_tooltip_at_pointer<R>(
     id: Id,
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
-    let suggested_pos = ctx
-        .input()
-        .pointer
-        .hover_pos()
-        .map(|pointer_pos| pointer_pos + vec2(16.0, 16.0));
-    show_tooltip_at(ctx, id, suggested_pos, add_contents)
+    show_tooltip_at_pointer_placed(ctx, id, TooltipPlacement::default(), add_contents)
+}
+
+/// Show a tooltip next to the current pointer position (if any), on the side of your choice.
+///
+/// Returns `None` if the tooltip could not be placed.
+pub fn show_tooltip_at_pointer_placed<R>(
+    ctx: &CtxRef,
+    id: Id,
+    placement: TooltipPlacement,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<R> {
+    show_tooltip_at_pointer_dyn(ctx, id, placement, false, Box::new(add_contents))
+}
+
+pub(crate) fn show_tooltip_at_pointer_dyn<'c, R>(
+    ctx: &CtxRef,
+    id: Id,
+    placement: TooltipPlacement,
+    interactable: bool,
+    add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+) -> Option<R> {
+    let suggested_pos = ctx.input().pointer.hover_pos().map(|pointer_pos| {
+        let expected_size = MonoState::load(ctx)
+            .and_then(|state| state.tooltip_size(id, 0))
+            .unwrap_or_else(|| vec2(64.0, 32.0));
+        placement.position(pointer_pos, expected_size, ctx.input().screen_rect())
+    });
+    show_tooltip_at_avoid_dyn(
+        ctx,
+        id,
+        suggested_pos,
+        false,
+        Rect::NOTHING,
+        interactable,
+        add_contents,
+    )
 }
 
 /// Show a tooltip under the given area.
@@ -108,6 +312,16 @@ pub fn show_tooltip_for<R>(
     id: Id,
     rect: &Rect,
     add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<R> {
+    show_tooltip_for_dyn(ctx, id, rect, false, Box::new(add_contents))
+}
+
+pub(crate) fn show_tooltip_for_dyn<'c, R>(
+    ctx: &CtxRef,
+    id: Id,
+    rect: &Rect,
+    interactable: bool,
+    add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
 ) -> Option<R> {
     let expanded_rect = rect.expand2(vec2(2.0, 4.0));
     let (above, position) = if ctx.input().any_touches() {
@@ -121,7 +335,8 @@ pub fn show_tooltip_for<R>(
         Some(position),
         above,
         expanded_rect,
-        Box::new(add_contents),
+        interactable,
+        add_contents,
     )
 }
 
@@ -141,6 +356,7 @@ pub fn show_tooltip_at<R>(
         suggested_position,
         above,
         Rect::NOTHING,
+        false,
         Box::new(add_contents),
     )
 }
@@ -151,6 +367,7 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
     suggested_position: Option<Pos2>,
     above: bool,
     mut avoid_rect: Rect,
+    interactable: bool,
     add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
 ) -> Option<R> {
     let mut tooltip_rect = Rect::NOTHING;
@@ -205,7 +422,8 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
 
     let position = position.at_least(ctx.input().screen_rect().min);
 
-    let InnerResponse { inner, response } = show_tooltip_area_dyn(ctx, id, position, add_contents);
+    let InnerResponse { inner, response } =
+        show_tooltip_area_dyn(ctx, id, position, interactable, add_contents);
 
     state.set_tooltip_size(id, count, response.rect.size());
     state.store(ctx);
@@ -240,13 +458,14 @@ fn show_tooltip_area_dyn<'c, R>(
     ctx: &CtxRef,
     id: Id,
     window_pos: Pos2,
+    interactable: bool,
     add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
 ) -> InnerResponse<R> {
     use containers::*;
     Area::new(id)
         .order(Order::Tooltip)
         .fixed_pos(window_pos)
-        .interactable(false)
+        .interactable(interactable)
         .drag_bounds(Rect::EVERYTHING) // disable clip rect
         .show(ctx, |ui| {
             Frame::popup(&ctx.style())
@@ -262,6 +481,10 @@ fn show_tooltip_area_dyn<'c, R>(
 ///
 /// Useful for drop-down menus (combo boxes) or suggestion menus under text fields.
 ///
+/// If there is not enough room below the widget, the popup is flipped to above the widget
+/// if there is more room there.
+/// The popup is limited to the height of the room it has, so put large contents in a [`ScrollArea`].
+///
 /// You must open the popup with [`Memory::open_popup`] or  [`Memory::toggle_popup`].
 ///
 /// Returns `None` if the popup is not open.
@@ -287,9 +510,37 @@ pub fn popup_below_widget<R>(
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
     if ui.memory().is_popup_open(popup_id) {
+        let widget_rect = widget_response.rect;
+        let screen_rect = ui.ctx().input().screen_rect();
+
+        // We don't know how large the popup is until we have shown it, so use the size from last frame:
+        let popup_size = ui
+            .memory()
+            .areas
+            .get(popup_id)
+            .map_or(Vec2::ZERO, |state| state.size);
+        let space_below = screen_rect.bottom() - widget_rect.bottom();
+        let space_above = widget_rect.top() - screen_rect.top();
+        let flip_above = popup_size.y > space_below && space_above > space_below;
+        let (mut pos, max_height) = if flip_above {
+            (
+                widget_rect.left_top() - vec2(0.0, popup_size.y),
+                space_above,
+            )
+        } else {
+            (widget_rect.left_bottom(), space_below)
+        };
+
+        // Keep it from sticking out to the side of the screen:
+        pos.x = ui
+            .ctx()
+            .constrain_window_rect_to_area(Rect::from_min_size(pos, popup_size), Some(screen_rect))
+            .min
+            .x;
+
         let inner = Area::new(popup_id)
             .order(Order::Foreground)
-            .fixed_pos(widget_response.rect.left_bottom())
+            .fixed_pos(pos)
             .show(ui.ctx(), |ui| {
                 // Note: we use a separate clip-rect for this area, so the popup can be outside the parent.
                 // See https://github.com/emilk/egui/issues/825
@@ -298,7 +549,8 @@ pub fn popup_below_widget<R>(
                 frame
                     .show(ui, |ui| {
                         ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
-                            ui.set_width(widget_response.rect.width() - 2.0 * frame_margin.x);
+                            ui.set_width(widget_rect.width() - 2.0 * frame_margin.x);
+                            ui.set_max_height((max_height - 2.0 * frame_margin.y).at_least(0.0));
                             add_contents(ui)
                         })
                         .inner
@@ -307,7 +559,7 @@ pub fn popup_below_widget<R>(
             })
             .inner;
 
-        if ui.input().key_pressed(Key::Escape) || widget_response.clicked_elsewhere() {
+        if ui.input().cancel_pressed() || widget_response.clicked_elsewhere() {
             ui.memory().close_popup();
         }
         Some(inner)
@@ -0,0 +1,446 @@
+//! A circular menu that opens at the pointer, also known as a pie menu.
+
+use crate::*;
+use std::f32::consts::TAU;
+
+/// The layout of the items from the previous frame, used for hit-testing.
+#[derive(Clone, Debug, Default)]
+struct Node {
+    children: Vec<Node>,
+}
+
+#[derive(Clone, Debug)]
+struct State {
+    /// Where the menu was opened.
+    center: Pos2,
+
+    /// The button was released without selecting anything,
+    /// so we keep the menu open until the next click.
+    click_mode: bool,
+
+    /// The innermost ring.
+    items: Vec<Node>,
+}
+
+/// A circular menu that opens at the pointer, with the items laid out around it.
+///
+/// Hold down the button (secondary by default) over a widget to open the menu,
+/// move the pointer towards an item, and release the button to select it.
+/// If the button is released in the middle of the menu, it stays open until the next click.
+///
+/// Submenus are shown as an outer ring when the pointer moves over them.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.label("Right-click and hold me");
+/// egui::RadialMenu::new("tools").show(&response, |menu| {
+///     if menu.item("Brush").clicked() {
+///         // …
+///     }
+///     menu.submenu("Shapes", |menu| {
+///         if menu.item("Circle").clicked() {
+///             // …
+///         }
+///         if menu.item("Square").clicked() {
+///             // …
+///         }
+///     });
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct RadialMenu {
+    id_source: Id,
+    button: PointerButton,
+    inner_radius: f32,
+    ring_width: f32,
+}
+
+impl RadialMenu {
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            button: PointerButton::Secondary,
+            inner_radius: 24.0,
+            ring_width: 64.0,
+        }
+    }
+
+    /// Which button opens the menu when held down. Default: [`PointerButton::Secondary`].
+    pub fn button(mut self, button: PointerButton) -> Self {
+        self.button = button;
+        self
+    }
+
+    /// Radius of the empty middle of the menu, where releasing the button selects nothing.
+    pub fn inner_radius(mut self, inner_radius: f32) -> Self {
+        self.inner_radius = inner_radius;
+        self
+    }
+
+    /// The thickness of each ring of items.
+    pub fn ring_width(mut self, ring_width: f32) -> Self {
+        self.ring_width = ring_width;
+        self
+    }
+
+    /// Open the menu when the button is held down over `response`, and show it while it is open.
+    ///
+    /// `add_items` is only called while the menu is open.
+    /// Returns `true` if the menu is open.
+    pub fn show(self, response: &Response, add_items: impl FnOnce(&mut RadialMenuUi)) -> bool {
+        let ctx = &response.ctx;
+        let id = response
+            .id
+            .with(self.id_source)
+            .derived_from(self.id_source);
+
+        let mut state = ctx.memory().data_temp.get_temp::<State>(id);
+        let just_opened = state.is_none()
+            && response.hovered()
+            && ctx.input().pointer.any_pressed()
+            && ctx.input().pointer.button_down(self.button);
+        if just_opened {
+            if let Some(center) = ctx.input().pointer.interact_pos() {
+                state = Some(State {
+                    center,
+                    click_mode: false,
+                    items: vec![],
+                });
+            }
+        }
+        let mut state = match state {
+            Some(state) => state,
+            None => return false,
+        };
+
+        let hovered_path = |items: &[Node], center: Pos2| {
+            ctx.input()
+                .pointer
+                .hover_pos()
+                .map_or(vec![], |pointer_pos| {
+                    self.hit_test(items, pointer_pos - center)
+                })
+        };
+        let is_leaf = |items: &[Node], path: &[usize]| {
+            let mut nodes = items;
+            for &index in path {
+                match nodes.get(index) {
+                    Some(node) => nodes = &node.children,
+                    None => return false,
+                }
+            }
+            !path.is_empty() && nodes.is_empty()
+        };
+
+        let path = hovered_path(&state.items, state.center);
+        let mut selected = None;
+        let mut close = false;
+        if ctx.input().cancel_pressed() {
+            close = true;
+        } else if state.click_mode {
+            if ctx.input().pointer.any_click() {
+                let pointer_distance = ctx
+                    .input()
+                    .pointer
+                    .interact_pos()
+                    .map_or(f32::INFINITY, |pos| pos.distance(state.center));
+                let menu_radius = self.inner_radius + path.len() as f32 * self.ring_width;
+                if pointer_distance > menu_radius {
+                    close = true; // Clicked outside the menu
+                } else if is_leaf(&state.items, &path) {
+                    selected = Some(path);
+                    close = true;
+                }
+            }
+        } else if !just_opened && !ctx.input().pointer.button_down(self.button) {
+            // Flick selection: releasing the button over an item selects it.
+            if is_leaf(&state.items, &path) {
+                selected = Some(path);
+                close = true;
+            } else {
+                state.click_mode = true;
+            }
+        }
+
+        let mut menu_ui = RadialMenuUi {
+            items: vec![],
+            path: vec![],
+            selected,
+        };
+        add_items(&mut menu_ui);
+
+        if close {
+            ctx.memory().data_temp.remove::<State>(id);
+            return false;
+        }
+
+        state.items = menu_ui.items.iter().map(RadialMenuItem::node).collect();
+        let path = hovered_path(&state.items, state.center);
+        let depth = path.len().max(1) as f32;
+        let radius = self.inner_radius + depth * self.ring_width;
+
+        Area::new(id)
+            .order(Order::Foreground)
+            .fixed_pos(state.center - Vec2::splat(radius))
+            .show(ctx, |ui| {
+                ui.allocate_exact_size(Vec2::splat(2.0 * radius), Sense::hover());
+                self.paint(ui, state.center, menu_ui.items, &path);
+            });
+
+        ctx.memory().data_temp.insert_temp(id, state);
+        true
+    }
+
+    /// The angle of the middle of the first item in the ring, and the angular width of each item.
+    fn ring_angles(parent: Option<(f32, f32)>, num_items: usize) -> (f32, f32) {
+        let num_items = num_items.max(1) as f32;
+        match parent {
+            None => (-TAU / 4.0, TAU / num_items),
+            Some((parent_angle, parent_width)) => {
+                // Center the children around their parent,
+                // making them at least as wide as the parent, while fitting in a circle.
+                let width = parent_width.min(TAU / num_items);
+                (parent_angle - 0.5 * (num_items - 1.0) * width, width)
+            }
+        }
+    }
+
+    /// The path of the item at `delta` from the center, e.g. `[2, 0]` for the first child of the third item.
+    fn hit_test(&self, items: &[Node], delta: Vec2) -> Vec<usize> {
+        let mut path = vec![];
+        let distance = delta.length();
+        if distance < self.inner_radius {
+            return path;
+        }
+        let angle = delta.angle();
+
+        let mut nodes = items;
+        let mut parent = None;
+        while !nodes.is_empty() {
+            let ring_min = self.inner_radius + path.len() as f32 * self.ring_width;
+            if !path.is_empty() && distance < ring_min {
+                break;
+            }
+
+            let (first_angle, width) = Self::ring_angles(parent, nodes.len());
+            let hovered = (0..nodes.len()).find(|&index| {
+                let item_angle = first_angle + index as f32 * width;
+                wrap_angle(angle - item_angle).abs() <= 0.5 * width
+            });
+            match hovered {
+                Some(index) => {
+                    path.push(index);
+                    parent = Some((first_angle + index as f32 * width, width));
+                    nodes = &nodes[index].children;
+                }
+                None => break,
+            }
+        }
+        path
+    }
+
+    fn paint(&self, ui: &Ui, center: Pos2, items: Vec<RadialMenuItem>, path: &[usize]) {
+        let mut items = items;
+        let mut parent = None;
+        let mut ring = 0;
+        while !items.is_empty() {
+            let inner_radius = self.inner_radius + ring as f32 * self.ring_width;
+            let outer_radius = inner_radius + self.ring_width;
+            let (first_angle, width) = Self::ring_angles(parent, items.len());
+
+            let mut hovered_children = vec![];
+            for (index, item) in items.into_iter().enumerate() {
+                let angle = first_angle + index as f32 * width;
+                let hovered = path.get(ring) == Some(&index);
+                let visuals = if hovered {
+                    &ui.visuals().widgets.hovered
+                } else {
+                    &ui.visuals().widgets.inactive
+                };
+
+                paint_sector(
+                    ui.painter(),
+                    center,
+                    (inner_radius, outer_radius),
+                    (angle - 0.5 * width, angle + 0.5 * width),
+                    visuals.bg_fill,
+                    ui.visuals().widgets.noninteractive.bg_stroke,
+                );
+
+                let text = item
+                    .text
+                    .into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button);
+                let text_center =
+                    center + 0.5 * (inner_radius + outer_radius) * Vec2::angled(angle);
+                let text_pos = text_center - 0.5 * text.size();
+                text.paint_with_visuals(ui.painter(), text_pos, visuals);
+
+                if hovered {
+                    hovered_children = item.children;
+                }
+            }
+
+            match path.get(ring) {
+                Some(&index) => {
+                    parent = Some((first_angle + index as f32 * width, width));
+                    items = hovered_children;
+                    ring += 1;
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+/// Wrap an angle into `-TAU/2..=TAU/2`.
+fn wrap_angle(angle: f32) -> f32 {
+    let angle = angle.rem_euclid(TAU);
+    if angle > 0.5 * TAU {
+        angle - TAU
+    } else {
+        angle
+    }
+}
+
+/// Paint the part of a ring that is between two angles.
+fn paint_sector(
+    painter: &Painter,
+    center: Pos2,
+    (inner_radius, outer_radius): (f32, f32),
+    (min_angle, max_angle): (f32, f32),
+    fill: Color32,
+    stroke: Stroke,
+) {
+    let num_segments = (((max_angle - min_angle) * outer_radius / 8.0).ceil() as usize).max(2);
+    let inner_points: Vec<Pos2> = (0..=num_segments)
+        .map(|i| {
+            let angle = lerp(min_angle..=max_angle, i as f32 / num_segments as f32);
+            center + inner_radius * Vec2::angled(angle)
+        })
+        .collect();
+    let outer_points: Vec<Pos2> = (0..=num_segments)
+        .map(|i| {
+            let angle = lerp(min_angle..=max_angle, i as f32 / num_segments as f32);
+            center + outer_radius * Vec2::angled(angle)
+        })
+        .collect();
+
+    let mut mesh = epaint::Mesh::default();
+    for (&inner, &outer) in inner_points.iter().zip(&outer_points) {
+        mesh.colored_vertex(inner, fill);
+        mesh.colored_vertex(outer, fill);
+    }
+    for i in 0..num_segments as u32 {
+        mesh.add_triangle(2 * i, 2 * i + 1, 2 * i + 2);
+        mesh.add_triangle(2 * i + 1, 2 * i + 2, 2 * i + 3);
+    }
+    painter.add(Shape::mesh(mesh));
+
+    let outline = outer_points
+        .into_iter()
+        .chain(inner_points.into_iter().rev())
+        .collect();
+    painter.add(Shape::closed_line(outline, stroke));
+}
+
+// ----------------------------------------------------------------------------
+
+/// An item in a [`RadialMenu`], and its submenu (if any).
+struct RadialMenuItem {
+    text: WidgetText,
+    children: Vec<RadialMenuItem>,
+}
+
+impl RadialMenuItem {
+    fn node(&self) -> Node {
+        Node {
+            children: self.children.iter().map(Self::node).collect(),
+        }
+    }
+}
+
+/// Add items to a [`RadialMenu`].
+pub struct RadialMenuUi {
+    /// The items of the submenu that is currently being added.
+    items: Vec<RadialMenuItem>,
+
+    /// The path to the submenu that is currently being added.
+    path: Vec<usize>,
+
+    /// The path to the item that was selected this frame (if any).
+    selected: Option<Vec<usize>>,
+}
+
+impl RadialMenuUi {
+    /// Add an item to the menu.
+    pub fn item(&mut self, text: impl Into<WidgetText>) -> RadialMenuItemResponse {
+        self.path.push(self.items.len());
+        let clicked = self.selected.as_ref() == Some(&self.path);
+        self.path.pop();
+
+        self.items.push(RadialMenuItem {
+            text: text.into(),
+            children: vec![],
+        });
+        RadialMenuItemResponse { clicked }
+    }
+
+    /// Add an item that opens a submenu in an outer ring when hovered.
+    pub fn submenu(&mut self, text: impl Into<WidgetText>, add_items: impl FnOnce(&mut Self)) {
+        self.path.push(self.items.len());
+        let parent_items = std::mem::take(&mut self.items);
+        add_items(self);
+        let children = std::mem::replace(&mut self.items, parent_items);
+        self.path.pop();
+
+        self.items.push(RadialMenuItem {
+            text: text.into(),
+            children,
+        });
+    }
+}
+
+/// Returned by [`RadialMenuUi::item`].
+#[derive(Clone, Copy, Debug)]
+pub struct RadialMenuItemResponse {
+    clicked: bool,
+}
+
+impl RadialMenuItemResponse {
+    /// Was this item selected this frame?
+    pub fn clicked(&self) -> bool {
+        self.clicked
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn radial_menu_hit_test() {
+    let menu = RadialMenu::new("test").inner_radius(10.0).ring_width(10.0);
+    let items = vec![
+        Node::default(),
+        Node {
+            children: vec![Node::default(), Node::default()],
+        },
+        Node::default(),
+        Node::default(),
+    ];
+
+    // The first item is at the top, and the rest follow clockwise:
+    assert_eq!(menu.hit_test(&items, vec2(0.0, -15.0)), vec![0]);
+    assert_eq!(menu.hit_test(&items, vec2(15.0, 0.0)), vec![1]);
+    assert_eq!(menu.hit_test(&items, vec2(0.0, 15.0)), vec![2]);
+    assert_eq!(menu.hit_test(&items, vec2(-15.0, 0.0)), vec![3]);
+
+    // Nothing in the middle:
+    assert_eq!(menu.hit_test(&items, vec2(5.0, 0.0)), Vec::<usize>::new());
+
+    // The submenu of the second item is in the outer ring:
+    assert_eq!(menu.hit_test(&items, vec2(25.0, -5.0)), vec![1, 0]);
+    assert_eq!(menu.hit_test(&items, vec2(25.0, 5.0)), vec![1, 1]);
+
+    // Flicking past the edge still selects the outermost ring:
+    assert_eq!(menu.hit_test(&items, vec2(100.0, 5.0)), vec![1, 1]);
+}
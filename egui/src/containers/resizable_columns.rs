@@ -0,0 +1,223 @@
+//! See [`ResizableColumns`].
+
+use crate::*;
+
+/// The contents of the columns, see [`ResizableColumns::show`].
+type AddColumns<'c, R> = Box<dyn FnOnce(&mut [Ui]) -> R + 'c>;
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub(crate) struct State {
+    /// How much of the width (not counting spacing) each column gets. Sums to one.
+    fractions: Vec<f32>,
+}
+
+impl State {
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.memory().data_persisted.get_persisted(id)
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.memory().data_persisted.insert_persisted(id, self);
+    }
+}
+
+/// Like [`Ui::columns`], but the user can drag the dividers between the columns to resize them.
+///
+/// The column widths are remembered in [`Memory`] as fractions of the available width,
+/// so they scale along with the parent [`Ui`].
+/// Double-click a divider to make all columns equally wide again.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::ResizableColumns::new("my_columns", 2)
+///     .min_width(40.0)
+///     .show(ui, |columns| {
+///         columns[0].label("First column");
+///         columns[1].label("Second column");
+///     });
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use = "You should call .show()"]
+pub struct ResizableColumns {
+    id_source: Id,
+    num_columns: usize,
+    min_width: f32,
+}
+
+impl ResizableColumns {
+    /// `id_source` must be unique within the parent [`Ui`].
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
+    pub fn new(id_source: impl std::hash::Hash, num_columns: usize) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            num_columns,
+            min_width: 16.0,
+        }
+    }
+
+    /// The user can't make a column narrower than this.
+    ///
+    /// If there isn't enough room for all columns at this width, they will be made equally wide.
+    /// Default: `16.0`.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut [Ui]) -> R) -> R {
+        self.show_dyn(ui, Box::new(add_contents))
+    }
+
+    fn show_dyn<'c, R>(self, ui: &mut Ui, add_contents: AddColumns<'c, R>) -> R {
+        let Self {
+            id_source,
+            num_columns,
+            min_width,
+        } = self;
+
+        if num_columns == 0 {
+            return add_contents(&mut []);
+        }
+
+        let id = ui.make_persistent_id(id_source).derived_from(id_source);
+        let spacing = ui.spacing().item_spacing.x;
+        let total_spacing = spacing * (num_columns as f32 - 1.0);
+        let content_width = (ui.available_width() - total_spacing).at_least(0.0);
+        let min_width = min_width.min(content_width / num_columns as f32);
+
+        let mut state = State::load(ui.ctx(), id).unwrap_or_default();
+        if state.fractions.len() != num_columns {
+            state.fractions = equal_fractions(num_columns);
+        }
+        let mut widths: Vec<f32> = state
+            .fractions
+            .iter()
+            .map(|fraction| fraction * content_width)
+            .collect();
+        enforce_min_width(&mut widths, min_width);
+
+        let top_left = ui.cursor().min;
+        let bottom = ui.max_rect().bottom();
+
+        let mut left = top_left.x;
+        let mut columns: Vec<Ui> = widths
+            .iter()
+            .enumerate()
+            .map(|(col_idx, &width)| {
+                let child_rect =
+                    Rect::from_min_max(pos2(left, top_left.y), pos2(left + width, bottom));
+                left += width + spacing;
+                let mut column_ui = ui.child_ui_with_id_source(
+                    child_rect,
+                    Layout::top_down_justified(Align::LEFT),
+                    (id_source, col_idx),
+                );
+                column_ui.set_width(width);
+                column_ui
+            })
+            .collect();
+
+        let result = add_contents(&mut columns[..]);
+
+        let mut right = top_left.x;
+        let mut height = 0.0_f32;
+        for column in &columns {
+            right = right.max(column.min_rect().right());
+            height = height.max(column.min_size().y);
+        }
+
+        // Dividers between the columns:
+        let grab_radius = ui.style().interaction.resize_grab_radius_side;
+        let y_range = top_left.y..=top_left.y + height;
+        let mut divider_x = top_left.x - 0.5 * spacing;
+        for i in 0..num_columns - 1 {
+            divider_x += widths[i] + spacing;
+            let rect = Rect::from_x_y_ranges(
+                divider_x - grab_radius..=divider_x + grab_radius,
+                y_range.clone(),
+            );
+            let response = ui
+                .interact(rect, id.with(i), Sense::click_and_drag())
+                .on_hover_cursor(CursorIcon::ResizeHorizontal);
+
+            if response.double_clicked() {
+                widths = vec![content_width / num_columns as f32; num_columns];
+                ui.ctx().request_repaint();
+            } else if response.dragged() {
+                ui.output().cursor_icon = CursorIcon::ResizeHorizontal;
+                let pair_width = widths[i] + widths[i + 1];
+                let left_width = (widths[i] + response.drag_delta().x)
+                    .at_most(pair_width - min_width)
+                    .at_least(min_width);
+                widths[i] = left_width;
+                widths[i + 1] = pair_width - left_width;
+            }
+
+            let stroke = if response.dragged() {
+                ui.visuals().widgets.active.bg_stroke
+            } else if response.hovered() {
+                ui.visuals().widgets.hovered.bg_stroke
+            } else {
+                ui.visuals().widgets.noninteractive.bg_stroke
+            };
+            ui.painter().line_segment(
+                [
+                    pos2(divider_x, *y_range.start()),
+                    pos2(divider_x, *y_range.end()),
+                ],
+                stroke,
+            );
+        }
+
+        if content_width > 0.0 {
+            state.fractions = widths.iter().map(|width| width / content_width).collect();
+        }
+        state.store(ui.ctx(), id);
+
+        let width = ui.available_width().max(right - top_left.x);
+        ui.advance_cursor_after_rect(Rect::from_min_size(top_left, vec2(width, height)));
+        result
+    }
+}
+
+fn equal_fractions(num_columns: usize) -> Vec<f32> {
+    vec![1.0 / num_columns as f32; num_columns]
+}
+
+/// Widen any column narrower than `min_width`, taking the space from the wider columns
+/// in proportion to how much wider than `min_width` they are.
+///
+/// Assumes there is room for all columns at `min_width`.
+fn enforce_min_width(widths: &mut [f32], min_width: f32) {
+    let mut deficit = 0.0;
+    let mut excess = 0.0;
+    for &width in widths.iter() {
+        if width < min_width {
+            deficit += min_width - width;
+        } else {
+            excess += width - min_width;
+        }
+    }
+    if deficit <= 0.0 || excess <= 0.0 {
+        return;
+    }
+
+    let shrink = ((excess - deficit) / excess).at_least(0.0);
+    for width in widths {
+        *width = min_width + (*width - min_width).at_least(0.0) * shrink;
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_enforce_min_width() {
+    let mut widths = [10.0, 50.0, 40.0];
+    enforce_min_width(&mut widths, 20.0);
+    assert_eq!(widths, [20.0, 44.0, 36.0]);
+
+    let mut widths = [30.0, 70.0];
+    enforce_min_width(&mut widths, 20.0);
+    assert_eq!(widths, [30.0, 70.0]);
+}
@@ -18,11 +18,11 @@ pub(crate) struct State {
 
 impl State {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
-        ctx.memory().data.get_persisted(id)
+        ctx.memory().data_persisted.get_persisted(id)
     }
 
     pub fn store(self, ctx: &Context, id: Id) {
-        ctx.memory().data.insert_persisted(id, self);
+        ctx.memory().data_persisted.insert_persisted(id, self);
     }
 }
 
@@ -66,6 +66,7 @@ impl Resize {
     }
 
     /// A source for the unique `Id`, e.g. `.id_source("second_resize_area")` or `.id_source(loop_index)`.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
         self.id_source = Some(Id::new(id_source));
         self
@@ -167,7 +168,7 @@ impl Resize {
         let position = ui.available_rect_before_wrap().min;
         let id = self.id.unwrap_or_else(|| {
             let id_source = self.id_source.unwrap_or_else(|| Id::new("resize"));
-            ui.make_persistent_id(id_source)
+            ui.make_persistent_id(id_source).derived_from(id_source)
         });
 
         let mut state = State::load(ui.ctx(), id).unwrap_or_else(|| {
@@ -20,6 +20,12 @@ pub(crate) struct State {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub vel: Vec2,
 
+    /// How far the content has been pulled past its ends (rubber banding).
+    /// Negative means past the top/left, positive past the bottom/right.
+    /// This springs back to zero when the user lets go.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    overscroll: Vec2,
+
     /// Mouse offset relative to the top of the handle when started moving the handle.
     scroll_start_offset_from_top_left: [Option<f32>; 2],
 
@@ -35,6 +41,7 @@ impl Default for State {
             offset: Vec2::ZERO,
             show_scroll: [false; 2],
             vel: Vec2::ZERO,
+            overscroll: Vec2::ZERO,
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: [true; 2],
         }
@@ -43,16 +50,60 @@ impl Default for State {
 
 impl State {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
-        ctx.memory().data.get_persisted(id)
+        ctx.memory().data_persisted.get_persisted(id)
     }
 
     pub fn store(self, ctx: &Context, id: Id) {
-        ctx.memory().data.insert_persisted(id, self);
+        ctx.memory().data_persisted.insert_persisted(id, self);
+    }
+}
+
+/// What is returned when showing a [`ScrollArea`].
+#[derive(Debug)]
+pub struct ScrollAreaOutput<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// [`Id`] of the [`ScrollArea`].
+    pub id: Id,
+
+    /// The current scroll offset, at the end of this frame.
+    /// Positive offset means scrolling down/right.
+    ///
+    /// Pass this to [`ScrollArea::scroll_offset`] to restore (or synchronize) the scrolling.
+    pub offset: Vec2,
+
+    /// Where on the screen the content is (excludes scroll bars).
+    pub inner_rect: Rect,
+}
+
+/// When to show the scroll bars of a [`ScrollArea`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ScrollBarVisibility {
+    /// Never show the scroll bars. The user can still scroll with the scroll wheel or by dragging.
+    AlwaysHidden,
+
+    /// Only show the scroll bars when the content doesn't fit (default).
+    VisibleWhenNeeded,
+
+    /// Always show the scroll bars, even when the content fits.
+    AlwaysVisible,
+}
+
+impl Default for ScrollBarVisibility {
+    fn default() -> Self {
+        Self::VisibleWhenNeeded
     }
 }
 
 /// Add vertical and/or horizontal scrolling to a contained [`Ui`].
 ///
+/// The user can scroll with the mouse wheel (hold down shift to scroll horizontally),
+/// by dragging the contents (on touch screens), or by dragging the scroll bars.
+///
+/// The look of the scroll bars is controlled by [`crate::style::ScrollBarVisuals`].
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// egui::ScrollArea::vertical().show(ui, |ui| {
@@ -67,7 +118,8 @@ pub struct ScrollArea {
     has_bar: [bool; 2],
     auto_shrink: [bool; 2],
     max_size: Vec2,
-    always_show_scroll: bool,
+    scroll_bar_visibility: ScrollBarVisibility,
+    click_to_page: bool,
     id_source: Option<Id>,
     offset_x: Option<f32>,
     offset_y: Option<f32>,
@@ -109,7 +161,8 @@ impl ScrollArea {
             has_bar,
             auto_shrink: [true; 2],
             max_size: Vec2::INFINITY,
-            always_show_scroll: false,
+            scroll_bar_visibility: ScrollBarVisibility::VisibleWhenNeeded,
+            click_to_page: false,
             id_source: None,
             offset_x: None,
             offset_y: None,
@@ -140,12 +193,33 @@ impl ScrollArea {
 
     /// If `false` (default), the scroll bar will be hidden when not needed/
     /// If `true`, the scroll bar will always be displayed even if not needed.
+    ///
+    /// See also [`Self::scroll_bar_visibility`].
     pub fn always_show_scroll(mut self, always_show_scroll: bool) -> Self {
-        self.always_show_scroll = always_show_scroll;
+        self.scroll_bar_visibility = if always_show_scroll {
+            ScrollBarVisibility::AlwaysVisible
+        } else {
+            ScrollBarVisibility::VisibleWhenNeeded
+        };
+        self
+    }
+
+    /// When to show the scroll bars. Default: [`ScrollBarVisibility::VisibleWhenNeeded`].
+    pub fn scroll_bar_visibility(mut self, scroll_bar_visibility: ScrollBarVisibility) -> Self {
+        self.scroll_bar_visibility = scroll_bar_visibility;
+        self
+    }
+
+    /// If `true`, clicking the scroll bar track outside of the handle scrolls one page in that direction.
+    ///
+    /// If `false` (default), the handle jumps to where you click.
+    pub fn click_to_page(mut self, click_to_page: bool) -> Self {
+        self.click_to_page = click_to_page;
         self
     }
 
     /// A source for the unique `Id`, e.g. `.id_source("second_scroll_area")` or `.id_source(loop_index)`.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
         self.id_source = Some(Id::new(id_source));
         self
@@ -257,7 +331,8 @@ struct Prepared {
     /// How much horizontal and vertical space are used up by the
     /// width of the vertical bar, and the height of the horizontal bar?
     current_bar_use: Vec2,
-    always_show_scroll: bool,
+    scroll_bar_visibility: ScrollBarVisibility,
+    click_to_page: bool,
     inner_rect: Rect,
     content_ui: Ui,
     /// Relative coordinates: the offset and size of the view of the inner UI.
@@ -273,7 +348,8 @@ impl ScrollArea {
             has_bar,
             auto_shrink,
             max_size,
-            always_show_scroll,
+            scroll_bar_visibility,
+            click_to_page,
             id_source,
             offset_x,
             offset_y,
@@ -284,7 +360,7 @@ impl ScrollArea {
         let ctx = ui.ctx().clone();
 
         let id_source = id_source.unwrap_or_else(|| Id::new("scroll_area"));
-        let id = ui.make_persistent_id(id_source);
+        let id = ui.make_persistent_id(id_source).derived_from(id_source);
         let mut state = State::load(&ctx, id).unwrap_or_default();
 
         state.offset.x = offset_x.unwrap_or(state.offset.x);
@@ -294,18 +370,26 @@ impl ScrollArea {
 
         let current_hscroll_bar_height = if !has_bar[0] {
             0.0
-        } else if always_show_scroll {
-            max_scroll_bar_width
         } else {
-            max_scroll_bar_width * ui.ctx().animate_bool(id.with("h"), state.show_scroll[0])
+            match scroll_bar_visibility {
+                ScrollBarVisibility::AlwaysHidden => 0.0,
+                ScrollBarVisibility::VisibleWhenNeeded => {
+                    max_scroll_bar_width * ui.ctx().animate_bool(id.with("h"), state.show_scroll[0])
+                }
+                ScrollBarVisibility::AlwaysVisible => max_scroll_bar_width,
+            }
         };
 
         let current_vscroll_bar_width = if !has_bar[1] {
             0.0
-        } else if always_show_scroll {
-            max_scroll_bar_width
         } else {
-            max_scroll_bar_width * ui.ctx().animate_bool(id.with("v"), state.show_scroll[1])
+            match scroll_bar_visibility {
+                ScrollBarVisibility::AlwaysHidden => 0.0,
+                ScrollBarVisibility::VisibleWhenNeeded => {
+                    max_scroll_bar_width * ui.ctx().animate_bool(id.with("v"), state.show_scroll[1])
+                }
+                ScrollBarVisibility::AlwaysVisible => max_scroll_bar_width,
+            }
         };
 
         let current_bar_use = vec2(current_vscroll_bar_width, current_hscroll_bar_height);
@@ -332,7 +416,10 @@ impl ScrollArea {
         }
 
         let mut content_ui = ui.child_ui(
-            Rect::from_min_size(inner_rect.min - state.offset, inner_child_max_size),
+            Rect::from_min_size(
+                inner_rect.min - state.offset - state.overscroll,
+                inner_child_max_size,
+            ),
             *ui.layout(),
         );
         let mut content_clip_rect = inner_rect.expand(ui.visuals().clip_rect_margin);
@@ -345,7 +432,8 @@ impl ScrollArea {
         }
         content_ui.set_clip_rect(content_clip_rect);
 
-        let viewport = Rect::from_min_size(Pos2::ZERO + state.offset, inner_size);
+        let viewport =
+            Rect::from_min_size(Pos2::ZERO + state.offset + state.overscroll, inner_size);
 
         Prepared {
             id,
@@ -353,7 +441,8 @@ impl ScrollArea {
             has_bar,
             auto_shrink,
             current_bar_use,
-            always_show_scroll,
+            scroll_bar_visibility,
+            click_to_page,
             inner_rect,
             content_ui,
             viewport,
@@ -365,7 +454,11 @@ impl ScrollArea {
     /// Show the `ScrollArea`, and add the contents to the viewport.
     ///
     /// If the inner area can be very long, consider using [`Self::show_rows`] instead.
-    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> ScrollAreaOutput<R> {
         self.show_viewport_dyn(ui, Box::new(|ui, _viewport| add_contents(ui)))
     }
 
@@ -391,7 +484,7 @@ impl ScrollArea {
         row_height_sans_spacing: f32,
         num_rows: usize,
         add_contents: impl FnOnce(&mut Ui, std::ops::Range<usize>) -> R,
-    ) -> R {
+    ) -> ScrollAreaOutput<R> {
         let spacing = ui.spacing().item_spacing;
         let row_height_with_spacing = row_height_sans_spacing + spacing.y;
         self.show_viewport(ui, |ui, viewport| {
@@ -420,7 +513,11 @@ impl ScrollArea {
     ///
     /// `add_contents` is past the viewport, which is the relative view of the content.
     /// So if the passed rect has min = zero, then show the top left content (the user has not scrolled).
-    pub fn show_viewport<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui, Rect) -> R) -> R {
+    pub fn show_viewport<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui, Rect) -> R,
+    ) -> ScrollAreaOutput<R> {
         self.show_viewport_dyn(ui, Box::new(add_contents))
     }
 
@@ -428,16 +525,23 @@ impl ScrollArea {
         self,
         ui: &mut Ui,
         add_contents: Box<dyn FnOnce(&mut Ui, Rect) -> R + 'c>,
-    ) -> R {
+    ) -> ScrollAreaOutput<R> {
         let mut prepared = self.begin(ui);
-        let ret = add_contents(&mut prepared.content_ui, prepared.viewport);
-        prepared.end(ui);
-        ret
+        let id = prepared.id;
+        let inner = add_contents(&mut prepared.content_ui, prepared.viewport);
+        let (offset, inner_rect) = prepared.end(ui);
+        ScrollAreaOutput {
+            inner,
+            id,
+            offset,
+            inner_rect,
+        }
     }
 }
 
 impl Prepared {
-    fn end(self, ui: &mut Ui) {
+    /// Returns the final scroll offset and the inner rect.
+    fn end(self, ui: &mut Ui) -> (Vec2, Rect) {
         let Prepared {
             id,
             mut state,
@@ -445,7 +549,8 @@ impl Prepared {
             has_bar,
             auto_shrink,
             mut current_bar_use,
-            always_show_scroll,
+            scroll_bar_visibility,
+            click_to_page,
             content_ui,
             viewport: _,
             scrolling_enabled,
@@ -514,6 +619,8 @@ impl Prepared {
             content_size.y > inner_rect.height(),
         ];
 
+        let max_offset = content_size - inner_rect.size();
+
         if content_is_too_small[0] || content_is_too_small[1] {
             // Drag contents to scroll (for touch screens mostly):
             let sense = if self.scrolling_enabled {
@@ -527,7 +634,12 @@ impl Prepared {
             if content_response.dragged() {
                 for d in 0..2 {
                     if has_bar[d] {
-                        state.offset[d] -= input.pointer.delta()[d];
+                        drag_with_overscroll(
+                            &mut state.offset[d],
+                            &mut state.overscroll[d],
+                            -input.pointer.delta()[d],
+                            max_offset[d],
+                        );
                         state.vel[d] = input.pointer.velocity()[d];
                         state.scroll_stuck_to_end[d] = false;
                     } else {
@@ -549,11 +661,21 @@ impl Prepared {
                     state.offset -= state.vel * dt;
                     ui.ctx().request_repaint();
                 }
+
+                // Spring back from any overscroll:
+                let spring_back = (dt * OVERSCROLL_SPRING_BACK_SPEED).at_most(1.0);
+                state.overscroll -= state.overscroll * spring_back;
+                if state.overscroll.length() < 0.5 {
+                    state.overscroll = Vec2::ZERO;
+                } else {
+                    ui.ctx().request_repaint();
+                }
             }
+        } else {
+            state.overscroll = Vec2::ZERO;
         }
-
-        let max_offset = content_size - inner_rect.size();
         if scrolling_enabled && ui.rect_contains_pointer(outer_rect) {
+            let mut scrolled = false;
             for d in 0..2 {
                 if has_bar[d] {
                     let mut frame_state = ui.ctx().frame_state();
@@ -567,15 +689,22 @@ impl Prepared {
                         // Clear scroll delta so no parent scroll will use it.
                         frame_state.scroll_delta[d] = 0.0;
                         state.scroll_stuck_to_end[d] = false;
+                        scrolled = true;
                     }
                 }
             }
+            if scrolled {
+                ui.ctx().consume_events(|event| {
+                    matches!(event, Event::Scroll(_) | Event::MouseWheel { .. })
+                });
+            }
         }
 
-        let show_scroll_this_frame = [
-            content_is_too_small[0] || always_show_scroll,
-            content_is_too_small[1] || always_show_scroll,
-        ];
+        let show_scroll_this_frame = match scroll_bar_visibility {
+            ScrollBarVisibility::AlwaysHidden => [false; 2],
+            ScrollBarVisibility::VisibleWhenNeeded => content_is_too_small,
+            ScrollBarVisibility::AlwaysVisible => [true; 2],
+        };
 
         let max_scroll_bar_width = max_scroll_bar_width_with_margin(ui);
 
@@ -644,7 +773,26 @@ impl Prepared {
             };
             let response = ui.interact(outer_scroll_rect, interact_id, sense);
 
-            if let Some(pointer_pos) = response.interact_pointer_pos() {
+            let pages_instead = |pointer_pos: Pos2| {
+                click_to_page
+                    && state.scroll_start_offset_from_top_left[d].is_none()
+                    && !handle_rect.contains(pointer_pos)
+            };
+
+            if let Some(pointer_pos) = response
+                .interact_pointer_pos()
+                .filter(|&pos| pages_instead(pos))
+            {
+                if response.clicked() {
+                    let page = inner_rect.size()[d];
+                    if pointer_pos[d] < handle_rect.min[d] {
+                        state.offset[d] -= page;
+                    } else {
+                        state.offset[d] += page;
+                    }
+                    state.scroll_stuck_to_end[d] = false;
+                }
+            } else if let Some(pointer_pos) = response.interact_pointer_pos() {
                 let scroll_start_offset_from_top_left = state.scroll_start_offset_from_top_left[d]
                     .get_or_insert_with(|| {
                         if handle_rect.contains(pointer_pos) {
@@ -672,6 +820,14 @@ impl Prepared {
             state.offset[d] = state.offset[d].min(max_offset[d]);
 
             if state.offset[d] != unbounded_offset {
+                if state.vel[d] != 0.0 {
+                    // A fling hit the end: bounce a bit.
+                    state.overscroll[d] +=
+                        OVERSCROLL_RESISTANCE * (unbounded_offset - state.offset[d]);
+                    state.overscroll[d] =
+                        state.overscroll[d].clamp(-MAX_OVERSCROLL, MAX_OVERSCROLL);
+                    ui.ctx().request_repaint();
+                }
                 state.vel[d] = 0.0;
             }
 
@@ -708,16 +864,28 @@ impl Prepared {
                 } else {
                     &ui.style().visuals.widgets.inactive
                 };
+                let scroll_bar_visuals = ui.visuals().scroll_bar;
+
+                // Draw the bar thin against the outer edge until the user interacts with it:
+                let mut track_rect = outer_scroll_rect;
+                if let Some(dormant_width) = scroll_bar_visuals.dormant_width {
+                    let expanded = response.hovered() || response.dragged();
+                    let expansion = ui.ctx().animate_bool(id.with(d).with("expand"), expanded);
+                    let full_width = track_rect.size()[1 - d];
+                    let width = lerp(dormant_width.min(full_width)..=full_width, expansion);
+                    track_rect.min[1 - d] = track_rect.max[1 - d] - width;
+                    handle_rect.min[1 - d] = track_rect.min[1 - d];
+                }
 
                 ui.painter().add(epaint::Shape::rect_filled(
-                    outer_scroll_rect,
-                    visuals.corner_radius,
-                    ui.visuals().extreme_bg_color,
+                    track_rect,
+                    scroll_bar_visuals.corner_radius,
+                    scroll_bar_visuals.track_fill,
                 ));
 
                 ui.painter().add(epaint::Shape::rect_filled(
                     handle_rect,
-                    visuals.corner_radius,
+                    scroll_bar_visuals.corner_radius,
                     visuals.bg_fill,
                 ));
             }
@@ -748,6 +916,40 @@ impl Prepared {
         state.show_scroll = show_scroll_this_frame;
 
         state.store(ui.ctx(), id);
+
+        (state.offset, inner_rect)
+    }
+}
+
+/// How much of a drag past the end of the content that is applied as overscroll.
+const OVERSCROLL_RESISTANCE: f32 = 0.5;
+
+/// The content can never be pulled further past its end than this many points.
+const MAX_OVERSCROLL: f32 = 100.0;
+
+/// How fast the overscroll springs back when released, in fractions per second.
+const OVERSCROLL_SPRING_BACK_SPEED: f32 = 10.0;
+
+/// Apply a drag of `delta` points to the scroll `offset`.
+///
+/// Any part of the drag that would take the offset outside of `0..=max_offset`
+/// is instead applied to `overscroll`, with increasing resistance (rubber banding).
+fn drag_with_overscroll(offset: &mut f32, overscroll: &mut f32, mut delta: f32, max_offset: f32) {
+    // First undo any overscroll we already have:
+    if *overscroll != 0.0 && delta.signum() != overscroll.signum() {
+        let undo = delta.abs().at_most(overscroll.abs()) * delta.signum();
+        *overscroll += undo;
+        delta -= undo;
+    }
+
+    let new_offset = *offset + delta;
+    *offset = new_offset.clamp(0.0, max_offset.at_least(0.0));
+
+    let excess = new_offset - *offset;
+    if excess != 0.0 {
+        // The further we pull, the harder it gets:
+        let resistance = OVERSCROLL_RESISTANCE * (1.0 - overscroll.abs() / MAX_OVERSCROLL);
+        *overscroll += excess * resistance.at_least(0.0);
     }
 }
 
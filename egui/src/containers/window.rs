@@ -30,12 +30,14 @@ pub struct Window<'open> {
     scroll: ScrollArea,
     collapsible: bool,
     with_title_bar: bool,
+    animated: bool,
 }
 
 impl<'open> Window<'open> {
     /// The window title is used as a unique [`Id`] and must be unique, and should not change.
     /// This is true even if you disable the title bar with `.title_bar(false)`.
     /// If you need a changing title, you must call `window.id(…)` with a fixed id.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn new(title: impl Into<WidgetText>) -> Self {
         let title = title.into().fallback_text_style(TextStyle::Heading);
         let area = Area::new(title.text());
@@ -51,6 +53,7 @@ impl<'open> Window<'open> {
             scroll: ScrollArea::neither(),
             collapsible: true,
             with_title_bar: true,
+            animated: false,
         }
     }
 
@@ -195,6 +198,19 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Fade and slide the window in when it is opened, and out when it is closed
+    /// (see [`Area::show_animated`]). `false` by default.
+    ///
+    /// NOTE: the contents are still added for a few frames after `open` turns `false`
+    /// (though [`Self::show`] returns `None`), so don't do things there that should only happen while the window is open.
+    ///
+    /// The pointer goes through the window until it has finished opening.
+    /// With [`Context::reduce_motion`] the window appears and disappears at once.
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
     /// Not resizable, just takes the size of its contents.
     /// Also disabled scrolling.
     /// Text will not wrap, but will instead make your window width expand.
@@ -232,6 +248,8 @@ impl<'open> Window<'open> {
 impl<'open> Window<'open> {
     /// Returns `None` if the window is not open (if [`Window::open`] was called with `&mut false`).
     /// Returns `Some(InnerResponse { inner: None })` if the window is collapsed.
+    ///
+    /// With [`Self::animated`], `add_contents` is still called for a few frames after the window is closed.
     #[inline]
     pub fn show<R>(
         self,
@@ -255,19 +273,41 @@ impl<'open> Window<'open> {
             scroll,
             collapsible,
             with_title_bar,
+            animated,
         } = self;
 
         let frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
 
         let is_open = !matches!(open, Some(false)) || ctx.memory().everything_is_visible();
-        area.show_open_close_animation(ctx, &frame, is_open);
+        record_open_close_event(ctx, &area, &title, is_open);
 
-        if !is_open {
+        let openness = if animated {
+            area.openness(ctx, is_open)
+        } else {
+            area.show_open_close_animation(ctx, &frame, is_open);
+            if is_open {
+                1.0
+            } else {
+                0.0
+            }
+        };
+        if openness <= 0.0 {
             return None;
         }
+        // Keep showing a closed window until it has faded out. It isn't painted
+        // where it will be while it slides, so let the pointer through until it is fully open:
+        let (area, resize) = if openness >= 1.0 {
+            (area, resize)
+        } else {
+            (area.input_transparent(true), resize.resizable(false))
+        };
 
         let area_id = area.id;
         let area_layer_id = area.layer();
+        let accessibility_node = ctx.memory().options.accessibility_tree.then(|| {
+            let index = ctx.output().accessibility_nodes.len();
+            (index, title.text().to_owned())
+        });
         let resize_id = area_id.with("resize");
         let collapsing_id = area_id.with("collapsing");
 
@@ -353,7 +393,7 @@ impl<'open> Window<'open> {
                         }
 
                         if scroll.has_any_bar() {
-                            scroll.show(ui, add_contents)
+                            scroll.show(ui, add_contents).inner
                         } else {
                             add_contents(ui)
                         }
@@ -404,6 +444,22 @@ impl<'open> Window<'open> {
             .min;
 
         let full_response = area.end(ctx, area_content_ui);
+        area::open_close_transition(ctx, area_layer_id, openness);
+        if !is_open {
+            return None;
+        }
+
+        if let Some((index, title)) = accessibility_node {
+            // Put the window before its contents:
+            let node = crate::output::AccessibilityNode {
+                id: area_id,
+                parent: None,
+                info: WidgetInfo::labeled(WidgetType::Window, title),
+                focused: false,
+                rect: full_response.rect,
+            };
+            ctx.output().accessibility_nodes.insert(index, node);
+        }
 
         let inner_response = InnerResponse {
             inner: content_inner,
@@ -413,6 +469,29 @@ impl<'open> Window<'open> {
     }
 }
 
+/// Emit [`crate::output::UiEvent::WindowOpened`] and [`crate::output::UiEvent::WindowClosed`].
+fn record_open_close_event(ctx: &CtxRef, area: &Area, title: &WidgetText, is_open: bool) {
+    if !ctx.memory().options.record_ui_events {
+        return;
+    }
+
+    let was_open = ctx.memory().areas.visible_last_frame(&area.layer());
+    let event = if is_open && !was_open {
+        crate::output::UiEvent::WindowOpened {
+            id: area.id,
+            title: title.text().to_owned(),
+        }
+    } else if !is_open && was_open {
+        crate::output::UiEvent::WindowClosed {
+            id: area.id,
+            title: title.text().to_owned(),
+        }
+    } else {
+        return;
+    };
+    ctx.output().ui_events.push(event);
+}
+
 fn paint_resize_corner(
     ui: &mut Ui,
     possible: &PossibleInteractions,
@@ -886,3 +965,85 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+#[cfg(test)]
+#[test]
+fn test_window_close_transition() {
+    use crate::RawInput;
+    use epaint::ClippedShape;
+
+    let mut ctx = CtxRef::default();
+    let run = |ctx: &mut CtxRef, time: f64, mut open: bool, animated: bool| {
+        let raw_input = RawInput {
+            time: Some(time),
+            predicted_dt: 0.0,
+            ..Default::default()
+        };
+        let mut shown = false;
+        let mut returned = false;
+        let (_, shapes) = ctx.run(raw_input, |ctx| {
+            returned = Window::new("Window")
+                .open(&mut open)
+                .animated(animated)
+                .show(ctx, |ui| {
+                    shown = true;
+                    ui.label("Contents");
+                })
+                .is_some();
+        });
+        let text_alpha = shapes
+            .iter()
+            .find_map(|ClippedShape(_, shape)| match shape {
+                Shape::Text(text) if text.galley.text() == "Contents" => text
+                    .galley
+                    .rows
+                    .iter()
+                    .flat_map(|row| &row.visuals.mesh.vertices)
+                    .map(|vertex| vertex.color.a())
+                    .max(),
+                _ => None,
+            });
+        (shown, returned, text_alpha)
+    };
+
+    let (shown, returned, open_alpha) = run(&mut ctx, 0.0, true, true);
+    assert!(shown && returned);
+    let open_alpha = open_alpha.unwrap();
+
+    let time = ctx.style().animation_time as f64;
+    let (shown, returned, alpha) = run(&mut ctx, 1.0, false, true);
+    assert!(shown, "still shown while fading out");
+    assert!(!returned);
+    assert!(alpha.unwrap() <= open_alpha);
+    let (_, _, alpha) = run(&mut ctx, 1.0 + 0.5 * time, false, true);
+    assert!(alpha.unwrap() < open_alpha, "fading out");
+
+    let (shown, returned, _) = run(&mut ctx, 2.0, false, true);
+    assert!(!shown && !returned, "gone once faded out");
+
+    // The window isn't painted where it is while it slides in, so the pointer goes through it:
+    let window_layer = LayerId::new(Order::Middle, Id::new("Window"));
+    run(&mut ctx, 3.0, true, true);
+    run(&mut ctx, 3.0 + 0.5 * time, true, true);
+    let center = ctx
+        .memory()
+        .areas
+        .get(window_layer.id)
+        .unwrap()
+        .rect()
+        .center();
+    assert_ne!(ctx.layer_id_at(center), Some(window_layer));
+    run(&mut ctx, 4.0, true, true);
+    assert_eq!(ctx.layer_id_at(center), Some(window_layer));
+
+    // With reduced motion the window disappears at once:
+    ctx.memory().options.reduce_motion = true;
+    let (shown, _, _) = run(&mut ctx, 5.0, false, true);
+    assert!(!shown);
+    ctx.memory().options.reduce_motion = false;
+
+    // Windows that aren't animated don't add their contents after closing:
+    run(&mut ctx, 6.0, true, false);
+    let (shown, returned, _) = run(&mut ctx, 7.0, false, false);
+    assert!(!shown && !returned);
+}
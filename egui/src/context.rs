@@ -8,11 +8,13 @@ use std::sync::{
 use crate::{
     animation_manager::AnimationManager,
     data::output::Output,
-    frame_state::FrameState,
+    frame_state::{FocusRing, FrameState},
     input_state::*,
     layers::GraphicLayers,
     menu::ContextMenuSystem,
     mutex::{Mutex, MutexGuard},
+    plugin::Plugins,
+    profiler::{Profiler, Stopwatch},
     *,
 };
 use epaint::{stats::*, text::Fonts, *};
@@ -143,17 +145,53 @@ impl CtxRef {
     /// let (output, shapes) = ctx.end_frame();
     /// // handle output, paint shapes
     /// ```
-    pub fn begin_frame(&mut self, new_input: RawInput) {
+    pub fn begin_frame(&mut self, mut new_input: RawInput) {
+        let stopwatch = Stopwatch::start();
+        self.with_plugins(|plugins| plugins.on_raw_input(&mut new_input));
+
         let mut self_: Context = (*self.0).clone();
         self_.begin_frame_mut(new_input);
         *self = Self(Arc::new(self_));
+
+        self.with_plugins(|plugins| plugins.on_begin_frame(self));
+        self.profiler.lock().begin_frame(&stopwatch);
+    }
+
+    /// Call at the end of each frame.
+    /// Returns what has happened this frame [`crate::Output`] as well as what you need to paint.
+    /// You can transform the returned shapes into triangles with a call to [`Context::tessellate`].
+    ///
+    /// This calls [`ContextPlugin::on_end_frame`] for all plugins before ending the frame.
+    #[must_use]
+    pub fn end_frame(&self) -> (Output, Vec<ClippedShape>) {
+        self.with_plugins(|plugins| plugins.on_end_frame(self));
+        self.0.end_frame()
+    }
+
+    /// Register a [`ContextPlugin`] that will be called at the start and end of each frame.
+    ///
+    /// Plugins are called in the order they were added.
+    pub fn add_plugin(&self, plugin: impl ContextPlugin) {
+        self.plugins.lock().add(Box::new(plugin));
+    }
+
+    /// Call some plugin hooks without holding the lock,
+    /// so that the plugins can use the context (and even add new plugins).
+    fn with_plugins(&self, call_hooks: impl FnOnce(&mut Plugins)) {
+        if self.plugins.lock().is_empty() {
+            return;
+        }
+        let mut plugins = std::mem::take(&mut *self.plugins.lock());
+        call_hooks(&mut plugins);
+        self.plugins.lock().restore(plugins);
     }
 
     // ---------------------------------------------------------------------
 
-    /// If the given [`Id`] is not unique, an error will be printed at the given position.
+    /// If the given [`Id`] is not unique, the clash is handled according to `policy`
+    /// (see [`crate::IdClashPolicy`]).
     /// Call this for [`Id`]:s that need interaction or persistence.
-    pub(crate) fn register_interaction_id(&self, id: Id, new_rect: Rect) {
+    pub(crate) fn register_interaction_id(&self, id: Id, new_rect: Rect, policy: IdClashPolicy) {
         let prev_rect = self.frame_state().used_ids.insert(id, new_rect);
         if let Some(prev_rect) = prev_rect {
             // it is ok to reuse the same ID for e.g. a frame around a widget,
@@ -164,34 +202,80 @@ impl CtxRef {
                 return;
             }
 
-            let show_error = |pos: Pos2, text: String| {
-                let painter = self.debug_painter();
-                let rect = painter.error(pos, text);
-                if let Some(pointer_pos) = self.input.pointer.hover_pos() {
-                    if rect.contains(pointer_pos) {
-                        painter.error(
-                            rect.left_bottom() + vec2(2.0, 4.0),
-                            "ID clashes happens when things like Windows or CollapsingHeaders share names,\n\
-                             or when things like ScrollAreas and Resize areas aren't given unique id_source:s.",
-                        );
-                    }
-                }
-            };
+            self.frame_state().id_clashes.push(IdClash {
+                id,
+                first_rect: prev_rect,
+                second_rect: new_rect,
+            });
 
-            let id_str = id.short_debug_format();
+            let mut id_str = id.short_debug_format();
+            let locations = id.source_locations();
+            if !locations.is_empty() {
+                let locations: Vec<String> = locations
+                    .iter()
+                    .map(|location| format!("{}:{}", location.file(), location.line()))
+                    .collect();
+                id_str += &format!(", created at {}", locations.join(" and "));
+            }
 
-            if prev_rect.min.distance(new_rect.min) < 4.0 {
-                show_error(new_rect.min, format!("Double use of ID {}", id_str));
-            } else {
-                show_error(prev_rect.min, format!("First use of ID {}", id_str));
-                show_error(new_rect.min, format!("Second use of ID {}", id_str));
+            match policy {
+                IdClashPolicy::Ignore => {}
+                IdClashPolicy::PaintOverlay => self.paint_id_clash(prev_rect, new_rect, &id_str),
+                IdClashPolicy::Log => {
+                    let message = format!(
+                        "Double use of ID {} at {:?} and {:?}",
+                        id_str, prev_rect, new_rect
+                    );
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("{}", message);
+                    #[cfg(not(feature = "tracing"))]
+                    eprintln!("egui: {}", message);
+                }
+                IdClashPolicy::Panic => {
+                    panic!(
+                        "Double use of ID {} at {:?} and {:?}",
+                        id_str, prev_rect, new_rect
+                    );
+                }
             }
         }
     }
 
+    fn paint_id_clash(&self, prev_rect: Rect, new_rect: Rect, id_str: &str) {
+        let show_error = |pos: Pos2, text: String| {
+            let painter = self.debug_painter();
+            let rect = painter.error(pos, text);
+            if let Some(pointer_pos) = self.input.pointer.hover_pos() {
+                if rect.contains(pointer_pos) {
+                    painter.error(
+                        rect.left_bottom() + vec2(2.0, 4.0),
+                        "ID clashes happens when things like Windows or CollapsingHeaders share names,\n\
+                         or when things like ScrollAreas and Resize areas aren't given unique id_source:s.",
+                    );
+                }
+            }
+        };
+
+        if prev_rect.min.distance(new_rect.min) < 4.0 {
+            show_error(new_rect.min, format!("Double use of ID {}", id_str));
+        } else {
+            show_error(prev_rect.min, format!("First use of ID {}", id_str));
+            show_error(new_rect.min, format!("Second use of ID {}", id_str));
+        }
+    }
+
+    /// The [`Id`] clashes detected so far this frame (or in the last frame, when called between frames).
+    ///
+    /// They are also handled according to `Memory::options.id_clash_policy`.
+    pub fn id_clashes(&self) -> Vec<IdClash> {
+        self.frame_state().id_clashes.clone()
+    }
+
     // ---------------------------------------------------------------------
 
     /// Use `ui.interact` instead
+    ///
+    /// If there is a `hit_shape`, the pointer must also be inside of it to hover the widget.
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn interact(
         &self,
@@ -202,6 +286,7 @@ impl CtxRef {
         rect: Rect,
         sense: Sense,
         enabled: bool,
+        hit_shape: Option<&HitShape>,
     ) -> Response {
         let gap = 0.5; // Just to make sure we don't accidentally hover two things at once (a small eps should be sufficient).
 
@@ -211,7 +296,14 @@ impl CtxRef {
                 .at_least(Vec2::splat(0.0))
                 .at_most(Vec2::splat(5.0)),
         ); // make it easier to click
-        let hovered = self.rect_contains_pointer(layer_id, clip_rect.intersect(interact_rect));
+        let mut hovered = self.rect_contains_pointer(layer_id, clip_rect.intersect(interact_rect));
+        if let Some(hit_shape) = hit_shape {
+            hovered &= self
+                .input
+                .pointer
+                .interact_pos()
+                .map_or(false, |pos| hit_shape.contains(pos));
+        }
         self.interact_with_hovered(layer_id, id, rect, sense, enabled, hovered)
     }
 
@@ -225,7 +317,12 @@ impl CtxRef {
         enabled: bool,
         hovered: bool,
     ) -> Response {
-        let hovered = hovered && enabled; // can't even hover disabled widgets
+        let contains_pointer = hovered;
+        let hovered = hovered && enabled; // can't hover disabled widgets, but see `Response::contains_pointer`
+
+        if hovered && sense.interactive() && layer_id.allow_interaction() {
+            self.frame_state().hovered_widget = Some((id, WidgetType::Other));
+        }
 
         let mut response = Response {
             ctx: self.clone(),
@@ -234,9 +331,11 @@ impl CtxRef {
             rect,
             sense,
             enabled,
+            contains_pointer,
             hovered,
             clicked: Default::default(),
             double_clicked: Default::default(),
+            long_touched: false,
             dragged: false,
             drag_released: false,
             is_pointer_button_down_on: false,
@@ -257,18 +356,24 @@ impl CtxRef {
             sense.interactive() || sense.focusable && memory.options.screen_reader;
 
         if interested_in_focus {
-            memory.interested_in_focus(id);
+            memory.interested_in_focus(id, rect);
         }
 
         if sense.click
             && memory.has_focus(response.id)
-            && (self.input().key_pressed(Key::Space) || self.input().key_pressed(Key::Enter))
+            && (self.input().key_pressed(Key::Space)
+                || self.input().key_pressed(Key::Enter)
+                || self.input().gamepad_button_pressed(GamepadButton::A))
         {
-            // Space/enter works like a primary click for e.g. selected buttons
+            // Space/enter/gamepad A works like a primary click for e.g. selected buttons
             response.clicked[PointerButton::Primary as usize] = true;
         }
 
-        self.register_interaction_id(id, rect);
+        // Painting an id clash reads the style, which locks `memory`:
+        let id_clash_policy = memory.options.id_clash_policy;
+        drop(memory);
+        self.register_interaction_id(id, rect, id_clash_policy);
+        let mut memory = self.memory();
 
         if sense.click || sense.drag {
             memory.interaction.click_interest |= hovered && sense.click;
@@ -307,6 +412,9 @@ impl CtxRef {
                             }
                         }
                     }
+                    PointerEvent::LongPress(_) => {
+                        response.long_touched = hovered && response.is_pointer_button_down_on;
+                    }
                     PointerEvent::Released(click) => {
                         response.drag_released = response.dragged;
                         response.dragged = false;
@@ -340,6 +448,17 @@ impl CtxRef {
             // e.g.: remove focus from a widget when you drag something else
             memory.stop_text_input();
         }
+        drop(memory);
+
+        // The presses and releases on this widget, and the movement while it is dragged, were used:
+        let pressed_on = response.is_pointer_button_down_on || response.drag_released;
+        if pressed_on || (hovered && (sense.click || sense.drag)) {
+            self.consume_events(|event| match event {
+                Event::PointerButton { .. } => true,
+                Event::PointerMoved(_) => pressed_on,
+                _ => false,
+            });
+        }
 
         response
     }
@@ -392,6 +511,22 @@ pub struct Context {
     output: Arc<Mutex<Output>>,
 
     paint_stats: Arc<Mutex<PaintStats>>,
+    layer_paint_stats: Arc<Mutex<Vec<crate::layers::LayerPaintStats>>>,
+    profiler: Arc<Mutex<Profiler>>,
+    tessellation_cache: Arc<Mutex<crate::tessellation_cache::TessellationCache>>,
+
+    /// The emptied shape list from the last call to [`Self::tessellate`], reused by [`Self::end_frame`].
+    recycled_shapes: Arc<Mutex<Vec<ClippedShape>>>,
+    /// Meshes given back with [`Self::recycle_meshes`], to be tessellated into by [`Self::tessellate`].
+    recycled_meshes: Arc<Mutex<Vec<Mesh>>>,
+
+    plugins: Arc<Mutex<Plugins>>,
+
+    /// The widgets of the last frame, if `Memory::options.record_widget_tree` is set.
+    widget_tree: Arc<Mutex<Arc<crate::WidgetTree>>>,
+
+    /// `Some` while recording.
+    input_recording: Arc<Mutex<Option<crate::InputRecording>>>,
 
     /// While positive, keep requesting repaints. Decrement at the end of each frame.
     repaint_requests: AtomicU32,
@@ -408,6 +543,14 @@ impl Clone for Context {
             graphics: self.graphics.clone(),
             output: self.output.clone(),
             paint_stats: self.paint_stats.clone(),
+            layer_paint_stats: self.layer_paint_stats.clone(),
+            profiler: self.profiler.clone(),
+            tessellation_cache: self.tessellation_cache.clone(),
+            recycled_shapes: self.recycled_shapes.clone(),
+            recycled_meshes: self.recycled_meshes.clone(),
+            plugins: self.plugins.clone(),
+            widget_tree: self.widget_tree.clone(),
+            input_recording: self.input_recording.clone(),
             repaint_requests: self.repaint_requests.load(SeqCst).into(),
             context_menu_system: self.context_menu_system.clone(),
         }
@@ -428,6 +571,21 @@ impl Context {
         self.memory.lock()
     }
 
+    /// Forget the state stored for this [`Id`], e.g. when a document shown with it was deleted.
+    ///
+    /// See [`Memory::forget_id`].
+    pub fn forget_id(&self, id: Id) {
+        self.memory().forget_id(id);
+    }
+
+    /// Forget the state of type `T` of all widgets,
+    /// as stored in [`Memory::data_temp`] and [`Memory::data_persisted`].
+    pub fn forget_state_of<T: 'static>(&self) {
+        let mut memory = self.memory();
+        memory.data_temp.remove_by_type::<T>();
+        memory.data_persisted.remove_by_type::<T>();
+    }
+
     pub(crate) fn context_menu_system(&self) -> MutexGuard<'_, ContextMenuSystem> {
         self.context_menu_system.lock()
     }
@@ -454,11 +612,184 @@ impl Context {
         self.repaint_requests.store(times_to_repaint, SeqCst);
     }
 
+    /// Ask for a repaint at the given time (in [`InputState::time`] seconds),
+    /// e.g. to blink a text cursor or to hide a notification after a timeout.
+    ///
+    /// Unlike [`Self::request_repaint`] this doesn't keep repainting every frame until then:
+    /// all the requests of a frame are coalesced into the earliest one,
+    /// which is reported in [`Output::repaint_after`].
+    /// The request only holds for this frame, so call this again each frame for as long as you need it.
+    pub fn request_repaint_at(&self, time: f64) {
+        let mut frame_state = self.frame_state();
+        let repaint_at = frame_state.repaint_at.get_or_insert(time);
+        *repaint_at = repaint_at.min(time);
+    }
+
+    /// Ask for a repaint in this many seconds, see [`Self::request_repaint_at`].
+    pub fn request_repaint_after(&self, seconds: f32) {
+        self.request_repaint_at(self.input.time + seconds as f64);
+    }
+
+    /// Ask the screen reader (or other assistive technology) to read out `text`,
+    /// e.g. to report the result of a background task ("3 files imported").
+    ///
+    /// This queues an [`crate::output::OutputEvent::Announcement`] in [`Output::events`].
+    /// Use [`crate::output::Priority::Assertive`] only for urgent messages, as it interrupts the user.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn announce(&self, text: impl ToString, priority: crate::output::Priority) {
+        self.output()
+            .events
+            .push(crate::output::OutputEvent::Announcement {
+                text: text.to_string(),
+                priority,
+            });
+    }
+
+    /// Is `Memory::options.power_save` on, and the user currently not interacting with egui?
+    ///
+    /// When this is true, repaints are throttled (see [`Output::repaint_after`]),
+    /// so an integration may also want to lower its own tick rate.
+    pub fn is_power_saving(&self) -> bool {
+        self.memory().options.power_save && !self.input.wants_repaint()
+    }
+
     #[inline(always)]
     pub fn input(&self) -> &InputState {
         &self.input
     }
 
+    /// Was the given shortcut pressed this frame, and not yet handled by an earlier call to this?
+    ///
+    /// This returns `true` at most once per frame for each shortcut,
+    /// so that e.g. a menu item and a global handler for the same shortcut don't both trigger.
+    /// The key events themselves are still visible in [`Self::input`].
+    ///
+    /// See also [`Self::consume_key`].
+    pub fn consume_shortcut(&self, shortcut: &KeyboardShortcut) -> bool {
+        self.consume_key(shortcut.modifiers, shortcut.key) > 0
+    }
+
+    /// Mark the presses of the given key (with exactly these modifiers) as handled,
+    /// and return how many of them hadn't been handled already this frame.
+    ///
+    /// Handled events are not reported in [`Output::unconsumed_events`].
+    /// They are still visible in [`Self::input`].
+    pub fn consume_key(&self, modifiers: Modifiers, key: Key) -> usize {
+        self.consume_events(|event| crate::input_state::is_key_press(event, modifiers, key))
+    }
+
+    /// Mark event number `index` of [`InputState::events`] as handled,
+    /// and return `true` if it hadn't been handled already this frame.
+    ///
+    /// Handled events are not reported in [`Output::unconsumed_events`].
+    /// They are still visible in [`Self::input`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let events = ctx.input().events.clone();
+    /// for (index, event) in events.iter().enumerate() {
+    ///     if let egui::Event::Text(text) = event {
+    ///         if text == " " && ctx.consume_event(index) {
+    ///             // jump…
+    ///         }
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub fn consume_event(&self, index: usize) -> bool {
+        match self.frame_state().consumed_events.get_mut(index) {
+            Some(consumed) => !std::mem::replace(consumed, true),
+            None => false,
+        }
+    }
+
+    /// Has event number `index` of [`InputState::events`] been handled this frame,
+    /// with e.g. [`Self::consume_event`], or by a widget using the pointer?
+    pub fn is_event_consumed(&self, index: usize) -> bool {
+        self.frame_state()
+            .consumed_events
+            .get(index)
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Mark all events matching the predicate as handled, and return how many weren't already.
+    pub(crate) fn consume_events(&self, mut predicate: impl FnMut(&Event) -> bool) -> usize {
+        let mut frame_state = self.frame_state.lock();
+        let mut count = 0;
+        for (event, consumed) in self
+            .input
+            .events
+            .iter()
+            .zip(&mut frame_state.consumed_events)
+        {
+            if !*consumed && predicate(event) {
+                *consumed = true;
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Tell egui about a keyboard shortcut your app handles, so that it is listed in the [`crate::ShortcutCheatSheet`].
+    ///
+    /// Call this every frame, where the shortcut is handled, so the list never gets out of date.
+    /// [`crate::Button::shortcut`] does this for you.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// const ZOOM_IN: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::PlusEquals);
+    ///
+    /// ctx.register_shortcut(ZOOM_IN, "View", "Zoom in");
+    /// if ctx.consume_shortcut(&ZOOM_IN) {
+    ///     // zoom in…
+    /// }
+    /// # });
+    /// ```
+    pub fn register_shortcut(
+        &self,
+        shortcut: KeyboardShortcut,
+        category: impl Into<String>,
+        description: impl Into<String>,
+    ) {
+        let info = ShortcutInfo {
+            shortcut,
+            category: category.into(),
+            description: description.into(),
+        };
+        let mut frame_state = self.frame_state();
+        if !frame_state.shortcuts.contains(&info) {
+            frame_state.shortcuts.push(info);
+        }
+    }
+
+    /// The shortcuts registered with [`Self::register_shortcut`] so far this frame
+    /// (or in the last frame, when called between frames), in the order they were registered.
+    pub fn registered_shortcuts(&self) -> Vec<ShortcutInfo> {
+        self.frame_state().shortcuts.clone()
+    }
+
+    /// Text for the given shortcut, as is customary on the current OS (see [`Self::os`]),
+    /// e.g. `"Ctrl+S"` or `"⌘S"`. Use this for menu items and tooltips.
+    pub fn format_shortcut(&self, shortcut: &KeyboardShortcut) -> String {
+        shortcut.format(self.os().is_mac())
+    }
+
+    /// The operating system egui is running on, as far as egui knows.
+    ///
+    /// This defaults to the OS egui was compiled for (or [`OperatingSystem::Unknown`] on the web).
+    pub fn os(&self) -> OperatingSystem {
+        self.memory().os
+    }
+
+    /// Tell egui which operating system it is running on.
+    ///
+    /// This is set automatically on native. On the web, the integration should call this.
+    pub fn set_os(&self, os: OperatingSystem) {
+        self.memory().os = os;
+    }
+
     /// Not valid until first call to [`CtxRef::run()`].
     /// That's because since we don't know the proper `pixels_per_point` until then.
     pub fn fonts(&self) -> &Fonts {
@@ -493,6 +824,18 @@ impl Context {
         self.memory().new_font_definitions = Some(font_definitions);
     }
 
+    /// The font definitions that will be used next frame.
+    #[cfg(feature = "theme")]
+    pub(crate) fn font_definitions(&self) -> FontDefinitions {
+        if let Some(font_definitions) = &self.memory().new_font_definitions {
+            return font_definitions.clone();
+        }
+        self.fonts
+            .as_ref()
+            .map(|fonts| fonts.definitions().clone())
+            .unwrap_or_default()
+    }
+
     /// The [`Style`] used by all subsequent windows, panels etc.
     pub fn style(&self) -> Arc<Style> {
         self.memory().options.style.clone()
@@ -526,6 +869,115 @@ impl Context {
         std::sync::Arc::make_mut(&mut self.memory().options.style).visuals = visuals;
     }
 
+    /// Use the style and fonts of the given theme.
+    ///
+    /// The fonts will become active at the start of the next frame.
+    ///
+    /// # Errors
+    /// If the theme refers to fonts that haven't been loaded,
+    /// in which case the current style and fonts are left unchanged.
+    #[cfg(feature = "theme")]
+    pub fn set_theme(
+        &self,
+        theme: crate::theme::ThemeFile,
+    ) -> Result<(), crate::theme::ThemeError> {
+        if let Some(theme_fonts) = &theme.fonts {
+            let mut font_definitions = self.font_definitions();
+            theme_fonts.apply_to(&mut font_definitions)?;
+            self.set_fonts(font_definitions);
+        }
+        self.set_style(theme.style);
+        Ok(())
+    }
+
+    /// Load a theme from RON, and use it (see [`Self::set_theme`]).
+    ///
+    /// See [`crate::theme`] for the format.
+    ///
+    /// # Errors
+    /// If the theme can't be parsed or refers to fonts that haven't been loaded,
+    /// in which case the current style and fonts are left unchanged.
+    #[cfg(feature = "theme")]
+    pub fn set_style_from_theme(&self, theme: &str) -> Result<(), crate::theme::ThemeError> {
+        self.set_theme(crate::theme::ThemeFile::from_ron(theme)?)
+    }
+
+    /// Whether the operating system is in dark or light mode, if the integration reports it
+    /// (see [`RawInput::system_theme`]).
+    pub fn system_theme(&self) -> Option<Theme> {
+        self.memory().system_theme
+    }
+
+    /// See [`Self::set_theme_preference`].
+    pub fn theme_preference(&self) -> ThemePreference {
+        self.memory().options.theme_preference
+    }
+
+    /// Choose between dark and light mode, or follow the operating system.
+    ///
+    /// With [`ThemePreference::FollowSystem`], the [`Visuals`] will switch automatically
+    /// whenever the integration reports a change in [`RawInput::system_theme`].
+    ///
+    /// When switching, the visuals are replaced with [`Theme::default_visuals`].
+    /// If the visuals are already in the right mode (see [`Visuals::dark_mode`]) they are left as is,
+    /// so any customizations are kept.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// ctx.set_theme_preference(egui::ThemePreference::FollowSystem);
+    /// ```
+    pub fn set_theme_preference(&self, theme_preference: ThemePreference) {
+        self.memory().options.theme_preference = theme_preference;
+        self.apply_theme_preference();
+    }
+
+    fn apply_theme_preference(&self) {
+        if self.is_high_contrast() {
+            return;
+        }
+        let theme = self.theme_preference().theme(self.system_theme());
+        if let Some(theme) = theme {
+            let dark_mode = theme == Theme::Dark;
+            if self.style().visuals.dark_mode != dark_mode {
+                self.set_visuals(theme.default_visuals());
+            }
+        }
+    }
+
+    /// See [`Self::set_high_contrast`].
+    pub fn is_high_contrast(&self) -> bool {
+        self.memory().options.high_contrast
+    }
+
+    /// Switch to (or from) [`Visuals::high_contrast`].
+    ///
+    /// While this is on, the [`Self::theme_preference`] is ignored.
+    /// When turning it off, the visuals go back to the default for the theme preference.
+    pub fn set_high_contrast(&self, high_contrast: bool) {
+        if high_contrast == self.is_high_contrast() {
+            return;
+        }
+        self.memory().options.high_contrast = high_contrast;
+        if high_contrast {
+            self.set_visuals(Visuals::high_contrast());
+        } else {
+            let theme = self
+                .theme_preference()
+                .theme(self.system_theme())
+                .unwrap_or(Theme::Dark);
+            self.set_visuals(theme.default_visuals());
+        }
+    }
+
+    /// Should animations be skipped?
+    ///
+    /// This is `true` if the operating system asks for reduced motion
+    /// (see [`RawInput::prefers_reduced_motion`]) or if `Memory::options.reduce_motion` is set.
+    pub fn reduce_motion(&self) -> bool {
+        let memory = self.memory();
+        memory.options.reduce_motion || memory.system_prefers_reduced_motion
+    }
+
     /// The number of physical pixels for each logical point.
     #[inline(always)]
     pub fn pixels_per_point(&self) -> f32 {
@@ -537,6 +989,9 @@ impl Context {
     ///
     /// Note that this may be overwritten by input from the integration via [`RawInput::pixels_per_point`].
     /// For instance, when using `egui_web` the browsers native zoom level will always be used.
+    ///
+    /// This will be multiplied by [`Self::zoom_factor`].
+    /// To let the user scale the ui, use [`Self::set_zoom_factor`] instead.
     pub fn set_pixels_per_point(&self, pixels_per_point: f32) {
         if pixels_per_point != self.pixels_per_point() {
             self.request_repaint();
@@ -545,6 +1000,34 @@ impl Context {
         self.memory().new_pixels_per_point = Some(pixels_per_point);
     }
 
+    /// How much the ui is scaled on top of the `pixels_per_point` reported by the integration.
+    ///
+    /// [`Self::pixels_per_point`] is the native `pixels_per_point` times this.
+    pub fn zoom_factor(&self) -> f32 {
+        self.memory().options.zoom_factor
+    }
+
+    /// Scale the whole ui, e.g. for a "UI scale" setting.
+    /// Will become active at the start of the next frame.
+    ///
+    /// Unlike [`Self::set_pixels_per_point`], this is kept when the integration
+    /// reports a new `pixels_per_point` (e.g. when moving the window to another monitor).
+    ///
+    /// By default the user can also zoom with Ctrl+Plus, Ctrl+Minus and Ctrl+0
+    /// (see `Memory::options.zoom_with_keyboard`).
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// ctx.set_zoom_factor(1.5); // Make everything 50% larger
+    /// ```
+    pub fn set_zoom_factor(&self, zoom_factor: f32) {
+        if zoom_factor != self.zoom_factor() {
+            self.request_repaint();
+        }
+
+        self.memory().options.zoom_factor = zoom_factor;
+    }
+
     /// Useful for pixel-perfect rendering
     pub(crate) fn round_to_pixel(&self, point: f32) -> f32 {
         let pixels_per_point = self.pixels_per_point();
@@ -607,19 +1090,70 @@ impl Context {
 
     // ---------------------------------------------------------------------
 
-    fn begin_frame_mut(&mut self, new_raw_input: RawInput) {
+    fn begin_frame_mut(&mut self, mut new_raw_input: RawInput) {
+        if let Some(recording) = &mut *self.input_recording.lock() {
+            recording.record(&new_raw_input);
+        }
+
+        self.zoom_with_keyboard(&new_raw_input);
+        self.apply_zoom_factor(&mut new_raw_input);
+
+        // The back/forward mouse buttons navigate, unless they are pressed on something
+        // that uses the pointer (judging by last frame):
+        let pointer_is_free =
+            !self.is_using_pointer() && self.frame_state().hovered_widget.is_none();
+
         self.memory().begin_frame(&self.input, &new_raw_input);
+        crate::id::begin_frame();
 
-        let mut input = std::mem::take(&mut self.input);
-        if let Some(new_pixels_per_point) = self.memory().new_pixels_per_point.take() {
-            input.pixels_per_point = new_pixels_per_point;
+        if new_raw_input.system_theme.is_some() && new_raw_input.system_theme != self.system_theme()
+        {
+            self.memory().system_theme = new_raw_input.system_theme;
+            self.apply_theme_preference();
+        }
+        if let Some(prefers_reduced_motion) = new_raw_input.prefers_reduced_motion {
+            self.memory().system_prefers_reduced_motion = prefers_reduced_motion;
         }
 
-        self.input = input.begin_frame(new_raw_input);
+        let input = std::mem::take(&mut self.input);
+        let options = self.memory().options.clone();
+        self.input = input.begin_frame(new_raw_input, &options);
         self.frame_state.lock().begin_frame(&self.input);
 
+        if self.memory().options.zoom_with_keyboard {
+            // Handled by `zoom_with_keyboard`:
+            for key in [Key::PlusEquals, Key::Minus, Key::Num0] {
+                self.consume_key(Modifiers::COMMAND, key);
+            }
+        }
+
         self.update_fonts(self.input.pixels_per_point());
 
+        for event in &self.input.events {
+            match event {
+                Event::Snapshot { id, snapshot } => {
+                    let mut memory = self.memory();
+                    memory.pending_snapshots.remove(id);
+                    memory.data_temp.insert_temp(*id, snapshot.clone());
+                }
+                Event::PointerButton {
+                    button: PointerButton::Extra1,
+                    pressed: true,
+                    ..
+                } if pointer_is_free => {
+                    self.back();
+                }
+                Event::PointerButton {
+                    button: PointerButton::Extra2,
+                    pressed: true,
+                    ..
+                } if pointer_is_free => {
+                    self.forward();
+                }
+                _ => {}
+            }
+        }
+
         // Ensure we register the background area so panels and background ui can catch clicks:
         let screen_rect = self.input.screen_rect();
         self.memory().areas.set_state(
@@ -628,10 +1162,82 @@ impl Context {
                 pos: screen_rect.min,
                 size: screen_rect.size(),
                 interactable: true,
+                input_transparent: false,
             },
         );
     }
 
+    /// Handle Ctrl+Plus, Ctrl+Minus and Ctrl+0.
+    fn zoom_with_keyboard(&self, raw_input: &RawInput) {
+        if !self.memory().options.zoom_with_keyboard {
+            return;
+        }
+
+        for event in &raw_input.events {
+            if let Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+            } = event
+            {
+                if !modifiers.command {
+                    continue;
+                }
+                let zoom_factor = self.zoom_factor();
+                match key {
+                    Key::PlusEquals => self.set_zoom_factor(zoom_in(zoom_factor)),
+                    Key::Minus => self.set_zoom_factor(zoom_out(zoom_factor)),
+                    Key::Num0 => self.set_zoom_factor(1.0),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Convert the input from the native points of the integration to zoomed points.
+    fn apply_zoom_factor(&self, raw_input: &mut RawInput) {
+        let zoom_factor = self.zoom_factor();
+
+        let mut memory = self.memory();
+        let new_pixels_per_point = memory.new_pixels_per_point.take();
+        let native_pixels_per_point = raw_input
+            .pixels_per_point
+            .or(new_pixels_per_point)
+            .or(memory.native_pixels_per_point)
+            .unwrap_or_else(|| self.input.pixels_per_point());
+        memory.native_pixels_per_point = Some(native_pixels_per_point);
+        raw_input.pixels_per_point = Some(native_pixels_per_point * zoom_factor);
+
+        if zoom_factor == 1.0 {
+            return;
+        }
+        let scale = 1.0 / zoom_factor;
+        if let Some(screen_rect) = &mut raw_input.screen_rect {
+            *screen_rect = Rect::from_min_max(
+                (screen_rect.min.to_vec2() * scale).to_pos2(),
+                (screen_rect.max.to_vec2() * scale).to_pos2(),
+            );
+        }
+        for event in &mut raw_input.events {
+            match event {
+                Event::PointerMoved(pos)
+                | Event::PointerButton { pos, .. }
+                | Event::Touch { pos, .. } => {
+                    *pos = (pos.to_vec2() * scale).to_pos2();
+                }
+                Event::Scroll(delta)
+                | Event::MouseWheel {
+                    unit: MouseWheelUnit::Point,
+                    delta,
+                    ..
+                } => {
+                    *delta *= scale;
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Load fonts unless already loaded.
     fn update_fonts(&mut self, pixels_per_point: f32) {
         let new_font_definitions = self.memory().new_font_definitions.take();
@@ -661,6 +1267,9 @@ impl Context {
     /// You can transform the returned shapes into triangles with a call to [`Context::tessellate`].
     #[must_use]
     pub fn end_frame(&self) -> (Output, Vec<ClippedShape>) {
+        self.profiler.lock().end_ui();
+        let stopwatch = Stopwatch::start();
+
         if self.input.wants_repaint() {
             self.request_repaint();
         }
@@ -670,40 +1279,303 @@ impl Context {
 
         self.fonts().end_frame();
 
+        let widget_tree = std::mem::take(&mut self.frame_state().widget_tree);
+        *self.widget_tree.lock() = Arc::new(widget_tree);
+
         let mut output: Output = std::mem::take(&mut self.output());
+        output.hit_regions = self.hit_regions();
+        remove_non_window_parents(&mut output.accessibility_nodes);
+        output.unconsumed_events = self.unconsumed_events();
+
+        // Convert back to the native points of the integration:
+        let zoom_factor = self.zoom_factor();
+        if zoom_factor != 1.0 {
+            let zoom_pos = |pos: Pos2| (pos.to_vec2() * zoom_factor).to_pos2();
+            if let Some(pos) = &mut output.text_cursor_pos {
+                *pos = zoom_pos(*pos);
+            }
+            if let Some(text_input) = &mut output.text_input {
+                let zoom_rect =
+                    |rect: Rect| Rect::from_min_max(zoom_pos(rect.min), zoom_pos(rect.max));
+                text_input.rect = zoom_rect(text_input.rect);
+                text_input.cursor_rect = zoom_rect(text_input.cursor_rect);
+            }
+            for rect in &mut output.hit_regions {
+                *rect = Rect::from_min_max(zoom_pos(rect.min), zoom_pos(rect.max));
+            }
+            for node in &mut output.accessibility_nodes {
+                node.rect = Rect::from_min_max(zoom_pos(node.rect.min), zoom_pos(node.rect.max));
+            }
+            for event in &mut output.unconsumed_events {
+                match event {
+                    Event::PointerMoved(pos)
+                    | Event::PointerButton { pos, .. }
+                    | Event::Touch { pos, .. } => {
+                        *pos = zoom_pos(*pos);
+                    }
+                    Event::Scroll(delta)
+                    | Event::MouseWheel {
+                        unit: MouseWheelUnit::Point,
+                        delta,
+                        ..
+                    } => {
+                        *delta *= zoom_factor;
+                    }
+                    _ => {}
+                }
+            }
+        }
         if self.repaint_requests.load(SeqCst) > 0 {
             self.repaint_requests.fetch_sub(1, SeqCst);
             output.needs_repaint = true;
+            if self.is_power_saving() {
+                output.repaint_after = self.memory().options.power_save_repaint_interval;
+            }
+        }
+        let repaint_at = self.frame_state().repaint_at;
+        if let Some(repaint_at) = repaint_at {
+            let repaint_after = (repaint_at - self.input.time).max(0.0) as f32;
+            if output.needs_repaint {
+                output.repaint_after = output.repaint_after.min(repaint_after);
+            } else {
+                output.needs_repaint = true;
+                output.repaint_after = repaint_after;
+            }
+        }
+
+        let focus_ring = self.frame_state().focus_ring;
+        if let Some(FocusRing {
+            layer_id,
+            clip_rect,
+            rect,
+            corner_radius,
+            stroke,
+        }) = focus_ring
+        {
+            // So keyboard users can see where they are:
+            self.graphics()
+                .list(layer_id)
+                .lock()
+                .add(clip_rect, Shape::rect_stroke(rect, corner_radius, stroke));
         }
 
         let shapes = self.drain_paint_lists();
+        let eyedropper_pos = self.frame_state().eyedropper_pos;
+        if let Some(pos) = eyedropper_pos {
+            crate::color_picker::store_shapes_color_at(self, &shapes, pos);
+        }
+        self.profiler.lock().end_frame(self.input.time, &stopwatch);
         (output, shapes)
     }
 
+    /// See [`Output::unconsumed_events`].
+    fn unconsumed_events(&self) -> Vec<Event> {
+        let wants_keyboard_input = self.wants_keyboard_input();
+        let wants_pointer_input = self.wants_pointer_input();
+        let consumed_events = self.frame_state().consumed_events.clone();
+
+        self.input
+            .events
+            .iter()
+            .zip(consumed_events)
+            .filter(|(event, consumed)| {
+                let used = match event {
+                    Event::Copy
+                    | Event::Cut
+                    | Event::Text(_)
+                    | Event::Paste(_)
+                    | Event::Key { .. }
+                    | Event::CompositionStart
+                    | Event::CompositionUpdate(_)
+                    | Event::CompositionEnd(_)
+                    | Event::GamepadButton { .. }
+                    | Event::GamepadAxis { .. } => wants_keyboard_input,
+                    Event::PointerMoved(pos)
+                    | Event::PointerButton { pos, .. }
+                    | Event::Touch { pos, .. } => self.wants_pointer_input_at(*pos),
+                    Event::PointerGone
+                    | Event::Scroll(_)
+                    | Event::MouseWheel { .. }
+                    | Event::Zoom(_) => wants_pointer_input,
+                    Event::Snapshot { .. } | Event::LongPress(_) => true, // Meant for egui only
+                };
+                !consumed && !used
+            })
+            .map(|(event, _)| event.clone())
+            .collect()
+    }
+
+    /// See [`Output::hit_regions`].
+    fn hit_regions(&self) -> Vec<Rect> {
+        // Panels cover everything except the unused rectangle:
+        let screen_rect = self.input.screen_rect();
+        let unused_rect = self.frame_state().unused_rect;
+        let mut rects = if unused_rect.is_positive() {
+            rect_difference(screen_rect, unused_rect)
+        } else {
+            vec![screen_rect]
+        };
+        rects.extend(self.memory().areas.hit_rects());
+        rects
+    }
+
     fn drain_paint_lists(&self) -> Vec<ClippedShape> {
-        let memory = self.memory();
-        self.graphics().drain(memory.areas.order()).collect()
+        let recycled_shapes = std::mem::take(&mut *self.recycled_shapes.lock());
+        let (shapes, layer_shapes) = self
+            .graphics()
+            .drain(self.memory().areas.order(), recycled_shapes);
+        let mut frame_state = self.frame_state();
+        frame_state.layer_shapes = layer_shapes;
+        frame_state.shape_list = Some((frame_state.generation, shapes.len()));
+        shapes
     }
 
     /// Tessellate the given shapes into triangle meshes.
-    pub fn tessellate(&self, shapes: Vec<ClippedShape>) -> Vec<ClippedMesh> {
-        // A tempting optimization is to reuse the tessellation from last frame if the
-        // shapes are the same, but just comparing the shapes takes about 50% of the time
-        // it takes to tessellate them, so it is not a worth optimization.
+    ///
+    /// If [`epaint::TessellationOptions::coarse_tessellation_culling`] is set (the default),
+    /// the clip rectangles are limited to the screen, so that shapes outside of it are skipped.
+    pub fn tessellate(&self, mut shapes: Vec<ClippedShape>) -> Vec<ClippedMesh> {
+        // Comparing the shapes with those of last frame takes a good fraction of the time
+        // it takes to tessellate them, so reusing the tessellation is opt-in
+        // (`Memory::options.cache_tessellation`), and done per layer so that a small change
+        // only re-tessellates the layer it happens in.
 
         let mut tessellation_options = self.memory().options.tessellation_options;
         tessellation_options.pixels_per_point = self.pixels_per_point();
         tessellation_options.aa_size = 1.0 / self.pixels_per_point();
+        let tessellation_stopwatch = Stopwatch::start();
+        if tessellation_options.coarse_tessellation_culling {
+            // Big scroll areas add a lot of content outside of the screen:
+            let screen_rect = self.input.screen_rect();
+            for ClippedShape(clip_rect, _) in &mut shapes {
+                *clip_rect = clip_rect.intersect(screen_rect);
+            }
+        }
         let paint_stats = PaintStats::from_shapes(&shapes);
-        let clipped_meshes = tessellator::tessellate_shapes(
-            shapes,
-            tessellation_options,
-            self.fonts().font_image().size(),
-        );
+        let font_image = self.fonts().font_image();
+        let font_image_size = font_image.size();
+
+        // Tessellate each layer separately, so we can tell which layer is expensive:
+        let (layer_shapes, shapes_are_from_end_frame) = {
+            let mut frame_state = self.frame_state();
+            // Only the list returned by `end_frame` this frame can be split by its layers:
+            let shapes_are_from_end_frame =
+                frame_state.shape_list.take() == Some((frame_state.generation, shapes.len()));
+            (
+                std::mem::take(&mut frame_state.layer_shapes),
+                shapes_are_from_end_frame,
+            )
+        };
+        let mut layer_paint_stats = Vec::new();
+        // Meshes that are not needed this frame are freed:
+        let mut recycled_meshes = std::mem::take(&mut *self.recycled_meshes.lock());
+        let clipped_meshes = if shapes_are_from_end_frame {
+            let mut cache = if self.memory().options.cache_tessellation {
+                let mut cache = self.tessellation_cache.lock();
+                cache.begin(crate::util::hash((
+                    tessellation_options,
+                    font_image_size,
+                    font_image.version,
+                )));
+                Some(cache)
+            } else {
+                *self.tessellation_cache.lock() = Default::default();
+                None
+            };
+
+            // Hash the shapes before they are moved out to be tessellated:
+            let hashes: Vec<u64> = match &cache {
+                Some(cache) => layer_shapes
+                    .iter()
+                    .map(|layer| cache.hash(&shapes[layer.range.clone()]))
+                    .collect(),
+                None => vec![],
+            };
+
+            let mut clipped_meshes = Vec::new();
+            let mut drain = shapes.drain(..);
+            for (index, layer) in layer_shapes.into_iter().enumerate() {
+                let num_shapes = layer.range.len();
+                let mut layer_shapes = drain.by_ref().take(num_shapes);
+                let stopwatch = Stopwatch::start();
+                let mut tessellate = |recycled_meshes: &mut Vec<Mesh>| {
+                    tessellator::tessellate_shapes_recycling(
+                        &mut layer_shapes,
+                        tessellation_options,
+                        font_image_size,
+                        recycled_meshes,
+                    )
+                };
+                let (meshes, cached) = match &mut cache {
+                    Some(cache) => cache.tessellate(
+                        layer.layer_id,
+                        hashes[index],
+                        &mut recycled_meshes,
+                        tessellate,
+                    ),
+                    None => (tessellate(&mut recycled_meshes), false),
+                };
+                layer_shapes.for_each(drop); // in case of a cache hit
+                layer_paint_stats.push(crate::layers::LayerPaintStats {
+                    layer_id: layer.layer_id,
+                    num_shapes,
+                    num_vertices: meshes.iter().map(|m| m.1.vertices.len()).sum(),
+                    num_triangles: meshes.iter().map(|m| m.1.indices.len() / 3).sum(),
+                    tessellation_time: stopwatch.elapsed(),
+                    cached,
+                });
+                clipped_meshes.extend(meshes);
+            }
+
+            if let Some(cache) = &mut cache {
+                cache.end();
+            }
+            drop(drain);
+            *self.recycled_shapes.lock() = shapes;
+            clipped_meshes
+        } else {
+            tessellator::tessellate_shapes_recycling(
+                shapes,
+                tessellation_options,
+                font_image_size,
+                &mut recycled_meshes,
+            )
+        };
+
         *self.paint_stats.lock() = paint_stats.with_clipped_meshes(&clipped_meshes);
+        *self.layer_paint_stats.lock() = layer_paint_stats;
+        self.profiler.lock().tessellated(&tessellation_stopwatch);
         clipped_meshes
     }
 
+    /// Give back the meshes from [`Self::tessellate`] once you have painted them,
+    /// so that the next call to [`Self::tessellate`] can reuse their memory
+    /// instead of growing new vertex and index buffers.
+    ///
+    /// This is optional, but saves a lot of allocations in large uis.
+    /// Meshes that are not reused by the next call to [`Self::tessellate`] are freed.
+    pub fn recycle_meshes(&self, clipped_meshes: Vec<ClippedMesh>) {
+        self.recycled_meshes
+            .lock()
+            .extend(clipped_meshes.into_iter().map(|ClippedMesh(_, mesh)| mesh));
+    }
+
+    /// How long egui spent on the different parts of recent frames, as `(time, timings)`, oldest first.
+    ///
+    /// Empty on the web, where there is no clock to measure it with.
+    /// See also [`Self::profiler_ui`].
+    pub fn frame_timings(&self) -> Vec<(f64, crate::FrameTimings)> {
+        self.profiler.lock().frames()
+    }
+
+    /// Paint statistics per layer, from the last call to [`Self::tessellate`], in paint order.
+    ///
+    /// Empty unless [`Self::tessellate`] was given the shapes returned by [`Self::end_frame`] this frame,
+    /// without adding or removing any.
+    pub fn layer_paint_stats(&self) -> Vec<crate::layers::LayerPaintStats> {
+        self.layer_paint_stats.lock().clone()
+    }
+
     // ---------------------------------------------------------------------
 
     /// How much space is used by panels and windows.
@@ -726,14 +1598,19 @@ impl Context {
     /// Is the pointer (mouse/touch) over any egui area?
     pub fn is_pointer_over_area(&self) -> bool {
         if let Some(pointer_pos) = self.input.pointer.interact_pos() {
-            if let Some(layer) = self.layer_id_at(pointer_pos) {
-                if layer.order == Order::Background {
-                    !self.frame_state().unused_rect.contains(pointer_pos)
-                } else {
-                    true
-                }
+            self.is_pos_over_area(pointer_pos)
+        } else {
+            false
+        }
+    }
+
+    /// Is the given position over an egui area (window, panel, …)?
+    fn is_pos_over_area(&self, pos: Pos2) -> bool {
+        if let Some(layer) = self.layer_id_at(pos) {
+            if layer.order == Order::Background {
+                !self.frame_state().unused_rect.contains(pos)
             } else {
-                false
+                true
             }
         } else {
             false
@@ -749,6 +1626,27 @@ impl Context {
         self.is_using_pointer() || (self.is_pointer_over_area() && !self.input().pointer.any_down())
     }
 
+    /// Like [`Self::wants_pointer_input`], but for an arbitrary position.
+    ///
+    /// This lets an integration decide per pointer event whether to forward it to e.g. a game world,
+    /// instead of ignoring all pointer input while some egui area is hovered.
+    pub fn wants_pointer_input_at(&self, pos: Pos2) -> bool {
+        self.is_using_pointer() || (self.is_pos_over_area(pos) && !self.input().pointer.any_down())
+    }
+
+    /// What kind of interactive widget is under the pointer, if any?
+    ///
+    /// This is the widget found so far this frame, so call it after adding your widgets,
+    /// e.g. between frames, when it reports the whole of the last frame.
+    ///
+    /// Widgets that don't describe themselves with [`Response::widget_info`]
+    /// are reported as [`WidgetType::Other`].
+    /// Together with [`Self::wants_pointer_input_at`] this lets a game
+    /// e.g. keep handling clicks on its world while the pointer is over a passive egui HUD.
+    pub fn hovered_widget_kind(&self) -> Option<WidgetType> {
+        self.frame_state().hovered_widget.map(|(_, kind)| kind)
+    }
+
     /// Is egui currently using the pointer position (e.g. dragging a slider).
     /// NOTE: this will return `false` if the pointer is just hovering over an egui area.
     pub fn is_using_pointer(&self) -> bool {
@@ -766,7 +1664,24 @@ impl Context {
     /// Can be used to implement drag-and-drop (see relevant demo).
     pub fn translate_layer(&self, layer_id: LayerId, delta: Vec2) {
         if delta != Vec2::ZERO {
-            self.graphics().list(layer_id).lock().translate(delta);
+            let mut graphics = self.graphics();
+            graphics.list(layer_id).lock().translate(delta);
+            graphics.overlay_list(layer_id).lock().translate(delta);
+        }
+    }
+
+    /// Make all the graphics at the given layer more transparent, e.g. to fade it out.
+    ///
+    /// `opacity` is in the range `[0, 1]`, where `1` leaves the graphics as they are.
+    /// Only affects what has already been painted to the layer this frame.
+    pub fn multiply_layer_opacity(&self, layer_id: LayerId, opacity: f32) {
+        if opacity < 1.0 {
+            let mut graphics = self.graphics();
+            graphics.list(layer_id).lock().multiply_opacity(opacity);
+            graphics
+                .overlay_list(layer_id)
+                .lock()
+                .multiply_opacity(opacity);
         }
     }
 
@@ -776,6 +1691,21 @@ impl Context {
         self.memory().layer_id_at(pos, resize_grab_radius_side)
     }
 
+    /// Catch the pointer in this rectangle for this frame, as if an egui area covered it.
+    ///
+    /// [`Self::layer_id_at`] and [`Self::wants_pointer_input`] will treat the rectangle as
+    /// belonging to egui, but it is below all [`Area`]s and [`Window`]s, so they still get the pointer as normal.
+    /// Widgets on panels below it will not get the pointer.
+    ///
+    /// This is useful when egui floats over a game viewport, and some region
+    /// (e.g. a HUD element painted by the game) should not let clicks through to the game.
+    /// Call this each frame you want the region to block input.
+    ///
+    /// See also [`Area::input_transparent`].
+    pub fn block_input_in(&self, rect: Rect) {
+        self.memory().areas.block_input_in(rect);
+    }
+
     pub(crate) fn rect_contains_pointer(&self, layer_id: LayerId, rect: Rect) -> bool {
         if let Some(pointer_pos) = self.input.pointer.interact_pos() {
             rect.contains(pointer_pos) && self.layer_id_at(pointer_pos) == Some(layer_id)
@@ -784,6 +1714,14 @@ impl Context {
         }
     }
 
+    /// All widgets and [`Ui`]s of the last finished frame.
+    ///
+    /// Only recorded if `Memory::options.record_widget_tree` is set, otherwise empty.
+    /// Useful for inspecting and diffing the ui between frames.
+    pub fn frame_widget_tree(&self) -> Arc<crate::WidgetTree> {
+        self.widget_tree.lock().clone()
+    }
+
     // ---------------------------------------------------------------------
 
     /// Wether or not to debug widget layout on hover.
@@ -799,6 +1737,28 @@ impl Context {
     }
 }
 
+/// ## Input recording
+impl Context {
+    /// Start recording all [`RawInput`] given to egui, e.g. to reproduce a bug.
+    ///
+    /// Any previous recording is discarded.
+    /// See [`crate::InputRecording`].
+    pub fn start_recording_input(&self) {
+        *self.input_recording.lock() = Some(Default::default());
+    }
+
+    /// Stop recording input, and return what was recorded.
+    ///
+    /// Returns `None` if not recording.
+    pub fn stop_recording_input(&self) -> Option<crate::InputRecording> {
+        self.input_recording.lock().take()
+    }
+
+    pub fn is_recording_input(&self) -> bool {
+        self.input_recording.lock().is_some()
+    }
+}
+
 /// ## Animation
 impl Context {
     /// Returns a value in the range [0, 1], to indicate "how on" this thing is.
@@ -816,24 +1776,289 @@ impl Context {
     }
 
     /// Like [`Self::animate_bool`] but allows you to control the animation time.
+    ///
+    /// With `Memory::options.power_save` on, or when [`Self::reduce_motion`] is `true`, animations are skipped.
     pub fn animate_bool_with_time(&self, id: Id, value: bool, animation_time: f32) -> f32 {
-        let animated_value =
-            self.animation_manager
-                .lock()
-                .animate_bool(&self.input, animation_time, id, value);
-        let animation_in_progress = 0.0 < animated_value && animated_value < 1.0;
+        let easing = self.style().animation_easing;
+        self.animate_bool_with_time_and_easing(id, value, animation_time, easing)
+    }
+
+    /// Like [`Self::animate_bool`] but with the given [`Easing`] instead of [`Style::animation_easing`].
+    ///
+    /// Note that [`Easing::BackOut`] and [`Easing::ElasticOut`] go above one along the way.
+    pub fn animate_bool_with_easing(&self, id: Id, value: bool, easing: Easing) -> f32 {
+        let animation_time = self.style().animation_time;
+        self.animate_bool_with_time_and_easing(id, value, animation_time, easing)
+    }
+
+    /// Like [`Self::animate_bool`] but allows you to control both the animation time and the [`Easing`].
+    pub fn animate_bool_with_time_and_easing(
+        &self,
+        id: Id,
+        value: bool,
+        animation_time: f32,
+        easing: Easing,
+    ) -> f32 {
+        // These animations are only decorative, so power save skips them:
+        let animation_time = if self.memory().options.power_save {
+            0.0
+        } else {
+            self.animation_time_or_zero(animation_time)
+        };
+        let (animated_value, animation_in_progress) = self.animation_manager.lock().animate_bool(
+            &self.input,
+            animation_time,
+            easing,
+            id,
+            value,
+        );
         if animation_in_progress {
             self.request_repaint();
         }
         animated_value
     }
 
+    /// Smoothly animate towards the `target` value, e.g. the width of a panel or a scroll offset.
+    ///
+    /// The first time called it will return `target`.
+    /// When `target` changes, the returned value moves from where it currently is to the new `target`
+    /// over [`Style::animation_time`] seconds, following [`Style::animation_easing`].
+    /// Use a different `id` for each animated value, including the [`Vec2`] and color variants.
+    ///
+    /// The function will call [`Self::request_repaint()`] when appropriate.
+    pub fn animate_value(&self, id: Id, target: f32) -> f32 {
+        let animation_time = self.style().animation_time;
+        self.animate_value_with_time(id, target, animation_time)
+    }
+
+    /// Like [`Self::animate_value`] but allows you to control the animation time.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let is_wide = true;
+    /// let target_width = if is_wide { 300.0 } else { 100.0 };
+    /// let width = ui.ctx().animate_value_with_time(egui::Id::new("width"), target_width, 0.2);
+    /// ui.set_width(width);
+    /// # });
+    /// ```
+    pub fn animate_value_with_time(&self, id: Id, target: f32, animation_time: f32) -> f32 {
+        let easing = self.style().animation_easing;
+        self.animate_value_with_time_and_easing(id, target, animation_time, easing)
+    }
+
+    /// Like [`Self::animate_value_with_time`] but with the given [`Easing`] instead of [`Style::animation_easing`].
+    pub fn animate_value_with_time_and_easing(
+        &self,
+        id: Id,
+        target: f32,
+        animation_time: f32,
+        easing: Easing,
+    ) -> f32 {
+        self.animate_values(id, [target, 0.0, 0.0, 0.0], animation_time, easing)[0]
+    }
+
+    /// Like [`Self::animate_value_with_time`], but for a [`Vec2`], e.g. a position or a size.
+    pub fn animate_vec2_with_time(&self, id: Id, target: Vec2, animation_time: f32) -> Vec2 {
+        let easing = self.style().animation_easing;
+        let [x, y, _, _] =
+            self.animate_values(id, [target.x, target.y, 0.0, 0.0], animation_time, easing);
+        vec2(x, y)
+    }
+
+    /// Like [`Self::animate_value_with_time`], but for a color, e.g. to fade in a highlight.
+    ///
+    /// The color is interpolated in linear space.
+    pub fn animate_color_with_time(&self, id: Id, target: Color32, animation_time: f32) -> Color32 {
+        let target = Rgba::from(target);
+        let easing = self.style().animation_easing;
+        let [r, g, b, a] = self.animate_values(
+            id,
+            [target.r(), target.g(), target.b(), target.a()],
+            animation_time,
+            easing,
+        );
+        Rgba::from_rgba_premultiplied(r, g, b, a).into()
+    }
+
+    fn animate_values(
+        &self,
+        id: Id,
+        target: animation_manager::Values,
+        animation_time: f32,
+        easing: Easing,
+    ) -> animation_manager::Values {
+        let animation_time = self.animation_time_or_zero(animation_time);
+        let (value, animation_in_progress) = self.animation_manager.lock().animate_value(
+            &self.input,
+            animation_time,
+            easing,
+            id,
+            target,
+        );
+        if animation_in_progress {
+            self.request_repaint();
+        }
+        value
+    }
+
+    /// Move towards the `target` value like a [`Spring`], e.g. for a drawer sliding open
+    /// or something snapping into place after being dragged.
+    ///
+    /// The first time called it will return `target`.
+    /// Unlike [`Self::animate_value`], the spring keeps its speed when the `target` changes
+    /// in the middle of an animation, instead of starting over.
+    /// Use a different `id` for each animated value.
+    ///
+    /// The function will call [`Self::request_repaint()`] when appropriate.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let drawer_open = true;
+    /// let target = if drawer_open { 200.0 } else { 0.0 };
+    /// let width = ui.ctx().animate_value_with_spring(egui::Id::new("drawer"), target, egui::Spring::default());
+    /// # });
+    /// ```
+    pub fn animate_value_with_spring(&self, id: Id, target: f32, spring: Spring) -> f32 {
+        self.animate_spring(id, [target, 0.0, 0.0, 0.0], spring)[0]
+    }
+
+    /// Like [`Self::animate_value_with_spring`], but for a [`Vec2`], e.g. a position.
+    pub fn animate_vec2_with_spring(&self, id: Id, target: Vec2, spring: Spring) -> Vec2 {
+        let [x, y, _, _] = self.animate_spring(id, [target.x, target.y, 0.0, 0.0], spring);
+        vec2(x, y)
+    }
+
+    fn animate_spring(
+        &self,
+        id: Id,
+        target: animation_manager::Values,
+        spring: Spring,
+    ) -> animation_manager::Values {
+        let instant = self.reduce_motion();
+        let (value, is_moving) =
+            self.animation_manager
+                .lock()
+                .animate_spring(&self.input, spring, instant, id, target);
+        if is_moving {
+            self.request_repaint();
+        }
+        value
+    }
+
+    fn animation_time_or_zero(&self, animation_time: f32) -> f32 {
+        if self.reduce_motion() {
+            0.0
+        } else {
+            animation_time
+        }
+    }
+
     /// Clear memory of any animations.
     pub fn clear_animations(&self) {
         *self.animation_manager.lock() = Default::default();
     }
 }
 
+/// ## Collapsing headers
+impl Context {
+    /// Is the [`CollapsingHeader`] with the given id open?
+    ///
+    /// The id is the one of [`CollapsingResponse::header_response`].
+    /// Returns `None` if the header hasn't been shown yet.
+    pub fn is_collapsing_open(&self, id: Id) -> Option<bool> {
+        containers::collapsing_header::State::is_open(self, id)
+    }
+
+    /// Open or close all of the given [`CollapsingHeader`]s, e.g. for "Expand all" and "Collapse all" buttons.
+    ///
+    /// The ids are the ones of [`CollapsingResponse::header_response`].
+    /// The headers animate to their new state the next time they are shown.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut header_ids = vec![];
+    /// for name in ["Apples", "Oranges"] {
+    ///     let response = ui.collapsing(name, |ui| ui.label("Juicy"));
+    ///     header_ids.push(response.header_response.id);
+    /// }
+    /// if ui.button("Expand all").clicked() {
+    ///     ui.ctx().set_collapsing_open(header_ids, true);
+    /// }
+    /// # });
+    /// ```
+    pub fn set_collapsing_open(&self, ids: impl IntoIterator<Item = Id>, open: bool) {
+        for id in ids {
+            let mut state =
+                containers::collapsing_header::State::load(self, id).unwrap_or_default();
+            state.open = open;
+            state.store(self, id);
+        }
+        self.request_repaint();
+    }
+}
+
+/// ## Navigation
+///
+/// egui keeps a back/forward history of views (pages, tabs, screens, …) that your app can use.
+/// The "back" and "forward" mouse buttons ([`PointerButton::Extra1`] and [`PointerButton::Extra2`])
+/// will automatically move through this history, unless they are pressed on an interactive widget.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// ctx.push_view(egui::Id::new("settings"));
+/// if ctx.current_view() == Some(egui::Id::new("settings")) {
+///     // show the settings page…
+/// }
+/// ```
+impl Context {
+    /// Make `id` the current view, putting the previous view on the back history.
+    ///
+    /// This clears the forward history, unless `id` already was the current view.
+    pub fn push_view(&self, id: Id) {
+        if self.memory().navigation.push(id) {
+            self.request_repaint();
+        }
+    }
+
+    /// Go back to the previous view, if any.
+    ///
+    /// Returns `true` if the current view changed.
+    pub fn back(&self) -> bool {
+        let changed = self.memory().navigation.back();
+        if changed {
+            self.request_repaint();
+        }
+        changed
+    }
+
+    /// Go forward to the view we most recently went [`Self::back`] from, if any.
+    ///
+    /// Returns `true` if the current view changed.
+    pub fn forward(&self) -> bool {
+        let changed = self.memory().navigation.forward();
+        if changed {
+            self.request_repaint();
+        }
+        changed
+    }
+
+    /// The view last set with [`Self::push_view`] (or reached with [`Self::back`] or [`Self::forward`]).
+    pub fn current_view(&self) -> Option<Id> {
+        self.memory().navigation.current()
+    }
+
+    /// Is there a view to go [`Self::back`] to?
+    pub fn can_go_back(&self) -> bool {
+        self.memory().navigation.can_go_back()
+    }
+
+    /// Is there a view to go [`Self::forward`] to?
+    pub fn can_go_forward(&self) -> bool {
+        self.memory().navigation.can_go_forward()
+    }
+}
+
 impl Context {
     pub fn settings_ui(&self, ui: &mut Ui) {
         use crate::containers::*;
@@ -860,9 +2085,59 @@ impl Context {
                 tessellation_options.ui(ui);
                 ui.vertical_centered(|ui| reset_button(ui, &mut tessellation_options));
                 self.memory().options.tessellation_options = tessellation_options;
+
+                let mut cache_tessellation = self.memory().options.cache_tessellation;
+                ui.checkbox(&mut cache_tessellation, "Cache tessellation")
+                    .on_hover_text(
+                        "Reuse the meshes of layers that didn't change since last frame",
+                    );
+                self.memory().options.cache_tessellation = cache_tessellation;
+            });
+
+        CollapsingHeader::new("🔋 Power")
+            .default_open(false)
+            .show(ui, |ui| {
+                let mut options = self.memory().options.clone();
+                ui.checkbox(&mut options.power_save, "Power save")
+                    .on_hover_text("Skip decorative animations and repaint less often when idle");
+                ui.add_enabled(
+                    options.power_save,
+                    Slider::new(&mut options.power_save_repaint_interval, 0.0..=1.0)
+                        .text("min seconds between repaints"),
+                );
+                self.memory().options = options;
+            });
+
+        CollapsingHeader::new("🔍 Zoom")
+            .default_open(false)
+            .show(ui, |ui| {
+                let mut zoom_factor = self.zoom_factor();
+                ui.horizontal(|ui| {
+                    if ui.button("➖").clicked() {
+                        zoom_factor = zoom_out(zoom_factor);
+                    }
+                    ui.label(format!("{:.0}%", 100.0 * zoom_factor));
+                    if ui.button("➕").clicked() {
+                        zoom_factor = zoom_in(zoom_factor);
+                    }
+                    if ui.button("Reset").clicked() {
+                        zoom_factor = 1.0;
+                    }
+                });
+                self.set_zoom_factor(zoom_factor);
+                let mut zoom_with_keyboard = self.memory().options.zoom_with_keyboard;
+                ui.checkbox(&mut zoom_with_keyboard, "Zoom with Ctrl +/-/0");
+                self.memory().options.zoom_with_keyboard = zoom_with_keyboard;
             });
     }
 
+    /// Show a graph of recent frame times, and how long egui spent on the different parts of them.
+    ///
+    /// See also [`Self::frame_timings`].
+    pub fn profiler_ui(&self, ui: &mut Ui) {
+        crate::profiler::profiler_ui(ui, &self.frame_timings());
+    }
+
     pub fn inspection_ui(&self, ui: &mut Ui) {
         use crate::containers::*;
         crate::trace!(ui);
@@ -918,11 +2193,31 @@ impl Context {
             .default_open(false)
             .show(ui, |ui| ui.input().clone().ui(ui));
 
+        CollapsingHeader::new("📑 Widget tree")
+            .default_open(false)
+            .show(ui, |ui| {
+                let mut record = self.memory().options.record_widget_tree;
+                ui.checkbox(&mut record, "Record")
+                    .on_hover_text("Record all widgets and Ui:s each frame");
+                self.memory().options.record_widget_tree = record;
+                self.frame_widget_tree().ui(ui);
+            });
+
         CollapsingHeader::new("📊 Paint stats")
             .default_open(true)
             .show(ui, |ui| {
                 self.paint_stats.lock().ui(ui);
             });
+
+        CollapsingHeader::new("⏱ Profiler")
+            .default_open(false)
+            .show(ui, |ui| self.profiler_ui(ui));
+
+        CollapsingHeader::new("📊 Paint stats per layer")
+            .default_open(false)
+            .show(ui, |ui| {
+                crate::introspection::layer_paint_stats_ui(ui, self.layer_paint_stats());
+            });
     }
 
     pub fn memory_ui(&self, ui: &mut crate::Ui) {
@@ -934,11 +2229,12 @@ impl Context {
             *self.memory() = Default::default();
         }
 
-        let num_state = self.memory().data.len();
-        let num_serialized = self.memory().data.count_serialized();
+        let num_temp = self.memory().data_temp.len();
+        let num_persisted = self.memory().data_persisted.len();
+        let num_serialized = self.memory().data_persisted.count_serialized();
         ui.label(format!(
-            "{} widget states stored (of which {} are serialized).",
-            num_state, num_serialized
+            "{} temporary and {} persisted widget states stored (of which {} are serialized).",
+            num_temp, num_persisted, num_serialized
         ));
 
         ui.horizontal(|ui| {
@@ -980,12 +2276,12 @@ impl Context {
             ui.label(format!(
                 "{} collapsing headers",
                 self.memory()
-                    .data
+                    .data_persisted
                     .count::<containers::collapsing_header::State>()
             ));
             if ui.button("Reset").clicked() {
                 self.memory()
-                    .data
+                    .data_persisted
                     .remove_by_type::<containers::collapsing_header::State>();
             }
         });
@@ -993,30 +2289,34 @@ impl Context {
         ui.horizontal(|ui| {
             ui.label(format!(
                 "{} menu bars",
-                self.memory().data.count::<menu::BarState>()
+                self.memory().data_temp.count::<menu::BarState>()
             ));
             if ui.button("Reset").clicked() {
-                self.memory().data.remove_by_type::<menu::BarState>();
+                self.memory().data_temp.remove_by_type::<menu::BarState>();
             }
         });
 
         ui.horizontal(|ui| {
             ui.label(format!(
                 "{} scroll areas",
-                self.memory().data.count::<scroll_area::State>()
+                self.memory().data_persisted.count::<scroll_area::State>()
             ));
             if ui.button("Reset").clicked() {
-                self.memory().data.remove_by_type::<scroll_area::State>();
+                self.memory()
+                    .data_persisted
+                    .remove_by_type::<scroll_area::State>();
             }
         });
 
         ui.horizontal(|ui| {
             ui.label(format!(
                 "{} resize areas",
-                self.memory().data.count::<resize::State>()
+                self.memory().data_persisted.count::<resize::State>()
             ));
             if ui.button("Reset").clicked() {
-                self.memory().data.remove_by_type::<resize::State>();
+                self.memory()
+                    .data_persisted
+                    .remove_by_type::<resize::State>();
             }
         });
 
@@ -1032,8 +2332,767 @@ impl Context {
 
 impl Context {
     pub fn style_ui(&self, ui: &mut Ui) {
+        let mut theme_preference = self.theme_preference();
+        theme_preference.radio_buttons(ui);
+        if theme_preference != self.theme_preference() {
+            self.set_theme_preference(theme_preference);
+        }
+
+        ui.horizontal(|ui| {
+            let mut high_contrast = self.is_high_contrast();
+            ui.checkbox(&mut high_contrast, "High contrast");
+            self.set_high_contrast(high_contrast);
+
+            let mut reduce_motion = self.memory().options.reduce_motion;
+            ui.checkbox(&mut reduce_motion, "Reduce motion")
+                .on_hover_text("Skip animations");
+            self.memory().options.reduce_motion = reduce_motion;
+        });
+
         let mut style: Style = (*self.style()).clone();
         style.ui(ui);
         self.set_style(style);
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// Widgets start out with the id of their layer as parent, but only windows are nodes.
+fn remove_non_window_parents(nodes: &mut [crate::output::AccessibilityNode]) {
+    let windows: epaint::ahash::AHashSet<Id> = nodes
+        .iter()
+        .filter(|node| node.info.typ == WidgetType::Window)
+        .map(|node| node.id)
+        .collect();
+    for node in nodes {
+        if node
+            .parent
+            .map_or(false, |parent| !windows.contains(&parent))
+        {
+            node.parent = None;
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+const MIN_ZOOM_FACTOR: f32 = 0.2;
+const MAX_ZOOM_FACTOR: f32 = 5.0;
+
+fn zoom_in(zoom_factor: f32) -> f32 {
+    (zoom_factor * 1.1).at_most(MAX_ZOOM_FACTOR)
+}
+
+fn zoom_out(zoom_factor: f32) -> f32 {
+    (zoom_factor / 1.1).at_least(MIN_ZOOM_FACTOR)
+}
+
+// ----------------------------------------------------------------------------
+
+/// The parts of `outer` outside of `inner`, as up to four rectangles.
+fn rect_difference(outer: Rect, inner: Rect) -> Vec<Rect> {
+    let inner = inner.intersect(outer);
+    let (left, right) = (outer.left(), outer.right());
+    let (top, bottom) = (outer.top(), outer.bottom());
+    [
+        Rect::from_min_max(pos2(left, top), pos2(right, inner.top())),
+        Rect::from_min_max(pos2(left, inner.bottom()), pos2(right, bottom)),
+        Rect::from_min_max(pos2(left, inner.top()), pos2(inner.left(), inner.bottom())),
+        Rect::from_min_max(
+            pos2(inner.right(), inner.top()),
+            pos2(right, inner.bottom()),
+        ),
+    ]
+    .into_iter()
+    .filter(|rect| rect.is_positive())
+    .collect()
+}
+
+#[cfg(test)]
+#[test]
+fn test_rect_difference() {
+    let outer = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+    let inner = Rect::from_min_max(pos2(20.0, 0.0), pos2(100.0, 90.0));
+    assert_eq!(
+        rect_difference(outer, inner),
+        vec![
+            Rect::from_min_max(pos2(0.0, 90.0), pos2(100.0, 100.0)),
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(20.0, 90.0)),
+        ]
+    );
+    assert!(rect_difference(outer, outer).is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn test_accessibility_nodes() {
+    let mut ctx = CtxRef::default();
+    ctx.memory().options.accessibility_tree = true;
+    let (output, _) = ctx.run(Default::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            let _ = ui.button("In panel");
+        });
+        crate::Window::new("My window").show(ctx, |ui| {
+            ui.checkbox(&mut true, "In window");
+        });
+    });
+
+    let nodes: Vec<_> = output
+        .accessibility_nodes
+        .iter()
+        .map(|node| (node.info.typ, node.info.label.as_deref(), node.parent))
+        .collect();
+    let window_id = output.accessibility_nodes[1].id;
+    assert_eq!(
+        nodes,
+        vec![
+            (WidgetType::Button, Some("In panel"), None),
+            (WidgetType::Window, Some("My window"), None),
+            (WidgetType::Checkbox, Some("In window"), Some(window_id)),
+        ]
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_unconsumed_events() {
+    let key_press = |key| Event::Key {
+        key,
+        pressed: true,
+        modifiers: Modifiers::NONE,
+    };
+    let raw_input = RawInput {
+        events: vec![key_press(Key::W), key_press(Key::Escape)],
+        ..Default::default()
+    };
+
+    let mut ctx = CtxRef::default();
+    let (output, _) = ctx.run(raw_input, |ctx| {
+        assert_eq!(ctx.consume_key(Modifiers::NONE, Key::Escape), 1);
+        assert_eq!(ctx.consume_key(Modifiers::NONE, Key::Escape), 0);
+    });
+    assert_eq!(output.unconsumed_events, vec![key_press(Key::W)]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_consume_event() {
+    let press = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    let mut ctx = CtxRef::default();
+    let frame = |ctx: &mut CtxRef, events: Vec<Event>| {
+        let raw_input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let mut button_rect = Rect::NOTHING;
+        let mut consumed = vec![];
+        let _ = ctx.run(raw_input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                button_rect = ui.button("Click me").rect;
+            });
+            consumed = (0..ctx.input().events.len())
+                .map(|index| ctx.is_event_consumed(index))
+                .collect();
+            assert!(!ctx.consume_event(consumed.len()), "no such event");
+        });
+        (button_rect, consumed)
+    };
+
+    let (button_rect, _) = frame(&mut ctx, vec![]);
+    let on_button = button_rect.center();
+    let elsewhere = button_rect.right_bottom() + vec2(100.0, 100.0);
+    assert_eq!(
+        frame(&mut ctx, vec![Event::PointerMoved(on_button)]).1,
+        vec![false],
+        "hovering doesn't use the pointer"
+    );
+    assert_eq!(frame(&mut ctx, vec![press(on_button, true)]).1, vec![true]);
+    assert_eq!(
+        frame(
+            &mut ctx,
+            vec![Event::PointerMoved(elsewhere), press(elsewhere, false)]
+        )
+        .1,
+        vec![true, true],
+        "the button was pressed, so it uses the movement and release"
+    );
+    assert_eq!(
+        frame(
+            &mut ctx,
+            vec![press(elsewhere, true), press(elsewhere, false)]
+        )
+        .1,
+        vec![false, false]
+    );
+
+    let raw_input = RawInput {
+        events: vec![Event::Text("a".to_owned())],
+        ..Default::default()
+    };
+    let (output, _) = ctx.run(raw_input, |ctx| {
+        assert!(ctx.consume_event(0));
+        assert!(!ctx.consume_event(0), "already consumed");
+    });
+    assert!(output.unconsumed_events.is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn test_long_press() {
+    let pos = pos2(20.0, 15.0);
+    let mut ctx = CtxRef::default();
+    let mut run = |time: f64, events: Vec<Event>| {
+        let raw_input = RawInput {
+            time: Some(time),
+            events,
+            ..Default::default()
+        };
+        let mut long_touched = false;
+        let _ = ctx.run(raw_input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                long_touched = ui.button("Hold me").long_touched();
+            });
+        });
+        long_touched
+    };
+
+    let press = Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed: true,
+        modifiers: Modifiers::NONE,
+    };
+    assert!(!run(0.0, vec![Event::PointerMoved(pos), press]));
+    assert!(!run(0.3, vec![]));
+    assert!(run(1.0, vec![]));
+    assert!(!run(1.1, vec![]), "Only reported once");
+}
+
+#[cfg(test)]
+#[test]
+fn test_back_button() {
+    let on_button = pos2(20.0, 15.0);
+    let off_button = pos2(300.0, 300.0);
+    let mut ctx = CtxRef::default();
+    ctx.push_view(Id::new("first"));
+    ctx.push_view(Id::new("second"));
+    let mut run = |events: Vec<Event>| {
+        let raw_input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(raw_input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let _ = ui.button("Button");
+            });
+        });
+        ctx.current_view()
+    };
+    let back = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Extra1,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+
+    run(vec![Event::PointerMoved(on_button)]);
+    let view = run(vec![back(on_button, true), back(on_button, false)]);
+    assert_eq!(view, Some(Id::new("second")), "pressed on a button");
+
+    run(vec![Event::PointerMoved(off_button)]);
+    let view = run(vec![back(off_button, true), back(off_button, false)]);
+    assert_eq!(view, Some(Id::new("first")));
+}
+
+#[cfg(test)]
+#[test]
+fn test_input_recording() {
+    let mut ctx = CtxRef::default();
+    let click = |pressed| Event::PointerButton {
+        pos: pos2(10.0, 10.0),
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    let inputs: Vec<RawInput> = [vec![click(true)], vec![click(false)], vec![]]
+        .into_iter()
+        .enumerate()
+        .map(|(i, events)| RawInput {
+            time: Some(i as f64 / 60.0),
+            events,
+            ..Default::default()
+        })
+        .collect();
+
+    let app = |ctx: &CtxRef, clicks: &mut usize| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            if ui.button("Click me").clicked() {
+                *clicks += 1;
+            }
+        });
+    };
+
+    let mut clicks = 0;
+    let _ = ctx.run(Default::default(), |ctx| app(ctx, &mut clicks));
+    ctx.start_recording_input();
+    for raw_input in &inputs {
+        let _ = ctx.run(raw_input.clone(), |ctx| app(ctx, &mut clicks));
+    }
+    let recording = ctx.stop_recording_input().unwrap();
+    assert!(!ctx.is_recording_input());
+    assert_eq!(recording.frames, inputs);
+    assert_eq!(clicks, 1);
+
+    // Replay in a fresh context:
+    let mut ctx = CtxRef::default();
+    let mut replayed_clicks = 0;
+    let _ = ctx.run(Default::default(), |ctx| app(ctx, &mut replayed_clicks));
+    for raw_input in crate::InputReplayer::new(recording) {
+        let _ = ctx.run(raw_input, |ctx| app(ctx, &mut replayed_clicks));
+    }
+    assert_eq!(replayed_clicks, 1);
+}
+
+#[cfg(test)]
+#[test]
+fn test_layer_paint_stats() {
+    let mut ctx = CtxRef::default();
+    let (_, shapes) = ctx.run(Default::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            ui.label("In panel");
+        });
+        crate::Window::new("My window").show(ctx, |ui| {
+            ui.label("In window");
+        });
+    });
+    let num_shapes = shapes.len();
+    let clipped_meshes = ctx.tessellate(shapes);
+
+    let stats = ctx.layer_paint_stats();
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[0].layer_id, LayerId::background());
+    assert_eq!(stats[1].layer_id.order, Order::Middle);
+    assert!(stats.iter().all(|layer| layer.num_triangles > 0));
+
+    let total_vertices: usize = clipped_meshes.iter().map(|m| m.1.vertices.len()).sum();
+    let layer_vertices: usize = stats.iter().map(|layer| layer.num_vertices).sum();
+    assert_eq!(total_vertices, layer_vertices);
+    assert_eq!(
+        stats.iter().map(|layer| layer.num_shapes).sum::<usize>(),
+        num_shapes
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_layer_paint_stats_of_other_shapes() {
+    let mut ctx = CtxRef::default();
+    let (_, shapes) = ctx.run(Default::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            ui.label("In panel");
+        });
+    });
+    // Not the list returned by `end_frame`:
+    let mut other_shapes = shapes.clone();
+    other_shapes.pop();
+    let _ = ctx.tessellate(other_shapes);
+    assert!(ctx.layer_paint_stats().is_empty());
+
+    // Only the first call to `tessellate` after `end_frame` can use the layers:
+    let _ = ctx.tessellate(shapes);
+    assert!(ctx.layer_paint_stats().is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn test_frame_timings() {
+    let mut ctx = CtxRef::default();
+    for i in 0..3 {
+        let raw_input = RawInput {
+            time: Some(i as f64),
+            ..Default::default()
+        };
+        let (_, shapes) = ctx.run(raw_input, |ctx| {
+            crate::Window::new("My window").show(ctx, |ui| {
+                ui.label("Hello");
+            });
+        });
+        let _ = ctx.tessellate(shapes);
+    }
+
+    let timings = ctx.frame_timings();
+    let times: Vec<f64> = timings.iter().map(|(time, _)| *time).collect();
+    assert_eq!(times, vec![0.0, 1.0, 2.0]);
+    let (_, latest) = timings[2];
+    assert!(latest.tessellation > 0.0);
+    assert!(latest.total() >= latest.ui + latest.tessellation);
+}
+
+#[cfg(test)]
+#[test]
+fn test_cull_shapes_outside_screen() {
+    let mut ctx = CtxRef::default();
+    let (_, shapes) = ctx.run(Default::default(), |ctx| {
+        let painter = Painter::new(ctx.clone(), LayerId::background(), Rect::EVERYTHING);
+        painter.rect_filled(
+            Rect::from_min_size(pos2(10.0, 10.0), vec2(10.0, 10.0)),
+            0.0,
+            Color32::RED,
+        );
+        painter.rect_filled(
+            Rect::from_min_size(pos2(-50.0, 10.0), vec2(10.0, 10.0)),
+            0.0,
+            Color32::RED,
+        );
+    });
+    let clipped_meshes = ctx.tessellate(shapes);
+    assert_eq!(ctx.paint_stats.lock().culled_shapes, 1);
+    assert_eq!(clipped_meshes.len(), 1);
+    assert!(ctx.input().screen_rect().contains_rect(clipped_meshes[0].0));
+}
+
+#[cfg(test)]
+#[test]
+fn test_recycle_meshes() {
+    let mut ctx = CtxRef::default();
+    let run = |ctx: &mut CtxRef| {
+        let (_, shapes) = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                ui.label("Hello");
+            });
+        });
+        ctx.tessellate(shapes)
+    };
+
+    let first = run(&mut ctx);
+    let vertex_buffers: Vec<_> = first.iter().map(|m| m.1.vertices.as_ptr()).collect();
+    let num_vertices: usize = first.iter().map(|m| m.1.vertices.len()).sum();
+    ctx.recycle_meshes(first);
+
+    let second = run(&mut ctx);
+    assert!(second
+        .iter()
+        .any(|m| vertex_buffers.contains(&m.1.vertices.as_ptr())));
+    assert_eq!(
+        second.iter().map(|m| m.1.vertices.len()).sum::<usize>(),
+        num_vertices
+    );
+    assert!(ctx.recycled_meshes.lock().is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn test_forget_id() {
+    let mut ctx = CtxRef::default();
+    let _ = ctx.run(Default::default(), |ctx| {
+        crate::Window::new("Document").show(ctx, |ui| {
+            ui.label("Hello");
+        });
+    });
+    let id = Id::new("Document");
+    ctx.memory().data_temp.insert_temp(id, 42_i32);
+    ctx.memory().data_temp.insert_temp(Id::new("Other"), 42_i32);
+    assert!(ctx.memory().areas.get(id).is_some());
+
+    ctx.forget_id(id);
+    assert!(ctx.memory().areas.get(id).is_none());
+    assert_eq!(ctx.memory().data_temp.get_temp::<i32>(id), None);
+
+    ctx.forget_state_of::<i32>();
+    assert_eq!(ctx.memory().data_temp.count::<i32>(), 0);
+}
+
+#[cfg(test)]
+#[test]
+fn test_id_clashes() {
+    let mut ctx = CtxRef::default();
+    ctx.memory().options.id_clash_policy = IdClashPolicy::Ignore;
+    let _ = ctx.run(Default::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            let _ = ui.add(crate::Button::new("First"));
+            crate::CollapsingHeader::new("Same").show(ui, |_| {});
+            crate::CollapsingHeader::new("Same").show(ui, |_| {});
+        });
+    });
+    let clashes = ctx.id_clashes();
+    assert_eq!(clashes.len(), 1);
+    assert!(clashes[0].first_rect.max.y <= clashes[0].second_rect.min.y);
+
+    ctx.memory().options.id_clash_policy = IdClashPolicy::Panic;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _ = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                crate::CollapsingHeader::new("Same").show(ui, |_| {});
+                crate::CollapsingHeader::new("Same").show(ui, |_| {});
+            });
+        });
+    }));
+    assert!(result.is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn test_animate_value() {
+    let id = Id::new("animated");
+    let mut ctx = CtxRef::default();
+    let mut style = (*ctx.style()).clone();
+    style.animation_easing = Easing::Linear;
+    ctx.set_style(style);
+    let mut run = |time: f64, target: f32| {
+        let raw_input = RawInput {
+            time: Some(time),
+            predicted_dt: 0.0,
+            ..Default::default()
+        };
+        let mut values = (0.0, Vec2::ZERO, Color32::TRANSPARENT);
+        let (output, _) = ctx.run(raw_input, |ctx| {
+            values = (
+                ctx.animate_value_with_time(id, target, 1.0),
+                ctx.animate_vec2_with_time(id.with("vec2"), Vec2::splat(target), 1.0),
+                ctx.animate_color_with_time(
+                    id.with("color"),
+                    Color32::from_gray(target as u8),
+                    1.0,
+                ),
+            );
+            let eased = ctx.animate_value_with_time_and_easing(
+                id.with("eased"),
+                target,
+                1.0,
+                Easing::CubicOut,
+            );
+            if time == 2.0 {
+                assert!(eased > values.0, "cubic out starts faster than linear");
+            }
+        });
+        (values, output.needs_repaint)
+    };
+
+    assert_eq!(run(0.0, 0.0).0 .0, 0.0, "starts at the target");
+    assert_eq!(run(1.0, 0.0).0 .0, 0.0);
+    let ((value, vec, color), needs_repaint) = run(1.5, 100.0);
+    assert_eq!(value, 0.0, "starts moving when the target changes");
+    assert!(needs_repaint);
+    assert_eq!(vec, Vec2::ZERO);
+    assert_eq!(color, Color32::BLACK);
+
+    let ((value, vec, color), _) = run(2.0, 100.0);
+    assert!((value - 50.0).abs() < 1e-3, "{}", value);
+    assert_eq!(vec, Vec2::splat(value));
+    assert!(0 < color.r() && color.r() < 100);
+
+    let ((value, _, _), _) = run(2.25, 0.0);
+    assert!((value - 75.0).abs() < 1e-3, "continues from where it is");
+    let ((value, _, _), _) = run(2.75, 0.0);
+    assert!((value - 37.5).abs() < 1e-3, "{}", value);
+    let ((value, vec, color), _) = run(3.5, 0.0);
+    assert_eq!(value, 0.0);
+    assert_eq!(vec, Vec2::ZERO);
+    assert_eq!(color, Color32::BLACK);
+    assert!(!run(3.6, 0.0).1, "no more repaints once done");
+}
+
+#[cfg(test)]
+#[test]
+fn test_power_save_skips_animate_bool() {
+    let id = Id::new("open");
+    let mut ctx = CtxRef::default();
+    ctx.memory().options.power_save = true;
+    let mut run = |time: f64, open: bool| {
+        let raw_input = RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let mut openness = 0.0;
+        let _ = ctx.run(raw_input, |ctx| {
+            openness = ctx.animate_bool(id, open);
+        });
+        openness
+    };
+
+    assert_eq!(run(0.0, false), 0.0);
+    assert_eq!(run(0.1, true), 1.0);
+    assert_eq!(run(0.2, false), 0.0);
+}
+
+#[cfg(test)]
+#[test]
+fn test_animate_value_with_spring() {
+    let id = Id::new("spring");
+    let mut ctx = CtxRef::default();
+    let mut run = |time: f64, target: f32, spring: Spring| {
+        let raw_input = RawInput {
+            time: Some(time),
+            predicted_dt: 0.0,
+            ..Default::default()
+        };
+        let mut value = 0.0;
+        let (output, _) = ctx.run(raw_input, |ctx| {
+            value = ctx.animate_value_with_spring(id, target, spring);
+        });
+        (value, output.needs_repaint)
+    };
+    let spring = Spring::new(1.0);
+
+    assert_eq!(run(0.0, 0.0, spring).0, 0.0, "starts at the target");
+    let (value, needs_repaint) = run(0.1, 100.0, spring);
+    assert!(0.0 < value && value < 100.0, "{}", value);
+    assert!(needs_repaint);
+
+    // Moving the target keeps the speed, so the value doesn't slow down or jump back:
+    let before = run(0.2, 100.0, spring).0;
+    let speed = (before - value) / 0.1;
+    let after = run(0.3, 200.0, spring).0;
+    assert!(after - before > 0.5 * speed * 0.1, "{} {}", before, after);
+
+    let (value, _) = run(10.0, 200.0, spring);
+    assert_eq!(value, 200.0, "settles exactly at the target");
+    assert!(!run(10.1, 200.0, spring).1, "no more repaints once settled");
+
+    // A bouncy spring overshoots:
+    let bouncy = Spring::new(1.0).damping_ratio(0.2);
+    run(20.0, 0.0, bouncy);
+    let max = (1..40)
+        .map(|i| run(20.0 + 0.05 * i as f64, 100.0, bouncy).0)
+        .fold(0.0, f32::max);
+    assert!(max > 100.0, "{}", max);
+}
+
+#[cfg(test)]
+#[test]
+fn test_zoom_factor() {
+    let mut ctx = CtxRef::default();
+    ctx.set_zoom_factor(2.0);
+    let raw_input = RawInput {
+        pixels_per_point: Some(1.5),
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+        events: vec![Event::PointerMoved(pos2(100.0, 200.0))],
+        ..Default::default()
+    };
+    let (output, _) = ctx.run(raw_input, |ctx| {
+        assert_eq!(ctx.pixels_per_point(), 3.0);
+        assert_eq!(ctx.input().screen_rect.size(), vec2(400.0, 300.0));
+        assert_eq!(ctx.input().pointer.hover_pos(), Some(pos2(50.0, 100.0)));
+        ctx.output().text_cursor_pos = Some(pos2(10.0, 20.0));
+    });
+    assert_eq!(
+        output.text_cursor_pos,
+        Some(pos2(20.0, 40.0)),
+        "output is in native points"
+    );
+
+    // The zoom factor stays when the integration reports a new `pixels_per_point`:
+    let raw_input = RawInput {
+        pixels_per_point: Some(1.0),
+        ..Default::default()
+    };
+    let _ = ctx.run(raw_input, |ctx| {
+        assert_eq!(ctx.pixels_per_point(), 2.0);
+    });
+}
+
+#[cfg(test)]
+#[test]
+fn test_zoom_with_keyboard() {
+    let mut ctx = CtxRef::default();
+    let press = |ctx: &mut CtxRef, key: Key, modifiers: Modifiers| {
+        let raw_input = RawInput {
+            events: vec![Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+            }],
+            ..Default::default()
+        };
+        let mut app_saw_key = false;
+        let _ = ctx.run(raw_input, |ctx| {
+            app_saw_key = ctx.consume_key(modifiers, key) > 0;
+        });
+        (ctx.zoom_factor(), app_saw_key)
+    };
+
+    let (zoom_factor, app_saw_key) = press(&mut ctx, Key::PlusEquals, Modifiers::COMMAND);
+    assert!(zoom_factor > 1.0);
+    assert!(!app_saw_key, "the zoom key is consumed");
+    assert!(press(&mut ctx, Key::Minus, Modifiers::COMMAND).0 < zoom_factor);
+    assert!(press(&mut ctx, Key::Minus, Modifiers::COMMAND).0 < 1.0);
+    assert_eq!(press(&mut ctx, Key::Num0, Modifiers::COMMAND).0, 1.0);
+
+    for _ in 0..100 {
+        press(&mut ctx, Key::PlusEquals, Modifiers::COMMAND);
+    }
+    assert_eq!(ctx.zoom_factor(), MAX_ZOOM_FACTOR);
+    press(&mut ctx, Key::Num0, Modifiers::COMMAND);
+
+    let (zoom_factor, app_saw_key) = press(&mut ctx, Key::PlusEquals, Modifiers::NONE);
+    assert_eq!(zoom_factor, 1.0, "needs the command modifier");
+    assert!(app_saw_key);
+
+    ctx.memory().options.zoom_with_keyboard = false;
+    let (zoom_factor, app_saw_key) = press(&mut ctx, Key::PlusEquals, Modifiers::COMMAND);
+    assert_eq!(zoom_factor, 1.0);
+    assert!(
+        app_saw_key,
+        "the app gets the key when zoom_with_keyboard is off"
+    );
+}
+
+#[test]
+fn test_request_repaint_at() {
+    let mut ctx = CtxRef::default();
+    let mut text = String::from("blink");
+    let mut run = |time: f64, request_focus: bool| {
+        let raw_input = RawInput {
+            time: Some(time),
+            predicted_dt: 0.0,
+            ..Default::default()
+        };
+        let (output, shapes) = ctx.run(raw_input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let response = ui.text_edit_singleline(&mut text);
+                if request_focus {
+                    response.request_focus();
+                }
+            });
+            ctx.request_repaint_at(time + 5.0);
+        });
+        let cursor_color = ctx.style().visuals.selection.stroke.color;
+        let cursor_shown = shapes.iter().any(|ClippedShape(_, shape)| {
+            matches!(shape, Shape::LineSegment { stroke, .. } if stroke.color == cursor_color)
+        });
+        (output, cursor_shown)
+    };
+
+    run(0.0, false);
+    let (output, cursor_shown) = run(0.5, false);
+    assert!(!cursor_shown);
+    assert!(output.needs_repaint);
+    assert_eq!(output.repaint_after, 5.0);
+
+    run(1.0, true);
+    let (output, cursor_shown) = run(2.0, false);
+    assert!(cursor_shown);
+    assert!(output.needs_repaint);
+    assert!(
+        0.0 < output.repaint_after && output.repaint_after <= 0.5,
+        "the earliest request wins: {}",
+        output.repaint_after
+    );
+
+    let mut blinks = vec![];
+    for i in 0..4 {
+        let (output, cursor_shown) = run(2.0 + 0.3 * i as f64 + 0.5, false);
+        blinks.push(cursor_shown);
+        assert!(output.repaint_after > 0.0, "no continuous repaints");
+    }
+    assert!(
+        blinks.contains(&true) && blinks.contains(&false),
+        "{:?}",
+        blinks
+    );
+}
@@ -28,6 +28,19 @@ pub struct RawInput {
     /// Set this the first frame, whenever it changes, or just on every frame.
     pub pixels_per_point: Option<f32>,
 
+    /// Is the operating system in dark or light mode?
+    /// Set this the first frame and whenever it changes, or just on every frame.
+    ///
+    /// `None` will be treated as "same as last frame".
+    /// See [`crate::Context::set_theme_preference`].
+    pub system_theme: Option<crate::Theme>,
+
+    /// Has the user asked the operating system to minimize non-essential motion?
+    ///
+    /// `None` will be treated as "same as last frame".
+    /// See [`crate::Context::reduce_motion`].
+    pub prefers_reduced_motion: Option<bool>,
+
     /// Monotonically increasing time, in seconds. Relative to whatever. Used for animations.
     /// If `None` is provided, egui will assume a time delta of `predicted_dt` (default 1/60 seconds).
     pub time: Option<f64>,
@@ -62,6 +75,8 @@ impl Default for RawInput {
         Self {
             screen_rect: None,
             pixels_per_point: None,
+            system_theme: None,
+            prefers_reduced_motion: None,
             time: None,
             predicted_dt: 1.0 / 60.0,
             modifiers: Modifiers::default(),
@@ -81,6 +96,8 @@ impl RawInput {
         RawInput {
             screen_rect: self.screen_rect.take(),
             pixels_per_point: self.pixels_per_point.take(),
+            system_theme: self.system_theme.take(),
+            prefers_reduced_motion: self.prefers_reduced_motion.take(),
             time: self.time.take(),
             predicted_dt: self.predicted_dt,
             modifiers: self.modifiers,
@@ -95,6 +112,8 @@ impl RawInput {
         let Self {
             screen_rect,
             pixels_per_point,
+            system_theme,
+            prefers_reduced_motion,
             time,
             predicted_dt,
             modifiers,
@@ -105,6 +124,8 @@ impl RawInput {
 
         self.screen_rect = screen_rect.or(self.screen_rect);
         self.pixels_per_point = pixels_per_point.or(self.pixels_per_point);
+        self.system_theme = system_theme.or(self.system_theme);
+        self.prefers_reduced_motion = prefers_reduced_motion.or(self.prefers_reduced_motion);
         self.time = time; // use latest time
         self.predicted_dt = predicted_dt; // use latest dt
         self.modifiers = modifiers; // use latest
@@ -152,6 +173,13 @@ pub enum Event {
     ///
     /// When the user presses enter/return, do not send a `Text` (just [`Key::Enter`]).
     Text(String),
+    /// The user pasted something from the clipboard (e.g. with Cmd+V).
+    ///
+    /// For now the integrations also send pasted text (but not images) as [`Self::Text`], right after this,
+    /// so that widgets that only read [`Self::Text`] keep working.
+    /// That is also what the built-in text fields use, so they don't paste twice.
+    /// This will change in the next release.
+    Paste(ClipboardData),
     Key {
         key: Key,
         pressed: bool,
@@ -173,9 +201,32 @@ pub enum Event {
     /// On touch-up first send `PointerButton{pressed: false, …}` followed by `PointerLeft`.
     PointerGone,
 
+    /// The primary pointer button (or a finger) was held down at this position without moving,
+    /// for long enough that it is no longer a click.
+    ///
+    /// egui detects this itself and adds it to [`crate::InputState::events`],
+    /// so integrations do not need to send it.
+    /// See [`crate::Response::long_touched`].
+    LongPress(Pos2),
+
     /// How many points (logical pixels) the user scrolled.
+    ///
+    /// Prefer [`Self::MouseWheel`], which lets egui scroll line-based wheels smoothly.
     Scroll(Vec2),
 
+    /// The user turned the mouse wheel or scrolled on a trackpad.
+    MouseWheel {
+        /// What `delta` is measured in.
+        unit: MouseWheelUnit,
+
+        /// How far the user scrolled, with positive values scrolling the content right and down
+        /// (i.e. revealing what is to the left and above).
+        delta: Vec2,
+
+        /// The state of the modifier keys at the time of the event.
+        modifiers: Modifiers,
+    },
+
     /// Zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
     /// * `zoom = 1`: no change.
     /// * `zoom < 1`: pinch together
@@ -206,6 +257,98 @@ pub enum Event {
         /// The value is in the range from 0.0 (no pressure) to 1.0 (maximum pressure).
         force: f32,
     },
+
+    /// A gamepad button was pressed or released.
+    ///
+    /// egui uses these to navigate the ui, see [`GamepadButton`].
+    ///
+    /// NOTE: none of the integrations in this repository send gamepad events (winit has no gamepad support).
+    /// If you want gamepad navigation, read the gamepad yourself (e.g. with the `gilrs` crate)
+    /// and push these events onto [`RawInput::events`].
+    GamepadButton {
+        button: GamepadButton,
+        pressed: bool,
+    },
+
+    /// A gamepad stick moved.
+    ///
+    /// Tilting the left stick moves keyboard focus, like the d-pad.
+    /// Like [`Self::GamepadButton`], this must be sent by your own integration code.
+    GamepadAxis {
+        axis: GamepadAxis,
+        /// In the range `-1.0..=1.0`, with positive values to the right and up.
+        value: f32,
+    },
+
+    /// The pixels of a widget, captured by the integration
+    /// in response to a [`crate::output::SnapshotRequest`] last frame.
+    Snapshot {
+        /// The [`crate::output::SnapshotRequest::id`].
+        id: crate::Id,
+        snapshot: std::sync::Arc<WidgetSnapshot>,
+    },
+}
+
+/// Something that can be put in, or taken out of, the system clipboard.
+///
+/// See [`crate::Output::copied`] and [`Event::Paste`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ClipboardData {
+    /// Plain text.
+    Text(String),
+
+    /// An image, e.g. a color swatch or a screenshot.
+    ///
+    /// NOTE: `egui-winit` can only copy and paste text, and ignores copied images.
+    Image(ClipboardImage),
+
+    /// Rich text, e.g. a table copied from a spreadsheet.
+    Html {
+        html: String,
+        /// The same content as plain text, for where HTML isn't supported.
+        text: String,
+    },
+}
+
+impl ClipboardData {
+    /// The plain text of this, unless it is an image.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) | Self::Html { text, .. } => Some(text),
+            Self::Image(_) => None,
+        }
+    }
+}
+
+/// An image in the clipboard, see [`ClipboardData::Image`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ClipboardImage {
+    /// Width and height, in pixels.
+    pub size: [usize; 2],
+
+    /// Unmultiplied RGBA, four bytes per pixel, row by row, starting at the top left.
+    pub rgba: Vec<u8>,
+}
+
+/// The pixels of a part of the screen, captured by the integration.
+///
+/// See [`crate::Response::snapshot`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct WidgetSnapshot {
+    /// Width and height, in physical pixels.
+    pub size: [usize; 2],
+
+    /// The `pixels_per_point` used when the pixels were captured.
+    pub pixels_per_point: f32,
+
+    /// The pixels, row by row, starting at the top left.
+    ///
+    /// These are the final colors as they were written to the (sRGB) frame buffer,
+    /// with premultiplied alpha.
+    pub pixels: Vec<crate::Color32>,
 }
 
 /// Mouse button (or similar for touch input)
@@ -219,13 +362,83 @@ pub enum PointerButton {
     Secondary = 1,
     /// The tertiary mouse button is usually the middle mouse button (e.g. clicking the scroll wheel).
     Middle = 2,
+    /// The first extra mouse button, usually the "back" button on the side of the mouse.
+    ///
+    /// Pressing it will call [`crate::Context::back`].
+    Extra1 = 3,
+    /// The second extra mouse button, usually the "forward" button on the side of the mouse.
+    ///
+    /// Pressing it will call [`crate::Context::forward`].
+    Extra2 = 4,
 }
 
 /// Number of pointer buttons supported by egui, i.e. the number of possible states of [`PointerButton`].
-pub const NUM_POINTER_BUTTONS: usize = 3;
+pub const NUM_POINTER_BUTTONS: usize = 5;
+
+/// What the `delta` of an [`Event::MouseWheel`] is measured in.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum MouseWheelUnit {
+    /// Points (logical pixels), e.g. from a trackpad or a free-spinning wheel.
+    ///
+    /// This is applied immediately.
+    Point,
+
+    /// Lines of text, e.g. from a mouse wheel with notches.
+    ///
+    /// egui converts this with `Memory::options.line_scroll_speed`
+    /// and scrolls there smoothly (see `Memory::options.scroll_smoothing`).
+    Line,
+
+    /// Pages, i.e. the height (or width) of the screen.
+    ///
+    /// This is scrolled smoothly, just like [`Self::Line`].
+    Page,
+}
+
+/// A button on a gamepad, named as on an Xbox controller.
+///
+/// egui maps these onto keyboard focus, so that a ui can be used with only a gamepad:
+/// the d-pad moves focus to the nearest widget in that direction,
+/// [`Self::A`] clicks the focused widget, [`Self::B`] closes popups and menus,
+/// and the triggers adjust a focused slider or [`crate::DragValue`].
+///
+/// The integrations in this repository don't read gamepads, see [`Event::GamepadButton`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum GamepadButton {
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    /// The bottom face button (✕ on Sony controllers).
+    A,
+    /// The right face button (○ on Sony controllers).
+    B,
+    /// The left face button (□ on Sony controllers).
+    X,
+    /// The top face button (△ on Sony controllers).
+    Y,
+    LeftShoulder,
+    RightShoulder,
+    LeftTrigger,
+    RightTrigger,
+    Start,
+    Select,
+}
+
+/// A stick on a gamepad. See [`Event::GamepadAxis`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+}
 
 /// State of the modifier keys. These must be fed to egui.
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Modifiers {
     /// Either of the alt keys are down (option ⌥ on Mac).
@@ -245,6 +458,51 @@ pub struct Modifiers {
 }
 
 impl Modifiers {
+    pub const NONE: Self = Self {
+        alt: false,
+        ctrl: false,
+        shift: false,
+        mac_cmd: false,
+        command: false,
+    };
+
+    pub const ALT: Self = Self {
+        alt: true,
+        ..Self::NONE
+    };
+    pub const CTRL: Self = Self {
+        ctrl: true,
+        ..Self::NONE
+    };
+    pub const SHIFT: Self = Self {
+        shift: true,
+        ..Self::NONE
+    };
+    /// The Mac ⌘ Command key. Prefer [`Self::COMMAND`] for cross-platform shortcuts.
+    pub const MAC_CMD: Self = Self {
+        mac_cmd: true,
+        command: true,
+        ..Self::NONE
+    };
+    /// The ⌘ Command key on Mac, and Ctrl on other platforms.
+    pub const COMMAND: Self = Self {
+        command: true,
+        ..Self::NONE
+    };
+
+    /// Both these modifiers and `rhs`, e.g. `Modifiers::COMMAND.plus(Modifiers::SHIFT)`.
+    ///
+    /// Same as `|`, but usable in `const` contexts.
+    pub const fn plus(self, rhs: Self) -> Self {
+        Self {
+            alt: self.alt | rhs.alt,
+            ctrl: self.ctrl | rhs.ctrl,
+            shift: self.shift | rhs.shift,
+            mac_cmd: self.mac_cmd | rhs.mac_cmd,
+            command: self.command | rhs.command,
+        }
+    }
+
     #[inline(always)]
     pub fn is_none(&self) -> bool {
         self == &Self::default()
@@ -265,6 +523,119 @@ impl Modifiers {
     pub fn command_only(&self) -> bool {
         !self.alt && !self.shift && self.command
     }
+
+    /// Are exactly the modifiers in `pattern` held down?
+    ///
+    /// If `pattern` has [`Self::command`] set, either ctrl or ⌘ (depending on the platform) will match it.
+    pub fn matches(&self, pattern: Modifiers) -> bool {
+        if self.alt != pattern.alt || self.shift != pattern.shift {
+            return false;
+        }
+        if pattern.command {
+            // `command` is either ctrl or ⌘, so allow whichever of the two is providing it:
+            self.command
+                && (self.ctrl == pattern.ctrl || !self.mac_cmd)
+                && (self.mac_cmd == pattern.mac_cmd || !self.ctrl)
+        } else {
+            self.ctrl == pattern.ctrl && self.mac_cmd == pattern.mac_cmd
+        }
+    }
+}
+
+impl std::ops::BitOr for Modifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.plus(rhs)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A keyboard shortcut, e.g. `Ctrl+S` (or `⌘S` on Mac).
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// use egui::{Key, KeyboardShortcut, Modifiers};
+/// const SAVE: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+///
+/// if ctx.consume_shortcut(&SAVE) {
+///     // save…
+/// }
+/// let menu_text = format!("Save ({})", ctx.format_shortcut(&SAVE));
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KeyboardShortcut {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl KeyboardShortcut {
+    pub const fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Human-readable text for this shortcut, e.g. `"Ctrl+Shift+S"`, or `"⇧⌘S"` if `is_mac`.
+    ///
+    /// Usually you want [`crate::Context::format_shortcut`] instead.
+    pub fn format(&self, is_mac: bool) -> String {
+        let keys = self.keys(is_mac);
+        if is_mac {
+            keys.concat()
+        } else {
+            keys.join("+")
+        }
+    }
+
+    /// The keys to press, modifiers first, e.g. `["Ctrl", "Shift", "S"]`, or `["⇧", "⌘", "S"]` if `is_mac`.
+    ///
+    /// See also [`crate::Kbd::shortcut`], which shows these as key caps.
+    pub fn keys(&self, is_mac: bool) -> Vec<&'static str> {
+        let Modifiers {
+            alt,
+            ctrl,
+            shift,
+            mac_cmd,
+            command,
+        } = self.modifiers;
+
+        let modifiers = if is_mac {
+            [
+                (ctrl, "⌃"),
+                (alt, "⌥"),
+                (shift, "⇧"),
+                (mac_cmd || command, "⌘"),
+            ]
+        } else {
+            [
+                (ctrl || command, "Ctrl"),
+                (alt, "Alt"),
+                (shift, "Shift"),
+                (mac_cmd, "Cmd"),
+            ]
+        };
+        let mut keys: Vec<&'static str> = modifiers
+            .iter()
+            .filter(|(is_down, _)| *is_down)
+            .map(|(_, name)| *name)
+            .collect();
+        keys.push(self.key.name());
+        keys
+    }
+}
+
+/// A keyboard shortcut and what it does, as registered with [`crate::Context::register_shortcut`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShortcutInfo {
+    pub shortcut: KeyboardShortcut,
+
+    /// Used to group the shortcuts, e.g. `"File"` or `"Navigation"`.
+    pub category: String,
+
+    /// What the shortcut does, e.g. `"Save"`.
+    pub description: String,
 }
 
 /// Keyboard keys.
@@ -295,6 +666,11 @@ pub enum Key {
     PageUp,
     PageDown,
 
+    /// The `-` key, either from the main row or from the numpad.
+    Minus,
+    /// The `+`/`=` key, or the numpad `+`.
+    PlusEquals,
+
     /// Either from the main row or from the numpad.
     Num0,
     /// Either from the main row or from the numpad.
@@ -342,6 +718,92 @@ pub enum Key {
     X,
     Y,
     Z, // Used for cmd+Z (undo)
+
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+}
+
+impl Key {
+    /// Human-readable name of the key, e.g. `"PageUp"` or `"A"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Key::ArrowDown => "Down",
+            Key::ArrowLeft => "Left",
+            Key::ArrowRight => "Right",
+            Key::ArrowUp => "Up",
+            Key::Escape => "Escape",
+            Key::Tab => "Tab",
+            Key::Backspace => "Backspace",
+            Key::Enter => "Enter",
+            Key::Space => "Space",
+            Key::Insert => "Insert",
+            Key::Delete => "Delete",
+            Key::Home => "Home",
+            Key::End => "End",
+            Key::PageUp => "PageUp",
+            Key::PageDown => "PageDown",
+            Key::Minus => "-",
+            Key::PlusEquals => "+",
+            Key::Num0 => "0",
+            Key::Num1 => "1",
+            Key::Num2 => "2",
+            Key::Num3 => "3",
+            Key::Num4 => "4",
+            Key::Num5 => "5",
+            Key::Num6 => "6",
+            Key::Num7 => "7",
+            Key::Num8 => "8",
+            Key::Num9 => "9",
+            Key::A => "A",
+            Key::B => "B",
+            Key::C => "C",
+            Key::D => "D",
+            Key::E => "E",
+            Key::F => "F",
+            Key::G => "G",
+            Key::H => "H",
+            Key::I => "I",
+            Key::J => "J",
+            Key::K => "K",
+            Key::L => "L",
+            Key::M => "M",
+            Key::N => "N",
+            Key::O => "O",
+            Key::P => "P",
+            Key::Q => "Q",
+            Key::R => "R",
+            Key::S => "S",
+            Key::T => "T",
+            Key::U => "U",
+            Key::V => "V",
+            Key::W => "W",
+            Key::X => "X",
+            Key::Y => "Y",
+            Key::Z => "Z",
+            Key::F1 => "F1",
+            Key::F2 => "F2",
+            Key::F3 => "F3",
+            Key::F4 => "F4",
+            Key::F5 => "F5",
+            Key::F6 => "F6",
+            Key::F7 => "F7",
+            Key::F8 => "F8",
+            Key::F9 => "F9",
+            Key::F10 => "F10",
+            Key::F11 => "F11",
+            Key::F12 => "F12",
+        }
+    }
 }
 
 impl RawInput {
@@ -349,6 +811,8 @@ impl RawInput {
         let Self {
             screen_rect,
             pixels_per_point,
+            system_theme,
+            prefers_reduced_motion,
             time,
             predicted_dt,
             modifiers,
@@ -362,6 +826,11 @@ impl RawInput {
             .on_hover_text(
                 "Also called HDPI factor.\nNumber of physical pixels per each logical pixel.",
             );
+        ui.label(format!("system_theme: {:?}", system_theme));
+        ui.label(format!(
+            "prefers_reduced_motion: {:?}",
+            prefers_reduced_motion
+        ));
         if let Some(time) = time {
             ui.label(format!("time: {:.3} s", time));
         } else {
@@ -423,3 +892,19 @@ impl From<u32> for TouchId {
         Self(id as u64)
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_keyboard_shortcut() {
+    let save = KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::S);
+    assert_eq!(save.format(false), "Ctrl+Shift+S");
+    assert_eq!(save.format(true), "⇧⌘S");
+    assert_eq!(save.keys(false), ["Ctrl", "Shift", "S"]);
+
+    let windows_ctrl = Modifiers::CTRL | Modifiers::COMMAND;
+    assert!(windows_ctrl.matches(Modifiers::COMMAND));
+    assert!(Modifiers::MAC_CMD.matches(Modifiers::COMMAND));
+    assert!(!(Modifiers::MAC_CMD | Modifiers::CTRL).matches(Modifiers::COMMAND));
+    assert!(!Modifiers::CTRL.matches(Modifiers::COMMAND));
+    assert!(!(windows_ctrl | Modifiers::SHIFT).matches(Modifiers::COMMAND));
+}
@@ -0,0 +1,165 @@
+//! Recording the input given to egui, and replaying it later.
+
+use super::input::RawInput;
+
+/// All the [`RawInput`] given to egui over a number of frames.
+///
+/// Record it with [`crate::Context::start_recording_input`] (or by calling [`Self::record`] yourself),
+/// save it with `serde` (requires the `serialize` feature),
+/// and replay it with an [`InputReplayer`].
+///
+/// This is useful for attaching reproductions to bug reports, and for turning them into tests.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InputRecording {
+    /// The input of each frame, in order.
+    pub frames: Vec<RawInput>,
+}
+
+impl InputRecording {
+    /// Add the input for one frame.
+    pub fn record(&mut self, raw_input: &RawInput) {
+        self.frames.push(raw_input.clone());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Time between the first and last recorded frame, in seconds.
+    pub fn duration(&self) -> f64 {
+        let mut times = self.frames.iter().filter_map(|frame| frame.time);
+        match (times.next(), times.next_back()) {
+            (Some(first), Some(last)) => last - first,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Feeds an [`InputRecording`] back to egui, frame by frame.
+///
+/// The recorded [`RawInput::time`] is kept as is, so that double-clicks, animations etc.
+/// behave just like when recording. For the same reason you should replay into a fresh [`crate::CtxRef`].
+///
+/// Use [`Self::poll`] to replay at the original pace (or a multiple of it),
+/// or iterate over the replayer to get all frames at once, e.g. in a test:
+///
+/// ```
+/// # let recording = egui::InputRecording::default();
+/// let mut ctx = egui::CtxRef::default();
+/// for raw_input in egui::InputReplayer::new(recording) {
+///     let (_output, _shapes) = ctx.run(raw_input, |ctx| {
+///         egui::CentralPanel::default().show(ctx, |ui| {
+///             ui.label("Hello world!");
+///         });
+///     });
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct InputReplayer {
+    recording: InputRecording,
+    next_frame: usize,
+    speed: f64,
+    /// The wall-clock time and the recorded time of the first replayed frame.
+    start: Option<(f64, f64)>,
+}
+
+impl InputReplayer {
+    pub fn new(recording: InputRecording) -> Self {
+        Self {
+            recording,
+            next_frame: 0,
+            speed: 1.0,
+            start: None,
+        }
+    }
+
+    /// How fast [`Self::poll`] replays the frames.
+    /// `1.0` (the default) is the original pace, `2.0` is twice as fast, and `f64::INFINITY` as fast as possible.
+    pub fn speed(mut self, speed: f64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Have all frames been replayed?
+    pub fn is_done(&self) -> bool {
+        self.next_frame >= self.recording.frames.len()
+    }
+
+    /// How many frames have been replayed so far.
+    pub fn frames_replayed(&self) -> usize {
+        self.next_frame
+    }
+
+    /// Returns the input for the next frame if it is time for it.
+    ///
+    /// `now` is the current time in seconds, on any clock (e.g. the one you use for [`RawInput::time`]).
+    /// Call this regularly (e.g. every frame) and give the input to egui whenever this returns something.
+    /// Returns `None` when it is not yet time for the next frame, or when the replay is done.
+    pub fn poll(&mut self, now: f64) -> Option<RawInput> {
+        let wait = self.seconds_until_next_frame(now)?;
+        if wait > 0.0 {
+            return None;
+        }
+        let frame = self.recording.frames[self.next_frame].clone();
+        if self.start.is_none() {
+            self.start = frame.time.map(|time| (now, time));
+        }
+        self.next_frame += 1;
+        Some(frame)
+    }
+
+    /// How long until [`Self::poll`] returns the next frame, in seconds.
+    ///
+    /// Returns `None` if the replay is done.
+    pub fn seconds_until_next_frame(&self, now: f64) -> Option<f64> {
+        let frame = self.recording.frames.get(self.next_frame)?;
+        match (self.start, frame.time) {
+            (Some((start_now, start_time)), Some(time)) => {
+                let due = start_now + (time - start_time) / self.speed;
+                Some((due - now).max(0.0))
+            }
+            _ => Some(0.0),
+        }
+    }
+}
+
+impl Iterator for InputReplayer {
+    type Item = RawInput;
+
+    /// The next frame, regardless of the replay speed.
+    fn next(&mut self) -> Option<RawInput> {
+        let frame = self.recording.frames.get(self.next_frame)?.clone();
+        self.next_frame += 1;
+        Some(frame)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_replay_pace() {
+    let frame = |time| RawInput {
+        time: Some(time),
+        ..Default::default()
+    };
+    let recording = InputRecording {
+        frames: vec![frame(10.0), frame(11.0), frame(13.0)],
+    };
+    assert_eq!(recording.duration(), 3.0);
+
+    let mut replayer = InputReplayer::new(recording.clone()).speed(2.0);
+    assert_eq!(replayer.poll(100.0), Some(frame(10.0)));
+    assert_eq!(replayer.poll(100.4), None);
+    assert!((replayer.seconds_until_next_frame(100.4).unwrap() - 0.1).abs() < 1e-6);
+    assert_eq!(replayer.poll(100.5), Some(frame(11.0)));
+    assert_eq!(replayer.poll(101.0), None);
+    assert_eq!(replayer.poll(101.5), Some(frame(13.0)));
+    assert!(replayer.is_done());
+    assert_eq!(replayer.poll(1000.0), None);
+
+    assert_eq!(InputReplayer::new(recording).count(), 3);
+}
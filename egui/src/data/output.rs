@@ -1,6 +1,6 @@
 //! All the data egui returns to the backend at the end of each frame.
 
-use crate::WidgetType;
+use crate::{Id, TextureId, Vec2, WidgetType};
 
 /// What egui emits each frame.
 /// The backend should use this.
@@ -10,13 +10,16 @@ pub struct Output {
     /// Set the cursor to this icon.
     pub cursor_icon: CursorIcon,
 
-    /// If set, open this url.
-    pub open_url: Option<OpenUrl>,
+    /// Open these urls, in order.
+    ///
+    /// See [`Self::open_url`] and [`crate::Hyperlink`].
+    pub open_urls: Vec<OpenUrl>,
 
-    /// If set, put this text in the system clipboard. Ignore if empty.
+    /// If set, put this in the system clipboard.
     ///
     /// This is often a response to [`crate::Event::Copy`] or [`crate::Event::Cut`].
-    pub copied_text: String,
+    /// See also [`Self::copy_text`].
+    pub copied: Option<crate::ClipboardData>,
 
     /// If `true`, egui is requesting immediate repaint (i.e. on the next frame).
     ///
@@ -26,64 +29,157 @@ pub struct Output {
     /// Call `Context::request_repaint()` instead and it will do so for you.
     pub needs_repaint: bool,
 
+    /// If [`Self::needs_repaint`] is set, the integration should wait this many seconds
+    /// before repainting (unless there is new input before then).
+    ///
+    /// This is zero unless [`crate::Context::is_power_saving`],
+    /// or all repaints were scheduled with [`crate::Context::request_repaint_at`].
+    pub repaint_after: f32,
+
     /// Events that may be useful to e.g. a screen reader.
     pub events: Vec<OutputEvent>,
 
+    /// The integration should capture these parts of the screen after painting this frame,
+    /// and send them back as [`crate::Event::Snapshot`] next frame.
+    ///
+    /// See [`crate::Response::snapshot`].
+    pub snapshot_requests: Vec<SnapshotRequest>,
+
+    /// Semantic events such as "button X was clicked" or "window Y was opened".
+    ///
+    /// Only recorded if `Memory::options.record_ui_events` is set.
+    /// Useful for usage analytics, macro recording and ui automation.
+    pub ui_events: Vec<UiEvent>,
+
+    /// The parts of the screen that egui covered this frame (panels, windows, popups, …),
+    /// i.e. where egui would catch the pointer. The union of these is the hit region.
+    ///
+    /// Integrations for transparent overlay windows can use this to make the rest
+    /// of the window click-through on the OS level.
+    /// Areas that are [`crate::Area::input_transparent`] are not included,
+    /// but regions passed to [`crate::Context::block_input_in`] are.
+    pub hit_regions: Vec<crate::Rect>,
+
+    /// All widgets and windows shown this frame, for feeding to the accessibility API of the platform.
+    ///
+    /// Only built if `Memory::options.accessibility_tree` is set.
+    /// A window comes before the widgets in it.
+    pub accessibility_nodes: Vec<AccessibilityNode>,
+
+    /// The input events of this frame that egui did not use.
+    ///
+    /// An event counts as used if it was handled with e.g. [`crate::Context::consume_event`],
+    /// if a widget used it (e.g. a click on a button or the scrolling of a [`crate::ScrollArea`]),
+    /// if it is a keyboard event while egui wants keyboard input,
+    /// or if it is a pointer event while egui wants pointer input at that position
+    /// (see [`crate::Context::wants_pointer_input_at`]).
+    ///
+    /// A game integration can forward these to e.g. camera controls,
+    /// instead of ignoring all input whenever egui is focused or hovered.
+    /// Positions are in the same points as the [`crate::RawInput`] given to egui.
+    pub unconsumed_events: Vec<crate::Event>,
+
+    /// Requests to move, resize, minimize etc. the native window.
+    ///
+    /// This lets you build your own title bar for a window without decorations,
+    /// see [`crate::Ui::window_drag_area`].
+    pub window_commands: Vec<WindowCommand>,
+
     /// Is there a mutable `TextEdit` under the cursor?
     /// Use by `egui_web` to show/hide mobile keyboard and IME agent.
     pub mutable_text_under_cursor: bool,
 
     /// Screen-space position of text edit cursor (used for IME).
     pub text_cursor_pos: Option<crate::Pos2>,
+
+    /// Set while a mutable [`crate::TextEdit`] has keyboard focus.
+    ///
+    /// Mobile and web integrations can use this to show the on-screen keyboard
+    /// and to scroll the text field so that it is not hidden behind it.
+    /// When this goes back to `None` the text field lost focus, and the keyboard should be hidden.
+    pub text_input: Option<TextInputState>,
 }
 
 impl Output {
     /// Open the given url in a web browser.
     /// If egui is running in a browser, the same tab will be reused.
     pub fn open_url(&mut self, url: impl ToString) {
-        self.open_url = Some(OpenUrl::same_tab(url));
+        self.open_urls.push(OpenUrl::same_tab(url));
+    }
+
+    /// Put the given text in the system clipboard.
+    pub fn copy_text(&mut self, text: impl Into<String>) {
+        self.copied = Some(crate::ClipboardData::Text(text.into()));
     }
 
     /// This can be used by a text-to-speech system to describe the events (if any).
+    ///
+    /// This describes the last widget event, preceded by any [`Priority::Assertive`] announcements
+    /// and followed by any [`Priority::Polite`] ones.
     pub fn events_description(&self) -> String {
-        // only describe last event:
-        if let Some(event) = self.events.iter().rev().next() {
-            match event {
-                OutputEvent::Clicked(widget_info)
-                | OutputEvent::DoubleClicked(widget_info)
-                | OutputEvent::FocusGained(widget_info)
-                | OutputEvent::TextSelectionChanged(widget_info)
-                | OutputEvent::ValueChanged(widget_info) => {
-                    return widget_info.description();
+        let announcements = |wanted_priority: Priority| {
+            self.events.iter().filter_map(move |event| match event {
+                OutputEvent::Announcement { text, priority } if *priority == wanted_priority => {
+                    Some(text.clone())
                 }
-            }
-        }
-        Default::default()
+                _ => None,
+            })
+        };
+
+        // only describe last widget event:
+        let widget_description = self.events.iter().rev().find_map(|event| match event {
+            OutputEvent::Clicked(widget_info)
+            | OutputEvent::DoubleClicked(widget_info)
+            | OutputEvent::FocusGained(widget_info)
+            | OutputEvent::TextSelectionChanged(widget_info)
+            | OutputEvent::ValueChanged(widget_info) => Some(widget_info.description()),
+            OutputEvent::Announcement { .. } => None,
+        });
+
+        let descriptions: Vec<String> = announcements(Priority::Assertive)
+            .chain(widget_description)
+            .chain(announcements(Priority::Polite))
+            .collect();
+        descriptions.join(". ")
     }
 
     /// Add on new output.
     pub fn append(&mut self, newer: Self) {
         let Self {
             cursor_icon,
-            open_url,
-            copied_text,
+            mut open_urls,
+            copied,
             needs_repaint,
+            repaint_after,
             mut events,
+            mut snapshot_requests,
+            mut ui_events,
+            hit_regions,
+            accessibility_nodes,
+            mut unconsumed_events,
+            mut window_commands,
             mutable_text_under_cursor,
             text_cursor_pos,
+            text_input,
         } = newer;
 
         self.cursor_icon = cursor_icon;
-        if open_url.is_some() {
-            self.open_url = open_url;
-        }
-        if !copied_text.is_empty() {
-            self.copied_text = copied_text;
+        self.open_urls.append(&mut open_urls);
+        if copied.is_some() {
+            self.copied = copied;
         }
         self.needs_repaint = needs_repaint; // if the last frame doesn't need a repaint, then we don't need to repaint
+        self.repaint_after = repaint_after;
         self.events.append(&mut events);
+        self.snapshot_requests.append(&mut snapshot_requests);
+        self.ui_events.append(&mut ui_events);
+        self.hit_regions = hit_regions;
+        self.accessibility_nodes = accessibility_nodes;
+        self.unconsumed_events.append(&mut unconsumed_events);
+        self.window_commands.append(&mut window_commands);
         self.mutable_text_under_cursor = mutable_text_under_cursor;
         self.text_cursor_pos = text_cursor_pos.or(self.text_cursor_pos);
+        self.text_input = text_input.or(self.text_input);
     }
 
     /// Take everything ephemeral (everything except `cursor_icon` currently)
@@ -94,7 +190,24 @@ impl Output {
     }
 }
 
-#[derive(Clone, PartialEq)]
+/// The focused text field, see [`Output::text_input`].
+///
+/// All rectangles are in screen-space points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TextInputState {
+    /// Where the text field is.
+    pub rect: crate::Rect,
+
+    /// Where the text cursor is, e.g. for placing an IME candidate window.
+    pub cursor_rect: crate::Rect,
+
+    /// `true` on the frame the text field gained keyboard focus.
+    pub gained_focus: bool,
+}
+
+/// A url for the integration to open, see [`Output::open_urls`].
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct OpenUrl {
     pub url: String,
@@ -122,6 +235,64 @@ impl OpenUrl {
     }
 }
 
+/// A request to the integration to do something with the native window.
+///
+/// See [`Output::window_commands`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WindowCommand {
+    /// Let the user move the window by dragging, starting now.
+    ///
+    /// Only send this while the primary pointer button is down.
+    StartDrag,
+
+    /// Let the user resize the window by dragging the given edge or corner, starting now.
+    ///
+    /// Only send this while the primary pointer button is down.
+    ///
+    /// NOTE: none of the current integrations support this yet, so it does nothing.
+    StartResize(ResizeDirection),
+
+    /// Minimize the window.
+    Minimize,
+
+    /// Maximize the window, or restore it if it is already maximized.
+    ToggleMaximized,
+
+    /// Close the window (and quit the app).
+    Close,
+}
+
+/// An edge or corner of a window, for [`WindowCommand::StartResize`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ResizeDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl ResizeDirection {
+    /// The cursor to show while hovering this edge or corner.
+    pub fn cursor_icon(self) -> CursorIcon {
+        match self {
+            Self::North => CursorIcon::ResizeNorth,
+            Self::NorthEast => CursorIcon::ResizeNorthEast,
+            Self::East => CursorIcon::ResizeEast,
+            Self::SouthEast => CursorIcon::ResizeSouthEast,
+            Self::South => CursorIcon::ResizeSouth,
+            Self::SouthWest => CursorIcon::ResizeSouthWest,
+            Self::West => CursorIcon::ResizeWest,
+            Self::NorthWest => CursorIcon::ResizeNorthWest,
+        }
+    }
+}
+
 /// A mouse cursor icon.
 ///
 /// egui emits a [`CursorIcon`] in [`Output`] each frame as a request to the integration.
@@ -204,14 +375,54 @@ pub enum CursorIcon {
     /// Vertical resize `|` (up-down or down-up)
     ResizeVertical,
 
+    // ------------------------------------
+    // Resizing in one direction:
+    /// Resize something rightwards (e.g. when dragging the right-most edge of something)
+    ResizeEast,
+    /// Resize something down and right (e.g. when dragging the bottom-right corner of something)
+    ResizeSouthEast,
+    /// Resize something downwards (e.g. when dragging the bottom edge of something)
+    ResizeSouth,
+    /// Resize something down and left (e.g. when dragging the bottom-left corner of something)
+    ResizeSouthWest,
+    /// Resize something leftwards (e.g. when dragging the left edge of something)
+    ResizeWest,
+    /// Resize something up and left (e.g. when dragging the top-left corner of something)
+    ResizeNorthWest,
+    /// Resize something up (e.g. when dragging the top edge of something)
+    ResizeNorth,
+    /// Resize something up and right (e.g. when dragging the top-right corner of something)
+    ResizeNorthEast,
+
+    // ------------------------------------
+    /// Resize a column
+    ResizeColumn,
+    /// Resize a row
+    ResizeRow,
+
+    // ------------------------------------
+    // Zooming:
     /// Enhance!
     ZoomIn,
     /// Let's get a better overview
     ZoomOut,
+
+    // ------------------------------------
+    /// An image of your own, e.g. for the tools of an image editor.
+    ///
+    /// The texture must have been registered with the integration (e.g. with `epi::Frame::alloc_texture`),
+    /// and is shown at its native size.
+    /// The second value is the hotspot: the texel (counting from the top-left corner)
+    /// that marks the exact position of the pointer.
+    ///
+    /// Not all integrations support this; those that don't will show [`Self::Default`] instead.
+    /// `egui_web` supports textures from `alloc_texture` of up to 128x128 pixels.
+    Custom(TextureId, Vec2),
 }
 
 impl CursorIcon {
-    pub const ALL: [CursorIcon; 25] = [
+    /// All the standard cursor icons, i.e. all except [`Self::Custom`].
+    pub const ALL: [CursorIcon; 35] = [
         CursorIcon::Default,
         CursorIcon::None,
         CursorIcon::ContextMenu,
@@ -235,6 +446,16 @@ impl CursorIcon {
         CursorIcon::ResizeNeSw,
         CursorIcon::ResizeNwSe,
         CursorIcon::ResizeVertical,
+        CursorIcon::ResizeEast,
+        CursorIcon::ResizeSouthEast,
+        CursorIcon::ResizeSouth,
+        CursorIcon::ResizeSouthWest,
+        CursorIcon::ResizeWest,
+        CursorIcon::ResizeNorthWest,
+        CursorIcon::ResizeNorth,
+        CursorIcon::ResizeNorthEast,
+        CursorIcon::ResizeColumn,
+        CursorIcon::ResizeRow,
         CursorIcon::ZoomIn,
         CursorIcon::ZoomOut,
     ];
@@ -262,6 +483,25 @@ pub enum OutputEvent {
     TextSelectionChanged(WidgetInfo),
     // A widget's value changed.
     ValueChanged(WidgetInfo),
+    /// Something the app wants read out loud, e.g. the result of a background task.
+    ///
+    /// See [`crate::Context::announce`].
+    Announcement {
+        text: String,
+        priority: Priority,
+    },
+}
+
+/// How urgently an [`OutputEvent::Announcement`] should be read out loud.
+///
+/// These correspond to the `aria-live` levels on the web.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Priority {
+    /// Read out when the user is idle, e.g. "3 files imported".
+    Polite,
+    /// Read out immediately, interrupting whatever is being read, e.g. "Connection lost".
+    Assertive,
 }
 
 impl std::fmt::Debug for OutputEvent {
@@ -272,10 +512,61 @@ impl std::fmt::Debug for OutputEvent {
             Self::FocusGained(wi) => write!(f, "FocusGained({:?})", wi),
             Self::TextSelectionChanged(wi) => write!(f, "TextSelectionChanged({:?})", wi),
             Self::ValueChanged(wi) => write!(f, "ValueChanged({:?})", wi),
+            Self::Announcement { text, priority } => {
+                write!(f, "Announcement({:?}, {:?})", text, priority)
+            }
         }
     }
 }
 
+/// A request to capture the pixels of a part of the screen.
+///
+/// See [`Output::snapshot_requests`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SnapshotRequest {
+    /// Send this back in [`crate::Event::Snapshot`].
+    pub id: Id,
+
+    /// The part of the screen to capture, in points.
+    pub rect: crate::Rect,
+}
+
+/// A semantic event in the ui, emitted in [`Output::ui_events`].
+///
+/// Widgets and windows are identified by their [`Id`],
+/// which is stable between frames (and between runs, as long as the ui code doesn't change).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum UiEvent {
+    /// A widget was clicked, changed its value, gained focus etc.
+    Widget { id: Id, event: OutputEvent },
+    /// A [`crate::Window`] was shown that wasn't shown last frame.
+    WindowOpened { id: Id, title: String },
+    /// A [`crate::Window`] was closed with its close button, or with [`crate::Window::open`].
+    WindowClosed { id: Id, title: String },
+}
+
+/// A widget or window in [`Output::accessibility_nodes`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AccessibilityNode {
+    /// Stable between frames.
+    pub id: Id,
+
+    /// The window this widget is in, if any.
+    pub parent: Option<Id>,
+
+    /// The role (widget type), label, value and state of the node.
+    pub info: WidgetInfo,
+
+    /// Does this widget have keyboard focus?
+    pub focused: bool,
+
+    /// The bounding rectangle, in points.
+    pub rect: crate::Rect,
+}
+
 /// Describes a widget such as a [`crate::Button`] or a [`crate::TextEdit`].
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -443,6 +734,7 @@ impl WidgetInfo {
             WidgetType::ColorButton => "color button",
             WidgetType::ImageButton => "image button",
             WidgetType::CollapsingHeader => "collapsing header",
+            WidgetType::Window => "window",
             WidgetType::Label | WidgetType::Other => "",
         };
 
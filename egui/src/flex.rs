@@ -0,0 +1,111 @@
+//! Book-keeping for [`crate::Ui::flex`] and [`crate::Ui::allocate_flex`].
+
+/// A flex item, as it was added last frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct FlexItem {
+    pub grow: f32,
+    pub shrink: f32,
+
+    /// Size along the main axis that the item starts out from before growing or shrinking:
+    /// zero for growing items, and the size of the contents (when last not shrunk) for the others.
+    pub basis: f32,
+}
+
+/// What the flex items of a [`crate::Ui::flex`] looked like last frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct FlexState {
+    /// Space along the main axis taken by everything except the flex items (including spacing).
+    pub fixed_size: f32,
+
+    pub items: Vec<FlexItem>,
+}
+
+impl FlexState {
+    /// The size of item number `index` along the main axis, if it has the same index as last frame,
+    /// given `space` along the main axis for the whole flex.
+    ///
+    /// Returns `None` if the item should just use the size of its contents.
+    pub fn item_size(&self, index: usize, space: f32) -> Option<f32> {
+        let item = self.items.get(index)?;
+        let total_basis: f32 = self.items.iter().map(|item| item.basis).sum();
+        let leftover = space - self.fixed_size - total_basis;
+        if leftover >= 0.0 {
+            let total_grow: f32 = self.items.iter().map(|item| item.grow).sum();
+            if item.grow > 0.0 {
+                Some(leftover * item.grow / total_grow)
+            } else {
+                None // keep the size of the contents
+            }
+        } else {
+            // Like CSS, shrink in proportion to the basis, so small items don't vanish first:
+            let total_shrink: f32 = self.items.iter().map(|i| i.shrink * i.basis).sum();
+            if item.grow > 0.0 {
+                Some(0.0)
+            } else if item.shrink > 0.0 && total_shrink > 0.0 {
+                let shrunk = item.basis + leftover * item.shrink * item.basis / total_shrink;
+                Some(shrunk.max(0.0))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Collected while flex items are added to a [`crate::Ui::flex`],
+/// and turned into a [`FlexState`] at the end of it.
+#[derive(Clone, Debug)]
+pub(crate) struct FlexFrame {
+    /// Where the [`FlexState`] is stored.
+    pub id: crate::Id,
+
+    /// From last frame.
+    pub state: FlexState,
+
+    pub items: Vec<FlexItem>,
+
+    /// Space along the main axis taken by the flex items.
+    pub flex_size: f32,
+}
+
+#[cfg(test)]
+#[test]
+fn test_item_size() {
+    let state = FlexState {
+        fixed_size: 20.0,
+        items: vec![
+            FlexItem {
+                grow: 1.0,
+                shrink: 0.0,
+                basis: 0.0,
+            },
+            FlexItem {
+                grow: 3.0,
+                shrink: 0.0,
+                basis: 0.0,
+            },
+            FlexItem {
+                grow: 0.0,
+                shrink: 1.0,
+                basis: 40.0,
+            },
+            FlexItem {
+                grow: 0.0,
+                shrink: 0.0,
+                basis: 40.0,
+            },
+        ],
+    };
+
+    // Room to grow: the growing items share what's left by their `grow` weight:
+    assert_eq!(state.item_size(0, 200.0), Some(25.0));
+    assert_eq!(state.item_size(1, 200.0), Some(75.0));
+    assert_eq!(state.item_size(2, 200.0), None);
+    assert_eq!(state.item_size(3, 200.0), None);
+    assert_eq!(state.item_size(4, 200.0), None, "new item");
+
+    // Too little room: only the items with a `shrink` weight give up space:
+    assert_eq!(state.item_size(0, 90.0), Some(0.0));
+    assert_eq!(state.item_size(2, 90.0), Some(30.0));
+    assert_eq!(state.item_size(3, 90.0), None);
+    assert_eq!(state.item_size(2, 0.0), Some(0.0));
+}
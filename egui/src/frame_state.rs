@@ -1,5 +1,29 @@
 use crate::*;
 
+/// Two widgets (or other things that need interaction or persistence) using the same [`Id`] in one frame.
+///
+/// See [`Context::id_clashes`] and [`crate::IdClashPolicy`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IdClash {
+    pub id: Id,
+
+    /// Where the id was used first.
+    pub first_rect: Rect,
+
+    /// Where the id was used again.
+    pub second_rect: Rect,
+}
+
+/// The outline around the widget with keyboard focus, see [`crate::Visuals::focus_ring`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FocusRing {
+    pub layer_id: LayerId,
+    pub clip_rect: Rect,
+    pub rect: Rect,
+    pub corner_radius: f32,
+    pub stroke: Stroke,
+}
+
 /// State that is collected during a frame and then cleared.
 /// Short-term (single frame) memory.
 #[derive(Clone)]
@@ -8,6 +32,9 @@ pub(crate) struct FrameState {
     /// Used to debug `Id` clashes of widgets.
     pub(crate) used_ids: IdMap<Rect>,
 
+    /// See [`Context::id_clashes`].
+    pub(crate) id_clashes: Vec<IdClash>,
+
     /// Starts off as the screen_rect, shrinks as panels are added.
     /// The `CentralPanel` does not change this.
     /// This is the area available to Window's.
@@ -29,18 +56,71 @@ pub(crate) struct FrameState {
     pub(crate) scroll_delta: Vec2, // TODO: move to a Mutex inside of `InputState` ?
     /// horizontal, vertical
     pub(crate) scroll_target: [Option<(f32, Align)>; 2],
+
+    /// The interactive widget under the pointer, if any.
+    /// The type is [`WidgetType::Other`] unless the widget reports it with [`Response::widget_info`].
+    pub(crate) hovered_widget: Option<(Id, WidgetType)>,
+
+    /// For each of [`InputState::events`]: has it been handled, see [`Context::is_event_consumed`]?
+    pub(crate) consumed_events: Vec<bool>,
+
+    /// Only recorded if `Memory::options.record_widget_tree` is set.
+    pub(crate) widget_tree: WidgetTree,
+
+    /// Which layer each shape returned by [`Context::end_frame`] came from,
+    /// used for the per-layer stats in [`Context::tessellate`].
+    pub(crate) layer_shapes: Vec<crate::layers::LayerShapes>,
+
+    /// Counts the frames, so that [`Self::shape_list`] can tell which frame it is from.
+    pub(crate) generation: u64,
+
+    /// The [`Self::generation`] and length of the shape list returned by [`Context::end_frame`],
+    /// so that [`Context::tessellate`] only uses [`Self::layer_shapes`] for the list of this frame.
+    ///
+    /// Another list of the same length would still be tessellated correctly,
+    /// since the layers only decide where the list is split; only the per-layer stats would be off.
+    pub(crate) shape_list: Option<(u64, usize)>,
+
+    /// Set by the color picker eyedropper to sample the shapes under the pointer at the end of the frame,
+    /// for when the integration can't capture the pixels of the screen.
+    pub(crate) eyedropper_pos: Option<Pos2>,
+
+    /// See [`Context::register_shortcut`].
+    pub(crate) shortcuts: Vec<ShortcutInfo>,
+
+    /// Has the innermost indented region under the pointer highlighted its guide?
+    /// Then the regions around it don't.
+    pub(crate) indent_guide_hovered: bool,
+
+    /// Painted by [`Context::end_frame`], so that it is on top of the focused widget.
+    pub(crate) focus_ring: Option<FocusRing>,
+
+    /// The earliest time a widget asked to be repainted at, see [`Context::request_repaint_at`].
+    pub(crate) repaint_at: Option<f64>,
 }
 
 impl Default for FrameState {
     fn default() -> Self {
         Self {
             used_ids: Default::default(),
+            id_clashes: Default::default(),
             available_rect: Rect::NAN,
             unused_rect: Rect::NAN,
             used_by_panels: Rect::NAN,
             tooltip_rect: None,
             scroll_delta: Vec2::ZERO,
             scroll_target: [None; 2],
+            hovered_widget: None,
+            consumed_events: Vec::new(),
+            widget_tree: Default::default(),
+            layer_shapes: Default::default(),
+            generation: 0,
+            shape_list: None,
+            eyedropper_pos: None,
+            shortcuts: Default::default(),
+            indent_guide_hovered: false,
+            focus_ring: None,
+            repaint_at: None,
         }
     }
 }
@@ -49,21 +129,45 @@ impl FrameState {
     pub(crate) fn begin_frame(&mut self, input: &InputState) {
         let Self {
             used_ids,
+            id_clashes,
             available_rect,
             unused_rect,
             used_by_panels,
             tooltip_rect,
             scroll_delta,
             scroll_target,
+            hovered_widget,
+            consumed_events,
+            widget_tree,
+            layer_shapes,
+            generation,
+            shape_list,
+            eyedropper_pos,
+            shortcuts,
+            indent_guide_hovered,
+            focus_ring,
+            repaint_at,
         } = self;
 
         used_ids.clear();
+        id_clashes.clear();
         *available_rect = input.screen_rect();
         *unused_rect = input.screen_rect();
         *used_by_panels = Rect::NOTHING;
         *tooltip_rect = None;
         *scroll_delta = input.scroll_delta;
         *scroll_target = [None; 2];
+        *hovered_widget = None;
+        *consumed_events = vec![false; input.events.len()];
+        widget_tree.clear();
+        layer_shapes.clear();
+        *generation += 1;
+        *shape_list = None;
+        *eyedropper_pos = None;
+        shortcuts.clear();
+        *indent_guide_hovered = false;
+        *focus_ring = None;
+        *repaint_at = None;
     }
 
     /// How much space is still available after panels has been added.
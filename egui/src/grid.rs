@@ -9,11 +9,11 @@ pub(crate) struct State {
 
 impl State {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
-        ctx.memory().data.get_persisted(id)
+        ctx.memory().data_persisted.get_persisted(id)
     }
 
     pub fn store(self, ctx: &Context, id: Id) {
-        ctx.memory().data.insert_persisted(id, self);
+        ctx.memory().data_persisted.insert_persisted(id, self);
     }
 
     fn set_min_col_width(&mut self, col: usize, width: f32) {
@@ -44,6 +44,22 @@ impl State {
 
 // ----------------------------------------------------------------------------
 
+/// Options for a single column of a [`Grid`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ColumnOptions {
+    align: Align,
+    min_width: Option<f32>,
+}
+
+impl Default for ColumnOptions {
+    fn default() -> Self {
+        Self {
+            align: Align::LEFT,
+            min_width: None,
+        }
+    }
+}
+
 pub(crate) struct GridLayout {
     ctx: CtxRef,
     style: std::sync::Arc<Style>,
@@ -62,10 +78,13 @@ pub(crate) struct GridLayout {
     min_cell_size: Vec2,
     max_cell_size: Vec2,
     striped: bool,
+    columns: Vec<ColumnOptions>,
 
     // Cursor:
     col: usize,
     row: usize,
+    /// How many columns the next cell spans.
+    colspan: usize,
 }
 
 impl GridLayout {
@@ -93,18 +112,36 @@ impl GridLayout {
             min_cell_size: ui.spacing().interact_size,
             max_cell_size: Vec2::INFINITY,
             striped: false,
+            columns: vec![],
 
             col: 0,
             row: 0,
+            colspan: 1,
         }
     }
 }
 
 impl GridLayout {
+    fn column(&self, col: usize) -> ColumnOptions {
+        self.columns.get(col).copied().unwrap_or_default()
+    }
+
+    fn min_col_width(&self, col: usize) -> f32 {
+        self.column(col).min_width.unwrap_or(self.min_cell_size.x)
+    }
+
     fn prev_col_width(&self, col: usize) -> f32 {
         self.prev_state
             .col_width(col)
-            .unwrap_or(self.min_cell_size.x)
+            .unwrap_or_else(|| self.min_col_width(col))
+    }
+
+    /// The width of the columns covered by the next cell, including the spacing between them.
+    fn prev_span_width(&self) -> f32 {
+        (self.col..self.col + self.colspan)
+            .map(|col| self.prev_col_width(col))
+            .sum::<f32>()
+            + (self.colspan - 1) as f32 * self.spacing.x
     }
     fn prev_row_height(&self, row: usize) -> f32 {
         self.prev_state
@@ -117,10 +154,12 @@ impl GridLayout {
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
-        let is_last_column = Some(self.col + 1) == self.num_columns;
+        let is_last_column = Some(self.col + self.colspan) == self.num_columns;
 
         let width = if is_last_column {
             (self.initial_available.right() - region.cursor.left()).at_most(self.max_cell_size.x)
+        } else if self.colspan > 1 {
+            self.prev_span_width()
         } else if self.max_cell_size.x.is_finite() {
             // TODO: should probably heed `prev_state` here too
             self.max_cell_size.x
@@ -130,7 +169,7 @@ impl GridLayout {
             self.prev_state
                 .col_width(self.col)
                 .or_else(|| self.curr_state.col_width(self.col))
-                .unwrap_or(self.min_cell_size.x)
+                .unwrap_or_else(|| self.min_col_width(self.col))
         };
 
         // If something above was wider, we can be wider:
@@ -147,16 +186,18 @@ impl GridLayout {
     }
 
     pub(crate) fn next_cell(&self, cursor: Rect, child_size: Vec2) -> Rect {
-        let width = self.prev_state.col_width(self.col).unwrap_or(0.0);
+        let width = if self.colspan > 1 {
+            self.prev_span_width()
+        } else {
+            self.prev_state.col_width(self.col).unwrap_or(0.0)
+        };
         let height = self.prev_row_height(self.row);
         let size = child_size.max(vec2(width, height));
         Rect::from_min_size(cursor.min, size)
     }
 
-    #[allow(clippy::unused_self)]
     pub(crate) fn align_size_within_rect(&self, size: Vec2, frame: Rect) -> Rect {
-        // TODO: allow this alignment to be customized
-        Align2::LEFT_CENTER.align_size_within_rect(size, frame)
+        Align2([self.column(self.col).align, Align::Center]).align_size_within_rect(size, frame)
     }
 
     pub(crate) fn justify_and_align(&self, frame: Rect, size: Vec2) -> Rect {
@@ -186,13 +227,34 @@ impl GridLayout {
             }
         }
 
-        self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
+        if self.colspan == 1 {
+            self.curr_state.set_min_col_width(
+                self.col,
+                widget_rect.width().max(self.min_col_width(self.col)),
+            );
+        } else {
+            // A spanning cell doesn't decide the width of the individual columns.
+            for col in self.col..self.col + self.colspan {
+                self.curr_state
+                    .set_min_col_width(col, self.min_col_width(col));
+            }
+        }
         self.curr_state
             .set_min_row_height(self.row, widget_rect.height().max(self.min_cell_size.y));
 
-        cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
-        self.col += 1;
+        cursor.min.x += self.prev_span_width() + self.spacing.x;
+        self.col += self.colspan;
+        self.colspan = 1;
+    }
+
+    /// Make the next cell span this many columns.
+    pub(crate) fn set_colspan(&mut self, colspan: usize) {
+        self.colspan = colspan.at_least(1);
+    }
+
+    /// The width of the whole grid, as of the previous frame.
+    pub(crate) fn full_width(&self) -> f32 {
+        self.prev_state.full_width(self.spacing.x)
     }
 
     pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
@@ -237,6 +299,8 @@ impl GridLayout {
 /// If you want to add multiple widgets to a cell you need to group them with
 /// [`Ui::horizontal`], [`Ui::vertical`] etc.
 ///
+/// Use [`Ui::set_colspan`] to make a cell span several columns.
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// egui::Grid::new("some_unique_id").show(ui, |ui| {
@@ -265,10 +329,13 @@ pub struct Grid {
     max_cell_size: Vec2,
     spacing: Option<Vec2>,
     start_row: usize,
+    columns: Vec<ColumnOptions>,
+    header: Vec<WidgetText>,
 }
 
 impl Grid {
     /// Create a new [`Grid`] with a locally unique identifier.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn new(id_source: impl std::hash::Hash) -> Self {
         Self {
             id_source: Id::new(id_source),
@@ -279,6 +346,8 @@ impl Grid {
             max_cell_size: Vec2::INFINITY,
             spacing: None,
             start_row: 0,
+            columns: vec![],
+            header: vec![],
         }
     }
 
@@ -330,6 +399,56 @@ impl Grid {
         self.start_row = start_row;
         self
     }
+
+    fn column_mut(&mut self, col: usize) -> &mut ColumnOptions {
+        if self.columns.len() <= col {
+            self.columns.resize(col + 1, Default::default());
+        }
+        &mut self.columns[col]
+    }
+
+    /// Set the horizontal alignment of the contents of a column.
+    /// Default: [`Align::LEFT`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Grid::new("prices")
+    ///     .col_align(1, egui::Align::RIGHT)
+    ///     .show(ui, |ui| {
+    ///         ui.label("Apple");
+    ///         ui.label("1.50");
+    ///         ui.end_row();
+    ///     });
+    /// # });
+    /// ```
+    pub fn col_align(mut self, col: usize, align: Align) -> Self {
+        self.column_mut(col).align = align;
+        self
+    }
+
+    /// Set the minimum width of one column, overriding [`Self::min_col_width`] for it.
+    pub fn col_min_width(mut self, col: usize, min_width: f32) -> Self {
+        self.column_mut(col).min_width = Some(min_width);
+        self
+    }
+
+    /// Add a header row with these titles, which are shown in a strong text with a line below them.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Grid::new("properties")
+    ///     .header(["Name", "Value"])
+    ///     .show(ui, |ui| {
+    ///         ui.label("Width");
+    ///         ui.label("42");
+    ///         ui.end_row();
+    ///     });
+    /// # });
+    /// ```
+    pub fn header(mut self, header: impl IntoIterator<Item = impl Into<WidgetText>>) -> Self {
+        self.header = header.into_iter().map(Into::into).collect();
+        self
+    }
 }
 
 impl Grid {
@@ -351,6 +470,8 @@ impl Grid {
             max_cell_size,
             spacing,
             start_row,
+            columns,
+            header,
         } = self;
         let min_col_width = min_col_width.unwrap_or_else(|| ui.spacing().interact_size.x);
         let min_row_height = min_row_height.unwrap_or_else(|| ui.spacing().interact_size.y);
@@ -363,18 +484,22 @@ impl Grid {
         let max_rect = ui.cursor().intersect(ui.max_rect());
         ui.allocate_ui_at_rect(max_rect, |ui| {
             ui.horizontal(|ui| {
-                let id = ui.make_persistent_id(id_source);
+                let id = ui.make_persistent_id(id_source).derived_from(id_source);
                 let grid = GridLayout {
                     num_columns,
                     striped,
                     min_cell_size: vec2(min_col_width, min_row_height),
                     max_cell_size,
                     spacing,
+                    columns,
                     row: start_row,
                     ..GridLayout::new(ui, id)
                 };
 
                 ui.set_grid(grid);
+                if !header.is_empty() {
+                    add_header_row(ui, header);
+                }
                 let r = add_contents(ui);
                 ui.save_grid();
                 r
@@ -383,3 +508,19 @@ impl Grid {
         })
     }
 }
+
+fn add_header_row(ui: &mut Ui, header: Vec<WidgetText>) {
+    for title in header {
+        ui.label(title.strong());
+    }
+    ui.end_row();
+
+    if let Some(grid) = ui.placer().grid() {
+        let left = ui.cursor().left();
+        let y = ui.cursor().top() - 0.5 * grid.spacing.y;
+        ui.painter().line_segment(
+            [pos2(left, y), pos2(left + grid.full_width(), y)],
+            ui.visuals().widgets.noninteractive.bg_stroke,
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Hit-testing for widgets that are not rectangular.
+//!
+//! See [`crate::Ui::interact_with_hit_shape`].
+
+use crate::{Pos2, Rect, Vec2};
+
+/// The shape of a widget for the purpose of hovering and clicking it.
+///
+/// By default widgets are hit anywhere in their rectangle,
+/// which is wrong for e.g. round buttons, knobs or diagonal connectors.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HitShape {
+    Rect(Rect),
+
+    Circle {
+        center: Pos2,
+        radius: f32,
+    },
+
+    RoundedRect {
+        rect: Rect,
+        corner_radius: f32,
+    },
+
+    /// A closed polygon. It may be concave or self-intersecting (the even-odd rule is used).
+    Polygon(Vec<Pos2>),
+}
+
+impl HitShape {
+    pub fn circle(center: Pos2, radius: f32) -> Self {
+        Self::Circle { center, radius }
+    }
+
+    pub fn rounded_rect(rect: Rect, corner_radius: f32) -> Self {
+        Self::RoundedRect {
+            rect,
+            corner_radius,
+        }
+    }
+
+    /// The smallest rectangle containing the shape.
+    pub fn bounding_rect(&self) -> Rect {
+        match self {
+            Self::Rect(rect) | Self::RoundedRect { rect, .. } => *rect,
+            Self::Circle { center, radius } => {
+                Rect::from_center_size(*center, Vec2::splat(2.0 * radius))
+            }
+            Self::Polygon(points) => Rect::from_points(points),
+        }
+    }
+
+    pub fn contains(&self, pos: Pos2) -> bool {
+        match self {
+            Self::Rect(rect) => rect.contains(pos),
+            Self::Circle { center, radius } => center.distance_sq(pos) <= radius * radius,
+            Self::RoundedRect {
+                rect,
+                corner_radius,
+            } => {
+                let radius = corner_radius
+                    .min(0.5 * rect.width())
+                    .min(0.5 * rect.height())
+                    .max(0.0);
+                let inner = rect.shrink(radius);
+                rect.contains(pos) && inner.clamp(pos).distance_sq(pos) <= radius * radius
+            }
+            Self::Polygon(points) => polygon_contains(points, pos),
+        }
+    }
+}
+
+impl From<Rect> for HitShape {
+    fn from(rect: Rect) -> Self {
+        Self::Rect(rect)
+    }
+}
+
+/// Even-odd rule: count the edges crossed by a ray going right from `pos`.
+pub(crate) fn polygon_contains(points: &[Pos2], pos: Pos2) -> bool {
+    let mut inside = false;
+    let mut prev = match points.last() {
+        Some(&last) => last,
+        None => return false,
+    };
+    for &point in points {
+        if (point.y > pos.y) != (prev.y > pos.y) {
+            let x = point.x + (pos.y - point.y) / (prev.y - point.y) * (prev.x - point.x);
+            if pos.x < x {
+                inside = !inside;
+            }
+        }
+        prev = point;
+    }
+    inside
+}
+
+#[cfg(test)]
+#[test]
+fn test_hit_shapes() {
+    use crate::{pos2, vec2};
+
+    let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+    let circle = HitShape::circle(rect.center(), 50.0);
+    assert!(circle.contains(pos2(50.0, 5.0)));
+    assert!(!circle.contains(pos2(5.0, 5.0)));
+    assert_eq!(circle.bounding_rect(), rect);
+
+    let rounded = HitShape::rounded_rect(rect, 20.0);
+    assert!(rounded.contains(pos2(10.0, 50.0)));
+    assert!(rounded.contains(pos2(10.0, 10.0)));
+    assert!(!rounded.contains(pos2(1.0, 1.0)));
+    assert!(!rounded.contains(pos2(101.0, 50.0)));
+
+    // A diagonal band from the top left to the bottom right:
+    let band = HitShape::Polygon(vec![
+        pos2(0.0, 0.0),
+        pos2(10.0, 0.0),
+        pos2(100.0, 90.0),
+        pos2(100.0, 100.0),
+        pos2(90.0, 100.0),
+        pos2(0.0, 10.0),
+    ]);
+    assert!(band.contains(pos2(50.0, 50.0)));
+    assert!(!band.contains(pos2(90.0, 10.0)));
+    assert!(!band.contains(pos2(10.0, 90.0)));
+    assert_eq!(band.bounding_rect(), rect);
+}
+
+#[cfg(test)]
+#[test]
+fn test_interact_with_hit_shape() {
+    use crate::{pos2, vec2, Event, Id, RawInput, Sense};
+
+    let mut ctx = crate::CtxRef::default();
+    let mut hovered_at = |pos: Pos2| {
+        let input = RawInput {
+            events: vec![Event::PointerMoved(pos)],
+            ..Default::default()
+        };
+        let mut hovered = false;
+        let _ = ctx.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let rect = Rect::from_min_size(pos2(20.0, 20.0), vec2(100.0, 100.0));
+                let knob = HitShape::circle(rect.center(), 50.0);
+                let response = ui.interact_with_hit_shape(Id::new("knob"), Sense::click(), &knob);
+                assert_eq!(response.rect, rect);
+                hovered = response.hovered();
+            });
+        });
+        hovered
+    };
+
+    assert!(hovered_at(pos2(70.0, 70.0)));
+    assert!(!hovered_at(pos2(25.0, 25.0)), "corner of the rect");
+}
@@ -30,7 +30,7 @@
 pub struct Id(u64);
 
 impl Id {
-    /// A special `Id`, in particular as a key to [`crate::Memory::data`]
+    /// A special `Id`, in particular as a key to [`crate::Memory::data_temp`] or [`crate::Memory::data_persisted`]
     /// for when there is no particular widget to attach the data.
     ///
     /// The null `Id` is still a valid id to use in all circumstances,
@@ -44,20 +44,56 @@ impl Id {
     }
 
     /// Generate a new `Id` by hashing some source (e.g. a string or integer).
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn new(source: impl std::hash::Hash) -> Id {
         use std::hash::Hasher;
         let mut hasher = epaint::ahash::AHasher::new_with_keys(123, 456);
         source.hash(&mut hasher);
-        Id(hasher.finish())
+        Id(hasher.finish()).created_here()
     }
 
     /// Generate a new `Id` by hashing the parent `Id` and the given argument.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn with(self, child: impl std::hash::Hash) -> Id {
         use std::hash::Hasher;
         let mut hasher = epaint::ahash::AHasher::new_with_keys(123, 456);
         hasher.write_u64(self.0);
         child.hash(&mut hasher);
-        Id(hasher.finish())
+        Id(hasher.finish()).created_here()
+    }
+
+    /// Where in the source code this `Id` has been created (with [`Self::new`] or [`Self::with`]),
+    /// e.g. to find out which two widgets clash.
+    ///
+    /// Only recorded with the `id_source_locations` feature, only on the current thread,
+    /// and only remembered for ids created this frame or last frame.
+    /// Without the feature, this is always empty.
+    ///
+    /// If a container (e.g. a [`crate::Window`]) is given an id source,
+    /// this is where the container was created rather than somewhere inside of egui.
+    pub fn source_locations(&self) -> Vec<&'static std::panic::Location<'static>> {
+        #[cfg(feature = "id_source_locations")]
+        return source_locations::get(*self);
+        #[cfg(not(feature = "id_source_locations"))]
+        return vec![];
+    }
+
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
+    #[inline(always)]
+    fn created_here(self) -> Self {
+        #[cfg(feature = "id_source_locations")]
+        source_locations::record(self, &[std::panic::Location::caller()]);
+        self
+    }
+
+    /// `self` was made from the id source `source`, so inherit its [`Self::source_locations`].
+    #[inline(always)]
+    pub(crate) fn derived_from(self, source: Id) -> Self {
+        #[cfg(feature = "id_source_locations")]
+        source_locations::record(self, &source_locations::get(source));
+        #[cfg(not(feature = "id_source_locations"))]
+        let _ = source;
+        self
     }
 
     /// Short and readable summary
@@ -79,6 +115,70 @@ impl std::fmt::Debug for Id {
 
 // ----------------------------------------------------------------------------
 
+#[cfg(feature = "id_source_locations")]
+mod source_locations {
+    use super::{Id, IdMap};
+    use std::{cell::RefCell, panic::Location};
+
+    /// Remembering a few is enough to tell clashing widgets apart.
+    const MAX_LOCATIONS_PER_ID: usize = 8;
+
+    /// Only the ids of this frame and the last one are remembered,
+    /// so that apps that keep making new ids don't keep using more memory.
+    #[derive(Default)]
+    struct Locations {
+        this_frame: IdMap<Vec<&'static Location<'static>>>,
+        last_frame: IdMap<Vec<&'static Location<'static>>>,
+    }
+
+    thread_local! {
+        static LOCATIONS: RefCell<Locations> = Default::default();
+    }
+
+    pub fn begin_frame() {
+        LOCATIONS.with(|locations| {
+            let mut locations = locations.borrow_mut();
+            locations.last_frame = std::mem::take(&mut locations.this_frame);
+        });
+    }
+
+    pub fn record(id: Id, locations: &[&'static Location<'static>]) {
+        LOCATIONS.with(|map| {
+            let mut map = map.borrow_mut();
+            let known = map.this_frame.entry(id).or_default();
+            add(known, locations);
+        });
+    }
+
+    pub fn get(id: Id) -> Vec<&'static Location<'static>> {
+        LOCATIONS.with(|map| {
+            let map = map.borrow();
+            let mut known = map.this_frame.get(&id).cloned().unwrap_or_default();
+            if let Some(last_frame) = map.last_frame.get(&id) {
+                add(&mut known, last_frame);
+            }
+            known
+        })
+    }
+
+    fn add(known: &mut Vec<&'static Location<'static>>, locations: &[&'static Location<'static>]) {
+        for location in locations {
+            if known.len() < MAX_LOCATIONS_PER_ID && !known.contains(location) {
+                known.push(location);
+            }
+        }
+    }
+}
+
+/// Forget the [`Id::source_locations`] of the ids that were not created last frame or this one.
+#[inline(always)]
+pub(crate) fn begin_frame() {
+    #[cfg(feature = "id_source_locations")]
+    source_locations::begin_frame();
+}
+
+// ----------------------------------------------------------------------------
+
 // Idea taken from the `nohash_hasher` crate.
 #[derive(Default)]
 pub struct IdHasher(u64);
@@ -144,3 +244,27 @@ impl std::hash::BuildHasher for BuilIdHasher {
 
 /// `IdMap<V>` is a `HashMap<Id, V>` optimized by knowing that `Id` has good entropy, and doesn't need more hashing.
 pub type IdMap<V> = std::collections::HashMap<Id, V, BuilIdHasher>;
+
+#[cfg(all(test, feature = "id_source_locations"))]
+#[test]
+fn test_source_locations() {
+    let first = Id::new("clash");
+    let second = Id::new("clash");
+    assert_eq!(first, second);
+    let locations = first.source_locations();
+    assert_eq!(locations.len(), 2);
+    assert!(locations.iter().all(|location| location.file() == file!()));
+
+    let window = crate::Window::new("clash window");
+    let id = Id::new("clash window");
+    let locations = id.source_locations();
+    assert_eq!(locations.len(), 2);
+    assert!(locations.iter().all(|location| location.file() == file!()));
+    drop(window);
+
+    // Ids are forgotten once they haven't been created for a frame:
+    begin_frame();
+    assert_eq!(id.source_locations().len(), 2);
+    begin_frame();
+    assert!(id.source_locations().is_empty());
+}
@@ -17,6 +17,11 @@ const MAX_CLICK_DURATION: f64 = 0.6; // TODO: move to settings
 /// The new pointer press must come within this many seconds from previous pointer release
 const MAX_DOUBLE_CLICK_DELAY: f64 = 0.3; // TODO: move to settings
 
+/// If the primary button is held still for this long, it is a long press (and no longer a click).
+///
+/// The same as [`MAX_CLICK_DURATION`], so that every press that stays still is either a click or a long press.
+const LONG_PRESS_DURATION: f64 = MAX_CLICK_DURATION;
+
 /// Input state that egui updates each frame.
 ///
 /// You can check if `egui` is using the inputs using
@@ -33,9 +38,14 @@ pub struct InputState {
     /// (We keep a separate `TouchState` for each encountered touch device.)
     touch_states: BTreeMap<TouchDeviceId, TouchState>,
 
-    /// How many pixels the user scrolled.
+    /// How many points the user scrolled this frame.
+    ///
+    /// Line and page based scrolling (see [`crate::MouseWheelUnit`]) is spread out over a few frames.
     pub scroll_delta: Vec2,
 
+    /// Line and page based scrolling that has not yet been applied to [`Self::scroll_delta`].
+    unprocessed_scroll_delta: Vec2,
+
     /// Zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
     ///
     /// * `zoom = 1`: no change.
@@ -79,6 +89,7 @@ impl Default for InputState {
             pointer: Default::default(),
             touch_states: Default::default(),
             scroll_delta: Vec2::ZERO,
+            unprocessed_scroll_delta: Vec2::ZERO,
             zoom_factor_delta: 1.0,
             screen_rect: Rect::from_min_size(Default::default(), vec2(10_000.0, 10_000.0)),
             pixels_per_point: 1.0,
@@ -92,9 +103,20 @@ impl Default for InputState {
     }
 }
 
+/// Shift + scroll wheel scrolls horizontally.
+fn shift_scroll(delta: Vec2, modifiers: Modifiers) -> Vec2 {
+    if modifiers.shift && delta.x == 0.0 {
+        // Some platforms (e.g. Mac) already do this for us, giving us a zero `y`.
+        vec2(delta.y, 0.0)
+    } else {
+        delta
+    }
+}
+
 impl InputState {
+    /// `options` controls how mouse wheel events are turned into [`Self::scroll_delta`].
     #[must_use]
-    pub fn begin_frame(mut self, new: RawInput) -> InputState {
+    pub fn begin_frame(mut self, new: RawInput, options: &crate::memory::Options) -> InputState {
         let time = new
             .time
             .unwrap_or_else(|| self.time + new.predicted_dt as f64);
@@ -105,9 +127,20 @@ impl InputState {
             touch_state.begin_frame(time, &new, self.pointer.interact_pos);
         }
         let pointer = self.pointer.begin_frame(time, &new);
+        let mut events = new.events.clone(); // TODO: remove clone() and use raw.events
+        events.extend(
+            pointer
+                .pointer_events
+                .iter()
+                .filter_map(|event| match event {
+                    PointerEvent::LongPress(pos) => Some(Event::LongPress(*pos)),
+                    _ => None,
+                }),
+        );
 
         let mut keys_down = self.keys_down;
         let mut scroll_delta = Vec2::ZERO;
+        let mut unprocessed_scroll_delta = self.unprocessed_scroll_delta;
         let mut zoom_factor_delta = 1.0;
         for event in &new.events {
             match event {
@@ -119,7 +152,23 @@ impl InputState {
                     }
                 }
                 Event::Scroll(delta) => {
-                    scroll_delta += *delta;
+                    scroll_delta += shift_scroll(*delta, new.modifiers);
+                }
+                Event::MouseWheel {
+                    unit,
+                    delta,
+                    modifiers,
+                } => {
+                    let delta = shift_scroll(*delta, *modifiers);
+                    match unit {
+                        MouseWheelUnit::Point => scroll_delta += delta,
+                        MouseWheelUnit::Line => {
+                            unprocessed_scroll_delta += delta * options.line_scroll_speed;
+                        }
+                        MouseWheelUnit::Page => {
+                            unprocessed_scroll_delta += delta * screen_rect.size();
+                        }
+                    }
                 }
                 Event::Zoom(factor) => {
                     zoom_factor_delta *= *factor;
@@ -127,10 +176,28 @@ impl InputState {
                 _ => {}
             }
         }
+
+        // Move part of the way towards where line/page scrolling wants us to be.
+        // Notches that come in quick succession add up, so fast wheel spins scroll further.
+        if unprocessed_scroll_delta != Vec2::ZERO {
+            let t = if options.scroll_smoothing > 0.0 {
+                1.0 - (-new.predicted_dt / options.scroll_smoothing).exp()
+            } else {
+                1.0
+            };
+            let mut step = unprocessed_scroll_delta * t;
+            if (unprocessed_scroll_delta - step).length() < 0.5 {
+                step = unprocessed_scroll_delta;
+            }
+            unprocessed_scroll_delta -= step;
+            scroll_delta += step;
+        }
+
         InputState {
             pointer,
             touch_states: self.touch_states,
             scroll_delta,
+            unprocessed_scroll_delta,
             zoom_factor_delta,
             screen_rect,
             pixels_per_point: new.pixels_per_point.unwrap_or(self.pixels_per_point),
@@ -139,7 +206,7 @@ impl InputState {
             predicted_dt: new.predicted_dt,
             modifiers: new.modifiers,
             keys_down,
-            events: new.events.clone(), // TODO: remove clone() and use raw.events
+            events,
             raw: new,
         }
     }
@@ -189,7 +256,10 @@ impl InputState {
     }
 
     pub fn wants_repaint(&self) -> bool {
-        self.pointer.wants_repaint() || self.scroll_delta != Vec2::ZERO || !self.events.is_empty()
+        self.pointer.wants_repaint()
+            || self.scroll_delta != Vec2::ZERO
+            || self.unprocessed_scroll_delta != Vec2::ZERO
+            || !self.events.is_empty()
     }
 
     /// Was the given key pressed this frame?
@@ -214,6 +284,63 @@ impl InputState {
             .count()
     }
 
+    /// How many times was the given key pressed with exactly these modifiers this frame?
+    ///
+    /// See [`Modifiers::matches`].
+    pub fn count_key(&self, modifiers: Modifiers, desired_key: Key) -> usize {
+        self.events
+            .iter()
+            .filter(|event| is_key_press(event, modifiers, desired_key))
+            .count()
+    }
+
+    /// Like [`Self::count_key`], but also removes the matching key events,
+    /// so that nothing else will react to them.
+    pub fn count_and_consume_key(&mut self, modifiers: Modifiers, desired_key: Key) -> usize {
+        let num_events = self.events.len();
+        self.events
+            .retain(|event| !is_key_press(event, modifiers, desired_key));
+        num_events - self.events.len()
+    }
+
+    /// Was the given shortcut pressed this frame?
+    pub fn shortcut_pressed(&self, shortcut: &KeyboardShortcut) -> bool {
+        self.count_key(shortcut.modifiers, shortcut.key) > 0
+    }
+
+    /// Was the given shortcut pressed this frame?
+    /// If so, remove its key events, so that nothing else triggers on it.
+    pub fn consume_shortcut(&mut self, shortcut: &KeyboardShortcut) -> bool {
+        self.count_and_consume_key(shortcut.modifiers, shortcut.key) > 0
+    }
+
+    /// How many times was the given gamepad button pressed this frame?
+    pub fn num_gamepad_presses(&self, desired_button: GamepadButton) -> usize {
+        self.events
+            .iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    Event::GamepadButton {
+                        button,
+                        pressed: true,
+                    } if *button == desired_button
+                )
+            })
+            .count()
+    }
+
+    /// Was the given gamepad button pressed this frame?
+    pub fn gamepad_button_pressed(&self, desired_button: GamepadButton) -> bool {
+        self.num_gamepad_presses(desired_button) > 0
+    }
+
+    /// Did the user ask to close the current popup or menu this frame,
+    /// i.e. press escape or [`GamepadButton::B`]?
+    pub fn cancel_pressed(&self) -> bool {
+        self.key_pressed(Key::Escape) || self.gamepad_button_pressed(GamepadButton::B)
+    }
+
     /// Is the given key currently held down?
     pub fn key_down(&self, desired_key: Key) -> bool {
         self.keys_down.contains(&desired_key)
@@ -306,6 +433,17 @@ impl InputState {
     }
 }
 
+pub(crate) fn is_key_press(event: &Event, modifiers: Modifiers, desired_key: Key) -> bool {
+    matches!(
+        event,
+        Event::Key {
+            key,
+            pressed: true,
+            modifiers: event_modifiers,
+        } if *key == desired_key && event_modifiers.matches(modifiers)
+    )
+}
+
 // ----------------------------------------------------------------------------
 
 /// A pointer (mouse or touch) click.
@@ -330,6 +468,8 @@ pub(crate) enum PointerEvent {
     Moved(Pos2),
     Pressed(Pos2),
     Released(Option<Click>),
+    /// See [`LONG_PRESS_DURATION`].
+    LongPress(Pos2),
 }
 
 impl PointerEvent {
@@ -381,6 +521,9 @@ pub struct PointerState {
     /// `None` if no mouse button is down.
     press_origin: Option<Pos2>,
 
+    /// Where did the click/drag that was released this frame originate?
+    released_press_origin: Option<Pos2>,
+
     /// When did the current click/drag originate?
     /// `None` if no mouse button is down.
     press_start_time: Option<f64>,
@@ -389,6 +532,9 @@ pub struct PointerState {
     /// for it to be registered as a click.
     pub(crate) has_moved_too_much_for_a_click: bool,
 
+    /// Has the current press already been reported as a long press?
+    has_long_pressed: bool,
+
     /// When did the pointer get click last?
     /// Used to check for double-clicks.
     last_click_time: f64,
@@ -408,8 +554,10 @@ impl Default for PointerState {
             pos_history: History::new(0..1000, 0.1),
             down: Default::default(),
             press_origin: None,
+            released_press_origin: None,
             press_start_time: None,
             has_moved_too_much_for_a_click: false,
+            has_long_pressed: false,
             last_click_time: std::f64::NEG_INFINITY,
             pointer_events: vec![],
         }
@@ -422,6 +570,7 @@ impl PointerState {
         self.time = time;
 
         self.pointer_events.clear();
+        self.released_press_origin = None;
 
         let old_pos = self.latest_pos;
         self.interact_pos = self.latest_pos;
@@ -465,6 +614,7 @@ impl PointerState {
                         self.press_origin = Some(pos);
                         self.press_start_time = Some(time);
                         self.has_moved_too_much_for_a_click = false;
+                        self.has_long_pressed = false;
                         self.pointer_events.push(PointerEvent::Pressed(pos));
                     } else {
                         let clicked = self.could_any_button_be_click();
@@ -488,6 +638,7 @@ impl PointerState {
 
                         self.pointer_events.push(PointerEvent::Released(click));
 
+                        self.released_press_origin = self.press_origin;
                         self.press_origin = None;
                         self.press_start_time = None;
                     }
@@ -502,6 +653,18 @@ impl PointerState {
             }
         }
 
+        if self.could_become_long_press() {
+            if let (Some(press_origin), Some(press_start_time)) =
+                (self.press_origin, self.press_start_time)
+            {
+                if time - press_start_time > LONG_PRESS_DURATION {
+                    self.has_long_pressed = true;
+                    self.pointer_events
+                        .push(PointerEvent::LongPress(press_origin));
+                }
+            }
+        }
+
         self.delta = if let (Some(old_pos), Some(new_pos)) = (old_pos, self.latest_pos) {
             new_pos - old_pos
         } else {
@@ -528,7 +691,15 @@ impl PointerState {
     }
 
     fn wants_repaint(&self) -> bool {
-        !self.pointer_events.is_empty() || self.delta != Vec2::ZERO
+        // Keep checking the time while waiting for a long press:
+        !self.pointer_events.is_empty()
+            || self.delta != Vec2::ZERO
+            || self.could_become_long_press()
+    }
+
+    /// Is the primary button down, still and not yet reported as a long press?
+    fn could_become_long_press(&self) -> bool {
+        self.primary_down() && !self.has_moved_too_much_for_a_click && !self.has_long_pressed
     }
 
     /// How much the pointer moved compared to last frame, in points.
@@ -550,6 +721,12 @@ impl PointerState {
         self.press_origin
     }
 
+    /// Like [`Self::press_origin`], but also during the frame the press was released.
+    #[inline(always)]
+    pub(crate) fn press_or_release_origin(&self) -> Option<Pos2> {
+        self.press_origin.or(self.released_press_origin)
+    }
+
     /// When did the current click/drag originate?
     /// `None` if no mouse button is down.
     #[inline(always)]
@@ -688,6 +865,7 @@ impl InputState {
             pointer,
             touch_states,
             scroll_delta,
+            unprocessed_scroll_delta,
             zoom_factor_delta,
             screen_rect,
             pixels_per_point,
@@ -715,6 +893,10 @@ impl InputState {
         }
 
         ui.label(format!("scroll_delta: {:?} points", scroll_delta));
+        ui.label(format!(
+            "unprocessed_scroll_delta: {:?} points",
+            unprocessed_scroll_delta
+        ));
         ui.label(format!("zoom_factor_delta: {:4.2}x", zoom_factor_delta));
         ui.label(format!("screen_rect: {:?} points", screen_rect));
         ui.label(format!(
@@ -745,8 +927,10 @@ impl PointerState {
             pos_history: _,
             down,
             press_origin,
+            released_press_origin: _,
             press_start_time,
             has_moved_too_much_for_a_click,
+            has_long_pressed,
             last_click_time,
             pointer_events,
         } = self;
@@ -765,7 +949,39 @@ impl PointerState {
             "has_moved_too_much_for_a_click: {}",
             has_moved_too_much_for_a_click
         ));
+        ui.label(format!("has_long_pressed: {}", has_long_pressed));
         ui.label(format!("last_click_time: {:#?}", last_click_time));
         ui.label(format!("pointer_events: {:?}", pointer_events));
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_mouse_wheel_smoothing() {
+    let options = crate::memory::Options::default();
+    let wheel = |unit| RawInput {
+        events: vec![Event::MouseWheel {
+            unit,
+            delta: vec2(0.0, -1.0),
+            modifiers: Modifiers::NONE,
+        }],
+        predicted_dt: 1.0 / 60.0,
+        ..Default::default()
+    };
+
+    let input = InputState::default().begin_frame(wheel(MouseWheelUnit::Point), &options);
+    assert_eq!(input.scroll_delta, vec2(0.0, -1.0));
+
+    let mut input = InputState::default().begin_frame(wheel(MouseWheelUnit::Line), &options);
+    let mut total = input.scroll_delta.y;
+    assert!(-options.line_scroll_speed < total && total < 0.0);
+    while input.wants_repaint() {
+        let raw = RawInput {
+            predicted_dt: 1.0 / 60.0,
+            ..Default::default()
+        };
+        input = input.begin_frame(raw, &options);
+        total += input.scroll_delta.y;
+    }
+    assert!((total + options.line_scroll_speed).abs() < 1e-3);
+}
@@ -91,6 +91,7 @@ impl Widget for &epaint::stats::PaintStats {
                 shape_vec,
                 text_shape_vertices,
                 text_shape_indices,
+                culled_shapes,
                 clipped_meshes,
                 vertices,
                 indices,
@@ -111,6 +112,10 @@ impl Widget for &epaint::stats::PaintStats {
             ui.add_space(10.0);
 
             ui.label("Tessellated (and culled):");
+            ui.add(Label::new(format!("{:6} {:14}", culled_shapes, "culled shapes")).wrap(false))
+                .on_hover_text(
+                    "Shapes outside of their clip rectangle or the screen that were skipped",
+                );
             label(ui, clipped_meshes, "clipped_meshes")
                 .on_hover_text("Number of separate clip rectangles");
             label(ui, vertices, "vertices");
@@ -124,6 +129,88 @@ impl Widget for &epaint::stats::PaintStats {
     }
 }
 
+/// Column to sort [`layer_paint_stats_ui`] by.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LayerStatsColumn {
+    PaintOrder,
+    Shapes,
+    Vertices,
+    Triangles,
+    Time,
+}
+
+impl Default for LayerStatsColumn {
+    fn default() -> Self {
+        Self::PaintOrder
+    }
+}
+
+/// A table of [`crate::layers::LayerPaintStats`] that can be sorted by clicking a column header.
+/// Hover a row to highlight the layer.
+pub(crate) fn layer_paint_stats_ui(ui: &mut Ui, mut stats: Vec<crate::layers::LayerPaintStats>) {
+    let id = ui.make_persistent_id("layer_paint_stats");
+    let mut sort_by: LayerStatsColumn = *ui.memory().data_temp.get_temp_mut_or_default(id);
+
+    match sort_by {
+        LayerStatsColumn::PaintOrder => {}
+        LayerStatsColumn::Shapes => stats.sort_by_key(|s| std::cmp::Reverse(s.num_shapes)),
+        LayerStatsColumn::Vertices => stats.sort_by_key(|s| std::cmp::Reverse(s.num_vertices)),
+        LayerStatsColumn::Triangles => stats.sort_by_key(|s| std::cmp::Reverse(s.num_triangles)),
+        LayerStatsColumn::Time => stats.sort_by(|a, b| {
+            let time = |s: &crate::layers::LayerPaintStats| s.tessellation_time.unwrap_or_default();
+            time(b)
+                .partial_cmp(&time(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        }),
+    }
+
+    Grid::new(id).striped(true).show(ui, |ui| {
+        for (column, name) in [
+            (LayerStatsColumn::PaintOrder, "Layer"),
+            (LayerStatsColumn::Shapes, "Shapes"),
+            (LayerStatsColumn::Vertices, "Vertices"),
+            (LayerStatsColumn::Triangles, "Triangles"),
+            (LayerStatsColumn::Time, "Time"),
+        ] {
+            ui.selectable_value(&mut sort_by, column, name)
+                .on_hover_text("Sort by this column");
+        }
+        ui.end_row();
+
+        for layer in &stats {
+            let response = ui.label(layer.layer_id.short_debug_format());
+            ui.label(layer.num_shapes.to_string());
+            ui.label(layer.num_vertices.to_string());
+            ui.label(layer.num_triangles.to_string());
+            let time = layer
+                .tessellation_time
+                .map_or_else(|| "-".to_owned(), |time| format!("{:.3} ms", 1e3 * time));
+            if layer.cached {
+                ui.label(format!("{} (cached)", time));
+            } else {
+                ui.label(time);
+            }
+            ui.end_row();
+
+            if response.hovered() {
+                // Panels and the background have no area, so can't be highlighted.
+                let area_rect = ui
+                    .memory()
+                    .areas
+                    .get(layer.layer_id.id)
+                    .map(|area| area.rect());
+                if let Some(rect) = area_rect {
+                    ui.ctx()
+                        .debug_painter()
+                        .rect_stroke(rect, 0.0, (1.0, Color32::RED));
+                }
+            }
+        }
+    });
+
+    ui.memory().data_temp.insert_temp(id, sort_by);
+}
+
 pub fn label(ui: &mut Ui, alloc_info: &epaint::stats::AllocInfo, what: &str) -> Response {
     ui.add(Label::new(alloc_info.format(what)).wrap(false))
 }
@@ -140,9 +227,16 @@ impl Widget for &mut epaint::TessellationOptions {
                 debug_paint_clip_rects,
                 debug_paint_text_rects,
                 debug_ignore_clip_rects,
+                parallel_tessellation,
+                max_vertices_per_mesh: _,
+                linear_vertex_colors: _,
             } = self;
             ui.checkbox(anti_alias, "Antialias")
                 .on_hover_text("Turn off for small performance gain.");
+            if cfg!(feature = "rayon") {
+                ui.checkbox(parallel_tessellation, "Parallel tessellation")
+                    .on_hover_text("Tessellate long lists of shapes on multiple threads.");
+            }
             ui.collapsing("debug", |ui| {
                 ui.checkbox(
                     coarse_tessellation_culling,
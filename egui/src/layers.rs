@@ -143,6 +143,17 @@ impl PaintList {
         self.0[idx.0] = ClippedShape(clip_rect, shape);
     }
 
+    /// Make every [`Shape`] more transparent, e.g. to fade it out.
+    ///
+    /// `opacity` is in the range `[0, 1]`, where `1` leaves the shapes as they are.
+    pub fn multiply_opacity(&mut self, opacity: f32) {
+        for ClippedShape(_, shape) in &mut self.0 {
+            epaint::shape_transform::adjust_colors(shape, &|color| {
+                *color = color.linear_multiply(opacity);
+            });
+        }
+    }
+
     /// Translate each [`Shape`] and clip rectangle by this much, in-place
     pub fn translate(&mut self, delta: Vec2) {
         for ClippedShape(clip_rect, shape) in &mut self.0 {
@@ -153,41 +164,129 @@ impl PaintList {
 }
 
 #[derive(Clone, Default)]
-pub(crate) struct GraphicLayers([IdMap<Arc<Mutex<PaintList>>>; Order::COUNT]);
+pub(crate) struct GraphicLayers {
+    lists: [IdMap<Arc<Mutex<PaintList>>>; Order::COUNT],
+
+    /// Painted on top of the corresponding list in `lists`, see [`crate::Painter::overlay`].
+    overlays: [IdMap<Arc<Mutex<PaintList>>>; Order::COUNT],
+}
 
 impl GraphicLayers {
     pub fn list(&mut self, layer_id: LayerId) -> &Arc<Mutex<PaintList>> {
-        self.0[layer_id.order as usize]
+        self.lists[layer_id.order as usize]
+            .entry(layer_id.id)
+            .or_default()
+    }
+
+    /// Shapes painted after everything in [`Self::list`] of the same layer.
+    pub fn overlay_list(&mut self, layer_id: LayerId) -> &Arc<Mutex<PaintList>> {
+        self.overlays[layer_id.order as usize]
             .entry(layer_id.id)
             .or_default()
     }
 
-    pub fn drain(&mut self, area_order: &[LayerId]) -> impl ExactSizeIterator<Item = ClippedShape> {
-        let mut all_shapes: Vec<_> = Default::default();
+    /// Returns all shapes in paint order, and which layer each range of them came from.
+    ///
+    /// The shapes are moved into `all_shapes` (e.g. the emptied list of last frame), to avoid reallocating it.
+    /// The paint lists keep their capacity for the next frame.
+    pub fn drain(
+        &mut self,
+        area_order: &[LayerId],
+        mut all_shapes: Vec<ClippedShape>,
+    ) -> (Vec<ClippedShape>, Vec<LayerShapes>) {
+        let num_shapes: usize = self
+            .lists
+            .iter()
+            .chain(&self.overlays)
+            .flat_map(|order_map| order_map.values())
+            .map(|list| list.lock().0.len())
+            .sum();
+        all_shapes.clear();
+        all_shapes.reserve(num_shapes);
+
+        let mut layers = Vec::new();
+        let mut append = |layer_id: LayerId,
+                          shapes: &mut Vec<ClippedShape>,
+                          overlay: Option<&Arc<Mutex<PaintList>>>| {
+            let start = all_shapes.len();
+            all_shapes.append(shapes);
+            if let Some(overlay) = overlay {
+                all_shapes.append(&mut overlay.lock().0);
+            }
+            if all_shapes.len() > start {
+                layers.push(LayerShapes {
+                    layer_id,
+                    range: start..all_shapes.len(),
+                });
+            }
+        };
 
         for &order in &Order::ALL {
-            let order_map = &mut self.0[order as usize];
+            let order_map = &mut self.lists[order as usize];
+            let overlay_map = &mut self.overlays[order as usize];
 
             // If a layer is empty at the start of the frame
             // then nobody has added to it, and it is old and defunct.
             // Free it to save memory:
             order_map.retain(|_, list| !list.lock().is_empty());
+            overlay_map.retain(|_, list| !list.lock().is_empty());
 
             // First do the layers part of area_order:
             for layer_id in area_order {
                 if layer_id.order == order {
                     if let Some(list) = order_map.get_mut(&layer_id.id) {
-                        all_shapes.append(&mut list.lock().0);
+                        append(*layer_id, &mut list.lock().0, overlay_map.get(&layer_id.id));
                     }
                 }
             }
 
             // Also draw areas that are missing in `area_order`:
-            for shapes in order_map.values_mut() {
-                all_shapes.append(&mut shapes.lock().0);
+            for (&id, shapes) in order_map.iter_mut() {
+                append(
+                    LayerId::new(order, id),
+                    &mut shapes.lock().0,
+                    overlay_map.get(&id),
+                );
+            }
+
+            // An overlay without anything under it:
+            for (&id, shapes) in overlay_map.iter_mut() {
+                append(LayerId::new(order, id), &mut shapes.lock().0, None);
             }
         }
 
-        all_shapes.into_iter()
+        (all_shapes, layers)
     }
 }
+
+/// Which of the shapes from [`GraphicLayers::drain`] belong to which layer.
+#[derive(Clone, Debug)]
+pub(crate) struct LayerShapes {
+    pub layer_id: LayerId,
+    pub range: std::ops::Range<usize>,
+}
+
+/// Paint statistics for a single layer, see [`crate::Context::layer_paint_stats`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayerPaintStats {
+    pub layer_id: LayerId,
+
+    /// Number of top-level shapes.
+    pub num_shapes: usize,
+
+    /// Number of tessellated vertices.
+    pub num_vertices: usize,
+
+    /// Number of tessellated triangles.
+    pub num_triangles: usize,
+
+    /// How long it took to tessellate the shapes of this layer, in seconds.
+    ///
+    /// `None` on the web, where there is no clock to measure it with.
+    pub tessellation_time: Option<f32>,
+
+    /// Was the tessellation of the previous frame reused, because the shapes were the same?
+    ///
+    /// See `Memory::options.cache_tessellation`.
+    pub cached: bool,
+}
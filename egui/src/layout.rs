@@ -141,6 +141,9 @@ pub struct Layout {
     /// For vertical layouts justify mean all widgets get maximum width.
     /// For horizontal layouts justify mean all widgets get maximum height.
     cross_justify: bool,
+
+    /// For horizontal layouts: line up text by its baseline instead of by `cross_align`?
+    baseline_align: bool,
 }
 
 impl Default for Layout {
@@ -161,6 +164,7 @@ impl Layout {
             main_justify: false,
             cross_align: Align::Center,
             cross_justify: false,
+            baseline_align: false,
         }
     }
 
@@ -173,6 +177,7 @@ impl Layout {
             main_justify: false,
             cross_align: Align::Center,
             cross_justify: false,
+            baseline_align: false,
         }
     }
 
@@ -185,6 +190,7 @@ impl Layout {
             main_justify: false,
             cross_align,
             cross_justify: false,
+            baseline_align: false,
         }
     }
 
@@ -203,6 +209,7 @@ impl Layout {
             main_justify: false,
             cross_align,
             cross_justify: false,
+            baseline_align: false,
         }
     }
 
@@ -215,6 +222,7 @@ impl Layout {
             main_justify: false,
             cross_align,
             cross_justify: false,
+            baseline_align: false,
         }
     }
 
@@ -227,6 +235,7 @@ impl Layout {
             main_justify: true,
             cross_align: Align::Center,
             cross_justify: true,
+            baseline_align: false,
         }
     }
 
@@ -258,6 +267,33 @@ impl Layout {
             ..self
         }
     }
+
+    /// In a horizontal layout, line up widgets containing text (labels, buttons, …)
+    /// by the baseline of their first row of text, instead of by the cross alignment.
+    ///
+    /// This makes e.g. a large heading next to a small button line up typographically.
+    /// Widgets without text are still placed according to [`Self::with_cross_align`].
+    ///
+    /// The baseline of the row is remembered from the previous frame,
+    /// so this works best for rows that don't wrap.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut value = 0.0;
+    /// ui.with_layout(egui::Layout::left_to_right().with_baseline_align(true), |ui| {
+    ///     ui.heading("Speed");
+    ///     ui.button("Reset");
+    ///     ui.add(egui::DragValue::new(&mut value));
+    /// });
+    /// # });
+    /// ```
+    #[inline(always)]
+    pub fn with_baseline_align(self, baseline_align: bool) -> Self {
+        Self {
+            baseline_align,
+            ..self
+        }
+    }
 }
 
 /// ## Inspectors
@@ -282,6 +318,12 @@ impl Layout {
         self.cross_justify
     }
 
+    /// Are widgets lined up by their text baseline? See [`Self::with_baseline_align`].
+    #[inline(always)]
+    pub fn baseline_align(&self) -> bool {
+        self.baseline_align && self.is_horizontal()
+    }
+
     #[inline(always)]
     pub fn is_horizontal(&self) -> bool {
         self.main_dir().is_horizontal()
@@ -803,3 +845,38 @@ impl Layout {
         painter.debug_text(next_pos, align, stroke.color, text);
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// The text baseline of a row in a [`Layout::with_baseline_align`] layout,
+/// collected as widgets are added and remembered until the next frame.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RowBaseline {
+    /// When [`Self::current`] was collected.
+    frame_nr: u64,
+
+    /// Largest distance from the top of the row to a text baseline, last frame.
+    last: f32,
+
+    /// Largest distance from the top of the row to a text baseline so far this frame.
+    current: f32,
+}
+
+impl RowBaseline {
+    /// Add a widget with the given `baseline` to the row in frame `frame_nr`.
+    ///
+    /// Returns where the row baseline is, and if it moved down since last frame,
+    /// i.e. if the widgets added before this one are misaligned.
+    pub fn add(&mut self, frame_nr: u64, baseline: f32) -> (f32, bool) {
+        if self.frame_nr != frame_nr {
+            if self.frame_nr + 1 == frame_nr {
+                self.last = self.current;
+            }
+            self.frame_nr = frame_nr;
+            self.current = 0.0;
+        }
+        let moved = baseline > self.last && baseline > self.current;
+        self.current = self.current.max(baseline);
+        (self.last.max(self.current), moved)
+    }
+}
@@ -360,8 +360,10 @@ mod animation_manager;
 pub mod containers;
 mod context;
 mod data;
+mod flex;
 mod frame_state;
 pub(crate) mod grid;
+mod hit_shape;
 mod id;
 mod input_state;
 mod introspection;
@@ -369,26 +371,43 @@ pub mod layers;
 mod layout;
 mod memory;
 pub mod menu;
+mod os;
 mod painter;
 pub(crate) mod placer;
+mod plugin;
+mod profiler;
 mod response;
 mod sense;
+mod shortcut_cheat_sheet;
+mod storage;
 pub mod style;
+mod tessellation_cache;
+#[cfg(any(test, feature = "testing"))]
+pub mod test;
+#[cfg(feature = "theme")]
+pub mod theme;
 mod ui;
+pub mod ui_description;
 pub mod util;
 mod widget_text;
+mod widget_tree;
 pub mod widgets;
 
 pub use epaint;
 pub use epaint::emath;
 
-pub use emath::{lerp, pos2, remap, remap_clamp, vec2, Align, Align2, NumExt, Pos2, Rect, Vec2};
+pub use emath::{
+    lerp, pos2, remap, remap_clamp, vec2, Align, Align2, Easing, NumExt, Pos2, Rect, Vec2,
+};
 pub use epaint::{
     color, mutex,
     text::{FontData, FontDefinitions, FontFamily, TextStyle},
     ClippedMesh, Color32, FontImage, Rgba, Shape, Stroke, TextureId,
 };
 
+#[cfg(feature = "icons")]
+pub use epaint::text::Icon;
+
 pub mod text {
     pub use epaint::text::{
         FontData, FontDefinitions, FontFamily, Fonts, Galley, LayoutJob, LayoutSection, TextFormat,
@@ -397,25 +416,35 @@ pub mod text {
 }
 
 pub use {
+    animation_manager::Spring,
     containers::*,
     context::{Context, CtxRef},
     data::{
         input::*,
+        input_recording::{InputRecording, InputReplayer},
         output::{self, CursorIcon, Output, WidgetInfo},
     },
+    frame_state::IdClash,
     grid::Grid,
+    hit_shape::HitShape,
     id::{Id, IdMap},
     input_state::{InputState, MultiTouchInfo, PointerState},
     layers::{LayerId, Order},
     layout::*,
-    memory::Memory,
+    memory::{IdClashPolicy, Memory},
+    os::OperatingSystem,
     painter::Painter,
+    plugin::ContextPlugin,
+    profiler::FrameTimings,
     response::{InnerResponse, Response},
     sense::Sense,
-    style::{Style, Visuals},
+    shortcut_cheat_sheet::ShortcutCheatSheet,
+    storage::{InMemoryStorage, Storage},
+    style::{Style, Theme, ThemePreference, Visuals},
     text::{Galley, TextFormat},
     ui::Ui,
     widget_text::{RichText, WidgetText},
+    widget_tree::{WidgetNode, WidgetTree},
     widgets::*,
 };
 
@@ -427,7 +456,7 @@ pub fn warn_if_debug_build(ui: &mut crate::Ui) {
         ui.label(
             RichText::new("‼ Debug build ‼")
                 .small()
-                .color(crate::Color32::RED),
+                .color(ui.visuals().warn_fg_color),
         )
         .on_hover_text("egui was compiled with debug assertions enabled.");
     }
@@ -564,6 +593,7 @@ pub enum WidgetType {
     ColorButton,
     ImageButton,
     CollapsingHeader,
+    Window,
 
     /// If you cannot fit any of the above slots.
     ///
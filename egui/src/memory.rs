@@ -1,6 +1,6 @@
 use epaint::ahash::AHashSet;
 
-use crate::{area, window, Id, IdMap, InputState, LayerId, Pos2, Rect, Style};
+use crate::{area, vec2, window, Id, IdMap, InputState, LayerId, Pos2, Rect, Style, Vec2};
 
 // ----------------------------------------------------------------------------
 
@@ -12,19 +12,32 @@ use crate::{area, window, Id, IdMap, InputState, LayerId, Pos2, Rect, Style};
 /// If you want this to persist when closing your app you should serialize `Memory` and store it.
 /// For this you need to enable the `persistence`.
 ///
-/// If you want to store data for your widgets, you should look at [`Memory::data`]
+/// If you want to store data for your widgets, you should look at [`Memory::data_temp`] and [`Memory::data_persisted`].
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "persistence", serde(default))]
 pub struct Memory {
     pub options: Options,
 
-    /// This map stores current states for all widgets with custom `Id`s.
+    /// Scratch state for widgets with custom `Id`s, that is never persisted.
     ///
-    /// This will be saved between different program runs if you use the `persistence` feature.
+    /// Use [`crate::util::IdTypeMap::insert_temp`] and [`crate::util::IdTypeMap::get_temp`] with this.
     ///
     /// To store a state common for all your widgets (a singleton), use [`Id::null`] as the key.
-    pub data: crate::util::IdTypeMap,
+    ///
+    /// The least recently used values are pruned when there are more than [`Options::max_data_entries`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub data_temp: crate::util::IdTypeMap,
+
+    /// State for widgets with custom `Id`s that should be remembered
+    /// between different program runs (if you use the `persistence` feature).
+    ///
+    /// Use [`crate::util::IdTypeMap::insert_persisted`] and [`crate::util::IdTypeMap::get_persisted`] with this.
+    /// Only values inserted with `insert_persisted` are saved, so keep scratch data in [`Self::data_temp`].
+    ///
+    /// The least recently used values are pruned when there are more than [`Options::max_data_entries`].
+    #[cfg_attr(feature = "persistence", serde(alias = "data"))]
+    pub data_persisted: crate::util::IdTypeMap,
 
     // ------------------------------------------
     /// Can be used to cache computations from one frame to another.
@@ -58,6 +71,22 @@ pub struct Memory {
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub(crate) new_pixels_per_point: Option<f32>,
 
+    /// The `pixels_per_point` from the integration, before applying [`Options::zoom_factor`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) native_pixels_per_point: Option<f32>,
+
+    /// The last [`crate::RawInput::system_theme`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) system_theme: Option<crate::Theme>,
+
+    /// The last [`crate::RawInput::prefers_reduced_motion`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) system_prefers_reduced_motion: bool,
+
+    /// See [`crate::Context::set_os`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) os: crate::OperatingSystem,
+
     /// new fonts that will be applied at the start of the next frame
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub(crate) new_font_definitions: Option<epaint::text::FontDefinitions>,
@@ -73,6 +102,15 @@ pub struct Memory {
 
     pub(crate) areas: Areas,
 
+    pub(crate) navigation: Navigation,
+
+    /// See [`Self::is_url_visited`].
+    visited_urls: AHashSet<String>,
+
+    /// The ids of the [`crate::Response::snapshot`] requests that the integration hasn't answered yet.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) pending_snapshots: AHashSet<Id>,
+
     /// Which popup-window is open (if any)?
     /// Could be a combo box, color picker, menu etc.
     #[cfg_attr(feature = "persistence", serde(skip))]
@@ -80,12 +118,20 @@ pub struct Memory {
 
     #[cfg_attr(feature = "persistence", serde(skip))]
     everything_is_visible: bool,
+
+    /// Number of finished frames.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) frame_nr: u64,
+
+    /// See [`Self::set_storage`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    storage: Option<crate::storage::SharedStorage>,
 }
 
 // ----------------------------------------------------------------------------
 
 /// Some global options that you can read and write.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct Options {
@@ -96,10 +142,146 @@ pub struct Options {
     /// Controls the tessellator.
     pub tessellation_options: epaint::TessellationOptions,
 
+    /// If `true`, [`crate::Context::tessellate`] reuses the meshes of last frame
+    /// for each layer whose shapes haven't changed.
+    /// Only layers that have stayed the same for a frame are kept in memory.
+    ///
+    /// This speeds up tessellation of mostly static uis (e.g. a big table that is only scrolled now and then),
+    /// but hashing the shapes costs a good fraction of the time it takes to tessellate them,
+    /// so it is a loss for uis that change every frame.
+    ///
+    /// Default: `false`.
+    pub cache_tessellation: bool,
+
+    /// If [`Memory::data_temp`] or [`Memory::data_persisted`] holds more values than this, the least recently used are removed
+    /// at the end of the frame, so that the state of widgets that are long gone doesn't
+    /// pile up (e.g. in the persisted state of a long-running app).
+    ///
+    /// Default: `10_000`.
+    pub max_data_entries: usize,
+
+    /// What to do when two widgets use the same [`Id`], see [`crate::Context::id_clashes`].
+    ///
+    /// Default: [`IdClashPolicy::PaintOverlay`].
+    pub id_clash_policy: IdClashPolicy,
+
     /// This does not at all change the behavior of egui,
     /// but is a signal to any backend that we want the [`crate::Output::events`] read out loud.
     /// Screen readers is an experimental feature of egui, and not supported on all platforms.
     pub screen_reader: bool,
+
+    /// If `true`, egui will emit [`crate::output::UiEvent`]s in [`crate::Output::ui_events`].
+    ///
+    /// Default: `false`.
+    pub record_ui_events: bool,
+
+    /// If `true`, egui will describe the widgets of each frame in [`crate::Output::accessibility_nodes`].
+    ///
+    /// Default: `false`.
+    pub accessibility_tree: bool,
+
+    /// If `true`, egui will record all widgets and [`crate::Ui`]s of each frame,
+    /// see [`crate::Context::frame_widget_tree`].
+    ///
+    /// Default: `false`.
+    pub record_widget_tree: bool,
+
+    /// Save power by repainting less: decorative animations (those of
+    /// [`crate::Context::animate_bool`], e.g. opening a collapsing header or fading a hover highlight)
+    /// are skipped, and while the user is not interacting with egui, repaints are throttled
+    /// to at most one per [`Self::power_save_repaint_interval`].
+    ///
+    /// Useful for always-on dashboards and for running on battery.
+    /// See [`crate::Context::is_power_saving`] and [`crate::Output::repaint_after`].
+    ///
+    /// Default: `false`.
+    pub power_save: bool,
+
+    /// When [`Self::power_save`] is active, this is the minimum number of seconds between repaints,
+    /// e.g. for a spinner or progress bar.
+    ///
+    /// Default: `0.1` (i.e. at most 10 frames per second).
+    pub power_save_repaint_interval: f32,
+
+    /// Scales the whole ui, on top of the `pixels_per_point` reported by the integration.
+    ///
+    /// Use [`crate::Context::set_zoom_factor`] to change this.
+    ///
+    /// Default: `1.0`.
+    pub(crate) zoom_factor: f32,
+
+    /// If `true`, Ctrl+Plus, Ctrl+Minus and Ctrl+0 (Cmd on Mac) will zoom in, zoom out
+    /// and reset [`crate::Context::zoom_factor`].
+    ///
+    /// `egui_web` turns this off, so the keys zoom the browser instead.
+    ///
+    /// Default: `true`.
+    pub zoom_with_keyboard: bool,
+
+    /// If `true`, a long press (see [`crate::Response::long_touched`]) opens
+    /// the [`crate::Response::context_menu`], just like a secondary click.
+    /// Useful on touch screens, which have no secondary button.
+    ///
+    /// Default: `true`.
+    pub long_press_opens_context_menu: bool,
+
+    /// How many points to scroll for each line of [`crate::MouseWheelUnit::Line`] scrolling,
+    /// i.e. for each notch of a typical mouse wheel.
+    ///
+    /// Default: `50.0`, decided by consensus in <https://github.com/emilk/egui/issues/461>.
+    /// `egui_web` sets this to `8.0`.
+    pub line_scroll_speed: f32,
+
+    /// Line and page based scrolling (see [`crate::MouseWheelUnit`]) is spread out over
+    /// roughly this many seconds, so that it doesn't jump.
+    /// Set to zero to scroll instantly.
+    ///
+    /// Pixel-precise scrolling (e.g. from a trackpad) is always applied immediately.
+    ///
+    /// Default: `0.1`.
+    pub scroll_smoothing: f32,
+
+    /// Use [`crate::Context::set_theme_preference`] to change this.
+    ///
+    /// Default: [`crate::ThemePreference::Dark`].
+    pub(crate) theme_preference: crate::ThemePreference,
+
+    /// Skip animations, even if the operating system doesn't ask for it
+    /// (see [`crate::RawInput::prefers_reduced_motion`]).
+    ///
+    /// Default: `false`.
+    pub reduce_motion: bool,
+
+    /// Use [`crate::Context::set_high_contrast`] to change this.
+    ///
+    /// Default: `false`.
+    pub(crate) high_contrast: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            tessellation_options: Default::default(),
+            cache_tessellation: false,
+            max_data_entries: 10_000,
+            id_clash_policy: Default::default(),
+            screen_reader: false,
+            record_ui_events: false,
+            accessibility_tree: false,
+            record_widget_tree: false,
+            power_save: false,
+            power_save_repaint_interval: 0.1,
+            zoom_factor: 1.0,
+            zoom_with_keyboard: true,
+            long_press_opens_context_menu: true,
+            line_scroll_speed: 50.0,
+            scroll_smoothing: 0.1,
+            theme_preference: Default::default(),
+            reduce_motion: false,
+            high_contrast: false,
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -164,6 +346,16 @@ pub(crate) struct Focus {
 
     /// Set at the beginning of the frame, set to `false` when "used".
     pressed_shift_tab: bool,
+
+    /// Set at the beginning of the frame if the user pressed the gamepad d-pad
+    /// or tilted the left stick: move focus to the nearest widget in this direction.
+    gamepad_direction: Option<Vec2>,
+
+    /// Where the left gamepad stick is (with positive y up).
+    left_stick: Vec2,
+
+    /// All widgets interested in focus this frame, and where they are.
+    candidates: Vec<(Id, Rect)>,
 }
 
 impl Interaction {
@@ -208,6 +400,8 @@ impl Focus {
 
         self.pressed_tab = false;
         self.pressed_shift_tab = false;
+        self.gamepad_direction = None;
+        self.candidates.clear();
         for event in &new_input.events {
             if matches!(
                 event,
@@ -236,6 +430,48 @@ impl Focus {
                     }
                 }
             }
+
+            match event {
+                crate::Event::GamepadButton {
+                    button,
+                    pressed: true,
+                } => {
+                    use crate::GamepadButton;
+                    let direction = match button {
+                        GamepadButton::DPadUp => Some(Vec2::UP),
+                        GamepadButton::DPadDown => Some(Vec2::DOWN),
+                        GamepadButton::DPadLeft => Some(Vec2::LEFT),
+                        GamepadButton::DPadRight => Some(Vec2::RIGHT),
+                        _ => None,
+                    };
+                    if direction.is_some() {
+                        self.gamepad_direction = direction;
+                    }
+                }
+                crate::Event::GamepadAxis { axis, value } => {
+                    use crate::GamepadAxis;
+                    let was_tilted = self.left_stick.length() >= STICK_THRESHOLD;
+                    match axis {
+                        GamepadAxis::LeftStickX => self.left_stick.x = *value,
+                        GamepadAxis::LeftStickY => self.left_stick.y = *value,
+                        _ => continue,
+                    }
+                    if !was_tilted && self.left_stick.length() >= STICK_THRESHOLD {
+                        // Only move one step each time the stick is tilted:
+                        let stick = self.left_stick;
+                        self.gamepad_direction = Some(if stick.x.abs() > stick.y.abs() {
+                            vec2(stick.x.signum(), 0.0)
+                        } else {
+                            vec2(0.0, -stick.y.signum())
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if self.is_focus_locked {
+            self.gamepad_direction = None;
         }
     }
 
@@ -249,13 +485,34 @@ impl Focus {
                 self.id = None;
             }
         }
+
+        if let Some(direction) = self.gamepad_direction.take() {
+            let current_rect = self.id.and_then(|id| {
+                self.candidates
+                    .iter()
+                    .find(|(candidate, _)| *candidate == id)
+                    .map(|(_, rect)| *rect)
+            });
+            let next = match current_rect {
+                Some(current_rect) => {
+                    nearest_in_direction(&self.candidates, current_rect, direction)
+                }
+                // Nothing has focus yet - start with the first widget that wants it:
+                None => self.candidates.first().map(|(id, _)| *id),
+            };
+            if next.is_some() {
+                self.id_next_frame = next; // frame-delay so gained_focus works
+            }
+        }
     }
 
     pub(crate) fn had_focus_last_frame(&self, id: Id) -> bool {
         self.id_previous_frame == Some(id)
     }
 
-    fn interested_in_focus(&mut self, id: Id) {
+    fn interested_in_focus(&mut self, id: Id, rect: Rect) {
+        self.candidates.push((id, rect));
+
         if self.give_to_next && !self.had_focus_last_frame(id) {
             self.id = Some(id);
             self.give_to_next = false;
@@ -277,6 +534,26 @@ impl Focus {
     }
 }
 
+/// How far the gamepad stick must be tilted to move focus.
+const STICK_THRESHOLD: f32 = 0.5;
+
+/// The widget whose center is closest to that of `from` in the given (axis-aligned) direction,
+/// preferring widgets that are straight ahead over ones that are off to the side.
+fn nearest_in_direction(candidates: &[(Id, Rect)], from: Rect, direction: Vec2) -> Option<Id> {
+    let dot = |a: Vec2, b: Vec2| a.x * b.x + a.y * b.y;
+    let sideways = direction.rot90();
+    candidates
+        .iter()
+        .filter_map(|(id, rect)| {
+            let delta = rect.center() - from.center();
+            let along = dot(delta, direction);
+            let across = dot(delta, sideways).abs();
+            (along > 0.0).then(|| (*id, along + 2.0 * across))
+        })
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(id, _)| id)
+}
+
 impl Memory {
     pub(crate) fn begin_frame(
         &mut self,
@@ -292,9 +569,12 @@ impl Memory {
 
     pub(crate) fn end_frame(&mut self, input: &InputState, used_ids: &IdMap<Rect>) {
         self.caches.update();
+        self.frame_nr += 1;
         self.areas.end_frame();
         self.interaction.focus.end_frame(used_ids);
         self.drag_value.end_frame(input);
+        self.data_temp.end_frame(self.options.max_data_entries);
+        self.data_persisted.end_frame(self.options.max_data_entries);
     }
 
     /// Top-most layer at the given position.
@@ -365,8 +645,8 @@ impl Memory {
     /// Register this widget as being interested in getting keyboard focus.
     /// This will allow the user to select it with tab and shift-tab.
     #[inline(always)]
-    pub(crate) fn interested_in_focus(&mut self, id: Id) {
-        self.interaction.focus.interested_in_focus(id);
+    pub(crate) fn interested_in_focus(&mut self, id: Id, rect: Rect) {
+        self.interaction.focus.interested_in_focus(id, rect);
     }
 
     /// Stop editing of active `TextEdit` (if any).
@@ -390,6 +670,44 @@ impl Memory {
     pub fn reset_areas(&mut self) {
         self.areas = Default::default();
     }
+
+    /// Forget all state stored for this [`Id`]: everything in [`Self::data_temp`] and [`Self::data_persisted`],
+    /// and the position, size and order of the area (e.g. window) with this id.
+    ///
+    /// Useful when the thing shown with this id is gone for good, e.g. a deleted document.
+    pub fn forget_id(&mut self, id: Id) {
+        self.data_temp.remove_by_id(id);
+        self.data_persisted.remove_by_id(id);
+        self.areas.forget(id);
+    }
+
+    /// Forget the snapshot taken with [`crate::Response::snapshot`] for this [`Id`],
+    /// so that the next call requests a new one, e.g. because the widget has changed.
+    pub fn forget_snapshot(&mut self, id: Id) {
+        self.data_temp
+            .remove::<std::sync::Arc<crate::WidgetSnapshot>>(id);
+        self.pending_snapshots.remove(&id);
+    }
+}
+
+/// ## Hyperlinks
+impl Memory {
+    /// Has a [`crate::Hyperlink`] to this url been clicked?
+    ///
+    /// Visited links are shown in [`crate::Visuals::hyperlink_visited_color`].
+    pub fn is_url_visited(&self, url: &str) -> bool {
+        self.visited_urls.contains(url)
+    }
+
+    /// Show links to this url as visited, as if a [`crate::Hyperlink`] to it had been clicked.
+    pub fn mark_url_visited(&mut self, url: impl Into<String>) {
+        self.visited_urls.insert(url.into());
+    }
+
+    /// Show all links as not visited.
+    pub fn clear_visited_urls(&mut self) {
+        self.visited_urls.clear();
+    }
 }
 
 /// ## Popups
@@ -436,6 +754,153 @@ impl Memory {
     }
 }
 
+/// ## Persistence
+/// Saving and restoring the state of egui (window positions, persisted widget state etc)
+/// with a [`crate::Storage`] of your choice.
+///
+/// Requires the `persistence` feature. Without it, nothing is saved or restored.
+impl Memory {
+    /// The key under which [`Self::save`] stores the memory.
+    pub const STORAGE_KEY: &'static str = "egui";
+
+    /// Restore the memory from the given storage (if anything was saved there),
+    /// and use it for [`Self::save`] from now on.
+    pub fn set_storage(&mut self, storage: impl crate::Storage) {
+        #[cfg(feature = "persistence")]
+        if let Some(memory) = storage
+            .get_string(Self::STORAGE_KEY)
+            .and_then(|ron| ron::from_str::<Self>(&ron).ok())
+        {
+            *self = memory;
+        }
+        let storage = crate::mutex::Mutex::new(Box::new(storage) as Box<dyn crate::Storage>);
+        self.storage = Some(crate::storage::SharedStorage(std::sync::Arc::new(storage)));
+    }
+
+    /// Save the memory to the storage given to [`Self::set_storage`], if any, and flush it.
+    ///
+    /// Call this e.g. every now and then and when your app exits.
+    pub fn save(&self) {
+        #[cfg(feature = "persistence")]
+        if let Some(storage) = &self.storage {
+            match ron::to_string(self) {
+                Ok(ron) => {
+                    let mut storage = storage.0.lock();
+                    storage.set_string(Self::STORAGE_KEY, ron);
+                    storage.flush();
+                }
+                Err(err) => {
+                    eprintln!("egui: failed to serialize memory: {}", err);
+                }
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// What egui does when two widgets use the same [`Id`] in one frame.
+///
+/// See [`Options::id_clash_policy`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum IdClashPolicy {
+    /// Only record the clash in [`crate::Context::id_clashes`].
+    Ignore,
+
+    /// Paint a red error message on top of the clashing widgets.
+    PaintOverlay,
+
+    /// Log a warning using `tracing` (with the `tracing` feature), or else print it to stderr.
+    Log,
+
+    /// Panic, e.g. to catch clashes in your tests or CI.
+    Panic,
+}
+
+impl Default for IdClashPolicy {
+    fn default() -> Self {
+        Self::PaintOverlay
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The view history used by [`crate::Context::push_view`], [`crate::Context::back`] and [`crate::Context::forward`].
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub(crate) struct Navigation {
+    /// Previous views. Most recent is last.
+    back_stack: Vec<Id>,
+    current: Option<Id>,
+    /// Views we have gone back from. Most recent is last.
+    forward_stack: Vec<Id>,
+}
+
+impl Navigation {
+    pub fn current(&self) -> Option<Id> {
+        self.current
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back_stack.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward_stack.is_empty()
+    }
+
+    /// Returns `false` if `id` already is the current view.
+    pub fn push(&mut self, id: Id) -> bool {
+        if self.current == Some(id) {
+            return false;
+        }
+        self.back_stack.extend(self.current.replace(id));
+        self.forward_stack.clear();
+        true
+    }
+
+    pub fn back(&mut self) -> bool {
+        if let Some(previous) = self.back_stack.pop() {
+            self.forward_stack.extend(self.current.replace(previous));
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn forward(&mut self) -> bool {
+        if let Some(next) = self.forward_stack.pop() {
+            self.back_stack.extend(self.current.replace(next));
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn navigation_history() {
+    let [a, b, c] = [Id::new("a"), Id::new("b"), Id::new("c")];
+    let mut nav = Navigation::default();
+    assert!(!nav.back());
+    nav.push(a);
+    nav.push(b);
+    nav.push(c);
+    assert!(nav.back());
+    assert!(nav.back());
+    assert_eq!(nav.current(), Some(a));
+    assert!(!nav.back());
+    assert!(nav.forward());
+    assert_eq!(nav.current(), Some(b));
+    nav.push(a);
+    assert!(!nav.can_go_forward());
+    assert!(nav.back());
+    assert_eq!(nav.current(), Some(b));
+}
+
 // ----------------------------------------------------------------------------
 
 /// Keeps track of `Area`s, which are free-floating `Ui`s.
@@ -456,6 +921,12 @@ pub struct Areas {
     /// So if you close three windows and then reopen them all in one frame,
     /// they will all be sent to the top, but keep their previous internal order.
     wants_to_be_on_top: AHashSet<LayerId>,
+
+    /// See [`crate::Context::block_input_in`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    blocked_rects_last_frame: Vec<Rect>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    blocked_rects_current_frame: Vec<Rect>,
 }
 
 impl Areas {
@@ -472,6 +943,15 @@ impl Areas {
         &self.order
     }
 
+    pub(crate) fn forget(&mut self, id: Id) {
+        self.areas.remove(&id);
+        self.order.retain(|layer_id| layer_id.id != id);
+        self.visible_last_frame.retain(|layer_id| layer_id.id != id);
+        self.visible_current_frame
+            .retain(|layer_id| layer_id.id != id);
+        self.wants_to_be_on_top.retain(|layer_id| layer_id.id != id);
+    }
+
     pub(crate) fn set_state(&mut self, layer_id: LayerId, state: area::State) {
         self.visible_current_frame.insert(layer_id);
         self.areas.insert(layer_id.id, state);
@@ -480,11 +960,58 @@ impl Areas {
         }
     }
 
+    /// The layer we report for a position covered by [`crate::Context::block_input_in`].
+    ///
+    /// It sits between the panels and the windows.
+    pub(crate) fn input_blocker_layer() -> LayerId {
+        LayerId::new(crate::Order::PanelResizeLine, Id::new("input_blocker"))
+    }
+
+    pub(crate) fn block_input_in(&mut self, rect: Rect) {
+        self.blocked_rects_current_frame.push(rect);
+    }
+
+    fn is_input_blocked_at(&self, pos: Pos2) -> bool {
+        self.blocked_rects_last_frame
+            .iter()
+            .chain(&self.blocked_rects_current_frame)
+            .any(|rect| rect.contains(pos))
+    }
+
+    /// Everywhere [`Self::layer_id_at`] would find something other than the background layer.
+    pub(crate) fn hit_rects(&self) -> impl Iterator<Item = Rect> + '_ {
+        let blocked_rects = self
+            .blocked_rects_last_frame
+            .iter()
+            .chain(&self.blocked_rects_current_frame)
+            .copied();
+        self.order
+            .iter()
+            .filter(|layer| **layer != LayerId::background() && self.is_visible(layer))
+            .filter_map(|layer| self.areas.get(&layer.id))
+            .filter(|state| !state.input_transparent)
+            .map(|state| state.rect())
+            .chain(blocked_rects)
+    }
+
     /// Top-most layer at the given position.
+    ///
+    /// Areas that are [`crate::Area::input_transparent`] are skipped.
     pub fn layer_id_at(&self, pos: Pos2, resize_interact_radius_side: f32) -> Option<LayerId> {
+        let input_blocker = Self::input_blocker_layer();
+        let mut checked_blocker = false;
         for layer in self.order.iter().rev() {
+            if !checked_blocker && layer.order <= input_blocker.order {
+                if self.is_input_blocked_at(pos) {
+                    return Some(input_blocker);
+                }
+                checked_blocker = true;
+            }
             if self.is_visible(layer) {
                 if let Some(state) = self.areas.get(&layer.id) {
+                    if state.input_transparent {
+                        continue;
+                    }
                     let mut rect = state.rect();
                     if state.interactable {
                         // Allow us to resize by dragging just outside the window:
@@ -496,6 +1023,9 @@ impl Areas {
                 }
             }
         }
+        if !checked_blocker && self.is_input_blocked_at(pos) {
+            return Some(input_blocker);
+        }
         None
     }
 
@@ -538,10 +1068,13 @@ impl Areas {
             visible_current_frame,
             order,
             wants_to_be_on_top,
+            blocked_rects_last_frame,
+            blocked_rects_current_frame,
             ..
         } = self;
 
         *visible_last_frame = std::mem::take(visible_current_frame);
+        *blocked_rects_last_frame = std::mem::take(blocked_rects_current_frame);
         order.sort_by_key(|layer| (layer.order, wants_to_be_on_top.contains(layer)));
         wants_to_be_on_top.clear();
     }
@@ -549,9 +1082,59 @@ impl Areas {
 
 // ----------------------------------------------------------------------------
 
+#[cfg(test)]
+#[test]
+fn layer_id_at_respects_transparency_and_blocking() {
+    use crate::{pos2, Order, Vec2};
+
+    let area_state = |size: f32, input_transparent: bool| area::State {
+        pos: Pos2::ZERO,
+        size: Vec2::splat(size),
+        interactable: !input_transparent,
+        input_transparent,
+    };
+    let background = LayerId::background();
+    let hud = LayerId::new(Order::Middle, Id::new("hud"));
+
+    let mut areas = Areas::default();
+    areas.set_state(background, area_state(100.0, false));
+    areas.set_state(hud, area_state(50.0, true));
+    assert_eq!(areas.layer_id_at(pos2(10.0, 10.0), 0.0), Some(background));
+
+    areas.block_input_in(Rect::from_min_size(Pos2::ZERO, Vec2::splat(20.0)));
+    assert_eq!(
+        areas.layer_id_at(pos2(10.0, 10.0), 0.0),
+        Some(Areas::input_blocker_layer())
+    );
+    assert_eq!(areas.layer_id_at(pos2(30.0, 30.0), 0.0), Some(background));
+}
+
 #[cfg(test)]
 #[test]
 fn memory_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Memory>();
 }
+
+#[cfg(test)]
+#[test]
+fn test_nearest_in_direction() {
+    use crate::pos2;
+    let rect_at = |x: f32, y: f32| Rect::from_center_size(pos2(x, y), vec2(10.0, 10.0));
+    let candidates = [
+        (Id::new("left"), rect_at(0.0, 0.0)),
+        (Id::new("middle"), rect_at(50.0, 0.0)),
+        (Id::new("right"), rect_at(100.0, 0.0)),
+        (Id::new("below"), rect_at(60.0, 40.0)),
+    ];
+    let from = rect_at(50.0, 0.0);
+    assert_eq!(
+        nearest_in_direction(&candidates, from, Vec2::RIGHT),
+        Some(Id::new("right"))
+    );
+    assert_eq!(
+        nearest_in_direction(&candidates, from, Vec2::DOWN),
+        Some(Id::new("below"))
+    );
+    assert_eq!(nearest_in_direction(&candidates, from, Vec2::UP), None);
+}
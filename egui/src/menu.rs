@@ -3,17 +3,55 @@
 //! Usage:
 //! ```
 //! fn show_menu(ui: &mut egui::Ui) {
-//!     use egui::{menu, Button};
+//!     use egui::{menu, Button, Key, KeyboardShortcut, Modifiers};
 //!
 //!     menu::bar(ui, |ui| {
-//!         ui.menu_button("File", |ui| {
+//!         // Alt+F opens this menu:
+//!         ui.menu_button("&File", |ui| {
 //!             if ui.button("Open").clicked() {
 //!                 // …
 //!             }
+//!             // Ctrl+S (⌘S on Mac) clicks this button while the menu is open:
+//!             let save = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+//!             if ui.add(Button::new("Save").shortcut(save)).clicked() {
+//!                 ui.close_menu();
+//!             }
 //!         });
 //!     });
 //! }
 //! ```
+//!
+//! The contents of a closed menu are not run, so nothing in there can react to a shortcut.
+//! To have a shortcut work while the menu is closed, handle it outside the menu
+//! and only show it in the menu with [`Button::shortcut_text`]:
+//!
+//! ```
+//! fn show_menu(ui: &mut egui::Ui) {
+//!     use egui::{menu, Button, Key, KeyboardShortcut, Modifiers};
+//!
+//!     let save = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+//!     ui.ctx().register_shortcut(save, "File", "Save");
+//!     let mut do_save = ui.ctx().consume_shortcut(&save);
+//!
+//!     menu::bar(ui, |ui| {
+//!         ui.menu_button("&File", |ui| {
+//!             let shortcut_text = ui.ctx().format_shortcut(&save);
+//!             if ui.add(Button::new("Save").shortcut_text(shortcut_text)).clicked() {
+//!                 do_save = true;
+//!                 ui.close_menu();
+//!             }
+//!         });
+//!     });
+//!
+//!     if do_save {
+//!         // …
+//!     }
+//! }
+//! ```
+//!
+//! In a menu bar, a `&` in a menu title marks the next letter as the mnemonic of the menu:
+//! pressing it together with Alt opens the menu, and it is underlined while Alt is held down.
+//! Use `&&` for a literal `&`.
 
 use super::{
     style::{Spacing, WidgetVisuals},
@@ -33,24 +71,13 @@ pub(crate) struct BarState {
 impl BarState {
     fn load(ctx: &Context, bar_id: Id) -> Self {
         ctx.memory()
-            .data
+            .data_temp
             .get_temp::<Self>(bar_id)
             .unwrap_or_default()
     }
 
     fn store(self, ctx: &Context, bar_id: Id) {
-        ctx.memory().data.insert_temp(bar_id, self);
-    }
-
-    /// Show a menu at pointer if primary-clicked response.
-    /// Should be called from [`Context`] on a [`Response`]
-    pub fn bar_menu<R>(
-        &mut self,
-        response: &Response,
-        add_contents: impl FnOnce(&mut Ui) -> R,
-    ) -> Option<InnerResponse<R>> {
-        MenuRoot::stationary_click_interaction(response, &mut self.open_menu, response.id);
-        self.open_menu.show(response, add_contents)
+        ctx.memory().data_temp.insert_temp(bar_id, self);
     }
 }
 impl std::ops::Deref for BarState {
@@ -164,9 +191,20 @@ fn stationary_menu_impl<'c, R>(
     title: impl Into<WidgetText>,
     add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
 ) -> InnerResponse<Option<R>> {
-    let title = title.into();
+    let mut title = title.into();
+    let mut mnemonic = None;
+    if let WidgetText::RichText(rich_text) = &mut title {
+        let (text, mnemonic_index) = parse_mnemonic(rich_text.text());
+        mnemonic = mnemonic_index.and_then(|index| {
+            let key = key_from_char(text[index..].chars().next()?)?;
+            Some((index, key))
+        });
+        *rich_text.text_mut() = text;
+    }
+
+    let title_text = title.text().to_owned();
     let bar_id = ui.id();
-    let menu_id = bar_id.with(title.text());
+    let menu_id = bar_id.with(&title_text);
 
     let mut bar_state = BarState::load(ui.ctx(), bar_id);
 
@@ -178,12 +216,138 @@ fn stationary_menu_impl<'c, R>(
     }
 
     let button_response = ui.add(button);
-    let inner = bar_state.bar_menu(&button_response, add_contents);
+
+    if let Some((index, key)) = mnemonic {
+        if ui.input().modifiers.alt {
+            paint_mnemonic_underline(ui, &button_response, &title_text, index);
+        }
+        if ui
+            .ctx()
+            .consume_shortcut(&KeyboardShortcut::new(Modifiers::ALT, key))
+        {
+            if bar_state.open_menu.is_menu_open(button_response.id) {
+                bar_state.open_menu.inner = None;
+            } else {
+                let pos = button_response.rect.left_bottom();
+                bar_state.open_menu.inner = Some(MenuRoot::new(pos, button_response.id));
+            }
+        }
+    }
+
+    MenuRoot::stationary_click_interaction(
+        &button_response,
+        &mut bar_state.open_menu,
+        button_response.id,
+    );
+    let inner = if bar_state.open_menu.is_menu_open(button_response.id) {
+        bar_state.open_menu.show(&button_response, add_contents)
+    } else {
+        None
+    };
 
     bar_state.store(ui.ctx(), bar_id);
     InnerResponse::new(inner.map(|r| r.inner), button_response)
 }
 
+/// Remove the `&` marking the mnemonic from a menu title,
+/// and return the byte index of the mnemonic in the resulting text (if any).
+///
+/// `&&` is a literal `&`, as is a `&` that isn't followed by a letter or digit.
+fn parse_mnemonic(title: &str) -> (String, Option<usize>) {
+    let mut text = String::with_capacity(title.len());
+    let mut mnemonic = None;
+    let mut chars = title.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.peek() {
+                Some('&') => {
+                    chars.next();
+                }
+                Some(next) if next.is_alphanumeric() && mnemonic.is_none() => {
+                    mnemonic = Some(text.len());
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        text.push(c);
+    }
+    (text, mnemonic)
+}
+
+fn key_from_char(c: char) -> Option<Key> {
+    let key = match c.to_ascii_uppercase() {
+        'A' => Key::A,
+        'B' => Key::B,
+        'C' => Key::C,
+        'D' => Key::D,
+        'E' => Key::E,
+        'F' => Key::F,
+        'G' => Key::G,
+        'H' => Key::H,
+        'I' => Key::I,
+        'J' => Key::J,
+        'K' => Key::K,
+        'L' => Key::L,
+        'M' => Key::M,
+        'N' => Key::N,
+        'O' => Key::O,
+        'P' => Key::P,
+        'Q' => Key::Q,
+        'R' => Key::R,
+        'S' => Key::S,
+        'T' => Key::T,
+        'U' => Key::U,
+        'V' => Key::V,
+        'W' => Key::W,
+        'X' => Key::X,
+        'Y' => Key::Y,
+        'Z' => Key::Z,
+        '0' => Key::Num0,
+        '1' => Key::Num1,
+        '2' => Key::Num2,
+        '3' => Key::Num3,
+        '4' => Key::Num4,
+        '5' => Key::Num5,
+        '6' => Key::Num6,
+        '7' => Key::Num7,
+        '8' => Key::Num8,
+        '9' => Key::Num9,
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// Underline the character at byte `index` of the title of a menu bar button.
+fn paint_mnemonic_underline(ui: &Ui, button_response: &Response, title: &str, index: usize) {
+    let color = ui.style().interact(button_response).text_color();
+    let layout = |text: &str| {
+        ui.fonts()
+            .layout_no_wrap(text.to_owned(), TextStyle::Button, color)
+    };
+    let mnemonic_len = title[index..].chars().next().map_or(0, char::len_utf8);
+    let full = layout(title);
+    let before = layout(&title[..index]);
+    let mnemonic = layout(&title[index..index + mnemonic_len]);
+
+    // Same placement as in `Button`:
+    let text_pos = ui
+        .layout()
+        .align_size_within_rect(
+            full.size(),
+            button_response.rect.shrink2(ui.spacing().button_padding),
+        )
+        .min;
+    let left = text_pos.x + before.size().x;
+    let y = ui
+        .painter()
+        .round_to_pixel(text_pos.y + full.size().y - 1.0);
+    ui.painter().line_segment(
+        [pos2(left, y), pos2(left + mnemonic.size().x, y)],
+        Stroke::new(1.0, color),
+    );
+}
+
 /// Stores the state for the context menu.
 #[derive(Default)]
 pub(crate) struct ContextMenuSystem {
@@ -293,9 +457,7 @@ impl MenuRoot {
         id: Id,
     ) -> MenuResponse {
         let pointer = &response.ctx.input().pointer;
-        if (response.clicked() && root.is_menu_open(id))
-            || response.ctx.input().key_pressed(Key::Escape)
-        {
+        if (response.clicked() && root.is_menu_open(id)) || response.ctx.input().cancel_pressed() {
             // menu open and button clicked or esc pressed
             return MenuResponse::Close;
         } else if (response.clicked() && !root.is_menu_open(id))
@@ -329,6 +491,11 @@ impl MenuRoot {
         id: Id,
     ) -> MenuResponse {
         let response = response.interact(Sense::click());
+        if response.long_touched() && response.ctx.memory().options.long_press_opens_context_menu {
+            if let Some(pos) = response.ctx.input().pointer.interact_pos() {
+                return MenuResponse::Create(pos, id);
+            }
+        }
         let pointer = &response.ctx.input().pointer;
         if pointer.any_pressed() {
             if let Some(pos) = pointer.interact_pos() {
@@ -486,10 +653,14 @@ impl SubMenu {
         self.parent_state
             .write()
             .submenu_button_interaction(ui, sub_id, &button);
-        let inner = self
-            .parent_state
-            .write()
-            .show_submenu(ui.ctx(), sub_id, add_contents);
+        let is_open = self.parent_state.read().is_open(sub_id);
+        let inner = if is_open {
+            self.parent_state
+                .write()
+                .show_submenu(ui.ctx(), sub_id, add_contents)
+        } else {
+            None
+        };
         InnerResponse::new(inner, button)
     }
 }
@@ -629,3 +800,59 @@ impl MenuState {
         self.sub_menu = None;
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_parse_mnemonic() {
+    assert_eq!(parse_mnemonic("&File"), ("File".to_owned(), Some(0)));
+    assert_eq!(parse_mnemonic("Sa&ve"), ("Save".to_owned(), Some(2)));
+    assert_eq!(
+        parse_mnemonic("Save && Quit"),
+        ("Save & Quit".to_owned(), None)
+    );
+    assert_eq!(
+        parse_mnemonic("Load & &Go"),
+        ("Load & Go".to_owned(), Some(7))
+    );
+    assert_eq!(parse_mnemonic("Edit"), ("Edit".to_owned(), None));
+}
+
+#[cfg(test)]
+#[test]
+fn test_shortcut_in_menu() {
+    let save = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+    let key = |key, modifiers| Event::Key {
+        key,
+        pressed: true,
+        modifiers,
+    };
+
+    let mut ctx = CtxRef::default();
+    let mut frame = |events: Vec<Event>| {
+        let raw_input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let mut ran = false;
+        let mut clicked = false;
+        let _ = ctx.run(raw_input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                bar(ui, |ui| {
+                    ui.menu_button("&File", |ui| {
+                        ran = true;
+                        clicked |= ui.add(Button::new("Save").shortcut(save)).clicked();
+                    });
+                });
+            });
+        });
+        (ran, clicked)
+    };
+
+    assert_eq!(
+        frame(vec![key(Key::S, Modifiers::COMMAND)]),
+        (false, false),
+        "the contents of a closed menu don't run"
+    );
+    assert_eq!(frame(vec![key(Key::F, Modifiers::ALT)]), (true, false));
+    assert_eq!(frame(vec![key(Key::S, Modifiers::COMMAND)]), (true, true));
+}
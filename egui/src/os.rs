@@ -0,0 +1,86 @@
+//! See [`OperatingSystem`].
+
+/// An `enum` of common operating systems.
+///
+/// egui uses this to show platform-correct keyboard shortcuts (see [`crate::Context::format_shortcut`]).
+/// On native this is known at compile time, but on the web the integration has to tell egui
+/// with [`crate::Context::set_os`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum OperatingSystem {
+    /// Unknown OS - could be wasm.
+    Unknown,
+
+    /// Android OS.
+    Android,
+
+    /// Apple iPhone OS.
+    IOS,
+
+    /// Linux or Unix other than Android.
+    Nix,
+
+    /// macOS.
+    Mac,
+
+    /// Windows.
+    Windows,
+}
+
+impl Default for OperatingSystem {
+    fn default() -> Self {
+        Self::from_target_os()
+    }
+}
+
+impl OperatingSystem {
+    /// The OS egui was compiled for.
+    pub const fn from_target_os() -> Self {
+        if cfg!(target_arch = "wasm32") {
+            Self::Unknown
+        } else if cfg!(target_os = "android") {
+            Self::Android
+        } else if cfg!(target_os = "ios") {
+            Self::IOS
+        } else if cfg!(target_os = "macos") {
+            Self::Mac
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else if cfg!(target_os = "linux")
+            || cfg!(target_os = "dragonfly")
+            || cfg!(target_os = "freebsd")
+            || cfg!(target_os = "netbsd")
+            || cfg!(target_os = "openbsd")
+        {
+            Self::Nix
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Guess the OS from a browser's user agent string.
+    pub fn from_user_agent(user_agent: &str) -> Self {
+        if user_agent.contains("Android") {
+            Self::Android
+        } else if user_agent.contains("like Mac") {
+            // iPhone, iPad and iPod say "like Mac OS X"
+            Self::IOS
+        } else if user_agent.contains("Mac") {
+            Self::Mac
+        } else if user_agent.contains("Win") {
+            Self::Windows
+        } else if user_agent.contains("Linux")
+            || user_agent.contains("X11")
+            || user_agent.contains("BSD")
+        {
+            Self::Nix
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Does this OS use the ⌘ Command key for shortcuts?
+    pub fn is_mac(self) -> bool {
+        matches!(self, Self::Mac | Self::IOS)
+    }
+}
@@ -1,11 +1,11 @@
 use crate::{
-    emath::{Align2, Pos2, Rect, Vec2},
+    emath::{Align, Align2, Pos2, Rect, Vec2},
     layers::{LayerId, PaintList, ShapeIdx},
     Color32, CtxRef,
 };
 use epaint::{
     mutex::Mutex,
-    text::{Fonts, Galley, TextStyle},
+    text::{Fonts, Galley, LayoutJob, TextStyle},
     CircleShape, RectShape, Shape, Stroke, TextShape,
 };
 
@@ -88,6 +88,21 @@ impl Painter {
             fade_to_color: self.fade_to_color,
         }
     }
+
+    /// A painter for the same layer and clip rectangle, whose shapes are painted
+    /// on top of everything else in the layer, no matter when they are added.
+    ///
+    /// Useful for things that overhang their widget, like a [`crate::Badge`],
+    /// which would otherwise be covered by the widgets added after it.
+    pub fn overlay(&self) -> Self {
+        Self {
+            ctx: self.ctx.clone(),
+            layer_id: self.layer_id,
+            paint_list: self.ctx.graphics().overlay_list(self.layer_id).clone(),
+            clip_rect: self.clip_rect,
+            fade_to_color: self.fade_to_color,
+        }
+    }
 }
 
 /// ## Accessors etc
@@ -208,8 +223,10 @@ impl Painter {
         );
     }
 
+    /// Text in [`crate::Visuals::error_fg_color`], e.g. about an id clash.
     pub fn error(&self, pos: Pos2, text: impl std::fmt::Display) -> Rect {
-        self.debug_text(pos, Align2::LEFT_TOP, Color32::RED, format!("🔥 {}", text))
+        let color = self.ctx.style().visuals.error_fg_color;
+        self.debug_text(pos, Align2::LEFT_TOP, color, format!("🔥 {}", text))
     }
 
     /// text with a background
@@ -330,6 +347,7 @@ impl Painter {
     ///
     /// To find out the size of text before painting it, use
     /// [`Self::layout`] or [`Self::layout_no_wrap`].
+    /// To wrap the text, use [`Self::text_wrapped`].
     ///
     /// Returns where the text ended up.
     #[allow(clippy::needless_pass_by_value)]
@@ -347,6 +365,35 @@ impl Painter {
         rect
     }
 
+    /// Lay out and paint some text, wrapping it at `wrap_width`.
+    ///
+    /// The rows of the text are aligned to each other horizontally according to the `anchor`,
+    /// e.g. with `anchor: (Center, Center)` every row is centered on `pos`.
+    ///
+    /// Returns where the text ended up.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn text_wrapped(
+        &self,
+        pos: Pos2,
+        anchor: Align2,
+        text: impl ToString,
+        text_style: TextStyle,
+        text_color: Color32,
+        wrap_width: f32,
+    ) -> Rect {
+        let mut job = LayoutJob::simple(text.to_string(), text_style, text_color, wrap_width);
+        job.halign = anchor.x();
+        let galley = self.fonts().layout_job(job);
+        let rect = anchor.anchor_rect(Rect::from_min_size(pos, galley.size()));
+        let galley_pos = match anchor.x() {
+            Align::Min => rect.left_top(),
+            Align::Center => rect.center_top(),
+            Align::Max => rect.right_top(),
+        };
+        self.galley(galley_pos, galley);
+        rect
+    }
+
     /// Will wrap text at the given width and line break at `\n`.
     ///
     /// Paint the results with [`Self::galley`].
@@ -412,3 +459,42 @@ fn tint_shape_towards(shape: &mut Shape, target: Color32) {
         *color = crate::color::tint_color_towards(*color, target);
     });
 }
+
+#[cfg(test)]
+#[test]
+fn test_text_wrapped() {
+    use epaint::ClippedShape;
+
+    let text = "Some text that is too long for one row";
+    let pos = Pos2::new(200.0, 200.0);
+    let mut ctx = CtxRef::default();
+    let mut rects = vec![];
+    let (_, shapes) = ctx.run(Default::default(), |ctx| {
+        let painter = ctx.debug_painter();
+        let (style, color) = (TextStyle::Body, Color32::WHITE);
+        rects.push(painter.text(pos, Align2::CENTER_CENTER, text, style, color));
+        rects.push(painter.text_wrapped(pos, Align2::CENTER_CENTER, text, style, color, 100.0));
+        rects.push(painter.text_wrapped(pos, Align2::RIGHT_TOP, text, style, color, 100.0));
+    });
+
+    assert!(rects[1].width() <= 100.0);
+    assert!(
+        rects[1].height() > rects[0].height(),
+        "wrapped over several rows"
+    );
+    assert!((rects[1].center() - pos).length() < 0.5);
+    assert!((rects[2].right_top() - pos).length() < 0.5);
+
+    let galleys: Vec<_> = shapes
+        .iter()
+        .filter_map(|ClippedShape(_, shape)| match shape {
+            Shape::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect();
+    let centered = galleys[1];
+    for row in &centered.galley.rows {
+        let row_center = centered.pos.x + row.rect.center().x;
+        assert!((row_center - pos.x).abs() < 0.5, "each row is centered");
+    }
+}
@@ -0,0 +1,161 @@
+//! Plugins let you package cross-cutting features (toasts, tours, analytics, custom shortcuts, …)
+//! so that they run every frame without having to be called from your app code.
+//!
+//! Implement [`ContextPlugin`] and register it with [`CtxRef::add_plugin`].
+
+use crate::{CtxRef, RawInput};
+
+/// A plugin that is called by egui at the start and end of each frame.
+///
+/// All hooks have default implementations that do nothing,
+/// so you only need to implement the ones you are interested in.
+///
+/// ```
+/// struct FrameCounter(u64);
+///
+/// impl egui::ContextPlugin for FrameCounter {
+///     fn on_end_frame(&mut self, _ctx: &egui::CtxRef) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let ctx = egui::CtxRef::default();
+/// ctx.add_plugin(FrameCounter(0));
+/// ```
+pub trait ContextPlugin: 'static + Send + Sync {
+    /// Called with the [`RawInput`] before egui processes it.
+    ///
+    /// You can add, remove or modify events here.
+    fn on_raw_input(&mut self, _raw_input: &mut RawInput) {}
+
+    /// Called at the start of each frame, after the input has been processed.
+    ///
+    /// You can read the input with [`crate::Context::input`],
+    /// and modify [`crate::Context::memory`] and [`crate::Context::output`].
+    /// You can also show windows and areas from here.
+    fn on_begin_frame(&mut self, _ctx: &CtxRef) {}
+
+    /// Called at the end of each frame, after all ui code has run,
+    /// but before the [`crate::Output`] is returned to the integration.
+    fn on_end_frame(&mut self, _ctx: &CtxRef) {}
+}
+
+/// All registered plugins, in the order they were added.
+#[derive(Default)]
+pub(crate) struct Plugins {
+    plugins: Vec<Box<dyn ContextPlugin>>,
+}
+
+impl Plugins {
+    pub fn add(&mut self, plugin: Box<dyn ContextPlugin>) {
+        self.plugins.push(plugin);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    pub fn on_raw_input(&mut self, raw_input: &mut RawInput) {
+        for plugin in &mut self.plugins {
+            plugin.on_raw_input(raw_input);
+        }
+    }
+
+    pub fn on_begin_frame(&mut self, ctx: &CtxRef) {
+        for plugin in &mut self.plugins {
+            plugin.on_begin_frame(ctx);
+        }
+    }
+
+    pub fn on_end_frame(&mut self, ctx: &CtxRef) {
+        for plugin in &mut self.plugins {
+            plugin.on_end_frame(ctx);
+        }
+    }
+
+    /// Put back the plugins that were taken out with [`std::mem::take`],
+    /// keeping any plugins that were added in the meantime.
+    pub fn restore(&mut self, mut taken: Plugins) {
+        taken.plugins.append(&mut self.plugins);
+        *self = taken;
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_plugin_hooks() {
+    use crate::{mutex::Mutex, Event, Key, Modifiers};
+    use std::sync::Arc;
+
+    struct Recorder {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+        add_second: bool,
+    }
+
+    impl ContextPlugin for Recorder {
+        fn on_raw_input(&mut self, raw_input: &mut RawInput) {
+            raw_input.events.push(Event::Key {
+                key: Key::A,
+                pressed: true,
+                modifiers: Modifiers::NONE,
+            });
+            self.log.lock().push(format!("{} raw_input", self.name));
+        }
+
+        fn on_begin_frame(&mut self, ctx: &CtxRef) {
+            let pressed = ctx.input().key_pressed(Key::A);
+            self.log
+                .lock()
+                .push(format!("{} begin_frame {}", self.name, pressed));
+            if std::mem::take(&mut self.add_second) {
+                // Plugins can add other plugins:
+                ctx.add_plugin(Recorder {
+                    name: "second",
+                    log: self.log.clone(),
+                    add_second: false,
+                });
+            }
+        }
+
+        fn on_end_frame(&mut self, _ctx: &CtxRef) {
+            self.log.lock().push(format!("{} end_frame", self.name));
+        }
+    }
+
+    let log = Arc::new(Mutex::new(vec![]));
+    let mut ctx = CtxRef::default();
+    ctx.add_plugin(Recorder {
+        name: "first",
+        log: log.clone(),
+        add_second: true,
+    });
+
+    let _ = ctx.run(Default::default(), |_ctx| log.lock().push("ui".to_owned()));
+    assert_eq!(
+        *log.lock(),
+        [
+            "first raw_input",
+            "first begin_frame true",
+            "ui",
+            "first end_frame",
+            "second end_frame"
+        ],
+        "the raw input can be modified, and a plugin added during a frame runs from then on"
+    );
+
+    log.lock().clear();
+    let _ = ctx.run(Default::default(), |_ctx| log.lock().push("ui".to_owned()));
+    assert_eq!(
+        *log.lock(),
+        [
+            "first raw_input",
+            "second raw_input",
+            "first begin_frame true",
+            "second begin_frame true",
+            "ui",
+            "first end_frame",
+            "second end_frame"
+        ]
+    );
+}
@@ -0,0 +1,202 @@
+//! Measures how long egui spends on the different parts of each frame.
+//!
+//! See [`crate::Context::profiler_ui`].
+
+use crate::{util::History, *};
+
+/// How long egui spent on the different parts of a frame, in seconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameTimings {
+    /// [`CtxRef::begin_frame`].
+    pub begin_frame: f32,
+
+    /// From the end of [`CtxRef::begin_frame`] to the start of [`Context::end_frame`],
+    /// i.e. mostly your own ui code.
+    pub ui: f32,
+
+    /// [`Context::end_frame`], i.e. updating memory, focus, windows etc.
+    pub end_frame: f32,
+
+    /// [`Context::tessellate`]. Zero if it has not been called (yet) for this frame.
+    pub tessellation: f32,
+}
+
+impl FrameTimings {
+    pub fn total(&self) -> f32 {
+        self.begin_frame + self.ui + self.end_frame + self.tessellation
+    }
+}
+
+impl std::iter::Sum for FrameTimings {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |sum, t| Self {
+            begin_frame: sum.begin_frame + t.begin_frame,
+            ui: sum.ui + t.ui,
+            end_frame: sum.end_frame + t.end_frame,
+            tessellation: sum.tessellation + t.tessellation,
+        })
+    }
+}
+
+impl std::ops::Div<f32> for FrameTimings {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Self {
+            begin_frame: self.begin_frame / rhs,
+            ui: self.ui / rhs,
+            end_frame: self.end_frame / rhs,
+            tessellation: self.tessellation / rhs,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Measures elapsed time, where there is a clock.
+pub(crate) struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: std::time::Instant,
+}
+
+impl Stopwatch {
+    pub fn start() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    /// Seconds since [`Self::start`], or `None` on the web.
+    #[allow(clippy::unused_self)]
+    pub fn elapsed(&self) -> Option<f32> {
+        #[cfg(not(target_arch = "wasm32"))]
+        return Some(self.start.elapsed().as_secs_f32());
+        #[cfg(target_arch = "wasm32")]
+        return None;
+    }
+}
+
+/// The timings of recent frames, kept in the [`Context`].
+pub(crate) struct Profiler {
+    history: History<FrameTimings>,
+
+    /// The frame in progress.
+    current: FrameTimings,
+
+    /// Started at the end of `begin_frame`.
+    ui_stopwatch: Option<Stopwatch>,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self {
+            history: History::new(0..300, 60.0),
+            current: Default::default(),
+            ui_stopwatch: None,
+        }
+    }
+}
+
+impl Profiler {
+    pub fn begin_frame(&mut self, begin_frame: &Stopwatch) {
+        self.current = FrameTimings {
+            begin_frame: begin_frame.elapsed().unwrap_or_default(),
+            ..Default::default()
+        };
+        self.ui_stopwatch = Some(Stopwatch::start());
+    }
+
+    /// Call at the start of `end_frame`.
+    pub fn end_ui(&mut self) {
+        if let Some(ui_stopwatch) = self.ui_stopwatch.take() {
+            self.current.ui = ui_stopwatch.elapsed().unwrap_or_default();
+        }
+    }
+
+    /// Call at the end of `end_frame`.
+    pub fn end_frame(&mut self, time: f64, end_frame: &Stopwatch) {
+        if let Some(end_frame) = end_frame.elapsed() {
+            self.current.end_frame = end_frame;
+            let time = time.max(self.history.iter().last().map_or(time, |(t, _)| t));
+            self.history.add(time, self.current);
+        }
+    }
+
+    pub fn tessellated(&mut self, tessellation: &Stopwatch) {
+        if let (Some(latest), Some(tessellation)) =
+            (self.history.latest_mut(), tessellation.elapsed())
+        {
+            latest.tessellation = tessellation;
+        }
+    }
+
+    /// `(time, timings)` of recent frames, oldest first.
+    pub fn frames(&self) -> Vec<(f64, FrameTimings)> {
+        self.history.iter().collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The name of a part of a frame, and how to get its duration.
+type Part = (&'static str, fn(&FrameTimings) -> f32);
+
+/// A graph of recent frame times and a table breaking them down into parts.
+pub(crate) fn profiler_ui(ui: &mut Ui, frames: &[(f64, FrameTimings)]) {
+    let (now, latest) = if let Some(&frame) = frames.last() {
+        frame
+    } else {
+        ui.label("No frame timings recorded. They are not available on the web.");
+        return;
+    };
+
+    let ms = |seconds: f32| 1e3 * seconds as f64;
+    let line = |name: &str, value: fn(&FrameTimings) -> f32| {
+        plot::Line::new(plot::Values::from_values_iter(frames.iter().map(
+            |(time, timings)| plot::Value::new(time - now, ms(value(timings))),
+        )))
+        .name(name)
+    };
+
+    plot::Plot::new("profiler_plot")
+        .height(120.0)
+        .allow_drag(false)
+        .allow_zoom(false)
+        .include_y(0.0)
+        .legend(plot::Legend::default())
+        .show(ui, |plot_ui| {
+            plot_ui.line(line("total", FrameTimings::total));
+            plot_ui.line(line("ui", |t| t.ui));
+            plot_ui.line(line("tessellation", |t| t.tessellation));
+        });
+    ui.label("Milliseconds per frame, over the last few seconds.");
+
+    let num_frames = frames.len() as f32;
+    let average = frames.iter().map(|(_, t)| *t).sum::<FrameTimings>() / num_frames;
+
+    ui.add_space(8.0);
+    Grid::new("profiler_table").striped(true).show(ui, |ui| {
+        ui.strong("Part");
+        ui.strong("Latest");
+        ui.strong("Average");
+        ui.strong("Max");
+        ui.end_row();
+
+        let rows: [Part; 5] = [
+            ("begin_frame", |t| t.begin_frame),
+            ("ui", |t| t.ui),
+            ("end_frame", |t| t.end_frame),
+            ("tessellation", |t| t.tessellation),
+            ("total", FrameTimings::total),
+        ];
+        for (name, value) in rows {
+            let max = frames.iter().map(|(_, t)| value(t)).fold(0.0, f32::max);
+            ui.monospace(name);
+            for seconds in [value(&latest), value(&average), max] {
+                ui.monospace(format!("{:6.2} ms", ms(seconds)));
+            }
+            ui.end_row();
+        }
+    });
+}
@@ -32,10 +32,13 @@ pub struct Response {
     pub sense: Sense,
 
     /// Was the widget enabled?
-    /// If `false`, there was no interaction attempted (not even hover).
+    /// If `false`, there was no interaction attempted (except for [`Self::contains_pointer`]).
     pub(crate) enabled: bool,
 
     // OUT:
+    /// The pointer is above this widget, even if it is disabled.
+    pub(crate) contains_pointer: bool,
+
     /// The pointer is hovering above this widget or the widget was clicked/tapped this frame.
     pub(crate) hovered: bool,
 
@@ -46,6 +49,9 @@ pub struct Response {
     /// The thing was double-clicked.
     pub(crate) double_clicked: [bool; NUM_POINTER_BUTTONS],
 
+    /// The primary button or a finger was held still on the widget for a long time this frame.
+    pub(crate) long_touched: bool,
+
     /// The widgets is being dragged
     pub(crate) dragged: bool,
 
@@ -75,9 +81,11 @@ impl std::fmt::Debug for Response {
             rect,
             sense,
             enabled,
+            contains_pointer,
             hovered,
             clicked,
             double_clicked,
+            long_touched,
             dragged,
             drag_released,
             is_pointer_button_down_on,
@@ -90,9 +98,11 @@ impl std::fmt::Debug for Response {
             .field("rect", rect)
             .field("sense", sense)
             .field("enabled", enabled)
+            .field("contains_pointer", contains_pointer)
             .field("hovered", hovered)
             .field("clicked", clicked)
             .field("double_clicked", double_clicked)
+            .field("long_touched", long_touched)
             .field("dragged", dragged)
             .field("drag_released", drag_released)
             .field("is_pointer_button_down_on", is_pointer_button_down_on)
@@ -137,6 +147,19 @@ impl Response {
         self.double_clicked[PointerButton::Primary as usize]
     }
 
+    /// Returns true if the primary button or a finger was held down on this widget,
+    /// without moving, for long enough that it won't be a click.
+    ///
+    /// This is only `true` on the frame the long press is detected, while the button is still down.
+    /// Since touch screens have no secondary button, this is a good way to show extra options,
+    /// and by default it opens [`Self::context_menu`]
+    /// (see `Memory::options.long_press_opens_context_menu`).
+    ///
+    /// Note that the widget must be sensing clicks or drags.
+    pub fn long_touched(&self) -> bool {
+        self.long_touched
+    }
+
     /// Returns true if this widget was double-clicked this frame by the given button.
     pub fn double_clicked_by(&self, button: PointerButton) -> bool {
         self.double_clicked[button as usize]
@@ -185,6 +208,16 @@ impl Response {
         self.hovered
     }
 
+    /// The pointer is above this widget, and no other area is covering it.
+    ///
+    /// Unlike [`Self::hovered`] this is also `true` for disabled widgets,
+    /// and while the pointer is interacting with another widget.
+    /// Use it to explain why a widget is disabled, e.g. with [`Self::on_disabled_hover_text`].
+    #[inline(always)]
+    pub fn contains_pointer(&self) -> bool {
+        self.contains_pointer
+    }
+
     /// This widget has the keyboard focus (i.e. is receiving key presses).
     pub fn has_focus(&self) -> bool {
         self.ctx.memory().has_focus(self.id)
@@ -251,15 +284,41 @@ impl Response {
         self.drag_released
     }
 
-    /// If dragged, how many points were we dragged and in what direction?
+    /// If dragged, how many points were we dragged this frame and in what direction?
+    ///
+    /// This includes the movement in the frame the drag was released,
+    /// so that the sum of all deltas is the full distance dragged.
     pub fn drag_delta(&self) -> Vec2 {
-        if self.dragged() {
+        if self.dragged() || self.drag_released() {
             self.ctx.input().pointer.delta()
         } else {
             Vec2::ZERO
         }
     }
 
+    /// If dragged, where did the drag start?
+    ///
+    /// This is still set in the frame the drag was released.
+    pub fn drag_origin(&self) -> Option<Pos2> {
+        if self.dragged() || self.drag_released() {
+            self.ctx.input().pointer.press_or_release_origin()
+        } else {
+            None
+        }
+    }
+
+    /// If dragged, how fast is the pointer moving, in points per second?
+    ///
+    /// This is still set in the frame the drag was released,
+    /// so you can use it to keep things moving after the user lets go (a "fling").
+    pub fn drag_velocity(&self) -> Vec2 {
+        if self.dragged() || self.drag_released() {
+            self.ctx.input().pointer.velocity()
+        } else {
+            Vec2::ZERO
+        }
+    }
+
     /// Where the pointer (mouse/touch) were when when this widget was clicked or dragged.
     /// `None` if the widget is not being interacted with.
     pub fn interact_pointer_pos(&self) -> Option<Pos2> {
@@ -283,6 +342,52 @@ impl Response {
         self.is_pointer_button_down_on
     }
 
+    /// The current multi-touch gesture (e.g. a pinch), if it is aimed at this widget.
+    ///
+    /// A gesture belongs to the widget the first finger went down on,
+    /// so this requires the widget to sense clicks or drags.
+    /// Use this instead of [`crate::InputState::multi_touch`] so that only one widget reacts to the gesture.
+    pub fn multi_touch(&self) -> Option<crate::MultiTouchInfo> {
+        if self.is_pointer_button_down_on {
+            self.ctx.input().multi_touch()
+        } else {
+            None
+        }
+    }
+
+    /// How much the user zoomed this widget this frame, with a pinch gesture aimed at it
+    /// (see [`Self::multi_touch`]) or by hovering it and using ctrl-scroll or a trackpad pinch.
+    ///
+    /// * `zoom = 1`: no change
+    /// * `zoom < 1`: pinch together
+    /// * `zoom > 1`: pinch spread
+    pub fn pinch_zoom_delta(&self) -> f32 {
+        let input = self.ctx.input();
+        match input.multi_touch() {
+            Some(touch) if self.is_pointer_button_down_on => touch.zoom_delta,
+            None if self.hovered => input.zoom_delta(),
+            _ => 1.0,
+        }
+    }
+
+    /// Like [`Self::pinch_zoom_delta`], but can be different horizontally and vertically
+    /// (see [`crate::InputState::zoom_delta_2d`]).
+    pub fn pinch_zoom_delta_2d(&self) -> Vec2 {
+        let input = self.ctx.input();
+        match input.multi_touch() {
+            Some(touch) if self.is_pointer_button_down_on => touch.zoom_delta_2d,
+            None if self.hovered => input.zoom_delta_2d(),
+            _ => Vec2::splat(1.0),
+        }
+    }
+
+    /// How much a multi-touch gesture aimed at this widget rotated this frame, in radians.
+    ///
+    /// See [`Self::multi_touch`].
+    pub fn pinch_rotation_delta(&self) -> f32 {
+        self.multi_touch().map_or(0.0, |touch| touch.rotation_delta)
+    }
+
     /// What the underlying data changed?
     ///
     /// e.g. the slider was dragged, text was entered in a `TextEdit` etc.
@@ -316,59 +421,106 @@ impl Response {
     /// For that, use [`Self::on_disabled_hover_ui`] instead.
     ///
     /// If you call this multiple times the tooltips will stack underneath the previous ones.
+    ///
+    /// The tooltip appears after [`crate::style::Interaction::tooltip_delay`].
+    /// Hold down [`crate::style::Interaction::pin_tooltip_modifiers`] while it is showing to pin it,
+    /// so that you can move the pointer into it and interact with any widgets in it.
     #[doc(alias = "tooltip")]
     pub fn on_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
-        if self.should_show_hover_ui() {
-            crate::containers::show_tooltip_for(
+        if !self.enabled {
+            return self;
+        }
+        if let Some(pinned) = self.should_show_hover_ui(self.hovered) {
+            crate::containers::show_tooltip_for_dyn(
                 &self.ctx,
                 self.id.with("__tooltip"),
                 &self.rect,
-                add_contents,
+                pinned,
+                Box::new(add_contents),
             );
+            self.tooltip_shown(pinned);
         }
         self
     }
 
     /// Show this UI when hovering if the widget is disabled.
+    ///
+    /// Useful for explaining why the widget is disabled.
     pub fn on_disabled_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
-        if !self.enabled && self.ctx.rect_contains_pointer(self.layer_id, self.rect) {
-            crate::containers::show_tooltip_for(
+        if self.enabled {
+            return self;
+        }
+        if let Some(pinned) = self.should_show_hover_ui(self.contains_pointer) {
+            crate::containers::show_tooltip_for_dyn(
                 &self.ctx,
                 self.id.with("__tooltip"),
                 &self.rect,
-                add_contents,
+                pinned,
+                Box::new(add_contents),
             );
+            self.tooltip_shown(pinned);
         }
         self
     }
 
     /// Like `on_hover_ui`, but show the ui next to cursor.
     pub fn on_hover_ui_at_pointer(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
-        if self.should_show_hover_ui() {
-            crate::containers::show_tooltip_at_pointer(
+        self.on_hover_ui_at_pointer_placed(Default::default(), add_contents)
+    }
+
+    /// Like [`Self::on_hover_ui_at_pointer`], but you choose on which side of the pointer
+    /// the tooltip goes, and how far from it.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let placement = egui::TooltipPlacement {
+    ///     side: egui::Align2::LEFT_TOP,
+    ///     offset: egui::vec2(8.0, 8.0),
+    /// };
+    /// ui.label("Hover me").on_hover_ui_at_pointer_placed(placement, |ui| {
+    ///     ui.label("Above and to the left of the pointer");
+    /// });
+    /// # });
+    /// ```
+    pub fn on_hover_ui_at_pointer_placed(
+        self,
+        placement: crate::containers::TooltipPlacement,
+        add_contents: impl FnOnce(&mut Ui),
+    ) -> Self {
+        if !self.enabled {
+            return self;
+        }
+        if let Some(pinned) = self.should_show_hover_ui(self.hovered) {
+            crate::containers::show_tooltip_at_pointer_dyn(
                 &self.ctx,
                 self.id.with("__tooltip"),
-                add_contents,
+                placement,
+                pinned,
+                Box::new(add_contents),
             );
+            self.tooltip_shown(pinned);
         }
         self
     }
 
-    fn should_show_hover_ui(&self) -> bool {
+    /// Should the tooltip be shown this frame?
+    ///
+    /// `hovered` is [`Self::hovered`] or, for disabled widgets, [`Self::contains_pointer`].
+    /// Returns `None` if not, otherwise whether it is pinned.
+    fn should_show_hover_ui(&self, hovered: bool) -> Option<bool> {
         if self.ctx.memory().everything_is_visible() {
-            return true;
+            return Some(false);
         }
 
-        if !self.hovered || !self.ctx.input().pointer.has_pointer() {
-            return false;
-        }
+        let mut hovered = hovered && self.ctx.input().pointer.has_pointer();
 
-        if self.ctx.style().interaction.show_tooltips_only_when_still
+        if hovered
+            && self.ctx.style().interaction.show_tooltips_only_when_still
             && !self.ctx.input().pointer.is_still()
         {
             // wait for mouse to stop
             self.ctx.request_repaint();
-            return false;
+            hovered = false;
         }
 
         // We don't want tooltips of things while we are dragging them,
@@ -376,10 +528,24 @@ impl Response {
         if self.ctx.input().pointer.any_down()
             && self.ctx.input().pointer.has_moved_too_much_for_a_click
         {
-            return false;
+            hovered = false;
         }
 
-        true
+        crate::containers::TooltipTiming::update(&self.ctx, self.id, self.rect, hovered)
+    }
+
+    /// Let a pinned tooltip stay open while the pointer is over it.
+    fn tooltip_shown(&self, pinned: bool) {
+        if pinned {
+            let tooltip_rect = self.ctx.frame_state().tooltip_rect.map(|(_, rect, _)| rect);
+            if let Some(tooltip_rect) = tooltip_rect {
+                crate::containers::TooltipTiming::pinned_tooltip_shown(
+                    &self.ctx,
+                    self.id,
+                    tooltip_rect,
+                );
+            }
+        }
     }
 
     /// Show this text if the widget was hovered (i.e. a tooltip).
@@ -410,7 +576,12 @@ impl Response {
         self
     }
 
-    /// Check for more interactions (e.g. sense clicks on a `Response` returned from a label).
+    /// Check for more interactions (e.g. sense clicks on a `Response` returned from a label,
+    /// or drags on the `Response` of a group).
+    ///
+    /// The same id and rectangle are interacted with again, with the union of
+    /// the old and the new [`Sense`]. The interactions already in `self` are kept,
+    /// so you can call this after the widget has been painted.
     ///
     /// Note that this call will not add any hover-effects to the widget, so when possible
     /// it is better to give the widget a `Sense` instead, e.g. using [`crate::Label::sense`].
@@ -420,18 +591,23 @@ impl Response {
     /// let response = ui.label("hello");
     /// assert!(!response.clicked()); // labels don't sense clicks by default
     /// let response = response.interact(egui::Sense::click());
+    /// assert!(response.sense.click && response.sense.focusable);
     /// if response.clicked() { /* … */ }
     /// # });
     /// ```
     pub fn interact(&self, sense: Sense) -> Self {
-        self.ctx.interact_with_hovered(
+        let sense = self.sense.union(sense);
+        if sense == self.sense {
+            return self.clone();
+        }
+        self.union(self.ctx.interact_with_hovered(
             self.layer_id,
             self.id,
             self.rect,
             sense,
             self.enabled,
-            self.hovered,
-        )
+            self.contains_pointer,
+        ))
     }
 
     /// Move the scroll to this UI with the specified alignment.
@@ -448,6 +624,8 @@ impl Response {
     /// });
     /// # });
     /// ```
+    ///
+    /// See also [`Ui::scroll_to_rect`](crate::Ui::scroll_to_rect).
     pub fn scroll_to_me(&self, align: Align) {
         let scroll_target = lerp(self.rect.x_range(), align.to_factor());
         self.ctx.frame_state().scroll_target[0] = Some((scroll_target, align));
@@ -456,11 +634,61 @@ impl Response {
         self.ctx.frame_state().scroll_target[1] = Some((scroll_target, align));
     }
 
-    /// For accessibility.
+    /// Request a snapshot (screenshot) of just this widget.
+    ///
+    /// The integration captures the pixels of this widget after painting this frame,
+    /// and sends them back to egui next frame (see [`crate::Output::snapshot_requests`]).
+    /// This requires support from the integration, e.g. `egui_glow`.
+    ///
+    /// Returns the snapshot once the integration has responded, and `None` until then.
+    /// Only one request is sent, so it is fine to call this every frame.
+    /// To take a new snapshot, e.g. because the widget has changed,
+    /// call [`crate::Memory::forget_snapshot`] first.
+    ///
+    /// Note that anything painted on top of the widget (e.g. a tooltip) will also be captured.
+    pub fn snapshot(&self) -> Option<std::sync::Arc<crate::WidgetSnapshot>> {
+        let rect = self.rect.intersect(self.ctx.input().screen_rect());
+        let mut memory = self.ctx.memory();
+        let snapshot = memory.data_temp.get_temp(self.id);
+        if snapshot.is_none() && rect.is_positive() && memory.pending_snapshots.insert(self.id) {
+            drop(memory);
+            self.ctx
+                .output()
+                .snapshot_requests
+                .push(crate::output::SnapshotRequest { id: self.id, rect });
+            self.ctx.request_repaint(); // so we get the response
+        }
+        snapshot
+    }
+
+    /// For accessibility (including [`crate::Output::accessibility_nodes`]),
+    /// for [`crate::Output::ui_events`] and for [`crate::Context::hovered_widget_kind`].
     ///
     /// Call after interacting and potential calls to [`Self::mark_changed`].
     pub fn widget_info(&self, make_info: impl Fn() -> crate::WidgetInfo) {
         use crate::output::OutputEvent;
+        if self.ctx.memory().options.record_widget_tree {
+            self.ctx
+                .frame_state()
+                .widget_tree
+                .set_info(self.id, make_info());
+        }
+        if self.ctx.memory().options.accessibility_tree {
+            let node = crate::output::AccessibilityNode {
+                id: self.id,
+                parent: Some(self.layer_id.id), // removed at the end of the frame unless it is a window
+                info: make_info(),
+                focused: self.has_focus(),
+                rect: self.rect,
+            };
+            self.ctx.output().accessibility_nodes.push(node);
+        }
+        let is_hovered_widget = self.hovered
+            && matches!(self.ctx.frame_state().hovered_widget, Some((id, _)) if id == self.id);
+        if is_hovered_widget {
+            let typ = make_info().typ;
+            self.ctx.frame_state().hovered_widget = Some((self.id, typ));
+        }
         let event = if self.clicked() {
             Some(OutputEvent::Clicked(make_info()))
         } else if self.double_clicked() {
@@ -473,6 +701,15 @@ impl Response {
             None
         };
         if let Some(event) = event {
+            if self.ctx.memory().options.record_ui_events {
+                self.ctx
+                    .output()
+                    .ui_events
+                    .push(crate::output::UiEvent::Widget {
+                        id: self.id,
+                        event: event.clone(),
+                    });
+            }
             self.ctx.output().events.push(event);
         }
     }
@@ -515,17 +752,23 @@ impl Response {
             rect: self.rect.union(other.rect),
             sense: self.sense.union(other.sense),
             enabled: self.enabled || other.enabled,
+            contains_pointer: self.contains_pointer || other.contains_pointer,
             hovered: self.hovered || other.hovered,
             clicked: [
                 self.clicked[0] || other.clicked[0],
                 self.clicked[1] || other.clicked[1],
                 self.clicked[2] || other.clicked[2],
+                self.clicked[3] || other.clicked[3],
+                self.clicked[4] || other.clicked[4],
             ],
             double_clicked: [
                 self.double_clicked[0] || other.double_clicked[0],
                 self.double_clicked[1] || other.double_clicked[1],
                 self.double_clicked[2] || other.double_clicked[2],
+                self.double_clicked[3] || other.double_clicked[3],
+                self.double_clicked[4] || other.double_clicked[4],
             ],
+            long_touched: self.long_touched || other.long_touched,
             dragged: self.dragged || other.dragged,
             drag_released: self.drag_released || other.drag_released,
             is_pointer_button_down_on: self.is_pointer_button_down_on
@@ -597,3 +840,237 @@ impl<R> InnerResponse<R> {
         Self { inner, response }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_drag_motion() {
+    use crate::{pos2, vec2, Event, Modifiers, PointerButton, RawInput};
+
+    let mut ctx = CtxRef::default();
+    let mut frame = |time: f64, events: Vec<Event>| {
+        let input = RawInput {
+            time: Some(time),
+            events,
+            ..Default::default()
+        };
+        let mut motion = None;
+        let _ = ctx.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+                let response = ui.allocate_rect(rect, Sense::drag());
+                motion = Some((
+                    response.drag_delta(),
+                    response.drag_origin(),
+                    response.drag_released(),
+                ));
+            });
+        });
+        motion.unwrap()
+    };
+    let button = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+
+    frame(0.0, vec![Event::PointerMoved(pos2(10.0, 10.0))]);
+    frame(0.1, vec![button(pos2(10.0, 10.0), true)]);
+    let (delta, origin, _) = frame(0.2, vec![Event::PointerMoved(pos2(30.0, 10.0))]);
+    assert_eq!(delta, vec2(20.0, 0.0));
+    assert_eq!(origin, Some(pos2(10.0, 10.0)));
+
+    let (delta, origin, released) = frame(0.3, vec![button(pos2(40.0, 10.0), false)]);
+    assert!(released);
+    assert_eq!(delta, vec2(10.0, 0.0));
+    assert_eq!(origin, Some(pos2(10.0, 10.0)));
+
+    let (delta, origin, _) = frame(0.4, vec![Event::PointerMoved(pos2(50.0, 10.0))]);
+    assert_eq!(delta, Vec2::ZERO);
+    assert_eq!(origin, None);
+}
+
+#[cfg(test)]
+#[test]
+fn test_tooltip_delay_and_pin() {
+    use crate::{pos2, vec2, Event, Modifiers, RawInput};
+
+    let mut ctx = CtxRef::default();
+    let mut style = (*ctx.style()).clone();
+    style.interaction.show_tooltips_only_when_still = false;
+    style.interaction.tooltip_delay = 0.5;
+    style.interaction.tooltip_hide_delay = 0.3;
+    ctx.set_style(style);
+
+    let mut frame = |time: f64, pointer: Pos2, modifiers: Modifiers| {
+        let input = RawInput {
+            time: Some(time),
+            events: vec![Event::PointerMoved(pointer)],
+            modifiers,
+            ..Default::default()
+        };
+        let mut shown = false;
+        let _ = ctx.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+                ui.allocate_rect(rect, Sense::hover())
+                    .on_hover_ui(|_| shown = true);
+            });
+        });
+        shown
+    };
+    let inside = pos2(50.0, 50.0);
+    let outside = pos2(500.0, 500.0);
+
+    assert!(!frame(0.0, inside, Modifiers::NONE));
+    assert!(!frame(0.2, inside, Modifiers::NONE));
+    assert!(frame(0.6, inside, Modifiers::NONE));
+    assert!(frame(0.7, outside, Modifiers::NONE), "hide delay");
+    assert!(!frame(1.1, outside, Modifiers::NONE));
+
+    assert!(!frame(1.2, inside, Modifiers::NONE));
+    assert!(frame(1.8, inside, Modifiers::ALT));
+    assert!(frame(1.9, outside, Modifiers::ALT), "pinned");
+    assert!(!frame(2.5, outside, Modifiers::NONE));
+}
+
+#[cfg(test)]
+#[test]
+fn test_disabled_hover() {
+    use crate::{pos2, vec2, Event, Modifiers, PointerButton, RawInput};
+
+    let mut ctx = CtxRef::default();
+    let mut frame = |events: Vec<Event>| {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let mut result = None;
+        let _ = ctx.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                ui.set_enabled(false);
+                let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+                let mut enabled_tooltip = false;
+                let mut disabled_tooltip = false;
+                let response = ui
+                    .allocate_rect(rect, Sense::click())
+                    .on_hover_ui(|_| enabled_tooltip = true)
+                    .on_disabled_hover_ui(|_| disabled_tooltip = true);
+                result = Some((
+                    response.hovered(),
+                    response.contains_pointer(),
+                    response.clicked(),
+                    enabled_tooltip,
+                    disabled_tooltip,
+                ));
+            });
+        });
+        result.unwrap()
+    };
+    let pos = pos2(50.0, 50.0);
+    let button = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+
+    assert_eq!(
+        frame(vec![Event::PointerMoved(pos)]),
+        (false, true, false, false, true)
+    );
+    frame(vec![button(true)]);
+    let (_, _, clicked, _, _) = frame(vec![button(false)]);
+    assert!(!clicked);
+}
+
+#[cfg(test)]
+#[test]
+fn test_interact_upgrades_sense() {
+    use crate::{pos2, vec2, Event, Modifiers, PointerButton, RawInput};
+
+    let mut ctx = CtxRef::default();
+    let mut frame = |events: Vec<Event>| {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let mut result = None;
+        let _ = ctx.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+                let response = ui.allocate_rect(rect, Sense::hover());
+                let response = response.interact(Sense::click()).interact(Sense::drag());
+                result = Some(response);
+            });
+        });
+        result.unwrap()
+    };
+    let pos = pos2(50.0, 50.0);
+    let button = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+
+    let response = frame(vec![Event::PointerMoved(pos)]);
+    assert_eq!(response.sense, Sense::click_and_drag());
+    assert!(response.hovered());
+    frame(vec![button(pos, true)]);
+    assert!(frame(vec![button(pos, false)]).clicked());
+
+    frame(vec![button(pos, true)]);
+    let response = frame(vec![Event::PointerMoved(pos + vec2(20.0, 0.0))]);
+    assert!(response.dragged());
+    assert_eq!(response.drag_delta(), vec2(20.0, 0.0));
+}
+
+#[cfg(test)]
+#[test]
+fn test_snapshot_is_requested_once() {
+    use crate::{pos2, vec2, Color32, Event, RawInput, WidgetSnapshot};
+    use std::sync::Arc;
+
+    let mut ctx = CtxRef::default();
+    let id = Id::new("snapshot");
+    let frame = |ctx: &mut CtxRef, events: Vec<Event>| {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let mut snapshot = None;
+        let (output, _) = ctx.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(10.0, 10.0));
+                snapshot = ui.interact(rect, id, Sense::hover()).snapshot();
+            });
+        });
+        (output.snapshot_requests.len(), snapshot)
+    };
+
+    let (requests, snapshot) = frame(&mut ctx, vec![]);
+    assert_eq!(requests, 1);
+    assert!(snapshot.is_none());
+    let (requests, snapshot) = frame(&mut ctx, vec![]);
+    assert_eq!(requests, 0, "the request is only sent once");
+    assert!(snapshot.is_none());
+
+    let snapshot = WidgetSnapshot {
+        size: [1, 1],
+        pixels_per_point: 1.0,
+        pixels: vec![Color32::RED],
+    };
+    let events = vec![Event::Snapshot {
+        id,
+        snapshot: Arc::new(snapshot),
+    }];
+    let (requests, snapshot) = frame(&mut ctx, events);
+    assert_eq!(requests, 0);
+    assert_eq!(snapshot.unwrap().pixels, vec![Color32::RED]);
+
+    ctx.memory().forget_snapshot(id);
+    let (requests, snapshot) = frame(&mut ctx, vec![]);
+    assert_eq!(requests, 1);
+    assert!(snapshot.is_none());
+}
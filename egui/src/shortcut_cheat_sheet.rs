@@ -0,0 +1,189 @@
+//! An overlay listing all keyboard shortcuts of the app, see [`ShortcutCheatSheet`].
+
+use crate::*;
+
+/// A [`ContextPlugin`] that shows all registered keyboard shortcuts, grouped by category,
+/// while a key is held down (F1 by default).
+///
+/// The list is built from the shortcuts registered with [`Context::register_shortcut`]
+/// (and [`Button::shortcut`]) in the same frame, so it is always up to date with what the app actually handles.
+///
+/// ```
+/// let ctx = egui::CtxRef::default();
+/// ctx.add_plugin(egui::ShortcutCheatSheet::default());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShortcutCheatSheet {
+    shortcut: KeyboardShortcut,
+    hold: bool,
+    open: bool,
+}
+
+impl Default for ShortcutCheatSheet {
+    fn default() -> Self {
+        Self::new(KeyboardShortcut::new(Modifiers::NONE, Key::F1))
+    }
+}
+
+impl ShortcutCheatSheet {
+    /// Show the cheat sheet while the given shortcut is held down.
+    pub fn new(shortcut: KeyboardShortcut) -> Self {
+        Self {
+            shortcut,
+            hold: true,
+            open: false,
+        }
+    }
+
+    /// If `false`, pressing the shortcut toggles the cheat sheet instead, and Escape closes it.
+    /// Default: `true`, the cheat sheet is shown while the shortcut is held down.
+    pub fn hold(mut self, hold: bool) -> Self {
+        self.hold = hold;
+        self
+    }
+
+    /// Is the cheat sheet currently shown?
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Show the given shortcuts grouped by category, in the order the categories were first registered.
+    ///
+    /// Use this if you want to show the shortcuts somewhere else, e.g. in a help window:
+    /// `ShortcutCheatSheet::shortcuts_ui(ui, &ui.ctx().registered_shortcuts())`.
+    /// Note that the shortcuts registered after this is called are only listed in the next frame.
+    pub fn shortcuts_ui(ui: &mut Ui, shortcuts: &[ShortcutInfo]) {
+        let mut categories: Vec<&str> = vec![];
+        for info in shortcuts {
+            if !categories.contains(&info.category.as_str()) {
+                categories.push(&info.category);
+            }
+        }
+
+        if categories.is_empty() {
+            ui.label(RichText::new("No keyboard shortcuts").weak());
+            return;
+        }
+
+        ui.horizontal_top(|ui| {
+            for category in categories {
+                ui.vertical(|ui| {
+                    ui.strong(category);
+                    Grid::new(("shortcut_cheat_sheet", category))
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            for info in shortcuts.iter().filter(|info| info.category == category) {
+                                ui.add(Kbd::shortcut(info.shortcut));
+                                ui.label(&info.description);
+                                ui.end_row();
+                            }
+                        });
+                });
+                ui.add_space(ui.spacing().item_spacing.x);
+            }
+        });
+    }
+}
+
+impl ContextPlugin for ShortcutCheatSheet {
+    fn on_begin_frame(&mut self, ctx: &CtxRef) {
+        let Self {
+            shortcut,
+            hold,
+            open,
+        } = self;
+
+        let pressed = ctx.consume_shortcut(shortcut);
+        if *hold {
+            *open = ctx.input().key_down(shortcut.key)
+                && ctx.input().modifiers.matches(shortcut.modifiers);
+        } else if pressed {
+            *open = !*open;
+        } else if ctx.input().key_pressed(Key::Escape) {
+            *open = false;
+        }
+    }
+
+    fn on_end_frame(&mut self, ctx: &CtxRef) {
+        if !self.open {
+            return;
+        }
+
+        let shortcuts = ctx.registered_shortcuts();
+        Area::new("shortcut_cheat_sheet")
+            .order(Order::Foreground)
+            .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+            .interactable(false)
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.heading("Keyboard shortcuts");
+                    ui.separator();
+                    Self::shortcuts_ui(ui, &shortcuts);
+                });
+            });
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_shortcut_cheat_sheet() {
+    use crate::{Event, RawInput};
+
+    let open = KeyboardShortcut::new(Modifiers::COMMAND, Key::O);
+    let save = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+    let zoom = KeyboardShortcut::new(Modifiers::COMMAND, Key::PlusEquals);
+    let mut ctx = CtxRef::default();
+    ctx.add_plugin(ShortcutCheatSheet::default());
+
+    let mut frame = |events: Vec<Event>| {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let (_, shapes) = ctx.run(input, |ctx| {
+            TopBottomPanel::top("menu_bar").show(ctx, |ui| {
+                menu::bar(ui, |ui| {
+                    ui.menu_button("File", |ui| {
+                        let _ = ui.add(Button::new("Open").shortcut(open));
+                    });
+                });
+            });
+            ctx.register_shortcut(save, "File", "Save");
+            ctx.register_shortcut(zoom, "View", "Zoom in");
+            ctx.register_shortcut(zoom, "View", "Zoom in");
+        });
+        let texts: Vec<String> = shapes
+            .iter()
+            .filter_map(|clipped| match &clipped.1 {
+                Shape::Text(text) => Some(text.galley.text().to_owned()),
+                _ => None,
+            })
+            .collect();
+        (ctx.registered_shortcuts(), texts)
+    };
+    let f1 = |pressed| Event::Key {
+        key: Key::F1,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+
+    let (shortcuts, texts) = frame(vec![]);
+    assert_eq!(shortcuts.len(), 2, "registering twice lists it once");
+    assert!(!texts.iter().any(|text| text == "Keyboard shortcuts"));
+
+    let (shortcuts, texts) = frame(vec![f1(true)]);
+    assert_eq!(shortcuts.len(), 2, "the closed menu doesn't run");
+    assert_eq!(shortcuts[0].description, "Save");
+    assert_eq!(shortcuts[1].category, "View");
+    assert!(texts.iter().any(|text| text == "Keyboard shortcuts"));
+    assert!(texts.iter().any(|text| text == "Zoom in"));
+
+    let (_, texts) = frame(vec![]);
+    assert!(
+        texts.iter().any(|text| text == "Keyboard shortcuts"),
+        "still open while F1 is held"
+    );
+
+    let (_, texts) = frame(vec![f1(false)]);
+    assert!(!texts.iter().any(|text| text == "Keyboard shortcuts"));
+}
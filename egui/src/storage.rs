@@ -0,0 +1,134 @@
+//! Where egui persists its state (window positions, persisted widget state etc) between runs of an app.
+//!
+//! Implement [`Storage`] and give it to [`crate::Memory::set_storage`].
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::mutex::Mutex;
+
+/// A key-value store, e.g. backed by files, browser local storage or a database.
+///
+/// Only [`Self::get_string`] and [`Self::set_string`] need to be implemented.
+/// By default bytes are stored as hexadecimal strings.
+///
+/// ```
+/// #[derive(Default)]
+/// struct MyStorage(std::collections::HashMap<String, String>);
+///
+/// impl egui::Storage for MyStorage {
+///     fn get_string(&self, key: &str) -> Option<String> {
+///         self.0.get(key).cloned()
+///     }
+///     fn set_string(&mut self, key: &str, value: String) {
+///         self.0.insert(key.to_owned(), value);
+///     }
+/// }
+///
+/// let ctx = egui::CtxRef::default();
+/// ctx.memory().set_storage(MyStorage::default());
+/// ```
+pub trait Storage: 'static + Send + Sync {
+    /// Get the value for the given key.
+    fn get_string(&self, key: &str) -> Option<String>;
+
+    /// Set the value for the given key.
+    fn set_string(&mut self, key: &str, value: String);
+
+    /// Get the bytes for the given key.
+    ///
+    /// Returns `None` if the value is missing, or was not stored with [`Self::set_bytes`].
+    fn get_bytes(&self, key: &str) -> Option<Vec<u8>> {
+        from_hex(&self.get_string(key)?)
+    }
+
+    /// Set the bytes for the given key.
+    fn set_bytes(&mut self, key: &str, value: &[u8]) {
+        self.set_string(key, to_hex(value));
+    }
+
+    /// Write to disk or similar. Called after saving.
+    fn flush(&mut self) {}
+}
+
+/// Keeps everything in memory, i.e. nothing survives a restart.
+///
+/// Useful for tests.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InMemoryStorage(pub BTreeMap<String, String>);
+
+impl Storage for InMemoryStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        self.0.get(key).cloned()
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        self.0.insert(key.to_owned(), value);
+    }
+}
+
+/// A [`Storage`] shared by all clones of a [`crate::Memory`].
+#[derive(Clone)]
+#[cfg_attr(not(feature = "persistence"), allow(dead_code))]
+pub(crate) struct SharedStorage(pub Arc<Mutex<Box<dyn Storage>>>);
+
+impl std::fmt::Debug for SharedStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SharedStorage")
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+#[test]
+fn test_bytes() {
+    let mut storage = InMemoryStorage::default();
+    storage.set_bytes("key", &[0, 1, 127, 128, 255]);
+    assert_eq!(storage.get_bytes("key"), Some(vec![0, 1, 127, 128, 255]));
+    storage.set_string("key", "not hex".to_owned());
+    assert_eq!(storage.get_bytes("key"), None);
+}
+
+#[cfg(all(test, feature = "persistence"))]
+#[test]
+fn test_save_and_restore_memory() {
+    #[derive(Clone)]
+    struct Shared(Arc<Mutex<InMemoryStorage>>);
+
+    impl Storage for Shared {
+        fn get_string(&self, key: &str) -> Option<String> {
+            self.0.lock().get_string(key)
+        }
+        fn set_string(&mut self, key: &str, value: String) {
+            self.0.lock().set_string(key, value);
+        }
+    }
+
+    let storage = Shared(Default::default());
+    let id = crate::Id::new("widget");
+
+    let mut memory = crate::Memory::default();
+    memory.set_storage(storage.clone());
+    memory.data_persisted.insert_persisted(id, 42_i32);
+    memory.data_temp.insert_temp(id, 1337_i32);
+    memory.save();
+    assert!(storage.0.lock().0.contains_key(crate::Memory::STORAGE_KEY));
+
+    let mut restored = crate::Memory::default();
+    restored.set_storage(storage);
+    assert_eq!(restored.data_persisted.get_persisted::<i32>(id), Some(42));
+    assert_eq!(restored.data_temp.get_temp::<i32>(id), None);
+}
@@ -45,6 +45,10 @@ pub struct Style {
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// How animations move towards their end, unless another [`Easing`] is asked for,
+    /// e.g. with [`crate::Context::animate_bool_with_easing`].
+    pub animation_easing: Easing,
+
     /// Options to help debug why egui behaves strangely.
     pub debug: DebugOptions,
 
@@ -162,6 +166,23 @@ pub struct Interaction {
 
     /// If `false`, tooltips will show up anytime you hover anything, even is mouse is still moving
     pub show_tooltips_only_when_still: bool,
+
+    /// Seconds to hover a widget before its tooltip is shown.
+    ///
+    /// There is no delay when moving from one widget with a tooltip to another,
+    /// while the first tooltip is still showing.
+    pub tooltip_delay: f32,
+
+    /// Seconds a tooltip stays open after the pointer has left its widget.
+    pub tooltip_hide_delay: f32,
+
+    /// Hold these modifier keys while a tooltip is showing to pin it,
+    /// so that you can move the pointer into it and interact with its contents.
+    ///
+    /// The tooltip is unpinned when the pointer leaves both the widget and the tooltip
+    /// (and the modifier keys are released).
+    /// `None` turns off pinning.
+    pub pin_tooltip_modifiers: Option<crate::Modifiers>,
 }
 
 /// Controls the visual style (colors etc) of egui.
@@ -199,11 +220,31 @@ pub struct Visuals {
     /// Visual styles of widgets
     pub widgets: Widgets,
 
+    /// Selected text and selected widgets.
+    /// Together with the link and text colors below, this is one of the semantic colors.
     pub selection: Selection,
 
+    /// The look of the scroll bars of [`crate::ScrollArea`]:s.
+    pub scroll_bar: ScrollBarVisuals,
+
     /// The color used for `Hyperlink`,
     pub hyperlink_color: Color32,
 
+    /// The color of a [`crate::Hyperlink`] to a url that has been visited, see [`crate::Memory::is_url_visited`].
+    pub hyperlink_visited_color: Color32,
+
+    /// Text color for things that went well, e.g. "Saved!".
+    pub success_fg_color: Color32,
+
+    /// Text color for warnings, e.g. about unsaved changes.
+    pub warn_fg_color: Color32,
+
+    /// Text color for errors, e.g. a failed validation or an id clash.
+    pub error_fg_color: Color32,
+
+    /// Text color for neutral notices and hints.
+    pub info_fg_color: Color32,
+
     /// Something just barely different from the background color.
     /// Used for [`crate::Grid::striped`].
     pub faint_bg_color: Color32,
@@ -227,6 +268,9 @@ pub struct Visuals {
     /// show where the text cursor would be if you clicked
     pub text_cursor_preview: bool,
 
+    /// Blink the text cursor of a focused [`crate::TextEdit`] after it has been still for a moment.
+    pub text_cursor_blink: bool,
+
     /// Allow child widgets to be just on the border and still have a stroke with some thickness
     pub clip_rect_margin: f32,
 
@@ -235,6 +279,19 @@ pub struct Visuals {
 
     /// Show a background behind collapsing headers.
     pub collapsing_header_frame: bool,
+
+    /// The vertical line to the left of indented regions, e.g. the body of a [`crate::CollapsingHeader`].
+    /// Use [`Stroke::none`] to hide the guides.
+    ///
+    /// The distance between the guides is [`Spacing::indent`].
+    pub indent_guide_stroke: Stroke,
+
+    /// The guide of the innermost indented region under the mouse pointer.
+    /// Use the same as [`Self::indent_guide_stroke`] to not highlight it.
+    pub indent_guide_hover_stroke: Stroke,
+
+    /// The outline painted on top of the widget with keyboard focus (e.g. after pressing tab).
+    pub focus_ring: Stroke,
 }
 
 impl Visuals {
@@ -277,6 +334,25 @@ pub struct Selection {
     pub stroke: Stroke,
 }
 
+/// The look of the scroll bars of a [`crate::ScrollArea`].
+///
+/// The width of the scroll bars is [`Spacing::scroll_bar_width`],
+/// and the color of the handle comes from [`Widgets`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ScrollBarVisuals {
+    /// Rounding of the track and the handle.
+    pub corner_radius: f32,
+
+    /// Background color of the track the handle moves in.
+    pub track_fill: Color32,
+
+    /// If set, the scroll bar is drawn this thin until the pointer is over it,
+    /// and then expands to the full [`Spacing::scroll_bar_width`].
+    pub dormant_width: Option<f32>,
+}
+
 /// The visuals of widgets for different states of interaction.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -293,6 +369,10 @@ pub struct Widgets {
     pub hovered: WidgetVisuals,
     /// The style of an interactive widget as you are clicking or dragging it.
     pub active: WidgetVisuals,
+    /// The style of an interactive widget that has keyboard focus (e.g. after pressing tab).
+    ///
+    /// See also [`Visuals::focus_ring`].
+    pub focused: WidgetVisuals,
     /// The style of a button that has an open menu beneath it (e.g. a combo-box)
     pub open: WidgetVisuals,
 }
@@ -301,8 +381,10 @@ impl Widgets {
     pub fn style(&self, response: &Response) -> &WidgetVisuals {
         if !response.sense.interactive() {
             &self.noninteractive
-        } else if response.is_pointer_button_down_on() || response.has_focus() {
+        } else if response.is_pointer_button_down_on() {
             &self.active
+        } else if response.has_focus() {
+            &self.focused
         } else if response.hovered() {
             &self.hovered
         } else {
@@ -365,6 +447,7 @@ impl Default for Style {
             interaction: Interaction::default(),
             visuals: Visuals::default(),
             animation_time: 1.0 / 12.0,
+            animation_easing: Easing::CubicOut,
             debug: Default::default(),
             explanation_tooltips: false,
         }
@@ -397,6 +480,9 @@ impl Default for Interaction {
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
             show_tooltips_only_when_still: false,
+            tooltip_delay: 0.0,
+            tooltip_hide_delay: 0.0,
+            pin_tooltip_modifiers: Some(crate::Modifiers::ALT),
         }
     }
 }
@@ -409,7 +495,13 @@ impl Visuals {
             override_text_color: None,
             widgets: Widgets::default(),
             selection: Selection::default(),
+            scroll_bar: ScrollBarVisuals::default(),
             hyperlink_color: Color32::from_rgb(90, 170, 255),
+            hyperlink_visited_color: Color32::from_rgb(180, 140, 255),
+            success_fg_color: Color32::from_rgb(100, 200, 100),
+            warn_fg_color: Color32::from_rgb(255, 143, 0),
+            error_fg_color: Color32::from_rgb(255, 0, 0),
+            info_fg_color: Color32::from_rgb(140, 180, 230),
             faint_bg_color: Color32::from_gray(24),
             extreme_bg_color: Color32::from_gray(10),
             code_bg_color: Color32::from_gray(64),
@@ -419,9 +511,13 @@ impl Visuals {
             resize_corner_size: 12.0,
             text_cursor_width: 2.0,
             text_cursor_preview: false,
+            text_cursor_blink: true,
             clip_rect_margin: 3.0, // should be at least half the size of the widest frame stroke + max WidgetVisuals::expansion
             button_frame: true,
             collapsing_header_frame: false,
+            indent_guide_stroke: Stroke::new(1.0, Color32::from_gray(60)),
+            indent_guide_hover_stroke: Stroke::new(1.0, Color32::from_gray(100)),
+            focus_ring: Stroke::new(2.0, Color32::from_rgb(90, 170, 255)),
         }
     }
 
@@ -431,15 +527,104 @@ impl Visuals {
             dark_mode: false,
             widgets: Widgets::light(),
             selection: Selection::light(),
+            scroll_bar: ScrollBarVisuals::light(),
             hyperlink_color: Color32::from_rgb(0, 155, 255),
+            hyperlink_visited_color: Color32::from_rgb(120, 70, 200),
+            success_fg_color: Color32::from_rgb(0, 140, 0),
+            warn_fg_color: Color32::from_rgb(190, 100, 0),
+            error_fg_color: Color32::from_rgb(200, 0, 0),
+            info_fg_color: Color32::from_rgb(30, 90, 170),
             faint_bg_color: Color32::from_gray(240),
             extreme_bg_color: Color32::from_gray(250),
             code_bg_color: Color32::from_gray(200),
+            indent_guide_stroke: Stroke::new(1.0, Color32::from_gray(190)),
+            indent_guide_hover_stroke: Stroke::new(1.0, Color32::from_gray(140)),
+            focus_ring: Stroke::new(2.0, Color32::from_rgb(0, 110, 230)),
             window_shadow: Shadow::big_light(),
             popup_shadow: Shadow::small_light(),
             ..Self::dark()
         }
     }
+
+    /// A dark theme with maximum contrast, thicker strokes and a strong focus ring.
+    ///
+    /// See [`crate::Context::set_high_contrast`].
+    pub fn high_contrast() -> Self {
+        Self {
+            widgets: Widgets::high_contrast(),
+            selection: Selection {
+                bg_fill: Color32::from_rgb(0, 70, 170),
+                stroke: Stroke::new(2.0, Color32::WHITE),
+            },
+            hyperlink_color: Color32::from_rgb(120, 200, 255),
+            hyperlink_visited_color: Color32::from_rgb(210, 170, 255),
+            success_fg_color: Color32::from_rgb(120, 255, 120),
+            warn_fg_color: Color32::from_rgb(255, 190, 0),
+            error_fg_color: Color32::from_rgb(255, 90, 90),
+            info_fg_color: Color32::from_rgb(160, 210, 255),
+            faint_bg_color: Color32::from_gray(30),
+            extreme_bg_color: Color32::BLACK,
+            code_bg_color: Color32::from_gray(50),
+            indent_guide_stroke: Stroke::new(1.5, Color32::from_gray(200)),
+            indent_guide_hover_stroke: Stroke::new(1.5, Color32::WHITE),
+            focus_ring: Stroke::new(3.0, Color32::YELLOW),
+            text_cursor_width: 3.0,
+            ..Self::dark()
+        }
+    }
+}
+
+/// Dark or light mode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Theme {
+    Dark,
+    Light,
+}
+
+impl Theme {
+    /// The default [`Visuals`] for this theme.
+    pub fn default_visuals(self) -> Visuals {
+        match self {
+            Self::Dark => Visuals::dark(),
+            Self::Light => Visuals::light(),
+        }
+    }
+}
+
+/// Which [`Theme`] to use. See [`crate::Context::set_theme_preference`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ThemePreference {
+    /// Use the theme of the operating system (see [`crate::RawInput::system_theme`]).
+    FollowSystem,
+    Dark,
+    Light,
+}
+
+impl Default for ThemePreference {
+    fn default() -> Self {
+        Self::Dark
+    }
+}
+
+impl ThemePreference {
+    /// The theme to use, given the theme of the operating system (if known).
+    pub fn theme(self, system_theme: Option<Theme>) -> Option<Theme> {
+        match self {
+            Self::FollowSystem => system_theme,
+            Self::Dark => Some(Theme::Dark),
+            Self::Light => Some(Theme::Light),
+        }
+    }
+
+    pub fn radio_buttons(&mut self, ui: &mut crate::Ui) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(self, Self::FollowSystem, "💻 System");
+            ui.selectable_value(self, Self::Dark, "🌙 Dark");
+            ui.selectable_value(self, Self::Light, "☀ Light");
+        });
+    }
 }
 
 impl Default for Visuals {
@@ -469,6 +654,28 @@ impl Default for Selection {
     }
 }
 
+impl ScrollBarVisuals {
+    fn dark() -> Self {
+        Self {
+            corner_radius: 2.0,
+            track_fill: Color32::from_gray(10),
+            dormant_width: None,
+        }
+    }
+    fn light() -> Self {
+        Self {
+            track_fill: Color32::from_gray(250),
+            ..Self::dark()
+        }
+    }
+}
+
+impl Default for ScrollBarVisuals {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
 impl Widgets {
     pub fn dark() -> Self {
         Self {
@@ -500,6 +707,13 @@ impl Widgets {
                 corner_radius: 2.0,
                 expansion: 1.0,
             },
+            focused: WidgetVisuals {
+                bg_fill: Color32::from_gray(70),
+                bg_stroke: Stroke::new(1.0, Color32::from_gray(150)),
+                fg_stroke: Stroke::new(1.5, Color32::from_gray(240)),
+                corner_radius: 3.0,
+                expansion: 1.0,
+            },
             open: WidgetVisuals {
                 bg_fill: Color32::from_gray(27),
                 bg_stroke: Stroke::new(1.0, Color32::from_gray(60)),
@@ -540,6 +754,13 @@ impl Widgets {
                 corner_radius: 2.0,
                 expansion: 1.0,
             },
+            focused: WidgetVisuals {
+                bg_fill: Color32::from_gray(210),
+                bg_stroke: Stroke::new(1.0, Color32::from_gray(105)),
+                fg_stroke: Stroke::new(1.5, Color32::BLACK),
+                corner_radius: 3.0,
+                expansion: 1.0,
+            },
             open: WidgetVisuals {
                 bg_fill: Color32::from_gray(220),
                 bg_stroke: Stroke::new(1.0, Color32::from_gray(160)),
@@ -551,6 +772,55 @@ impl Widgets {
     }
 }
 
+impl Widgets {
+    pub fn high_contrast() -> Self {
+        Self {
+            noninteractive: WidgetVisuals {
+                bg_fill: Color32::BLACK,                              // window background
+                bg_stroke: Stroke::new(1.5, Color32::from_gray(200)), // separators, indentation lines, windows outlines
+                fg_stroke: Stroke::new(1.5, Color32::WHITE),          // normal text color
+                corner_radius: 2.0,
+                expansion: 0.0,
+            },
+            inactive: WidgetVisuals {
+                bg_fill: Color32::from_gray(40), // button background
+                bg_stroke: Stroke::new(1.5, Color32::from_gray(220)),
+                fg_stroke: Stroke::new(1.5, Color32::WHITE), // button text
+                corner_radius: 2.0,
+                expansion: 0.0,
+            },
+            hovered: WidgetVisuals {
+                bg_fill: Color32::from_gray(70),
+                bg_stroke: Stroke::new(2.0, Color32::WHITE),
+                fg_stroke: Stroke::new(2.0, Color32::WHITE),
+                corner_radius: 3.0,
+                expansion: 1.0,
+            },
+            active: WidgetVisuals {
+                bg_fill: Color32::from_gray(90),
+                bg_stroke: Stroke::new(2.5, Color32::WHITE),
+                fg_stroke: Stroke::new(2.5, Color32::WHITE),
+                corner_radius: 2.0,
+                expansion: 1.0,
+            },
+            focused: WidgetVisuals {
+                bg_fill: Color32::from_gray(70),
+                bg_stroke: Stroke::new(2.0, Color32::WHITE),
+                fg_stroke: Stroke::new(2.0, Color32::WHITE),
+                corner_radius: 3.0,
+                expansion: 1.0,
+            },
+            open: WidgetVisuals {
+                bg_fill: Color32::BLACK,
+                bg_stroke: Stroke::new(1.5, Color32::from_gray(200)),
+                fg_stroke: Stroke::new(1.5, Color32::WHITE),
+                corner_radius: 2.0,
+                expansion: 0.0,
+            },
+        }
+    }
+}
+
 impl Default for Widgets {
     fn default() -> Self {
         Self::dark()
@@ -571,6 +841,7 @@ impl Style {
             interaction,
             visuals,
             animation_time,
+            animation_easing,
             debug,
             explanation_tooltips,
         } = self;
@@ -609,6 +880,16 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Animation easing:");
+            crate::ComboBox::from_id_source("Animation easing")
+                .selected_text(format!("{:?}", animation_easing))
+                .show_ui(ui, |ui| {
+                    for easing in Easing::ALL {
+                        ui.selectable_value(animation_easing, easing, format!("{:?}", easing));
+                    }
+                });
+            ui.end_row();
         });
 
         ui.collapsing("📏 Spacing", |ui| spacing.ui(ui));
@@ -704,6 +985,9 @@ impl Interaction {
             resize_grab_radius_side,
             resize_grab_radius_corner,
             show_tooltips_only_when_still,
+            tooltip_delay,
+            tooltip_hide_delay,
+            pin_tooltip_modifiers,
         } = self;
         ui.add(Slider::new(resize_grab_radius_side, 0.0..=20.0).text("resize_grab_radius_side"));
         ui.add(
@@ -713,6 +997,14 @@ impl Interaction {
             show_tooltips_only_when_still,
             "Only show tooltips if mouse is still",
         );
+        ui.add(Slider::new(tooltip_delay, 0.0..=2.0).text("tooltip_delay (s)"));
+        ui.add(Slider::new(tooltip_hide_delay, 0.0..=2.0).text("tooltip_hide_delay (s)"));
+        let mut pin_with_alt = pin_tooltip_modifiers.is_some();
+        ui.checkbox(&mut pin_with_alt, "Pin tooltips with Alt")
+            .on_hover_text("Hold Alt while a tooltip is showing to interact with it");
+        if pin_with_alt != pin_tooltip_modifiers.is_some() {
+            *pin_tooltip_modifiers = pin_with_alt.then(|| crate::Modifiers::ALT);
+        }
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -722,6 +1014,7 @@ impl Widgets {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         let Self {
             active,
+            focused,
             hovered,
             inactive,
             noninteractive,
@@ -746,6 +1039,10 @@ impl Widgets {
             ui.label("The style of an interactive widget as you are clicking or dragging it.");
             active.ui(ui);
         });
+        ui.collapsing("Interactive and focused", |ui| {
+            ui.label("The style of an interactive widget that has keyboard focus.");
+            focused.ui(ui);
+        });
         ui.collapsing("Open menu", |ui| {
             ui.label("The style of an open combo-box or menu button");
             open.ui(ui);
@@ -764,6 +1061,29 @@ impl Selection {
     }
 }
 
+impl ScrollBarVisuals {
+    pub fn ui(&mut self, ui: &mut crate::Ui) {
+        let Self {
+            corner_radius,
+            track_fill,
+            dormant_width,
+        } = self;
+        ui.add(Slider::new(corner_radius, 0.0..=10.0).text("corner_radius"));
+        ui_color(ui, track_fill, "track_fill");
+        ui.horizontal(|ui| {
+            let mut thin = dormant_width.is_some();
+            ui.checkbox(&mut thin, "Thin until hovered");
+            match (thin, dormant_width.as_mut()) {
+                (true, Some(width)) => {
+                    ui.add(Slider::new(width, 0.0..=16.0).text("dormant_width"));
+                }
+                (true, None) => *dormant_width = Some(2.0),
+                (false, _) => *dormant_width = None,
+            }
+        });
+    }
+}
+
 impl WidgetVisuals {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         let Self {
@@ -821,7 +1141,13 @@ impl Visuals {
             override_text_color: _,
             widgets,
             selection,
+            scroll_bar,
             hyperlink_color,
+            hyperlink_visited_color,
+            success_fg_color,
+            warn_fg_color,
+            error_fg_color,
+            info_fg_color,
             faint_bg_color,
             extreme_bg_color,
             code_bg_color,
@@ -831,9 +1157,13 @@ impl Visuals {
             resize_corner_size,
             text_cursor_width,
             text_cursor_preview,
+            text_cursor_blink,
             clip_rect_margin,
             button_frame,
             collapsing_header_frame,
+            indent_guide_stroke,
+            indent_guide_hover_stroke,
+            focus_ring,
         } = self;
 
         ui.collapsing("Background Colors", |ui| {
@@ -856,7 +1186,9 @@ impl Visuals {
         });
 
         ui.collapsing("Widgets", |ui| widgets.ui(ui));
+        stroke_ui(ui, focus_ring, "Focus ring");
         ui.collapsing("Selection", |ui| selection.ui(ui));
+        ui.collapsing("Scroll bars", |ui| scroll_bar.ui(ui));
 
         ui_color(
             ui,
@@ -872,10 +1204,24 @@ impl Visuals {
             });
         });
 
-        ui_color(ui, hyperlink_color, "hyperlink_color");
+        ui.collapsing("Indent guides", |ui| {
+            stroke_ui(ui, indent_guide_stroke, "Guides");
+            stroke_ui(ui, indent_guide_hover_stroke, "Hovered guide");
+        });
+
+        ui.collapsing("Semantic colors", |ui| {
+            ui_color(ui, hyperlink_color, "Links");
+            ui_color(ui, hyperlink_visited_color, "Visited links");
+            ui_color(ui, success_fg_color, "Success");
+            ui_color(ui, warn_fg_color, "Warnings");
+            ui_color(ui, error_fg_color, "Errors");
+            ui_color(ui, info_fg_color, "Info");
+        });
+
         ui.add(Slider::new(resize_corner_size, 0.0..=20.0).text("resize_corner_size"));
         ui.add(Slider::new(text_cursor_width, 0.0..=4.0).text("text_cursor_width"));
         ui.checkbox(text_cursor_preview, "Preview text cursor on hover");
+        ui.checkbox(text_cursor_blink, "Blink text cursor");
         ui.add(Slider::new(clip_rect_margin, 0.0..=20.0).text("clip_rect_margin"));
 
         ui.checkbox(button_frame, "Button has a frame");
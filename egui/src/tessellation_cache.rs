@@ -0,0 +1,146 @@
+//! Reuses the tessellation of layers that look the same as last frame.
+//!
+//! See `Memory::options.cache_tessellation`.
+
+use std::sync::Arc;
+
+use crate::{
+    epaint::{ahash::AHashMap, ClippedMesh, ClippedShape, Mesh},
+    LayerId,
+};
+
+/// The tessellated meshes of each layer from the last frame.
+///
+/// A layer is re-tessellated when its shapes, the [`crate::epaint::TessellationOptions`]
+/// or the font texture have changed.
+/// Layers that were not painted last frame are forgotten.
+///
+/// Only layers that were unchanged for a frame keep their meshes,
+/// so that layers that change every frame don't cost any memory.
+#[derive(Default)]
+pub(crate) struct TessellationCache {
+    /// The layers of last frame.
+    layers: AHashMap<LayerId, CachedLayer>,
+
+    /// The layers seen since [`Self::begin`].
+    seen: AHashMap<LayerId, CachedLayer>,
+
+    /// Hash of everything besides the shapes that affects the tessellation.
+    frame_key: u64,
+}
+
+struct CachedLayer {
+    /// Hash of the shapes and the frame key.
+    hash: u64,
+
+    /// `None` if the layer had changed since the frame before.
+    meshes: Option<Arc<[ClippedMesh]>>,
+}
+
+impl TessellationCache {
+    /// Call before tessellating the layers of a frame.
+    ///
+    /// `frame_key` is a hash of everything that affects the tessellation of all layers,
+    /// e.g. the tessellation options (including `pixels_per_point`) and the font texture.
+    pub fn begin(&mut self, frame_key: u64) {
+        self.frame_key = frame_key;
+        self.seen.clear();
+    }
+
+    /// What to pass to [`Self::tessellate`] for the given shapes of a layer.
+    pub fn hash(&self, shapes: &[ClippedShape]) -> u64 {
+        crate::util::hash((self.frame_key, shapes))
+    }
+
+    /// Returns a copy of the cached meshes if the shapes (see [`Self::hash`]) are unchanged since last frame,
+    /// otherwise calls `tessellate`.
+    ///
+    /// The copies and `tessellate` take their buffers from `recycled_meshes`.
+    /// The second return value is `true` for a cache hit.
+    pub fn tessellate(
+        &mut self,
+        layer_id: LayerId,
+        hash: u64,
+        recycled_meshes: &mut Vec<Mesh>,
+        tessellate: impl FnOnce(&mut Vec<Mesh>) -> Vec<ClippedMesh>,
+    ) -> (Vec<ClippedMesh>, bool) {
+        let previous = self
+            .layers
+            .remove(&layer_id)
+            .filter(|layer| layer.hash == hash);
+        let (result, meshes) = match previous {
+            Some(CachedLayer {
+                meshes: Some(meshes),
+                ..
+            }) => {
+                let copies = meshes
+                    .iter()
+                    .map(|clipped_mesh| copy_mesh(clipped_mesh, recycled_meshes))
+                    .collect();
+                ((copies, true), Some(meshes))
+            }
+            Some(CachedLayer { meshes: None, .. }) => {
+                // Unchanged for a frame, so it is likely to stay that way:
+                let meshes = tessellate(recycled_meshes);
+                let cached = Arc::from(meshes.as_slice());
+                ((meshes, false), Some(cached))
+            }
+            None => ((tessellate(recycled_meshes), false), None),
+        };
+        self.seen.insert(layer_id, CachedLayer { hash, meshes });
+        result
+    }
+
+    /// Call after tessellating the layers of a frame. Forgets the layers that were not painted.
+    pub fn end(&mut self) {
+        std::mem::swap(&mut self.layers, &mut self.seen);
+        self.seen.clear();
+    }
+}
+
+/// Copy a cached mesh into one of the `recycled_meshes`, so that its buffers are reused.
+fn copy_mesh(
+    ClippedMesh(clip_rect, mesh): &ClippedMesh,
+    recycled_meshes: &mut Vec<Mesh>,
+) -> ClippedMesh {
+    let mut copy = recycled_meshes.pop().unwrap_or_default();
+    copy.clear();
+    copy.indices.extend_from_slice(&mesh.indices);
+    copy.vertices.extend_from_slice(&mesh.vertices);
+    copy.texture_id = mesh.texture_id;
+    ClippedMesh(*clip_rect, copy)
+}
+
+#[cfg(test)]
+#[test]
+fn test_tessellation_cache() {
+    let mut ctx = crate::CtxRef::default();
+    ctx.memory().options.cache_tessellation = true;
+    let run = |ctx: &mut crate::CtxRef, label: &str| {
+        let (_, shapes) = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                ui.label(label);
+            });
+        });
+        let meshes = ctx.tessellate(shapes);
+        let cached = ctx.layer_paint_stats().iter().all(|layer| layer.cached);
+        (meshes, cached)
+    };
+
+    let (first, cached) = run(&mut ctx, "Hello");
+    assert!(!cached);
+    let (_, cached) = run(&mut ctx, "Hello");
+    assert!(
+        !cached,
+        "only layers that stayed the same for a frame are cached"
+    );
+    let (second, cached) = run(&mut ctx, "Hello");
+    assert!(cached);
+    assert_eq!(first.len(), second.len());
+    assert!(first
+        .iter()
+        .zip(&second)
+        .all(|(a, b)| a.0 == b.0 && a.1 == b.1));
+    let (_, cached) = run(&mut ctx, "World");
+    assert!(!cached);
+}
@@ -0,0 +1,366 @@
+//! Run egui without a backend, for unit testing widgets and app logic.
+//!
+//! A [`Harness`] feeds synthetic [`RawInput`] to a [`CtxRef`] one frame at a time,
+//! and finds widgets using the accessibility tree (see [`crate::output::AccessibilityNode`]).
+//!
+//! ```
+//! # #[cfg(feature = "testing")] {
+//! use egui::test::Harness;
+//!
+//! let mut harness = Harness::with_state(0, |ctx, clicks| {
+//!     egui::CentralPanel::default().show(ctx, |ui| {
+//!         if ui.button("Save").clicked() {
+//!             *clicks += 1;
+//!         }
+//!         ui.label(format!("Saved {} times", clicks));
+//!     });
+//! });
+//!
+//! harness.click_button("Save");
+//! assert_eq!(*harness.state(), 1);
+//! harness.find_label("Saved 1 times");
+//! # }
+//! ```
+//!
+//! Only available with the `testing` feature.
+
+use crate::{
+    epaint::{software_rasterizer::ColorImage, ClippedShape},
+    output::AccessibilityNode,
+    CtxRef, Event, InputRecording, Key, Modifiers, Output, PointerButton, Pos2, RawInput, Rect,
+    Vec2, WidgetType,
+};
+
+type App<'a, State> = Box<dyn FnMut(&CtxRef, &mut State) + 'a>;
+
+/// Runs an egui app frame by frame with synthetic input.
+///
+/// The app is a closure that is given the [`CtxRef`] and some `State` each frame.
+/// The state is owned by the harness so that you can inspect it between frames.
+///
+/// The harness runs a first frame when it is created,
+/// so that there is something to click on.
+pub struct Harness<'a, State = ()> {
+    ctx: CtxRef,
+    app: App<'a, State>,
+    state: State,
+    screen_size: Vec2,
+    /// Time of the last frame.
+    time: f64,
+    /// Events to send next frame.
+    events: Vec<Event>,
+    output: Output,
+    shapes: Vec<ClippedShape>,
+}
+
+impl<'a> Harness<'a, ()> {
+    /// Run an app that has no state of its own (or that keeps its state in egui memory).
+    pub fn new(mut app: impl FnMut(&CtxRef) + 'a) -> Self {
+        Self::with_state((), move |ctx, _| app(ctx))
+    }
+}
+
+impl<'a, State> Harness<'a, State> {
+    /// Run an app with some state that you can inspect with [`Self::state`].
+    pub fn with_state(state: State, app: impl FnMut(&CtxRef, &mut State) + 'a) -> Self {
+        let ctx = CtxRef::default();
+        ctx.memory().options.accessibility_tree = true;
+        let mut harness = Self {
+            ctx,
+            app: Box::new(app),
+            state,
+            screen_size: Vec2::new(800.0, 600.0),
+            time: 0.0,
+            events: Default::default(),
+            output: Default::default(),
+            shapes: Default::default(),
+        };
+        harness.run();
+        harness
+    }
+
+    /// Change the size of the screen (in points). Default: 800x600.
+    ///
+    /// Takes effect on the next frame.
+    pub fn set_screen_size(&mut self, screen_size: Vec2) {
+        self.screen_size = screen_size;
+    }
+
+    pub fn ctx(&self) -> &CtxRef {
+        &self.ctx
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut State {
+        &mut self.state
+    }
+
+    /// The output of the last frame.
+    pub fn output(&self) -> &Output {
+        &self.output
+    }
+
+    /// What was painted last frame.
+    pub fn shapes(&self) -> &[ClippedShape] {
+        &self.shapes
+    }
+
+    /// Send this event next frame.
+    pub fn push_event(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    /// Run one frame with all events pushed since the last frame.
+    ///
+    /// Every frame is exactly 1/60th of a second long.
+    pub fn run(&mut self) {
+        let dt = 1.0 / 60.0;
+        let raw_input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, self.screen_size)),
+            time: Some(self.time + dt as f64),
+            predicted_dt: dt,
+            events: std::mem::take(&mut self.events),
+            ..Default::default()
+        };
+        self.run_raw(raw_input);
+    }
+
+    /// Run one frame per recorded frame of input, e.g. to reproduce a bug report.
+    ///
+    /// Best done on a fresh harness, since the recorded times are used as is.
+    pub fn replay(&mut self, recording: InputRecording) {
+        for raw_input in crate::InputReplayer::new(recording) {
+            self.run_raw(raw_input);
+        }
+    }
+
+    fn run_raw(&mut self, raw_input: RawInput) {
+        if let Some(time) = raw_input.time {
+            self.time = time;
+        }
+        let Self {
+            ctx, app, state, ..
+        } = self;
+        let (output, shapes) = ctx.run(raw_input, |ctx| app(ctx, state));
+        self.output = output;
+        self.shapes = shapes;
+    }
+
+    /// Run frames until egui no longer asks for a repaint (e.g. animations have finished),
+    /// but at most `max_frames`.
+    pub fn run_until_idle(&mut self, max_frames: usize) {
+        for _ in 0..max_frames {
+            self.run();
+            if !self.output.needs_repaint {
+                break;
+            }
+        }
+    }
+
+    /// Paint the last frame on the CPU, e.g. for comparing against a golden image with
+    /// [`ColorImage::assert_matches_golden`].
+    ///
+    /// The background is transparent wherever egui painted nothing.
+    pub fn render(&self) -> ColorImage {
+        let pixels_per_point = self.ctx.pixels_per_point();
+        let size_in_pixels = (self.screen_size * pixels_per_point).round();
+        let clipped_meshes = self.ctx.tessellate(self.shapes.clone());
+        crate::epaint::software_rasterizer::rasterize(
+            &clipped_meshes,
+            &self.ctx.font_image(),
+            pixels_per_point,
+            [size_in_pixels.x as usize, size_in_pixels.y as usize],
+            crate::Color32::TRANSPARENT,
+        )
+    }
+
+    // ------------------------------------------------------------------------
+
+    /// All widgets shown last frame.
+    pub fn nodes(&self) -> &[AccessibilityNode] {
+        &self.output.accessibility_nodes
+    }
+
+    /// The first widget shown last frame that matches the predicate.
+    pub fn find(
+        &self,
+        predicate: impl Fn(&AccessibilityNode) -> bool,
+    ) -> Option<&AccessibilityNode> {
+        self.nodes().iter().find(|node| predicate(node))
+    }
+
+    /// The first widget of the given type shown last frame.
+    ///
+    /// Panics if there is none.
+    pub fn find_type(&self, typ: WidgetType) -> &AccessibilityNode {
+        self.find(|node| node.info.typ == typ).unwrap_or_else(|| {
+            panic!(
+                "Found no {:?}. Widgets: {:#?}",
+                typ,
+                self.nodes().iter().map(|n| &n.info).collect::<Vec<_>>()
+            )
+        })
+    }
+
+    /// The first widget (of any type) with this exact label shown last frame.
+    ///
+    /// Panics if there is none.
+    pub fn find_label(&self, label: &str) -> &AccessibilityNode {
+        self.find(|node| node.info.label.as_deref() == Some(label))
+            .unwrap_or_else(|| {
+                panic!(
+                    "Found no widget labeled {:?}. Labels: {:?}",
+                    label,
+                    self.labels()
+                )
+            })
+    }
+
+    /// The labels of all widgets shown last frame.
+    pub fn labels(&self) -> Vec<&str> {
+        self.nodes()
+            .iter()
+            .filter_map(|node| node.info.label.as_deref())
+            .collect()
+    }
+
+    /// The widget with keyboard focus, if any.
+    pub fn focused(&self) -> Option<&AccessibilityNode> {
+        self.find(|node| node.focused)
+    }
+
+    // ------------------------------------------------------------------------
+
+    /// Click the primary mouse button at the given position.
+    ///
+    /// This runs two frames (press and release), and then one more
+    /// so that the app has a chance to show the result of the click.
+    pub fn click_at(&mut self, pos: Pos2) {
+        self.push_event(Event::PointerMoved(pos));
+        self.push_event(pointer_button(pos, true));
+        self.run();
+        self.push_event(pointer_button(pos, false));
+        self.run();
+        self.run();
+    }
+
+    /// Click the center of the first widget with this label.
+    ///
+    /// Panics if there is no such widget.
+    pub fn click(&mut self, label: &str) {
+        let pos = self.find_label(label).rect.center();
+        self.click_at(pos);
+    }
+
+    /// Click the first button with this label.
+    ///
+    /// Panics if there is no such button.
+    pub fn click_button(&mut self, label: &str) {
+        let button = self
+            .find(|node| {
+                node.info.typ == WidgetType::Button && node.info.label.as_deref() == Some(label)
+            })
+            .unwrap_or_else(|| {
+                panic!(
+                    "Found no button labeled {:?}. Labels: {:?}",
+                    label,
+                    self.labels()
+                )
+            });
+        let pos = button.rect.center();
+        self.click_at(pos);
+    }
+
+    /// Type some text into the focused widget, and run a frame.
+    ///
+    /// Click on a [`crate::TextEdit`] first to focus it.
+    pub fn type_text(&mut self, text: &str) {
+        self.push_event(Event::Text(text.to_owned()));
+        self.run();
+    }
+
+    /// Press and release a key, and run a frame.
+    pub fn press_key(&mut self, key: Key) {
+        self.push_event(Event::Key {
+            key,
+            pressed: true,
+            modifiers: Modifiers::NONE,
+        });
+        self.push_event(Event::Key {
+            key,
+            pressed: false,
+            modifiers: Modifiers::NONE,
+        });
+        self.run();
+    }
+
+    /// Move the mouse pointer away from all widgets, e.g. to close tooltips.
+    pub fn move_pointer_away(&mut self) {
+        self.push_event(Event::PointerGone);
+        self.run();
+    }
+}
+
+fn pointer_button(pos: Pos2, pressed: bool) -> Event {
+    Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_harness() {
+    let mut harness = Harness::with_state((false, String::new()), |ctx, (checked, text)| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            ui.checkbox(checked, "Check me");
+            ui.text_edit_singleline(text);
+            ui.label(format!("You wrote {:?}", text));
+        });
+    });
+
+    harness.click("Check me");
+    assert!(harness.state().0);
+    assert_eq!(harness.find_label("Check me").info.selected, Some(true));
+
+    let text_edit = harness.find_type(WidgetType::TextEdit).rect.center();
+    harness.click_at(text_edit);
+    assert_eq!(
+        harness.focused().map(|node| node.info.typ),
+        Some(WidgetType::TextEdit)
+    );
+    harness.type_text("hello");
+    harness.run();
+    assert_eq!(harness.state().1, "hello");
+    harness.find_label("You wrote \"hello\"");
+
+    harness.press_key(Key::Escape);
+    assert!(harness.focused().is_none());
+}
+
+#[cfg(test)]
+#[test]
+fn test_render() {
+    let harness = Harness::new(|ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            let _ = ui.button("Paint me");
+        });
+    });
+    let image = harness.render();
+    assert_eq!(image.size, [800, 600]);
+    assert_eq!(image.diff(&harness.render(), 0).num_different_pixels, 0);
+
+    // The button text is painted on top of the panel background:
+    let button = harness.find_label("Paint me").rect;
+    let panel_color = image.pixels[599 * 800 + 799];
+    let button_pixels = (button.min.y as usize..button.max.y as usize)
+        .flat_map(|y| (button.min.x as usize..button.max.x as usize).map(move |x| (x, y)));
+    assert!(button_pixels
+        .into_iter()
+        .any(|(x, y)| image.pixels[y * 800 + x] != panel_color));
+}
@@ -0,0 +1,159 @@
+//! Save and load the look of egui as a [`ThemeFile`], so you can ship and hot-swap themes without recompiling.
+//!
+//! A theme is a [`Style`] (including its [`crate::style::Spacing`] and [`crate::Visuals`])
+//! plus optional font settings ([`ThemeFonts`]).
+//! Themes are stored as [RON](https://github.com/ron-rs/ron), like the rest of egui's persisted state,
+//! but since [`ThemeFile`] implements `serde` you can also use any other format, e.g. JSON or TOML.
+//!
+//! All fields are optional: anything left out gets its default value.
+//! The easiest way to start a new theme is to save the current one with [`ThemeFile::from_ctx`] and [`ThemeFile::to_ron`].
+//!
+//! ```
+//! # let mut ctx = egui::CtxRef::default();
+//! let theme = r#"(
+//!     style: (
+//!         spacing: (item_spacing: (x: 12.0, y: 6.0)),
+//!         visuals: (dark_mode: false, hyperlink_color: ((0, 120, 200, 255))),
+//!     ),
+//!     fonts: Some((
+//!         family_and_size: {
+//!             small: (proportional, 12.0),
+//!             body: (proportional, 16.0),
+//!             button: (proportional, 16.0),
+//!             heading: (proportional, 24.0),
+//!             monospace: (monospace, 15.0),
+//!         },
+//!     )),
+//! )"#;
+//! ctx.set_style_from_theme(theme).unwrap();
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::{Context, FontDefinitions, FontFamily, Style, TextStyle};
+
+/// The look of egui: style and fonts.
+///
+/// See the [module-level documentation](crate::theme) for the format.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ThemeFile {
+    /// Spacing, interaction, colors etc.
+    pub style: Style,
+
+    /// Which fonts to use, and how large.
+    ///
+    /// If `None`, the fonts are left unchanged when applying the theme.
+    pub fonts: Option<ThemeFonts>,
+}
+
+impl ThemeFile {
+    /// The current style and fonts of the given context.
+    pub fn from_ctx(ctx: &Context) -> Self {
+        Self {
+            style: (*ctx.style()).clone(),
+            fonts: Some(ThemeFonts::from(&ctx.font_definitions())),
+        }
+    }
+
+    /// Parse a theme from RON.
+    ///
+    /// # Errors
+    /// If the text is not a valid theme.
+    pub fn from_ron(ron: &str) -> Result<Self, ThemeError> {
+        ron::from_str(ron).map_err(|err| ThemeError::Parse(err.to_string()))
+    }
+
+    /// Write the theme as human-readable RON.
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, Default::default()).expect("Failed to serialize theme")
+    }
+}
+
+/// The font settings of a [`ThemeFile`]: [`FontDefinitions`] without the font data.
+///
+/// The fonts referred to by name must already be loaded with [`crate::Context::set_fonts`].
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ThemeFonts {
+    /// See [`FontDefinitions::fonts_for_family`].
+    pub fonts_for_family: BTreeMap<FontFamily, Vec<String>>,
+
+    /// See [`FontDefinitions::family_and_size`].
+    pub family_and_size: BTreeMap<TextStyle, (FontFamily, f32)>,
+}
+
+impl Default for ThemeFonts {
+    fn default() -> Self {
+        Self::from(&FontDefinitions::default())
+    }
+}
+
+impl From<&FontDefinitions> for ThemeFonts {
+    fn from(font_definitions: &FontDefinitions) -> Self {
+        Self {
+            fonts_for_family: font_definitions.fonts_for_family.clone(),
+            family_and_size: font_definitions.family_and_size.clone(),
+        }
+    }
+}
+
+impl ThemeFonts {
+    /// Use these settings in the given font definitions, keeping its font data.
+    ///
+    /// # Errors
+    /// If these settings refer to a font that is not in [`FontDefinitions::font_data`].
+    pub fn apply_to(&self, font_definitions: &mut FontDefinitions) -> Result<(), ThemeError> {
+        for font_name in self.fonts_for_family.values().flatten() {
+            if !font_definitions.font_data.contains_key(font_name) {
+                return Err(ThemeError::UnknownFont(font_name.clone()));
+            }
+        }
+        font_definitions.fonts_for_family = self.fonts_for_family.clone();
+        font_definitions.family_and_size = self.family_and_size.clone();
+        Ok(())
+    }
+}
+
+/// Why a [`ThemeFile`] could not be loaded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ThemeError {
+    /// The theme is not valid RON, or doesn't match the format.
+    Parse(String),
+
+    /// The theme refers to a font that hasn't been loaded.
+    UnknownFont(String),
+}
+
+impl std::fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "Failed to parse theme: {}", err),
+            Self::UnknownFont(name) => write!(f, "Theme refers to unknown font {:?}", name),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+#[cfg(test)]
+#[test]
+fn test_theme_ron_round_trip() {
+    let mut theme = ThemeFile::default();
+    theme.style.spacing.item_spacing = crate::vec2(3.0, 4.0);
+    theme.style.visuals = crate::Visuals::light();
+    assert_eq!(ThemeFile::from_ron(&theme.to_ron()), Ok(theme));
+
+    let theme = ThemeFile::from_ron("(style: (animation_time: 0.5))").unwrap();
+    assert_eq!(theme.style.animation_time, 0.5);
+    assert_eq!(theme.fonts, None);
+
+    let mut fonts = ThemeFonts::default();
+    fonts
+        .fonts_for_family
+        .insert(FontFamily::Monospace, vec!["no such font".to_owned()]);
+    assert_eq!(
+        fonts.apply_to(&mut FontDefinitions::default()),
+        Err(ThemeError::UnknownFont("no such font".to_owned()))
+    );
+}
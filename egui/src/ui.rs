@@ -5,8 +5,17 @@ use std::hash::Hash;
 use std::sync::Arc;
 
 use crate::{
-    color::*, containers::*, epaint::text::Fonts, layout::*, menu::MenuState, mutex::MutexGuard,
-    placer::Placer, widgets::*, *,
+    color::*,
+    containers::*,
+    epaint::text::Fonts,
+    flex::{FlexFrame, FlexItem, FlexState},
+    frame_state::FocusRing,
+    layout::*,
+    menu::MenuState,
+    mutex::MutexGuard,
+    placer::Placer,
+    widgets::*,
+    *,
 };
 
 // ----------------------------------------------------------------------------
@@ -60,6 +69,12 @@ pub struct Ui {
 
     /// Indicates whether this Ui belongs to a Menu.
     menu_state: Option<Arc<RwLock<MenuState>>>,
+
+    /// Set inside [`Self::flex`].
+    flex: Option<FlexFrame>,
+
+    /// Where [`Self::allocate_exact_size_with_baseline`] remembers the baseline of this row.
+    row_baseline_id: Option<Id>,
 }
 
 impl Ui {
@@ -72,6 +87,11 @@ impl Ui {
     /// [`SidePanel`], [`TopBottomPanel`], [`CentralPanel`], [`Window`] or [`Area`].
     pub fn new(ctx: CtxRef, layer_id: LayerId, id: Id, max_rect: Rect, clip_rect: Rect) -> Self {
         let style = ctx.style();
+        if ctx.memory().options.record_widget_tree {
+            ctx.frame_state()
+                .widget_tree
+                .add_ui(id, None, layer_id, max_rect);
+        }
         Ui {
             id,
             next_auto_id_source: id.with("auto").value(),
@@ -80,6 +100,8 @@ impl Ui {
             placer: Placer::new(max_rect, Layout::default()),
             enabled: true,
             menu_state: None,
+            flex: None,
+            row_baseline_id: None,
         }
     }
 
@@ -95,18 +117,30 @@ impl Ui {
         layout: Layout,
         id_source: impl Hash,
     ) -> Self {
+        let id = self.id.with(id_source);
+        self.child_ui_with_id(max_rect, layout, id)
+    }
+
+    /// Create a new `Ui` at a specific region with exactly the given [`Id`],
+    /// instead of one derived from the id of this `Ui`.
+    pub(crate) fn child_ui_with_id(&mut self, max_rect: Rect, layout: Layout, id: Id) -> Self {
         crate::egui_assert!(!max_rect.any_nan());
         let next_auto_id_source = Id::new(self.next_auto_id_source).with("child").value();
         self.next_auto_id_source = self.next_auto_id_source.wrapping_add(1);
         let menu_state = self.get_menu_state();
+        self.record_in_widget_tree(|tree| {
+            tree.add_ui(id, Some(self.id), self.layer_id(), max_rect);
+        });
         Ui {
-            id: self.id.with(id_source),
+            id,
             next_auto_id_source,
             painter: self.painter.clone(),
             style: self.style.clone(),
             placer: Placer::new(max_rect, layout),
             enabled: self.enabled,
             menu_state,
+            flex: None,
+            row_baseline_id: None,
         }
     }
 
@@ -526,6 +560,7 @@ impl Ui {
 /// # `Id` creation
 impl Ui {
     /// Use this to generate widget ids for widgets that have persistent state in `Memory`.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn make_persistent_id<IdSource>(&self, id_source: IdSource) -> Id
     where
         IdSource: Hash + std::fmt::Debug,
@@ -553,6 +588,57 @@ impl Ui {
 impl Ui {
     /// Check for clicks, drags and/or hover on a specific region of this `Ui`.
     pub fn interact(&self, rect: Rect, id: Id, sense: Sense) -> Response {
+        self.interact_with_focus_ring(rect, id, sense, None)
+    }
+
+    /// Like [`Self::interact`], but the widget is only hovered and clicked inside of the given shape,
+    /// e.g. a round button or a diagonal connector.
+    ///
+    /// The [`Response::rect`] is the bounding rectangle of the shape.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (rect, _) = ui.allocate_exact_size(egui::vec2(32.0, 32.0), egui::Sense::hover());
+    /// let knob = egui::HitShape::circle(rect.center(), 16.0);
+    /// let response = ui.interact_with_hit_shape(egui::Id::new("knob"), egui::Sense::drag(), &knob);
+    /// # });
+    /// ```
+    pub fn interact_with_hit_shape(&self, id: Id, sense: Sense, hit_shape: &HitShape) -> Response {
+        self.interact_with_focus_ring(hit_shape.bounding_rect(), id, sense, Some(hit_shape))
+    }
+
+    fn interact_with_focus_ring(
+        &self,
+        rect: Rect,
+        id: Id,
+        sense: Sense,
+        hit_shape: Option<&HitShape>,
+    ) -> Response {
+        let response = self.interact_without_focus_ring(rect, id, sense, hit_shape);
+        if sense.interactive() && response.has_focus() {
+            // Painted at the end of the frame, on top of the widget:
+            let visuals = self.visuals();
+            self.ctx().frame_state().focus_ring = Some(FocusRing {
+                layer_id: self.layer_id(),
+                clip_rect: self.clip_rect(),
+                rect: rect.expand(visuals.widgets.focused.expansion + 1.0),
+                corner_radius: visuals.widgets.focused.corner_radius,
+                stroke: visuals.focus_ring,
+            });
+        }
+        response
+    }
+
+    /// Like [`Self::interact`], for widgets that show that they are focused in their own way,
+    /// like [`TextEdit`].
+    pub(crate) fn interact_without_focus_ring(
+        &self,
+        rect: Rect,
+        id: Id,
+        sense: Sense,
+        hit_shape: Option<&HitShape>,
+    ) -> Response {
+        self.record_in_widget_tree(|tree| tree.add_widget(id, self.id, self.layer_id(), rect));
         self.ctx().interact(
             self.clip_rect(),
             self.spacing().item_spacing,
@@ -561,6 +647,7 @@ impl Ui {
             rect,
             sense,
             self.enabled,
+            hit_shape,
         )
     }
 
@@ -573,6 +660,12 @@ impl Ui {
             .rect_contains_pointer(self.layer_id(), self.clip_rect().intersect(rect))
     }
 
+    fn record_in_widget_tree(&self, record: impl FnOnce(&mut crate::WidgetTree)) {
+        if self.ctx().memory().options.record_widget_tree {
+            record(&mut self.ctx().frame_state().widget_tree);
+        }
+    }
+
     /// Is the pointer (mouse/touch) above this `Ui`?
     /// Equivalent to `ui.rect_contains_pointer(ui.min_rect())`
     pub fn ui_contains_pointer(&self) -> bool {
@@ -620,6 +713,45 @@ impl Ui {
         (rect, response)
     }
 
+    /// Like [`Self::allocate_exact_size`], but for a widget containing text
+    /// whose first baseline is `baseline` points below the top of the widget.
+    ///
+    /// In a [`Layout::with_baseline_align`] layout, the widget is moved vertically
+    /// so that its baseline lines up with that of the other widgets in the row.
+    pub fn allocate_exact_size_with_baseline(
+        &mut self,
+        desired_size: Vec2,
+        baseline: f32,
+        sense: Sense,
+    ) -> (Rect, Response) {
+        if !self.layout().baseline_align() {
+            return self.allocate_exact_size(desired_size, sense);
+        }
+
+        let id = match self.row_baseline_id {
+            Some(id) => id,
+            None => self.auto_id_with("baseline"),
+        };
+        self.row_baseline_id = Some(id);
+        let (row_baseline, moved) = {
+            let mut memory = self.memory();
+            let frame_nr = memory.frame_nr;
+            let row = memory.data_temp.get_temp_mut_or_default::<RowBaseline>(id);
+            row.add(frame_nr, baseline)
+        };
+        if moved {
+            self.ctx().request_repaint(); // so the widgets before this one are lined up too
+        }
+
+        let item_spacing = self.spacing().item_spacing;
+        let frame_rect = self.placer.next_space(desired_size, item_spacing);
+        let mut rect = self.placer.justify_and_align(frame_rect, desired_size);
+        let top = self.cursor().top() + row_baseline - baseline;
+        rect = rect.translate(vec2(0.0, top - rect.top()));
+        let response = self.allocate_rect(rect, sense);
+        (rect, response)
+    }
+
     /// Allocate at least as much space as needed, and interact with that rect.
     ///
     /// The returned `Rect` will be the same size as `Response::rect`.
@@ -808,6 +940,162 @@ impl Ui {
         InnerResponse::new(ret, response)
     }
 
+    /// How much space would the given contents take up, if added here?
+    ///
+    /// The contents are laid out in the available space, with the current layout,
+    /// but are neither shown nor interactive, and no space is allocated.
+    /// You can then use the size to e.g. right-align or center a group of widgets
+    /// before adding them for real.
+    ///
+    /// **NOTE**: this runs `add_contents`, so when you then add the contents for real,
+    /// your code runs twice each frame. Make sure `add_contents` has no side effects
+    /// (the widgets can't be clicked, but e.g. `println!` or a counter would still run twice),
+    /// and don't use this for large or expensive contents: store the size from
+    /// [`Response::rect`] and use it next frame instead.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.horizontal(|ui| {
+    ///     let buttons = |ui: &mut egui::Ui| {
+    ///         ui.button("Ok");
+    ///         ui.button("Cancel");
+    ///     };
+    ///     let width = ui.measure(buttons).x;
+    ///     ui.add_space(ui.available_width() - width); // right-align the buttons
+    ///     buttons(ui);
+    /// });
+    /// # });
+    /// ```
+    pub fn measure(&mut self, add_contents: impl FnOnce(&mut Ui)) -> Vec2 {
+        let mut child_ui = self.child_ui_with_id_source(
+            self.available_rect_before_wrap(),
+            *self.layout(),
+            "measure",
+        );
+        child_ui.set_visible(false);
+        add_contents(&mut child_ui);
+        child_ui.min_size()
+    }
+
+    /// Lay out flex items (see [`Self::allocate_flex`]) in the direction of the current layout.
+    ///
+    /// The flex items share the space left over by the other items added inside `add_contents`.
+    /// That space is measured on the previous frame,
+    /// so there may be a frame delay when the other items change size.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut text = String::new();
+    /// ui.horizontal(|ui| {
+    ///     ui.flex(|ui| {
+    ///         ui.button("Back");
+    ///         ui.allocate_flex(1.0, 0.0, |ui| {
+    ///             ui.add(egui::TextEdit::singleline(&mut text).desired_width(f32::INFINITY));
+    ///         });
+    ///         ui.button("Go");
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    pub fn flex<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let id = self.auto_id_with("flex");
+        let state = self.memory().data_temp.get_temp(id).unwrap_or_default();
+        self.scope(|ui| {
+            ui.flex = Some(FlexFrame {
+                id,
+                state,
+                items: Default::default(),
+                flex_size: 0.0,
+            });
+            let inner = add_contents(ui);
+            ui.end_flex();
+            inner
+        })
+    }
+
+    /// Remember the flex items and how much space the other items took, for the next frame.
+    fn end_flex(&mut self) {
+        if let Some(flex) = self.flex.take() {
+            let main_axis = if self.layout().is_horizontal() { 0 } else { 1 };
+            let state = FlexState {
+                fixed_size: (self.min_size()[main_axis] - flex.flex_size).at_least(0.0),
+                items: flex.items,
+            };
+            if state != flex.state {
+                self.ctx().request_repaint();
+            }
+            self.memory().data_temp.insert_temp(flex.id, state);
+        }
+    }
+
+    /// Allocate space for a flex item inside [`Self::flex`].
+    ///
+    /// * An item with a `grow` weight above zero gets a share of the space left over
+    ///   by the other items, in proportion to its `grow` weight.
+    /// * The other items keep the size of their contents, unless there is too little space.
+    ///   Then those with a `shrink` weight above zero give up space, in proportion to
+    ///   their `shrink` weight and size.
+    ///
+    /// Use this instead of manual [`Self::available_width`] arithmetic when e.g.
+    /// a `TextEdit` should take up all the room left by the buttons next to it.
+    ///
+    /// Inside the flex item, [`Self::available_width`] (or height, in a vertical layout)
+    /// is its share of the space.
+    ///
+    /// Outside of [`Self::flex`] this is the same as [`Self::scope`].
+    pub fn allocate_flex<R>(
+        &mut self,
+        grow: f32,
+        shrink: f32,
+        add_contents: impl FnOnce(&mut Self) -> R,
+    ) -> InnerResponse<R> {
+        let mut flex = match self.flex.take() {
+            Some(flex) => flex,
+            None => return self.scope(add_contents),
+        };
+        let main_axis = if self.layout().is_horizontal() { 0 } else { 1 };
+        let index = flex.items.len();
+        let space = self.max_rect().size()[main_axis];
+        let available_size = self.available_size_before_wrap();
+
+        let target_size = flex.state.item_size(index, space);
+        let inner = match target_size {
+            Some(size) => {
+                let size = size.at_most(available_size[main_axis]).at_least(0.0);
+                let mut desired_size = available_size;
+                desired_size[main_axis] = size;
+                self.allocate_ui(desired_size, |ui| {
+                    if main_axis == 0 {
+                        ui.set_min_width(size);
+                    } else {
+                        ui.set_min_height(size);
+                    }
+                    add_contents(ui)
+                })
+            }
+            None => self.allocate_ui(available_size, add_contents),
+        };
+
+        let size = inner.response.rect.size()[main_axis];
+        let basis = if grow > 0.0 {
+            0.0
+        } else {
+            match flex.state.items.get(index) {
+                // Shrunk, so the contents may be smaller than they want to be:
+                Some(last) if target_size.is_some() => last.basis,
+                _ => size,
+            }
+        };
+        flex.items.push(FlexItem {
+            grow,
+            shrink,
+            basis,
+        });
+        flex.flex_size += size;
+        self.flex = Some(flex);
+        inner
+    }
+
     /// Allocated the given rectangle and then adds content to that rectangle.
     /// If the contents overflow, more space will be allocated.
     /// When finished, the amount of space actually used (`min_rect`) will be allocated.
@@ -860,6 +1148,48 @@ impl Ui {
         (response, painter)
     }
 
+    /// Use the rest of the available space for moving the native window,
+    /// e.g. in a custom title bar of a window without decorations.
+    ///
+    /// Dragging it sends [`crate::output::WindowCommand::StartDrag`] to the integration,
+    /// and double-clicking it [`crate::output::WindowCommand::ToggleMaximized`].
+    /// Add any buttons of the title bar before calling this.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::output::WindowCommand;
+    /// ui.horizontal(|ui| {
+    ///     ui.label("My app");
+    ///     ui.with_layout(egui::Layout::right_to_left(), |ui| {
+    ///         if ui.button("🗙").clicked() {
+    ///             ui.output().window_commands.push(WindowCommand::Close);
+    ///         }
+    ///         if ui.button("🗖").clicked() {
+    ///             ui.output().window_commands.push(WindowCommand::ToggleMaximized);
+    ///         }
+    ///         if ui.button("🗕").clicked() {
+    ///             ui.output().window_commands.push(WindowCommand::Minimize);
+    ///         }
+    ///         ui.window_drag_area();
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    pub fn window_drag_area(&mut self) -> Response {
+        use crate::output::WindowCommand;
+
+        let rect = self.available_rect_before_wrap();
+        let response = self.allocate_rect(rect, Sense::click_and_drag());
+        if response.double_clicked() {
+            self.output()
+                .window_commands
+                .push(WindowCommand::ToggleMaximized);
+        } else if response.drag_started() && self.input().pointer.primary_down() {
+            self.output().window_commands.push(WindowCommand::StartDrag);
+        }
+        response
+    }
+
     /// Move the scroll to this cursor position with the specified alignment.
     ///
     /// ```
@@ -883,6 +1213,39 @@ impl Ui {
             self.ctx().frame_state().scroll_target[d] = Some((target[d], align));
         }
     }
+
+    /// Scroll the enclosing [`ScrollArea`] so that the given screen-space rectangle is visible,
+    /// with the specified alignment.
+    ///
+    /// `Align::Min` puts the rectangle at the top (or left) of the scroll area,
+    /// `Align::Center` in the middle, and `Align::Max` at the bottom (or right).
+    ///
+    /// See also [`Response::scroll_to_me`] and [`ScrollArea::scroll_offset`].
+    ///
+    /// ```
+    /// # use egui::Align;
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     let mut selected_rect = None;
+    ///     for i in 0..1000 {
+    ///         let response = ui.label(format!("Item {}", i));
+    ///         if i == 500 {
+    ///             selected_rect = Some(response.rect);
+    ///         }
+    ///     }
+    ///
+    ///     if let Some(rect) = selected_rect {
+    ///         ui.scroll_to_rect(rect, Align::Center);
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn scroll_to_rect(&self, rect: Rect, align: Align) {
+        for d in 0..2 {
+            let target = lerp(rect.min[d]..=rect.max[d], align.to_factor());
+            self.ctx().frame_state().scroll_target[d] = Some((target, align));
+        }
+    }
 }
 
 /// # Adding widgets
@@ -1013,8 +1376,10 @@ impl Ui {
         if self.is_visible() && !visible {
             // temporary make us invisible:
             let old_painter = self.painter.clone();
+            let old_enabled = self.enabled;
             self.set_visible(false);
             let response = self.add(widget);
+            self.enabled = old_enabled;
             self.painter = old_painter;
             response
         } else {
@@ -1073,6 +1438,28 @@ impl Ui {
         Label::new(text).ui(self)
     }
 
+    /// Show one of the built-in icons, in the size and color of normal text.
+    ///
+    /// Icons can also be used in any text, e.g. `ui.button(format!("{} Save", Icon::Save))`,
+    /// and styled like any text with [`RichText`]: `RichText::new(Icon::Warning.to_string()).color(Color32::YELLOW)`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::Icon;
+    /// ui.horizontal(|ui| {
+    ///     ui.icon(Icon::Warning);
+    ///     ui.label("The file has unsaved changes");
+    ///     if ui.button(format!("{} Save", Icon::Save)).clicked() {
+    ///         // …
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    #[cfg(feature = "icons")]
+    pub fn icon(&mut self, icon: crate::Icon) -> Response {
+        Label::new(icon).ui(self)
+    }
+
     /// Show colored text.
     ///
     /// Shortcut for `ui.label(RichText::new(text).color(color))`
@@ -1283,6 +1670,36 @@ impl Ui {
         Separator::default().ui(self)
     }
 
+    /// A horizontal line with some text in the middle, e.g. to break up a long form into sections.
+    ///
+    /// Shortcut for `add(Separator::default().text(text))` (see [`Separator`]).
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let (mut name, mut verbose) = (String::new(), false);
+    /// ui.text_edit_singleline(&mut name);
+    /// ui.separator_with_label("Advanced");
+    /// ui.checkbox(&mut verbose, "Verbose logging");
+    /// # });
+    /// ```
+    pub fn separator_with_label(&mut self, text: impl Into<WidgetText>) -> Response {
+        Separator::default().text(text).ui(self)
+    }
+
+    /// A label that you can drag horizontally to change the value. See [`Scrub`].
+    ///
+    /// `format` is the text of the label, where `{}` (or `{:.N}` for `N` decimals) is replaced by the value.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut speed = 1.0;
+    /// ui.scrub(&mut speed, "Speed: {:.1}");
+    /// # });
+    /// ```
+    pub fn scrub<Num: emath::Numeric>(&mut self, value: &mut Num, format: &str) -> Response {
+        Scrub::new(value, format).ui(self)
+    }
+
     /// Modify an angle. The given angle should be in radians, but is shown to the user in degrees.
     /// The angle is NOT wrapped, so the user may select, for instance 720° = 2𝞃 = 4π
     pub fn drag_angle(&mut self, radians: &mut f32) -> Response {
@@ -1463,6 +1880,112 @@ impl Ui {
         InnerResponse::new(ret, response)
     }
 
+    /// Like [`Self::scope`], but when the size of the contents changes, the space taken up
+    /// animates smoothly to the new size instead of jumping there at once.
+    ///
+    /// This is useful around contents that change a lot from one frame to the next,
+    /// e.g. the contents of tabs, so that switching tabs doesn't make the rest of the layout jump.
+    /// While growing, the contents are clipped to the animated size.
+    ///
+    /// The animation follows [`Style::animation_time`], and starts one frame after the contents change size.
+    /// Works best in top-down and left-to-right layouts.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut tab = 0;
+    /// ui.animate_size("tab_contents", |ui| {
+    ///     for i in 0..=tab {
+    ///         ui.label(format!("Line {}", i));
+    ///     }
+    /// });
+    /// ui.label("This moves smoothly when switching tabs");
+    /// # });
+    /// ```
+    pub fn animate_size<R>(
+        &mut self,
+        id_source: impl Hash + std::fmt::Debug,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let id = self.make_persistent_id(id_source);
+        let last_size: Option<Vec2> = self.memory().data_temp.get_temp(id);
+        let animated_size = last_size.map(|last_size| {
+            let animation_time = self.style().animation_time;
+            self.ctx()
+                .animate_vec2_with_time(id, last_size, animation_time)
+        });
+
+        let child_rect = self.available_rect_before_wrap();
+        let mut child_ui = self.child_ui(child_rect, *self.layout());
+        if let Some(animated_size) = animated_size {
+            let clip_rect = Rect::from_min_size(child_rect.min, animated_size);
+            child_ui.set_clip_rect(child_ui.clip_rect().intersect(clip_rect));
+        }
+        let ret = add_contents(&mut child_ui);
+
+        let size = child_ui.min_rect().size();
+        if last_size != Some(size) {
+            self.memory().data_temp.insert_temp(id, size);
+            if last_size.is_some() {
+                // Start animating towards the new size next frame:
+                self.ctx().request_repaint();
+            }
+        }
+
+        let rect = Rect::from_min_size(child_rect.min, animated_size.unwrap_or(size));
+        let response = self.allocate_rect(rect, Sense::hover());
+        InnerResponse::new(ret, response)
+    }
+
+    /// Like [`Self::scope`], but with a modified [`Style`].
+    ///
+    /// The change only applies to the contents, so there is no need to restore the style afterwards.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.scope_style(
+    ///     |style| {
+    ///         style.visuals.override_text_color = Some(egui::Color32::RED);
+    ///         style.spacing.item_spacing.x = 2.0;
+    ///     },
+    ///     |ui| {
+    ///         ui.label("Danger zone");
+    ///         if ui.button("Delete everything").clicked() { /* … */ }
+    ///     },
+    /// );
+    /// ui.label("This uses the normal style again");
+    /// # });
+    /// ```
+    pub fn scope_style<R>(
+        &mut self,
+        change_style: impl FnOnce(&mut Style),
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.scope(|ui| {
+            change_style(ui.style_mut());
+            add_contents(ui)
+        })
+    }
+
+    /// Like [`Self::scope`], but with modified [`crate::Visuals`].
+    ///
+    /// Short for `ui.scope_style(|style| change_visuals(&mut style.visuals), add_contents)`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.scope_visuals(
+    ///     |visuals| *visuals = egui::Visuals::light(),
+    ///     |ui| ui.label("Light mode"),
+    /// );
+    /// # });
+    /// ```
+    pub fn scope_visuals<R>(
+        &mut self,
+        change_visuals: impl FnOnce(&mut crate::Visuals),
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.scope_style(|style| change_visuals(&mut style.visuals), add_contents)
+    }
+
     /// Redirect shapes to another paint layer.
     pub fn with_layer_id<R>(
         &mut self,
@@ -1475,6 +1998,92 @@ impl Ui {
         })
     }
 
+    /// A section with a header that stays pinned to the top of the enclosing [`ScrollArea`]
+    /// for as long as the body of the section is visible.
+    /// When the end of the section scrolls by, the header is pushed out of view by the next one.
+    ///
+    /// In a horizontal layout the header is instead pinned to the left,
+    /// which you can use for frozen columns in a table.
+    ///
+    /// The body is clipped so that it never shows underneath the header.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     for section in 0..10 {
+    ///         ui.sticky_section(
+    ///             |ui| {
+    ///                 ui.heading(format!("Section {}", section));
+    ///             },
+    ///             |ui| {
+    ///                 for row in 0..20 {
+    ///                     ui.label(format!("Row {}", row));
+    ///                 }
+    ///             },
+    ///         );
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn sticky_section<R>(
+        &mut self,
+        add_header: impl FnOnce(&mut Ui),
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.sticky_section_dyn(Box::new(add_header), Box::new(add_body))
+    }
+
+    fn sticky_section_dyn<'c, R>(
+        &mut self,
+        add_header: Box<dyn FnOnce(&mut Ui) + 'c>,
+        add_body: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+    ) -> InnerResponse<R> {
+        let d = if self.layout().main_dir().is_horizontal() {
+            0
+        } else {
+            1
+        };
+        let id = self.next_auto_id().with("sticky_section");
+
+        let natural_rect = self.available_rect_before_wrap();
+        let natural_min = natural_rect.min[d];
+
+        // We don't know the size of the header and the section until we have laid them out,
+        // so we use the sizes from the previous frame:
+        let mut header_min = natural_min;
+        if let Some((header_size, section_size)) =
+            self.memory().data_temp.get_temp::<(f32, f32)>(id)
+        {
+            let pinned_min = self.clip_rect().min[d];
+            let pushed_min = natural_min + section_size - header_size;
+            header_min = header_min.max(pinned_min.min(pushed_min));
+        }
+
+        let mut header_offset = Vec2::ZERO;
+        header_offset[d] = header_min - natural_min;
+        let mut header_ui = self.child_ui(natural_rect.translate(header_offset), *self.layout());
+        add_header(&mut header_ui);
+        let header_rect = header_ui.min_rect();
+        let header_response = self
+            .allocate_exact_size(header_rect.size(), Sense::hover())
+            .1;
+
+        let body_rect = self.available_rect_before_wrap();
+        let mut body_ui = self.child_ui(body_rect, *self.layout());
+        let mut body_clip_rect = body_ui.clip_rect();
+        body_clip_rect.min[d] = body_clip_rect.min[d].max(header_rect.max[d]);
+        body_ui.set_clip_rect(body_clip_rect);
+        let ret = add_body(&mut body_ui);
+        let body_response = self.allocate_rect(body_ui.min_rect(), Sense::hover());
+
+        let section_size = body_response.rect.max[d] - natural_min;
+        self.memory()
+            .data_temp
+            .insert_temp(id, (header_rect.size()[d], section_size));
+
+        InnerResponse::new(ret, header_response | body_response)
+    }
+
     /// A [`CollapsingHeader`] that starts out collapsed.
     pub fn collapsing<R>(
         &mut self,
@@ -1512,10 +2121,7 @@ impl Ui {
         let mut child_rect = self.placer.available_rect_before_wrap();
         child_rect.min.x += indent;
 
-        let mut child_ui = Self {
-            id: self.id.with(id_source),
-            ..self.child_ui(child_rect, *self.layout())
-        };
+        let mut child_ui = self.child_ui_with_id_source(child_rect, *self.layout(), id_source);
         let ret = add_contents(&mut child_ui);
 
         let end_with_horizontal_line = self.spacing().indent_ends_with_horizontal_line;
@@ -1524,8 +2130,23 @@ impl Ui {
             child_ui.add_space(4.0);
         }
 
-        // draw a faint line on the left to mark the indented section
-        let stroke = self.visuals().widgets.noninteractive.bg_stroke;
+        // draw a faint line on the left to mark the indented section,
+        // highlighted if this is the innermost indented section under the pointer:
+        let guide_rect = Rect::from_min_max(
+            pos2(child_rect.left() - indent, child_rect.top()),
+            child_ui.min_rect().max,
+        );
+        let mut hovered = false;
+        if self.rect_contains_pointer(guide_rect) {
+            let mut frame_state = self.ctx().frame_state();
+            hovered = !frame_state.indent_guide_hovered;
+            frame_state.indent_guide_hovered = true;
+        }
+        let stroke = if hovered {
+            self.visuals().indent_guide_hover_stroke
+        } else {
+            self.visuals().indent_guide_stroke
+        };
         let left_top = child_rect.min - 0.5 * indent * Vec2::X;
         let left_top = self.painter().round_pos_to_pixels(left_top);
         let left_bottom = pos2(left_top.x, child_ui.min_rect().bottom() - 2.0);
@@ -1601,7 +2222,8 @@ impl Ui {
     /// but can be used for tooltips (`on_hover_text`).
     /// It also contains the `Rect` used by the horizontal layout.
     ///
-    /// See also [`Self::with_layout`] for more options.
+    /// See also [`Self::with_layout`] for more options,
+    /// and [`Flow`] for wrapping widgets of different heights.
     pub fn horizontal_wrapped<R>(
         &mut self,
         add_contents: impl FnOnce(&mut Ui) -> R,
@@ -1764,8 +2386,32 @@ impl Ui {
         self.placer.set_row_height(height);
     }
 
+    /// Make the next cell in a [`Grid`] span this many columns.
+    /// Otherwise does nothing.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Grid::new("some_unique_id").show(ui, |ui| {
+    ///     ui.set_colspan(2);
+    ///     ui.label("This cell spans two columns");
+    ///     ui.end_row();
+    ///
+    ///     ui.label("First column");
+    ///     ui.label("Second column");
+    ///     ui.end_row();
+    /// });
+    /// # });
+    /// ```
+    pub fn set_colspan(&mut self, num_columns: usize) {
+        if let Some(grid) = self.placer.grid_mut() {
+            grid.set_colspan(num_columns);
+        }
+    }
+
     /// Temporarily split split an Ui into several columns.
     ///
+    /// See [`ResizableColumns`] for columns that the user can resize.
+    ///
     /// ```
     /// # egui::__run_test_ui(|ui| {
     /// ui.columns(2, |columns| {
@@ -1825,6 +2471,50 @@ impl Ui {
         result
     }
 
+    /// Show the same ui twice side by side: once with each of the given styles.
+    ///
+    /// This is useful for comparing themes, e.g. light and dark mode,
+    /// or before and after a tweak to the [`Style`].
+    ///
+    /// Each half gets its own [`Id`] namespace under `id_source`, so for instance a [`CollapsingHeader`]
+    /// can be open on one side and closed on the other.
+    /// Things with global ids, like [`Window`]s, are shared between the two halves.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let styles = [
+    ///     egui::Style { visuals: egui::Visuals::light(), ..Default::default() },
+    ///     egui::Style { visuals: egui::Visuals::dark(), ..Default::default() },
+    /// ];
+    /// ui.compare_styles("light_vs_dark", styles, |ui| {
+    ///     ui.heading("Hello");
+    ///     ui.button("World");
+    /// });
+    /// # });
+    /// ```
+    pub fn compare_styles(
+        &mut self,
+        id_source: impl Hash + std::fmt::Debug,
+        styles: [impl Into<Arc<Style>>; 2],
+        mut add_contents: impl FnMut(&mut Ui),
+    ) {
+        let id = self.make_persistent_id(id_source);
+        self.columns(2, |columns| {
+            for (index, (column, style)) in columns.iter_mut().zip(styles).enumerate() {
+                let max_rect = column.available_rect_before_wrap();
+                let mut ui = column.child_ui_with_id(max_rect, *column.layout(), id.with(index));
+                ui.set_style(style);
+                let fill = ui.visuals().window_fill();
+                let margin = ui.spacing().window_padding;
+                Frame::none().fill(fill).margin(margin).show(&mut ui, |ui| {
+                    ui.set_min_size(ui.available_size());
+                    add_contents(ui);
+                });
+                column.allocate_rect(ui.min_rect(), Sense::hover());
+            }
+        });
+    }
+
     /// Close the menu we are in (including submenus), if any.
     ///
     /// See also: [`Self::menu_button`] and [`Response::context_menu`].
@@ -1893,3 +2583,290 @@ impl Ui {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_add_enabled_and_visible() {
+    __run_test_ui(|ui| {
+        assert!(!ui.add_enabled(false, Label::new("a")).enabled());
+        assert!(ui.is_enabled());
+
+        ui.add_visible(false, Label::new("b"));
+        assert!(ui.is_enabled());
+        assert!(ui.is_visible());
+
+        let cursor = ui.cursor().top();
+        ui.add_visible_ui(false, |ui| {
+            assert!(!ui.is_visible());
+            assert!(!ui.is_enabled());
+            ui.label("c");
+        });
+        assert!(
+            ui.cursor().top() > cursor,
+            "invisible ui still allocates space"
+        );
+
+        ui.add_enabled_ui(false, |ui| {
+            ui.add_enabled_ui(true, |ui| assert!(!ui.is_enabled()));
+        });
+        assert!(ui.is_enabled());
+    });
+}
+
+#[cfg(all(test, feature = "icons"))]
+#[test]
+fn test_icon() {
+    __run_test_ui(|ui| {
+        let response = ui.icon(crate::Icon::Save);
+        assert!(response.rect.width() > 0.0);
+
+        let galley = ui.fonts().layout_no_wrap(
+            crate::Icon::Save.to_string(),
+            TextStyle::Body,
+            Color32::WHITE,
+        );
+        let glyph = &galley.rows[0].glyphs[0];
+        assert_eq!(glyph.chr, crate::Icon::Save.char());
+        assert!(glyph.uv_rect.size.x > 0.0 && glyph.uv_rect.max != glyph.uv_rect.min);
+    });
+}
+
+#[cfg(test)]
+#[test]
+fn test_indent_guide_hover() {
+    use crate::{Event, RawInput};
+    use epaint::ClippedShape;
+
+    let guide = Stroke::new(1.0, Color32::RED);
+    let hovered_guide = Stroke::new(1.0, Color32::GREEN);
+    let mut ctx = CtxRef::default();
+    let mut frame = |events: Vec<Event>| {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let mut inner_rect = Rect::NOTHING;
+        let (_, shapes) = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.visuals_mut().indent_guide_stroke = guide;
+                ui.visuals_mut().indent_guide_hover_stroke = hovered_guide;
+                ui.indent("outer", |ui| {
+                    ui.label("outer");
+                    inner_rect = ui.indent("inner", |ui| ui.label("inner")).response.rect;
+                    ui.label("outer again");
+                });
+            });
+        });
+        // The inner guide is painted first:
+        let guide_strokes: Vec<Stroke> = shapes
+            .iter()
+            .filter_map(|ClippedShape(_, shape)| match shape {
+                Shape::LineSegment { stroke, .. } => Some(*stroke),
+                _ => None,
+            })
+            .collect();
+        (inner_rect, guide_strokes)
+    };
+
+    let (inner_rect, strokes) = frame(vec![]);
+    assert_eq!(strokes, [guide, guide]);
+
+    let (_, strokes) = frame(vec![Event::PointerMoved(inner_rect.center())]);
+    assert_eq!(
+        strokes,
+        [hovered_guide, guide],
+        "only the innermost is highlighted"
+    );
+
+    let (_, strokes) = frame(vec![Event::PointerMoved(
+        inner_rect.left_bottom() + vec2(0.0, 10.0),
+    )]);
+    assert_eq!(strokes, [guide, hovered_guide]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_animate_size() {
+    use crate::RawInput;
+
+    let mut ctx = CtxRef::default();
+    let mut run = |time: f64, num_lines: usize| {
+        let raw_input = RawInput {
+            time: Some(time),
+            predicted_dt: 0.0,
+            ..Default::default()
+        };
+        let mut heights = (0.0, 0.0);
+        let _ = ctx.run(raw_input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let animated = ui.animate_size("lines", |ui| {
+                    ui.vertical(|ui| {
+                        for i in 0..num_lines {
+                            ui.label(format!("Line {}", i));
+                        }
+                    })
+                    .response
+                    .rect
+                    .height()
+                });
+                heights = (animated.response.rect.height(), animated.inner);
+            });
+        });
+        heights
+    };
+
+    let (small, contents) = run(0.0, 1);
+    assert_eq!(small, contents, "starts at the size of the contents");
+    let (height, large) = run(1.0, 5);
+    assert_eq!(height, small, "animates from the old size");
+    let (height, _) = run(1.1, 5);
+    assert_eq!(height, small, "starts animating the frame after");
+    let (height, _) = run(1.1 + 0.5 * Style::default().animation_time as f64, 5);
+    assert!(
+        small < height && height < large,
+        "{} {} {}",
+        small,
+        height,
+        large
+    );
+    let (height, _) = run(2.0, 5);
+    assert_eq!(height, large);
+}
+
+#[cfg(test)]
+#[test]
+fn test_compare_styles_ids() {
+    __run_test_ui(|ui| {
+        let id = ui.make_persistent_id("compare");
+        let mut ids = vec![];
+        ui.compare_styles("compare", [Style::default(), Style::default()], |ui| {
+            ids.push(ui.id());
+        });
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+        assert!(ids.iter().all(|&child| child != id));
+    });
+}
+
+#[cfg(test)]
+#[test]
+fn test_flex_grow_and_shrink() {
+    let mut ctx = crate::CtxRef::default();
+    let mut frame = |width: f32| {
+        let input = crate::RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(width, 100.0))),
+            ..Default::default()
+        };
+        let mut widths = vec![];
+        let _ = ctx.run(input, |ctx| {
+            crate::Area::new("flex").show(ctx, |ui| {
+                ui.set_max_width(width);
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    ui.flex(|ui| {
+                        for (grow, shrink) in [(1.0, 0.0), (0.0, 1.0), (3.0, 0.0)] {
+                            let response = ui.allocate_flex(grow, shrink, |ui| {
+                                if shrink > 0.0 {
+                                    ui.add(Label::new("x".repeat(10)).wrap(true));
+                                }
+                            });
+                            widths.push(response.response.rect.width());
+                        }
+                    });
+                });
+            });
+        });
+        widths
+    };
+
+    frame(400.0);
+    let widths = frame(400.0);
+    let label_width = widths[1];
+    assert!(label_width > 0.0);
+    assert_eq!(widths[0], (400.0 - label_width) * 0.25);
+    assert_eq!(widths[2], (400.0 - label_width) * 0.75);
+
+    // Too narrow for the label: it shrinks, and the growing items get nothing:
+    frame(label_width / 2.0);
+    let widths = frame(label_width / 2.0);
+    assert_eq!(widths[1], label_width / 2.0);
+
+    // Room again: the label gets its size back.
+    frame(400.0);
+    let widths = frame(400.0);
+    assert_eq!(widths[1], label_width);
+}
+
+#[cfg(test)]
+#[test]
+fn test_baseline_align() {
+    let mut ctx = crate::CtxRef::default();
+    let mut frame = || {
+        let mut baselines = vec![];
+        let (output, _) = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let layout = Layout::left_to_right().with_baseline_align(true);
+                ui.with_layout(layout, |ui| {
+                    for style in [TextStyle::Small, TextStyle::Heading, TextStyle::Body] {
+                        let text = WidgetText::from(RichText::new("x").text_style(style));
+                        let galley = text.into_galley(ui, None, f32::INFINITY, style);
+                        let baseline = galley.baseline(ui.fonts());
+                        let response = ui.add(Label::new(galley.galley));
+                        baselines.push(response.rect.top() + baseline);
+                    }
+                });
+            });
+        });
+        (baselines, output.needs_repaint)
+    };
+
+    let (_, needs_repaint) = frame();
+    assert!(needs_repaint, "the first label was misaligned");
+    let (baselines, _) = frame();
+    assert!(
+        baselines.iter().all(|&b| b == baselines[0]),
+        "{:?}",
+        baselines
+    );
+    let (baselines_again, _) = frame();
+    assert_eq!(baselines, baselines_again);
+}
+
+#[cfg(test)]
+#[test]
+fn test_focus_ring_on_top() {
+    use epaint::ClippedShape;
+
+    let mut ctx = crate::CtxRef::default();
+    let frame = |ctx: &mut crate::CtxRef, focus: bool| {
+        let mut button_rect = Rect::NOTHING;
+        let (_, shapes) = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let response = ui.button("Focus me");
+                if focus {
+                    response.request_focus();
+                }
+                button_rect = response.rect;
+            });
+        });
+        (button_rect, shapes)
+    };
+
+    frame(&mut ctx, true);
+    let (button_rect, shapes) = frame(&mut ctx, false);
+    let focus_ring = ctx.style().visuals.focus_ring;
+    let rings: Vec<_> = shapes
+        .iter()
+        .filter(|ClippedShape(_, shape)| {
+            matches!(shape, Shape::Rect(rect) if rect.stroke == focus_ring)
+        })
+        .collect();
+    assert_eq!(rings.len(), 1, "exactly one focus ring");
+    match shapes.last() {
+        Some(ClippedShape(_, Shape::Rect(rect))) => {
+            assert_eq!(rect.stroke, focus_ring, "the ring is painted last");
+            assert!(rect.rect.contains_rect(button_rect));
+        }
+        other => panic!("expected the focus ring last, got {:?}", other),
+    }
+}
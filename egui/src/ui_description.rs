@@ -0,0 +1,337 @@
+//! Build a ui from a data description, e.g. loaded from JSON or generated by a script.
+//!
+//! This lets tools expose panels that can be extended at runtime (by plugins, scripts, config files…)
+//! without recompiling. Build a [`UiDescription`] and show it with [`ui_from_value`].
+//!
+//! The description is the source of truth: the widgets show the values in the description,
+//! and any user interaction is reported back as a [`UiDescriptionEvent`].
+//! It is up to you to apply changes (e.g. a new slider value) to the description.
+//!
+//! ```
+//! use egui::ui_description::{ui_from_value, UiDescription, UiDescriptionEvent};
+//!
+//! let mut description = UiDescription::Vertical(vec![
+//!     UiDescription::Heading("Settings".to_owned()),
+//!     UiDescription::Checkbox {
+//!         id: "vsync".to_owned(),
+//!         text: "Enable vsync".to_owned(),
+//!         checked: true,
+//!     },
+//!     UiDescription::Button {
+//!         id: "apply".to_owned(),
+//!         text: "Apply".to_owned(),
+//!     },
+//! ]);
+//!
+//! # egui::__run_test_ui(|ui| {
+//! for event in ui_from_value(ui, &description) {
+//!     match event {
+//!         UiDescriptionEvent::Clicked { .. } => { /* notify the script */ }
+//!         event => {
+//!             description.apply(&event);
+//!         }
+//!     }
+//! }
+//! # });
+//! ```
+
+use crate::*;
+
+/// A serializable description of a piece of ui.
+///
+/// Interactive widgets have an `id` which is used to identify them in [`UiDescriptionEvent`]s.
+/// These should be unique within a description.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum UiDescription {
+    /// See [`Ui::label`].
+    Label(String),
+
+    /// See [`Ui::heading`].
+    Heading(String),
+
+    /// See [`Ui::separator`].
+    Separator,
+
+    /// See [`Ui::button`].
+    Button { id: String, text: String },
+
+    /// See [`Ui::checkbox`].
+    Checkbox {
+        id: String,
+        text: String,
+        checked: bool,
+    },
+
+    /// See [`Slider`].
+    Slider {
+        id: String,
+        text: String,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+
+    /// See [`DragValue`].
+    DragValue { id: String, value: f64 },
+
+    /// See [`TextEdit`].
+    TextEdit {
+        id: String,
+        text: String,
+        multiline: bool,
+    },
+
+    /// Lay out the children left-to-right. See [`Ui::horizontal`].
+    Horizontal(Vec<UiDescription>),
+
+    /// Lay out the children top-to-bottom. See [`Ui::vertical`].
+    Vertical(Vec<UiDescription>),
+
+    /// Put the children in a frame. See [`Ui::group`].
+    Group(Vec<UiDescription>),
+
+    /// A collapsible region. See [`CollapsingHeader`].
+    Collapsing {
+        id: String,
+        heading: String,
+        children: Vec<UiDescription>,
+    },
+}
+
+/// What the user did with a widget in a [`UiDescription`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum UiDescriptionEvent {
+    /// A [`UiDescription::Button`] was clicked.
+    Clicked { id: String },
+
+    /// A [`UiDescription::Checkbox`] was toggled.
+    Toggled { id: String, checked: bool },
+
+    /// A [`UiDescription::Slider`] or [`UiDescription::DragValue`] changed its value.
+    ValueChanged { id: String, value: f64 },
+
+    /// The text of a [`UiDescription::TextEdit`] was edited.
+    TextChanged { id: String, text: String },
+}
+
+impl UiDescriptionEvent {
+    /// The id of the widget that the event came from.
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Clicked { id }
+            | Self::Toggled { id, .. }
+            | Self::ValueChanged { id, .. }
+            | Self::TextChanged { id, .. } => id,
+        }
+    }
+}
+
+impl UiDescription {
+    /// Update the values in this description with those in the event.
+    ///
+    /// Returns `true` if a matching widget was found.
+    pub fn apply(&mut self, event: &UiDescriptionEvent) -> bool {
+        match (self, event) {
+            (
+                Self::Checkbox { id, checked, .. },
+                UiDescriptionEvent::Toggled {
+                    id: event_id,
+                    checked: new_checked,
+                },
+            ) if id == event_id => {
+                *checked = *new_checked;
+                true
+            }
+            (
+                Self::Slider { id, value, .. } | Self::DragValue { id, value },
+                UiDescriptionEvent::ValueChanged {
+                    id: event_id,
+                    value: new_value,
+                },
+            ) if id == event_id => {
+                *value = *new_value;
+                true
+            }
+            (
+                Self::TextEdit { id, text, .. },
+                UiDescriptionEvent::TextChanged {
+                    id: event_id,
+                    text: new_text,
+                },
+            ) if id == event_id => {
+                *text = new_text.clone();
+                true
+            }
+            (
+                Self::Horizontal(children)
+                | Self::Vertical(children)
+                | Self::Group(children)
+                | Self::Collapsing { children, .. },
+                event,
+            ) => children.iter_mut().any(|child| child.apply(event)),
+            _ => false,
+        }
+    }
+}
+
+/// Show the described ui, and return what the user did with it.
+pub fn ui_from_value(ui: &mut Ui, description: &UiDescription) -> Vec<UiDescriptionEvent> {
+    let mut events = vec![];
+    add_description(ui, description, &mut events);
+    events
+}
+
+fn add_description(ui: &mut Ui, description: &UiDescription, events: &mut Vec<UiDescriptionEvent>) {
+    match description {
+        UiDescription::Label(text) => {
+            ui.label(text);
+        }
+        UiDescription::Heading(text) => {
+            ui.heading(text);
+        }
+        UiDescription::Separator => {
+            ui.separator();
+        }
+        UiDescription::Button { id, text } => {
+            if ui.button(text).clicked() {
+                events.push(UiDescriptionEvent::Clicked { id: id.clone() });
+            }
+        }
+        UiDescription::Checkbox { id, text, checked } => {
+            let mut checked = *checked;
+            if ui.checkbox(&mut checked, text).changed() {
+                events.push(UiDescriptionEvent::Toggled {
+                    id: id.clone(),
+                    checked,
+                });
+            }
+        }
+        UiDescription::Slider {
+            id,
+            text,
+            value,
+            min,
+            max,
+        } => {
+            let mut value = *value;
+            if ui
+                .add(Slider::new(&mut value, *min..=*max).text(text))
+                .changed()
+            {
+                events.push(UiDescriptionEvent::ValueChanged {
+                    id: id.clone(),
+                    value,
+                });
+            }
+        }
+        UiDescription::DragValue { id, value } => {
+            let mut value = *value;
+            if ui.add(DragValue::new(&mut value)).changed() {
+                events.push(UiDescriptionEvent::ValueChanged {
+                    id: id.clone(),
+                    value,
+                });
+            }
+        }
+        UiDescription::TextEdit {
+            id,
+            text,
+            multiline,
+        } => {
+            let mut text = text.clone();
+            let text_edit = if *multiline {
+                TextEdit::multiline(&mut text)
+            } else {
+                TextEdit::singleline(&mut text)
+            };
+            if ui.add(text_edit.id_source(id)).changed() {
+                events.push(UiDescriptionEvent::TextChanged {
+                    id: id.clone(),
+                    text,
+                });
+            }
+        }
+        UiDescription::Horizontal(children) => {
+            ui.horizontal(|ui| add_children(ui, children, events));
+        }
+        UiDescription::Vertical(children) => {
+            ui.vertical(|ui| add_children(ui, children, events));
+        }
+        UiDescription::Group(children) => {
+            ui.group(|ui| add_children(ui, children, events));
+        }
+        UiDescription::Collapsing {
+            id,
+            heading,
+            children,
+        } => {
+            CollapsingHeader::new(heading)
+                .id_source(id)
+                .show(ui, |ui| add_children(ui, children, events));
+        }
+    }
+}
+
+fn add_children(ui: &mut Ui, children: &[UiDescription], events: &mut Vec<UiDescriptionEvent>) {
+    for child in children {
+        add_description(ui, child, events);
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_ui_from_value() {
+    use crate::test::Harness;
+
+    let description = UiDescription::Vertical(vec![
+        UiDescription::Checkbox {
+            id: "vsync".to_owned(),
+            text: "Enable vsync".to_owned(),
+            checked: false,
+        },
+        UiDescription::Button {
+            id: "apply".to_owned(),
+            text: "Apply".to_owned(),
+        },
+    ]);
+
+    // The description, and the events since the last check:
+    let state: (UiDescription, Vec<UiDescriptionEvent>) = (description, vec![]);
+    let mut harness = Harness::with_state(state, |ctx, (description, events)| {
+        CentralPanel::default().show(ctx, |ui| {
+            events.extend(ui_from_value(ui, description));
+        });
+    });
+    assert!(harness.state().1.is_empty());
+
+    harness.click("Enable vsync");
+    let (description, ui_events) = harness.state_mut();
+    assert_eq!(
+        *ui_events,
+        [UiDescriptionEvent::Toggled {
+            id: "vsync".to_owned(),
+            checked: true
+        }]
+    );
+    assert!(
+        description.apply(&ui_events[0]),
+        "found in the nested children"
+    );
+    assert!(matches!(
+        &description,
+        UiDescription::Vertical(children)
+            if matches!(children[0], UiDescription::Checkbox { checked: true, .. })
+    ));
+    ui_events.clear();
+
+    harness.click("Apply");
+    let (description, ui_events) = harness.state_mut();
+    assert_eq!(ui_events.len(), 1);
+    assert_eq!(ui_events[0].id(), "apply");
+    assert!(
+        !description.apply(&ui_events[0]),
+        "clicks don't change the description"
+    );
+}
@@ -59,6 +59,11 @@ impl<T> SerializableAny for T where T: 'static + Any + Clone + for<'a> Send + Sy
 struct SerializedElement {
     type_id: TypeId,
     ron: Arc<str>,
+    /// See [`Slot::last_used`].
+    ///
+    /// Missing from what egui persisted before there was a version.
+    #[serde(default)]
+    last_used: u64,
 }
 
 #[cfg(feature = "persistence")]
@@ -246,7 +251,7 @@ impl Element {
     }
 
     #[cfg(feature = "persistence")]
-    fn to_serialize(&self) -> Option<SerializedElement> {
+    fn to_serialize(&self, last_used: u64) -> Option<SerializedElement> {
         match self {
             Self::Value {
                 value,
@@ -258,6 +263,7 @@ impl Element {
                     Some(SerializedElement {
                         type_id: (**value).type_id().into(),
                         ron: ron.into(),
+                        last_used,
                     })
                 } else {
                     None
@@ -266,11 +272,31 @@ impl Element {
             Self::Serialized { type_id, ron } => Some(SerializedElement {
                 type_id: *type_id,
                 ron: ron.clone(),
+                last_used,
             }),
         }
     }
 }
 
+/// An [`Element`] and when it was last used.
+#[derive(Clone, Debug)]
+struct Slot {
+    element: Element,
+
+    /// The [`IdTypeMap::generation`] when this was last inserted or read.
+    /// Used to prune the least recently used values.
+    last_used: u64,
+}
+
+impl Slot {
+    /// Mark as used, and return the element.
+    #[inline]
+    fn touch(&mut self, generation: u64) -> &mut Element {
+        self.last_used = generation;
+        &mut self.element
+    }
+}
+
 #[cfg(feature = "persistence")]
 fn from_ron_str<T: serde::de::DeserializeOwned>(ron: &str) -> Option<T> {
     match ron::from_str::<T>(ron) {
@@ -300,6 +326,9 @@ use crate::Id;
 ///
 /// You can store state using the key [`Id::null`]. The state will then only be identified by its type.
 ///
+/// Values that have not been used for a long time can be removed with [`Self::prune`],
+/// which egui does automatically for [`crate::Memory::data_temp`] and [`crate::Memory::data_persisted`].
+///
 /// ```
 /// # use egui::{Id, util::IdTypeMap};
 /// let a = Id::new("a");
@@ -327,40 +356,53 @@ use crate::Id;
 /// assert_eq!(map.get_temp::<String>(b), Some("Hello World".to_string()));
 /// ```
 #[derive(Clone, Debug, Default)]
-// We store use `id XOR typeid` as a key, so we don't need to hash again!
-pub struct IdTypeMap(nohash_hasher::IntMap<u64, Element>);
+pub struct IdTypeMap {
+    // We store use `id XOR typeid` as a key, so we don't need to hash again!
+    map: nohash_hasher::IntMap<u64, Slot>,
+
+    /// Increases by one each frame, see [`Self::end_frame`].
+    generation: u64,
+}
 
 impl IdTypeMap {
+    #[inline]
+    fn insert(&mut self, hash: u64, element: Element) {
+        let last_used = self.generation;
+        self.map.insert(hash, Slot { element, last_used });
+    }
+
+    #[inline]
+    fn get_mut(&mut self, hash: u64) -> Option<&mut Element> {
+        let generation = self.generation;
+        self.map.get_mut(&hash).map(|slot| slot.touch(generation))
+    }
+
     /// Insert a value that will not be persisted.
     #[inline]
     pub fn insert_temp<T: 'static + Any + Clone + Send + Sync>(&mut self, id: Id, value: T) {
         let hash = hash(TypeId::of::<T>(), id);
-        self.0.insert(hash, Element::new_temp(value));
+        self.insert(hash, Element::new_temp(value));
     }
 
     /// Insert a value that will be persisted next time you start the app.
     #[inline]
     pub fn insert_persisted<T: SerializableAny>(&mut self, id: Id, value: T) {
         let hash = hash(TypeId::of::<T>(), id);
-        self.0.insert(hash, Element::new_persisted(value));
+        self.insert(hash, Element::new_persisted(value));
     }
 
     /// Read a value without trying to deserialize a persisted value.
     #[inline]
     pub fn get_temp<T: 'static + Clone>(&mut self, id: Id) -> Option<T> {
         let hash = hash(TypeId::of::<T>(), id);
-        self.0
-            .get_mut(&hash)
-            .and_then(|x| x.get_mut_temp())
-            .cloned()
+        self.get_mut(hash).and_then(|x| x.get_mut_temp()).cloned()
     }
 
     /// Read a value, optionally deserializing it if available.
     #[inline]
     pub fn get_persisted<T: SerializableAny>(&mut self, id: Id) -> Option<T> {
         let hash = hash(TypeId::of::<T>(), id);
-        self.0
-            .get_mut(&hash)
+        self.get_mut(hash)
             .and_then(|x| x.get_mut_persisted())
             .cloned()
     }
@@ -398,15 +440,21 @@ impl IdTypeMap {
         insert_with: impl FnOnce() -> T,
     ) -> &mut T {
         let hash = hash(TypeId::of::<T>(), id);
+        let generation = self.generation;
         use std::collections::hash_map::Entry;
-        match self.0.entry(hash) {
+        match self.map.entry(hash) {
             Entry::Vacant(vacant) => vacant
-                .insert(Element::new_temp(insert_with()))
+                .insert(Slot {
+                    element: Element::new_temp(insert_with()),
+                    last_used: generation,
+                })
+                .element
                 .get_mut_temp()
                 .unwrap(), // this unwrap will never panic, because we insert correct type right now
-            Entry::Occupied(occupied) => {
-                occupied.into_mut().get_temp_mut_or_insert_with(insert_with)
-            }
+            Entry::Occupied(occupied) => occupied
+                .into_mut()
+                .touch(generation)
+                .get_temp_mut_or_insert_with(insert_with),
         }
     }
 
@@ -416,14 +464,20 @@ impl IdTypeMap {
         insert_with: impl FnOnce() -> T,
     ) -> &mut T {
         let hash = hash(TypeId::of::<T>(), id);
+        let generation = self.generation;
         use std::collections::hash_map::Entry;
-        match self.0.entry(hash) {
+        match self.map.entry(hash) {
             Entry::Vacant(vacant) => vacant
-                .insert(Element::new_persisted(insert_with()))
+                .insert(Slot {
+                    element: Element::new_persisted(insert_with()),
+                    last_used: generation,
+                })
+                .element
                 .get_mut_persisted()
                 .unwrap(), // this unwrap will never panic, because we insert correct type right now
             Entry::Occupied(occupied) => occupied
                 .into_mut()
+                .touch(generation)
                 .get_persisted_mut_or_insert_with(insert_with),
         }
     }
@@ -432,53 +486,83 @@ impl IdTypeMap {
     #[inline]
     pub fn remove<T: 'static>(&mut self, id: Id) {
         let hash = hash(TypeId::of::<T>(), id);
-        self.0.remove(&hash);
+        self.map.remove(&hash);
     }
 
     /// Note all state of the given type.
     pub fn remove_by_type<T: 'static>(&mut self) {
         let key = TypeId::of::<T>();
-        self.0.retain(|_, e| {
-            let e: &Element = e;
-            e.type_id() != key
-        });
+        self.map.retain(|_, slot| slot.element.type_id() != key);
+    }
+
+    /// Remove the state of all types for this id.
+    pub fn remove_by_id(&mut self, id: Id) {
+        // The key is `id XOR typeid`, so we can recover the id:
+        self.map
+            .retain(|&hash, slot| hash ^ slot.element.type_id().value() != id.value());
     }
 
     #[inline]
     pub fn clear(&mut self) {
-        self.0.clear();
+        self.map.clear();
     }
 
     #[inline]
     pub fn is_empty(&mut self) -> bool {
-        self.0.is_empty()
+        self.map.is_empty()
     }
 
     #[inline]
     pub fn len(&mut self) -> usize {
-        self.0.len()
+        self.map.len()
     }
 
     /// Count how many values are stored but not yet deserialized.
     #[inline]
     pub fn count_serialized(&mut self) -> usize {
-        self.0
+        self.map
             .values()
-            .filter(|e| matches!(e, Element::Serialized { .. }))
+            .filter(|slot| matches!(slot.element, Element::Serialized { .. }))
             .count()
     }
 
     /// Count the number of values are stored with the given type.
     pub fn count<T: 'static>(&mut self) -> usize {
         let key = TypeId::of::<T>();
-        self.0
-            .iter()
-            .filter(|(_, e)| {
-                let e: &Element = e;
-                e.type_id() == key
-            })
+        self.map
+            .values()
+            .filter(|slot| slot.element.type_id() == key)
             .count()
     }
+
+    /// Remove the least recently used values (inserted or read the longest ago),
+    /// so that at most `max_len` remain.
+    pub fn prune(&mut self, max_len: usize) {
+        if self.map.len() <= max_len {
+            return;
+        }
+        let mut by_age: Vec<(u64, u64)> = self
+            .map
+            .iter()
+            .map(|(&hash, slot)| (slot.last_used, hash))
+            .collect();
+        by_age.sort_unstable();
+        let num_to_remove = self.map.len() - max_len;
+        for (_, hash) in &by_age[..num_to_remove] {
+            self.map.remove(hash);
+        }
+    }
+
+    /// Called by egui at the end of each frame.
+    ///
+    /// If there are more than `max_len` values, the least recently used are pruned,
+    /// a quarter of them at a time so that we don't need to prune every frame.
+    pub(crate) fn end_frame(&mut self, max_len: usize) {
+        self.generation += 1;
+        if self.map.len() > max_len {
+            self.prune(max_len - max_len / 4);
+        }
+    }
 }
 
 #[inline(always)]
@@ -488,31 +572,60 @@ fn hash(type_id: TypeId, id: Id) -> u64 {
 
 // ----------------------------------------------------------------------------
 
+/// Bump this when the persisted state of egui's widgets changes in an incompatible way.
+///
+/// State persisted by other versions is then discarded when loaded,
+/// rather than being misinterpreted.
+#[cfg(feature = "persistence")]
+const PERSISTENCE_VERSION: u32 = 1;
+
 /// How [`IdTypeMap`] is persisted.
 #[cfg(feature = "persistence")]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
-struct PersistedMap(Vec<(u64, SerializedElement)>);
+struct PersistedMap {
+    /// [`PERSISTENCE_VERSION`] of the egui that wrote this.
+    version: u32,
+    generation: u64,
+    elements: Vec<(u64, SerializedElement)>,
+}
 
 #[cfg(feature = "persistence")]
 impl PersistedMap {
     fn from_map(map: &IdTypeMap) -> Self {
         // filter out the elements which cannot be serialized:
-        Self(
-            map.0
+        Self {
+            version: PERSISTENCE_VERSION,
+            generation: map.generation,
+            elements: map
+                .map
                 .iter()
-                .filter_map(|(&hash, element)| Some((hash, element.to_serialize()?)))
+                .filter_map(|(&hash, slot)| {
+                    Some((hash, slot.element.to_serialize(slot.last_used)?))
+                })
                 .collect(),
-        )
+        }
     }
     fn into_map(self) -> IdTypeMap {
-        IdTypeMap(
-            self.0
+        IdTypeMap {
+            map: self
+                .elements
                 .into_iter()
-                .map(|(hash, SerializedElement { type_id, ron })| {
-                    (hash, Element::Serialized { type_id, ron })
-                })
+                .map(
+                    |(
+                        hash,
+                        SerializedElement {
+                            type_id,
+                            ron,
+                            last_used,
+                        },
+                    )| {
+                        let element = Element::Serialized { type_id, ron };
+                        (hash, Slot { element, last_used })
+                    },
+                )
                 .collect(),
-        )
+            generation: self.generation,
+        }
     }
 }
 
@@ -532,7 +645,87 @@ impl<'de> serde::Deserialize<'de> for IdTypeMap {
     where
         D: serde::Deserializer<'de>,
     {
-        <PersistedMap>::deserialize(deserializer).map(PersistedMap::into_map)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(HumanReadableVisitor)
+        } else {
+            let map = <PersistedMap>::deserialize(deserializer)?;
+            if map.version == PERSISTENCE_VERSION {
+                Ok(map.into_map())
+            } else {
+                Ok(Default::default())
+            }
+        }
+    }
+}
+
+/// Deserializes a [`PersistedMap`] in a human readable format (e.g. RON),
+/// discarding the elements if it was persisted by another version of egui.
+///
+/// What egui persisted before there was a version (just the elements) is still loaded.
+#[cfg(feature = "persistence")]
+struct HumanReadableVisitor;
+
+#[cfg(feature = "persistence")]
+impl<'de> serde::de::Visitor<'de> for HumanReadableVisitor {
+    type Value = IdTypeMap;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a persisted IdTypeMap")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<IdTypeMap, A::Error> {
+        use serde::de::IgnoredAny;
+
+        #[derive(serde::Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            Version,
+            Generation,
+            Elements,
+            #[serde(other)]
+            Other,
+        }
+
+        let mut version = None;
+        let mut generation = 0;
+        let mut elements = vec![];
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Version => version = Some(map.next_value()?),
+                Field::Generation => generation = map.next_value()?,
+                // Elements of other versions may not even parse:
+                Field::Elements if version == Some(PERSISTENCE_VERSION) => {
+                    elements = map.next_value()?;
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        if version == Some(PERSISTENCE_VERSION) {
+            let persisted = PersistedMap {
+                version: PERSISTENCE_VERSION,
+                generation,
+                elements,
+            };
+            Ok(persisted.into_map())
+        } else {
+            Ok(Default::default())
+        }
+    }
+
+    /// What egui persisted before there was a version.
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<IdTypeMap, A::Error> {
+        let mut elements = vec![];
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+        let persisted = PersistedMap {
+            version: PERSISTENCE_VERSION,
+            generation: 0,
+            elements,
+        };
+        Ok(persisted.into_map())
     }
 }
 
@@ -707,3 +900,64 @@ fn test_mix_serialize() {
     );
     assert_eq!(map.get_temp::<Serializable>(id), Some(Serializable(555)));
 }
+
+#[test]
+fn test_remove_by_id() {
+    let a = Id::new("a");
+    let b = Id::new("b");
+
+    let mut map: IdTypeMap = Default::default();
+    map.insert_temp(a, 1_i32);
+    map.insert_temp(a, 1.0_f32);
+    map.insert_temp(b, 2_i32);
+
+    map.remove_by_id(a);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get_temp::<i32>(a), None);
+    assert_eq!(map.get_temp::<f32>(a), None);
+    assert_eq!(map.get_temp::<i32>(b), Some(2));
+}
+
+#[test]
+fn test_prune_least_recently_used() {
+    let mut map: IdTypeMap = Default::default();
+    for i in 0..10 {
+        map.insert_temp(Id::new(i), i);
+        map.end_frame(usize::MAX);
+    }
+    // Reading a value makes it recently used:
+    assert_eq!(map.get_temp::<i32>(Id::new(0)), Some(0));
+
+    map.end_frame(8);
+    assert_eq!(map.len(), 6);
+    assert_eq!(map.get_temp::<i32>(Id::new(0)), Some(0));
+    for i in 1..5 {
+        assert_eq!(map.get_temp::<i32>(Id::new(i)), None);
+    }
+    for i in 5..10 {
+        assert_eq!(map.get_temp::<i32>(Id::new(i)), Some(i));
+    }
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_deserialize_other_version() {
+    let id = Id::new("a");
+    let mut map: IdTypeMap = Default::default();
+    map.insert_persisted(id, 42_i32);
+
+    let mut persisted = PersistedMap::from_map(&map);
+    persisted.version += 1;
+    let mut map: IdTypeMap = ron::from_str(&ron::to_string(&persisted).unwrap()).unwrap();
+    assert!(map.is_empty());
+
+    // What egui persisted before there was a version is still loaded:
+    let type_id = ron::to_string(&TypeId::of::<i32>()).unwrap();
+    let unversioned = format!(
+        "[({}, (type_id: {}, ron: \"42\"))]",
+        hash(TypeId::of::<i32>(), id),
+        type_id
+    );
+    let mut map: IdTypeMap = ron::from_str(&unversioned).unwrap();
+    assert_eq!(map.get_persisted::<i32>(id), Some(42));
+}
@@ -45,6 +45,14 @@ impl From<String> for RichText {
     }
 }
 
+#[cfg(feature = "icons")]
+impl From<crate::Icon> for RichText {
+    #[inline]
+    fn from(icon: crate::Icon) -> Self {
+        RichText::new(icon.to_string())
+    }
+}
+
 impl RichText {
     #[inline]
     pub fn new(text: impl Into<String>) -> Self {
@@ -64,6 +72,11 @@ impl RichText {
         &self.text
     }
 
+    #[inline]
+    pub(crate) fn text_mut(&mut self) -> &mut String {
+        &mut self.text
+    }
+
     /// Override the [`TextStyle`].
     #[inline]
     pub fn text_style(mut self, text_style: TextStyle) -> Self {
@@ -178,7 +191,7 @@ impl RichText {
         fonts.row_height(text_style)
     }
 
-    fn into_text_job(
+    pub(crate) fn into_text_job(
         self,
         style: &Style,
         default_text_style: TextStyle,
@@ -532,6 +545,14 @@ impl From<String> for WidgetText {
     }
 }
 
+#[cfg(feature = "icons")]
+impl From<crate::Icon> for WidgetText {
+    #[inline]
+    fn from(icon: crate::Icon) -> Self {
+        Self::RichText(icon.into())
+    }
+}
+
 impl From<RichText> for WidgetText {
     #[inline]
     fn from(rich_text: RichText) -> Self {
@@ -597,6 +618,24 @@ impl WidgetTextGalley {
         &self.galley
     }
 
+    /// Distance from the top of the text to the baseline of its first row.
+    ///
+    /// If the row mixes font sizes, this is the lowest of their baselines.
+    pub fn baseline(&self, fonts: &epaint::text::Fonts) -> f32 {
+        let job = &self.galley.job;
+        let ascent = |section_index: usize| {
+            job.sections
+                .get(section_index)
+                .map_or(0.0, |section| fonts.ascent(section.format.style))
+        };
+        let row = &self.galley.rows[0]; // Galleys are never empty
+        row.glyphs
+            .iter()
+            .map(|glyph| glyph.pos.y + ascent(glyph.section_index as usize))
+            .reduce(f32::max)
+            .unwrap_or_else(|| row.rect.min.y + ascent(0))
+    }
+
     /// Use the colors in the original [`WidgetText`] if any,
     /// else fall back to the one specified by the [`WidgetVisuals`].
     pub fn paint_with_visuals(
@@ -633,3 +672,43 @@ impl WidgetTextGalley {
         painter.galley_with_color(text_pos, self.galley, text_color);
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_baseline_with_mixed_font_sizes() {
+    use epaint::text::{FontDefinitions, Fonts, LayoutJob, TextFormat};
+
+    let fonts = Fonts::new(1.0, FontDefinitions::default());
+    let galley = |sections: &[(&str, TextStyle)]| {
+        let mut job = LayoutJob::default();
+        for &(text, style) in sections {
+            job.append(text, 0.0, TextFormat::simple(style, Color32::WHITE));
+        }
+        WidgetTextGalley {
+            galley: fonts.layout_job(job),
+            galley_has_color: false,
+        }
+    };
+    // 'x' has no descender, so it sits right on the baseline:
+    let bottom_of_glyphs = |text: &WidgetTextGalley| text.galley.rows[0].visuals.mesh_bounds.max.y;
+
+    for style in [TextStyle::Small, TextStyle::Body, TextStyle::Heading] {
+        let text = galley(&[("xx", style)]);
+        let baseline = text.baseline(&fonts);
+        assert!(
+            baseline < text.size().y,
+            "{:?}: the baseline is above the row bottom",
+            style
+        );
+        assert!(
+            (baseline - bottom_of_glyphs(&text)).abs() <= 1.0,
+            "{:?}",
+            style
+        );
+    }
+
+    let mixed = galley(&[("xx", TextStyle::Small), ("xx", TextStyle::Heading)]);
+    let heading = galley(&[("xx", TextStyle::Heading)]);
+    assert_eq!(mixed.baseline(&fonts), heading.baseline(&fonts));
+    assert!((mixed.baseline(&fonts) - bottom_of_glyphs(&mixed)).abs() <= 1.0);
+}
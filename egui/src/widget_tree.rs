@@ -0,0 +1,195 @@
+use crate::{Id, IdMap, LayerId, Rect, WidgetInfo};
+
+/// A widget or [`crate::Ui`] in a [`WidgetTree`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct WidgetNode {
+    pub id: Id,
+
+    /// The [`crate::Ui`] this was added to, or `None` for the top [`crate::Ui`] of an area, panel or window.
+    pub parent: Option<Id>,
+
+    pub layer_id: LayerId,
+
+    /// For widgets: the interact rectangle.
+    /// For a [`crate::Ui`]: the max rectangle it was given.
+    pub rect: Rect,
+
+    /// Is this a [`crate::Ui`] rather than a widget?
+    pub is_ui: bool,
+
+    /// Set for widgets that describe themselves with [`crate::Response::widget_info`].
+    pub info: Option<WidgetInfo>,
+}
+
+/// All widgets and [`crate::Ui`]s created during a frame, in the order they were created.
+///
+/// Only recorded if `Memory::options.record_widget_tree` is set.
+/// See [`crate::Context::frame_widget_tree`].
+///
+/// Note that sibling [`crate::Ui`]s often share the same [`Id`] (e.g. two `ui.horizontal` in a row),
+/// in which case they are merged into one node.
+#[derive(Clone, Debug, Default)]
+pub struct WidgetTree {
+    nodes: Vec<WidgetNode>,
+    index: IdMap<usize>,
+}
+
+impl WidgetTree {
+    /// All nodes, in the order they were created.
+    pub fn nodes(&self) -> &[WidgetNode] {
+        &self.nodes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn get(&self, id: Id) -> Option<&WidgetNode> {
+        self.index.get(&id).map(|&index| &self.nodes[index])
+    }
+
+    /// The top [`crate::Ui`]s of all areas, panels and windows.
+    pub fn roots(&self) -> impl Iterator<Item = &WidgetNode> {
+        self.nodes.iter().filter(|node| node.parent.is_none())
+    }
+
+    /// The widgets and [`crate::Ui`]s added directly to the [`crate::Ui`] with the given id.
+    pub fn children(&self, id: Id) -> impl Iterator<Item = &WidgetNode> {
+        self.nodes
+            .iter()
+            .filter(move |node| node.parent == Some(id) && node.id != id)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.nodes.clear();
+        self.index.clear();
+    }
+
+    pub(crate) fn add_ui(&mut self, id: Id, parent: Option<Id>, layer_id: LayerId, rect: Rect) {
+        if !self.index.contains_key(&id) {
+            self.push(WidgetNode {
+                id,
+                parent,
+                layer_id,
+                rect,
+                is_ui: true,
+                info: None,
+            });
+        }
+    }
+
+    pub(crate) fn add_widget(&mut self, id: Id, parent: Id, layer_id: LayerId, rect: Rect) {
+        if let Some(&index) = self.index.get(&id) {
+            // Interacted with more than once; the latest rectangle wins.
+            self.nodes[index].rect = rect;
+        } else {
+            self.push(WidgetNode {
+                id,
+                parent: Some(parent),
+                layer_id,
+                rect,
+                is_ui: false,
+                info: None,
+            });
+        }
+    }
+
+    pub(crate) fn set_info(&mut self, id: Id, info: WidgetInfo) {
+        if let Some(&index) = self.index.get(&id) {
+            self.nodes[index].info = Some(info);
+        }
+    }
+
+    fn push(&mut self, node: WidgetNode) {
+        self.index.insert(node.id, self.nodes.len());
+        self.nodes.push(node);
+    }
+
+    /// Show the tree in the [`crate::Context::inspection_ui`].
+    /// Hover a node to highlight it on screen.
+    pub fn ui(&self, ui: &mut crate::Ui) {
+        for root in self.roots() {
+            self.node_ui(ui, root);
+        }
+    }
+
+    fn node_ui(&self, ui: &mut crate::Ui, node: &WidgetNode) {
+        let description = match &node.info {
+            Some(info) => format!(
+                "{:?} {}",
+                info.typ,
+                info.label.as_deref().unwrap_or_default()
+            ),
+            None if node.is_ui => "Ui".to_owned(),
+            None => "Widget".to_owned(),
+        };
+        let text = format!("{} {}", description, node.id.short_debug_format());
+
+        let response = if self.children(node.id).next().is_some() {
+            crate::CollapsingHeader::new(text)
+                .id_source(node.id)
+                .show(ui, |ui| {
+                    for child in self.children(node.id) {
+                        self.node_ui(ui, child);
+                    }
+                })
+                .header_response
+        } else {
+            ui.label(text)
+        };
+
+        if response.hovered() {
+            ui.ctx().debug_painter().rect_stroke(
+                node.rect,
+                0.0,
+                (1.0, crate::Color32::from_rgb(255, 0, 255)),
+            );
+        }
+
+        let locations = node.id.source_locations();
+        if !locations.is_empty() {
+            response.on_hover_ui(|ui| {
+                ui.label("Id created at:");
+                for location in locations {
+                    ui.monospace(format!("{}:{}", location.file(), location.line()));
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_widget_tree() {
+    let mut ctx = crate::CtxRef::default();
+    ctx.memory().options.record_widget_tree = true;
+    let _ = ctx.run(Default::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let _ = ui.button("Inner");
+            });
+            let _ = ui.button("Outer");
+        });
+    });
+
+    let tree = ctx.frame_widget_tree();
+    let button = |label: &str| {
+        tree.nodes()
+            .iter()
+            .find(|node| node.info.as_ref().and_then(|info| info.label.as_deref()) == Some(label))
+            .unwrap()
+    };
+    let inner = button("Inner");
+    let outer = button("Outer");
+
+    let root = tree.get(outer.parent.unwrap()).unwrap();
+    assert!(root.is_ui);
+    let horizontal = tree.get(inner.parent.unwrap()).unwrap();
+    assert!(horizontal.is_ui);
+    assert_eq!(horizontal.parent, Some(root.id));
+    assert!(tree.children(horizontal.id).any(|node| node.id == inner.id));
+}
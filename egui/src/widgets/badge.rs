@@ -0,0 +1,151 @@
+use crate::*;
+
+/// What a [`Badge`] shows.
+#[derive(Clone, Debug, PartialEq)]
+enum Content {
+    Nothing,
+    Dot,
+    Text(String),
+}
+
+/// A small dot or counter on the corner of a widget, e.g. the number of unread messages on a tab.
+///
+/// The badge is painted on top of everything else in the same layer (see [`Painter::overlay`]),
+/// so it is not covered by the widgets that come after it, and it is clipped like the rest of the [`Ui`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let num_unread = 3;
+/// let response = ui.button("Inbox");
+/// egui::Badge::count(num_unread).show(ui, &response);
+///
+/// let response = ui.button("Settings");
+/// egui::Badge::dot().show(ui, &response);
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct Badge {
+    content: Content,
+    fill: Option<Color32>,
+    corner: Align2,
+}
+
+impl Badge {
+    /// Larger counts are shown as `99+`.
+    const MAX_COUNT: usize = 99;
+
+    fn new(content: Content) -> Self {
+        Self {
+            content,
+            fill: None,
+            corner: Align2::RIGHT_TOP,
+        }
+    }
+
+    /// A dot without any text.
+    pub fn dot() -> Self {
+        Self::new(Content::Dot)
+    }
+
+    /// A number, e.g. of unread messages. Nothing is shown if the count is zero.
+    pub fn count(count: usize) -> Self {
+        Self::new(if count == 0 {
+            Content::Nothing
+        } else if count > Self::MAX_COUNT {
+            Content::Text(format!("{}+", Self::MAX_COUNT))
+        } else {
+            Content::Text(count.to_string())
+        })
+    }
+
+    /// A short text, e.g. "new".
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn text(text: impl ToString) -> Self {
+        Self::new(Content::Text(text.to_string()))
+    }
+
+    /// Background color of the badge. Default: [`Visuals::error_fg_color`].
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    /// Which corner of the widget to show the badge on. Default: [`Align2::RIGHT_TOP`].
+    pub fn corner(mut self, corner: Align2) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Show the badge centered on a corner of `response.rect`.
+    pub fn show(self, ui: &Ui, response: &Response) {
+        let Self {
+            content,
+            fill,
+            corner,
+        } = self;
+
+        let center = corner.pos_in_rect(&response.rect);
+        let fill = fill.unwrap_or(ui.visuals().error_fg_color);
+        // A ring in the background color, to separate the badge from the widget:
+        let ring = Stroke::new(1.0, ui.visuals().window_fill());
+        let painter = ui.painter().overlay();
+
+        match content {
+            Content::Nothing => {}
+            Content::Dot => {
+                painter.circle(center, 4.0, fill, ring);
+            }
+            Content::Text(text) => {
+                let galley = ui.fonts().layout_no_wrap(
+                    text,
+                    TextStyle::Small,
+                    super::color_picker::contrast_color(fill),
+                );
+                let height = galley.size().y + 2.0;
+                let width = (galley.size().x + height * 0.5).at_least(height);
+                let rect = Rect::from_center_size(center, vec2(width, height));
+                if ui.is_rect_visible(rect) {
+                    painter.rect(rect, 0.5 * height, fill, ring);
+                    painter.galley(rect.center() - 0.5 * galley.size(), galley);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_badge_is_painted_over_later_widgets() {
+    use epaint::ClippedShape;
+
+    let mut ctx = CtxRef::default();
+    let (_, shapes) = ctx.run(Default::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            let response = ui.button("Inbox");
+            Badge::count(3).show(ui, &response);
+            Badge::count(0).show(ui, &response);
+            ui.painter()
+                .rect_filled(response.rect.expand(10.0), 0.0, Color32::GREEN);
+        });
+    });
+
+    let index_of = |predicate: &dyn Fn(&Shape) -> bool| {
+        shapes
+            .iter()
+            .position(|ClippedShape(_, shape)| predicate(shape))
+            .unwrap()
+    };
+    let later_widget =
+        index_of(&|shape| matches!(shape, Shape::Rect(rect) if rect.fill == Color32::GREEN));
+    let badge_text =
+        index_of(&|shape| matches!(shape, Shape::Text(text) if text.galley.text() == "3"));
+    assert!(badge_text > later_widget);
+    assert_eq!(
+        shapes.len(),
+        later_widget + 3,
+        "the badge (background and text) comes last, and a count of zero shows nothing"
+    );
+
+    assert_eq!(Badge::count(1000).content, Content::Text("99+".to_owned()));
+}
@@ -30,6 +30,8 @@ pub struct Button {
     frame: Option<bool>,
     min_size: Vec2,
     image: Option<widgets::Image>,
+    shortcut: Option<KeyboardShortcut>,
+    shortcut_text: WidgetText,
 }
 
 impl Button {
@@ -44,6 +46,8 @@ impl Button {
             frame: None,
             min_size: Vec2::ZERO,
             image: None,
+            shortcut: None,
+            shortcut_text: Default::default(),
         }
     }
 
@@ -64,6 +68,8 @@ impl Button {
             wrap: None,
             min_size: Vec2::ZERO,
             image: Some(widgets::Image::new(texture_id, size)),
+            shortcut: None,
+            shortcut_text: Default::default(),
         }
     }
 
@@ -116,6 +122,28 @@ impl Button {
         self
     }
 
+    /// Show some text on the right side of the button, in weak color.
+    ///
+    /// Designed for menu buttons, for showing a keyboard shortcut (e.g. `Ctrl+S`).
+    /// See also [`Self::shortcut`].
+    pub fn shortcut_text(mut self, shortcut_text: impl Into<WidgetText>) -> Self {
+        self.shortcut_text = shortcut_text.into();
+        self
+    }
+
+    /// Also click this button when the given keyboard shortcut is pressed.
+    ///
+    /// The shortcut is shown on the right side of the button (see [`Self::shortcut_text`])
+    /// using [`Context::format_shortcut`].
+    ///
+    /// The shortcut only works while the button is shown: the contents of a closed [`crate::menu`] are not run.
+    /// See the [`crate::menu`] docs for how to handle a shortcut while its menu is closed.
+    /// The shortcut is also listed in the [`crate::ShortcutCheatSheet`] (see [`Context::register_shortcut`]).
+    pub fn shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
     pub(crate) fn min_size(mut self, min_size: Vec2) -> Self {
         self.min_size = min_size;
         self
@@ -134,6 +162,8 @@ impl Widget for Button {
             frame,
             min_size,
             image,
+            shortcut,
+            mut shortcut_text,
         } = self;
 
         let frame = frame.unwrap_or_else(|| ui.visuals().button_frame);
@@ -144,10 +174,22 @@ impl Widget for Button {
         }
         let total_extra = button_padding + button_padding;
 
-        let wrap_width = ui.available_width() - total_extra.x;
+        if shortcut_text.is_empty() {
+            if let Some(shortcut) = &shortcut {
+                shortcut_text = ui.ctx().format_shortcut(shortcut).into();
+            }
+        }
+        let shortcut_text = (!shortcut_text.is_empty())
+            .then(|| shortcut_text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button));
+        let shortcut_width = shortcut_text.as_ref().map_or(0.0, |shortcut_text| {
+            ui.spacing().item_spacing.x + shortcut_text.size().x
+        });
+
+        let wrap_width = ui.available_width() - total_extra.x - shortcut_width;
         let text = text.into_galley(ui, wrap, wrap_width, TextStyle::Button);
 
         let mut desired_size = text.size() + 2.0 * button_padding;
+        desired_size.x += shortcut_width;
         if !small {
             desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
         }
@@ -158,7 +200,36 @@ impl Widget for Button {
             desired_size.y = desired_size.y.max(image.size().y + 2.0 * button_padding.y);
         }
 
-        let (rect, response) = ui.allocate_at_least(desired_size, sense);
+        let (rect, mut response) = if ui.layout().baseline_align() {
+            let text_top = if image.is_some() {
+                0.5 * (desired_size.y - text.size().y)
+            } else {
+                let inner_rect =
+                    Rect::from_min_size(Pos2::ZERO, desired_size).shrink2(button_padding);
+                ui.layout()
+                    .align_size_within_rect(text.size(), inner_rect)
+                    .min
+                    .y
+            };
+            ui.allocate_exact_size_with_baseline(
+                desired_size,
+                text_top + text.baseline(ui.fonts()),
+                sense,
+            )
+        } else {
+            ui.allocate_at_least(desired_size, sense)
+        };
+        if let Some(shortcut) = &shortcut {
+            let category = if ui.get_menu_state().is_some() {
+                "Menu"
+            } else {
+                "General"
+            };
+            ui.ctx().register_shortcut(*shortcut, category, text.text());
+            if ui.is_enabled() && ui.ctx().consume_shortcut(shortcut) {
+                response.clicked[PointerButton::Primary as usize] = true;
+            }
+        }
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, text.text()));
 
         if ui.is_rect_visible(rect) {
@@ -187,6 +258,17 @@ impl Widget for Button {
             }
 
             text.paint_with_visuals(ui.painter(), text_pos, visuals);
+
+            if let Some(shortcut_text) = shortcut_text {
+                let shortcut_text_pos = Align2::RIGHT_CENTER
+                    .align_size_within_rect(shortcut_text.size(), rect.shrink2(button_padding))
+                    .min;
+                shortcut_text.paint_with_fallback_color(
+                    ui.painter(),
+                    shortcut_text_pos,
+                    ui.visuals().weak_text_color(),
+                );
+            }
         }
 
         if let Some(image) = image {
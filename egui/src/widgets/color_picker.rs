@@ -4,7 +4,7 @@ use crate::util::fixed_cache::FixedCache;
 use crate::*;
 use epaint::{color::*, *};
 
-fn contrast_color(color: impl Into<Rgba>) -> Color32 {
+pub(crate) fn contrast_color(color: impl Into<Rgba>) -> Color32 {
     if color.into().intensity() < 0.5 {
         Color32::WHITE
     } else {
@@ -207,6 +207,15 @@ fn color_slider_2d(
     response
 }
 
+/// A small square of the given color, for copying to the clipboard.
+fn swatch_image(color: Color32) -> ClipboardImage {
+    const SIZE: usize = 32;
+    ClipboardImage {
+        size: [SIZE, SIZE],
+        rgba: color.to_srgba_unmultiplied().repeat(SIZE * SIZE),
+    }
+}
+
 /// What options to show for alpha
 #[derive(Clone, Copy, PartialEq)]
 pub enum Alpha {
@@ -223,13 +232,24 @@ fn color_text_ui(ui: &mut Ui, color: impl Into<Color32>, alpha: Alpha) {
     ui.horizontal(|ui| {
         let [r, g, b, a] = color.to_array();
 
-        if ui.button("📋").on_hover_text("Click to copy").clicked() {
+        let response = ui
+            .button("📋")
+            .on_hover_text("Click to copy\nRight-click to copy as an image");
+        if response.clicked() {
             if alpha == Alpha::Opaque {
-                ui.output().copied_text = format!("{}, {}, {}", r, g, b);
+                ui.output().copy_text(format!("{}, {}, {}", r, g, b));
             } else {
-                ui.output().copied_text = format!("{}, {}, {}, {}", r, g, b, a);
+                ui.output().copy_text(format!("{}, {}, {}, {}", r, g, b, a));
             }
         }
+        if response.secondary_clicked() {
+            let color = if alpha == Alpha::Opaque {
+                color.to_opaque()
+            } else {
+                color
+            };
+            ui.output().copied = Some(ClipboardData::Image(swatch_image(color)));
+        }
 
         if alpha == Alpha::Opaque {
             ui.label(format!("rgb({}, {}, {})", r, g, b))
@@ -241,11 +261,470 @@ fn color_text_ui(ui: &mut Ui, color: impl Into<Color32>, alpha: Alpha) {
     });
 }
 
+/// Parses `#rrggbb` and `#rrggbbaa` (unmultiplied alpha), and `r, g, b` and `r, g, b, a` (premultiplied alpha)
+/// as copied by the color pickers, with the numbers in `0..=255`.
+fn parse_color(text: &str) -> Option<Color32> {
+    let text = text.trim();
+    if let Some(hex) = text.strip_prefix('#') {
+        let byte = |i: usize| u8::from_str_radix(hex.get(2 * i..2 * i + 2)?, 16).ok();
+        match hex.len() {
+            6 => Some(Color32::from_rgb(byte(0)?, byte(1)?, byte(2)?)),
+            8 => Some(Color32::from_rgba_unmultiplied(
+                byte(0)?,
+                byte(1)?,
+                byte(2)?,
+                byte(3)?,
+            )),
+            _ => None,
+        }
+    } else {
+        let text = text
+            .trim_start_matches("rgba(")
+            .trim_start_matches("rgb(")
+            .trim_end_matches(')');
+        let numbers = text
+            .split(',')
+            .map(|number| number.trim().parse().ok())
+            .collect::<Option<Vec<u8>>>()?;
+        match numbers[..] {
+            [r, g, b] => Some(Color32::from_rgb(r, g, b)),
+            [r, g, b, a] => Some(Color32::from_rgba_premultiplied(r, g, b, a)),
+            _ => None,
+        }
+    }
+}
+
+/// Text and number fields for the color in hex, RGB (0-255) and HSV.
+fn color_values_ui(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
+    let additive = hsva.a < 0.0;
+    let with_alpha = alpha != Alpha::Opaque && !additive;
+    let [r, g, b, _] = Color32::from(HsvaGamma { a: 1.0, ..*hsva }).to_array();
+    let a = (hsva.a.max(0.0) * 255.0).round() as u8;
+
+    Grid::new("color_values").show(ui, |ui| {
+        ui.label("Hex:");
+        let hex = if with_alpha {
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+        } else {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        };
+        let id = ui.auto_id_with("hex");
+        let mut text = ui.memory().data_temp.get_temp(id).unwrap_or(hex);
+        let invalid = parse_color(&text).is_none();
+        let response = ui.add(
+            TextEdit::singleline(&mut text)
+                .id(id)
+                .desired_width(ui.spacing().slider_width - 16.0)
+                .text_color_opt(invalid.then(|| ui.visuals().error_fg_color)),
+        );
+        let response =
+            response.on_hover_text("#rrggbb or r, g, b.\nPasting a copied color works too.");
+        if response.changed() {
+            if let Some(color) = parse_color(&text) {
+                let mut new_hsva = HsvaGamma::from(color);
+                if !with_alpha {
+                    new_hsva.a = hsva.a;
+                }
+                *hsva = new_hsva;
+            }
+        }
+        if response.has_focus() {
+            ui.memory().data_temp.insert_temp(id, text);
+        } else {
+            ui.memory().data_temp.remove::<String>(id);
+        }
+        ui.end_row();
+
+        ui.label("RGB:");
+        ui.horizontal(|ui| {
+            let mut rgb = [r, g, b];
+            let mut changed = false;
+            for value in &mut rgb {
+                changed |= ui.add(DragValue::new(value)).changed();
+            }
+            if changed {
+                let new_hsva = HsvaGamma::from(Color32::from_rgb(rgb[0], rgb[1], rgb[2]));
+                *hsva = HsvaGamma {
+                    a: hsva.a,
+                    ..new_hsva
+                };
+            }
+            if with_alpha {
+                let mut a = a;
+                if ui
+                    .add(DragValue::new(&mut a))
+                    .on_hover_text("Alpha")
+                    .changed()
+                {
+                    hsva.a = a as f32 / 255.0;
+                }
+            }
+        });
+        ui.end_row();
+
+        ui.label("HSV:");
+        ui.horizontal(|ui| {
+            let mut h = hsva.h * 360.0;
+            if ui
+                .add(DragValue::new(&mut h).clamp_range(0.0..=360.0).suffix("°"))
+                .changed()
+            {
+                hsva.h = h / 360.0;
+            }
+            for value in [&mut hsva.s, &mut hsva.v] {
+                let mut percent = *value * 100.0;
+                if ui
+                    .add(
+                        DragValue::new(&mut percent)
+                            .clamp_range(0.0..=100.0)
+                            .suffix("%"),
+                    )
+                    .changed()
+                {
+                    *value = percent / 100.0;
+                }
+            }
+        });
+        ui.end_row();
+    });
+}
+
+/// Recently used and favorite colors, shared by all color pickers.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct ColorSwatches {
+    recent: Vec<Color32>,
+    favorites: Vec<Color32>,
+}
+
+impl ColorSwatches {
+    const MAX_RECENT: usize = 8;
+
+    fn id() -> Id {
+        Id::new("color_picker_swatches")
+    }
+
+    fn load(ctx: &Context) -> Self {
+        ctx.memory()
+            .data_persisted
+            .get_persisted(Self::id())
+            .unwrap_or_default()
+    }
+
+    fn store(self, ctx: &Context) {
+        ctx.memory()
+            .data_persisted
+            .insert_persisted(Self::id(), self);
+    }
+
+    fn add_recent(ctx: &Context, color: Color32) {
+        let mut swatches = Self::load(ctx);
+        swatches.recent.retain(|&recent| recent != color);
+        swatches.recent.insert(0, color);
+        swatches.recent.truncate(Self::MAX_RECENT);
+        swatches.store(ctx);
+    }
+}
+
+/// The favorite and recently used colors. Click one to pick it.
+fn swatches_ui(ui: &mut Ui, hsva: &mut HsvaGamma) {
+    let mut swatches = ColorSwatches::load(ui.ctx());
+    let current = Color32::from(*hsva);
+    let size = Vec2::splat(ui.spacing().interact_size.y);
+    let mut picked = None;
+    let mut changed = false;
+
+    ui.horizontal_wrapped(|ui| {
+        eyedropper_ui(ui, hsva);
+
+        let is_favorite = swatches.favorites.contains(&current);
+        let response = ui
+            .selectable_label(is_favorite, "★")
+            .on_hover_text("Add the selected color to the favorites");
+        if response.clicked() {
+            if is_favorite {
+                swatches.favorites.retain(|&favorite| favorite != current);
+            } else {
+                swatches.favorites.push(current);
+            }
+            changed = true;
+        }
+
+        let mut removed = None;
+        for &color in &swatches.favorites {
+            let response = show_color(ui, color, size)
+                .interact(Sense::click())
+                .on_hover_text("Right-click to remove");
+            if response.clicked() {
+                picked = Some(color);
+            }
+            if response.secondary_clicked() {
+                removed = Some(color);
+            }
+        }
+        if let Some(removed) = removed {
+            swatches.favorites.retain(|&favorite| favorite != removed);
+            changed = true;
+        }
+    });
+
+    if !swatches.recent.is_empty() {
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Recent:");
+            for &color in &swatches.recent {
+                if show_color(ui, color, size)
+                    .interact(Sense::click())
+                    .clicked()
+                {
+                    picked = Some(color);
+                }
+            }
+        });
+    }
+
+    if let Some(color) = picked {
+        *hsva = HsvaGamma::from(color);
+    }
+    if changed {
+        swatches.store(ui.ctx());
+    }
+}
+
+/// Picking a color from anywhere on the screen. Only one eyedropper is active at a time.
+#[derive(Clone, Copy, Debug)]
+struct Eyedropper {
+    /// The color picker that started the eyedropper, and gets the picked color.
+    picker_id: Id,
+
+    /// The part of the screen we asked the integration to capture last frame.
+    requested_rect: Option<Rect>,
+
+    /// Has the integration sent us any pixels?
+    /// If not, we use the color of the egui shapes under the pointer instead.
+    has_pixels: bool,
+
+    /// The color under the pointer.
+    color: Option<Color32>,
+}
+
+impl Eyedropper {
+    fn id() -> Id {
+        Id::new("color_picker_eyedropper")
+    }
+
+    fn snapshot_id() -> Id {
+        Self::id().with("snapshot")
+    }
+
+    fn shapes_color_id() -> Id {
+        Self::id().with("shapes_color")
+    }
+
+    fn load(ctx: &Context) -> Option<Self> {
+        ctx.memory().data_temp.get_temp(Self::id())
+    }
+
+    fn store(eyedropper: Option<Self>, ctx: &Context) {
+        let mut memory = ctx.memory();
+        if let Some(eyedropper) = eyedropper {
+            memory.data_temp.insert_temp(Self::id(), eyedropper);
+        } else {
+            memory.data_temp.remove::<Self>(Self::id());
+            memory
+                .data_temp
+                .remove::<std::sync::Arc<WidgetSnapshot>>(Self::snapshot_id());
+            memory.data_temp.remove::<Color32>(Self::shapes_color_id());
+        }
+    }
+}
+
+/// The color of the shapes painted at `pos`, blended together.
+///
+/// Called at the end of the frame when the eyedropper is active,
+/// so we can pick colors even if the integration doesn't support [`Response::snapshot`].
+pub(crate) fn store_shapes_color_at(ctx: &Context, shapes: &[ClippedShape], pos: Pos2) {
+    let mut color: Option<Rgba> = None;
+    for ClippedShape(clip_rect, shape) in shapes {
+        if clip_rect.contains(pos) {
+            blend_shape_color_at(&mut color, shape, pos);
+        }
+    }
+    if let Some(color) = color {
+        ctx.memory()
+            .data_temp
+            .insert_temp(Eyedropper::shapes_color_id(), Color32::from(color));
+    }
+}
+
+/// Blends the fill of `shape` at `pos` (if any) on top of `color`.
+/// Strokes and text are too thin to be worth picking, and are ignored.
+fn blend_shape_color_at(color: &mut Option<Rgba>, shape: &Shape, pos: Pos2) {
+    let fill = match shape {
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                blend_shape_color_at(color, shape, pos);
+            }
+            return;
+        }
+        Shape::Rect(rect) => (rect.fill.a() > 0
+            && HitShape::rounded_rect(rect.rect, rect.corner_radius).contains(pos))
+        .then(|| Rgba::from(rect.fill)),
+        Shape::Circle(circle) => (circle.center.distance(pos) <= circle.radius
+            && circle.fill.a() > 0)
+            .then(|| Rgba::from(circle.fill)),
+        Shape::Path(path) => (path.closed
+            && path.fill.a() > 0
+            && crate::hit_shape::polygon_contains(&path.points, pos))
+        .then(|| Rgba::from(path.fill)),
+        Shape::Mesh(mesh) if mesh.texture_id == TextureId::Egui => mesh_color_at(mesh, pos),
+        _ => None,
+    };
+    if let Some(fill) = fill {
+        let below = color.unwrap_or(Rgba::TRANSPARENT);
+        *color = Some(fill + below * (1.0 - fill.a()));
+    }
+}
+
+/// The vertex color interpolated at `pos`, from the last triangle containing it.
+fn mesh_color_at(mesh: &Mesh, pos: Pos2) -> Option<Rgba> {
+    let mut color = None;
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| &mesh.vertices[triangle[i] as usize]);
+        let (ab, ac, ap) = (b.pos - a.pos, c.pos - a.pos, pos - a.pos);
+        let det = ab.x * ac.y - ab.y * ac.x;
+        if det == 0.0 {
+            continue;
+        }
+        let u = (ap.x * ac.y - ap.y * ac.x) / det;
+        let v = (ab.x * ap.y - ab.y * ap.x) / det;
+        if u >= 0.0 && v >= 0.0 && u + v <= 1.0 {
+            color = Some(
+                Rgba::from(a.color) * (1.0 - u - v)
+                    + Rgba::from(b.color) * u
+                    + Rgba::from(c.color) * v,
+            );
+        }
+    }
+    color
+}
+
+/// The pixel in the center of a snapshot.
+fn snapshot_center_color(snapshot: &WidgetSnapshot) -> Option<Color32> {
+    let [width, height] = snapshot.size;
+    snapshot.pixels.get(height / 2 * width + width / 2).copied()
+}
+
+/// A toggle to pick the color from anywhere on the screen.
+///
+/// Uses the pixels captured by the integration with a [`crate::output::SnapshotRequest`],
+/// or the color of what egui painted if the integration doesn't support that.
+fn eyedropper_ui(ui: &mut Ui, hsva: &mut HsvaGamma) {
+    let ctx = ui.ctx().clone();
+    let picker_id = ui.auto_id_with("eyedropper");
+    let eyedropper = Eyedropper::load(&ctx).filter(|e| e.picker_id == picker_id);
+
+    let response = ui
+        .selectable_label(eyedropper.is_some(), "💧")
+        .on_hover_text("Pick a color from the screen");
+    if response.clicked() && eyedropper.is_none() {
+        let eyedropper = Eyedropper {
+            picker_id,
+            requested_rect: None,
+            has_pixels: false,
+            color: None,
+        };
+        Eyedropper::store(Some(eyedropper), &ctx);
+        ctx.request_repaint();
+        return;
+    }
+    let mut eyedropper = if let Some(eyedropper) = eyedropper {
+        eyedropper
+    } else {
+        return;
+    };
+
+    // The pixels we asked for last frame:
+    let snapshot = ctx
+        .memory()
+        .data_temp
+        .get_temp::<std::sync::Arc<WidgetSnapshot>>(Eyedropper::snapshot_id());
+    if let (Some(snapshot), Some(_)) = (snapshot, eyedropper.requested_rect) {
+        eyedropper.has_pixels = true;
+        eyedropper.color = snapshot_center_color(&snapshot);
+        ctx.memory()
+            .data_temp
+            .remove::<std::sync::Arc<WidgetSnapshot>>(Eyedropper::snapshot_id());
+    }
+    if !eyedropper.has_pixels {
+        let shapes_color = ctx
+            .memory()
+            .data_temp
+            .get_temp::<Color32>(Eyedropper::shapes_color_id());
+        eyedropper.color = shapes_color;
+        ctx.memory()
+            .data_temp
+            .remove::<Color32>(Eyedropper::shapes_color_id());
+    }
+
+    let screen_rect = ctx.input().screen_rect();
+    let pointer_pos = ctx.input().pointer.hover_pos();
+    eyedropper.requested_rect = None;
+    if let Some(pos) = pointer_pos {
+        let rect = Rect::from_center_size(pos, Vec2::splat(1.0)).intersect(screen_rect);
+        if rect.is_positive() {
+            ctx.output()
+                .snapshot_requests
+                .push(crate::output::SnapshotRequest {
+                    id: Eyedropper::snapshot_id(),
+                    rect,
+                });
+            eyedropper.requested_rect = Some(rect);
+        }
+        if !eyedropper.has_pixels {
+            ctx.frame_state().eyedropper_pos = Some(pos);
+        }
+    }
+    ctx.request_repaint();
+
+    // Cover the screen, so the click picks a color instead of hitting a widget:
+    let overlay = Area::new(Eyedropper::id())
+        .order(Order::Foreground)
+        .fixed_pos(screen_rect.min)
+        .show(&ctx, |ui| {
+            let response = ui.allocate_rect(screen_rect, Sense::click());
+            if let (Some(pos), Some(color)) = (pointer_pos, eyedropper.color) {
+                // Not under the pointer, or we would pick the preview:
+                let size = ui.spacing().interact_size.y;
+                let preview = Rect::from_min_size(pos + Vec2::splat(0.5 * size), Vec2::splat(size));
+                let painter = ui.painter();
+                background_checkers(painter, preview);
+                painter.rect_filled(preview, 0.0, color);
+                painter.rect_stroke(preview, 0.0, ui.visuals().window_stroke());
+            }
+            response
+        })
+        .inner;
+    ctx.output().cursor_icon = CursorIcon::Crosshair;
+
+    if overlay.clicked() {
+        if let Some(color) = eyedropper.color {
+            *hsva = HsvaGamma::from(color);
+        }
+        Eyedropper::store(None, &ctx);
+    } else if overlay.secondary_clicked() || ui.input().key_pressed(Key::Escape) {
+        Eyedropper::store(None, &ctx);
+    } else {
+        Eyedropper::store(Some(eyedropper), &ctx);
+    }
+}
+
 fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
     let current_color_size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
     show_color(ui, *hsva, current_color_size).on_hover_text("Selected color");
 
     color_text_ui(ui, *hsva, alpha);
+    color_values_ui(ui, hsva, alpha);
+    swatches_ui(ui, hsva);
 
     if alpha == Alpha::BlendOrAdditive {
         // We signal additive blending by storing a negative alpha (a bit ironic).
@@ -334,9 +813,21 @@ pub fn color_picker_color32(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> b
     changed
 }
 
+/// Shows a color picker where the user can change the given color.
+///
+/// Returns `true` on change.
+pub fn color_picker_rgba(ui: &mut Ui, rgba: &mut Rgba, alpha: Alpha) -> bool {
+    let mut hsva = color_cache_get(ui.ctx(), *rgba);
+    let changed = color_picker_hsva_2d(ui, &mut hsva, alpha);
+    *rgba = Rgba::from(hsva);
+    color_cache_set(ui.ctx(), *rgba, hsva);
+    changed
+}
+
 pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Response {
     let pupup_id = ui.auto_id_with("popup");
     let open = ui.memory().is_popup_open(pupup_id);
+    let eyedropper_active = Eyedropper::load(ui.ctx()).is_some();
     let mut button_response = color_button(ui, (*hsva).into(), open);
     if ui.style().explanation_tooltips {
         button_response = button_response.on_hover_text("Click to edit color");
@@ -344,6 +835,9 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
 
     if button_response.clicked() {
         ui.memory().toggle_popup(pupup_id);
+        if open {
+            ColorSwatches::add_recent(ui.ctx(), (*hsva).into());
+        }
     }
     // TODO: make it easier to show a temporary popup that closes when you click outside it
     if ui.memory().is_popup_open(pupup_id) {
@@ -361,9 +855,11 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
             .response;
 
         if !button_response.clicked()
-            && (ui.input().key_pressed(Key::Escape) || area_response.clicked_elsewhere())
+            && !eyedropper_active
+            && (ui.input().cancel_pressed() || area_response.clicked_elsewhere())
         {
             ui.memory().close_popup();
+            ColorSwatches::add_recent(ui.ctx(), (*hsva).into());
         }
     }
 
@@ -427,5 +923,92 @@ fn color_cache_set(ctx: &Context, rgba: impl Into<Rgba>, hsva: Hsva) {
 
 // To ensure we keep hue slider when `srgba` is gray we store the full `Hsva` in a cache:
 fn use_color_cache<R>(ctx: &Context, f: impl FnOnce(&mut FixedCache<Rgba, Hsva>) -> R) -> R {
-    f(ctx.memory().data.get_temp_mut_or_default(Id::null()))
+    f(ctx.memory().data_temp.get_temp_mut_or_default(Id::null()))
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_color() {
+    assert_eq!(parse_color("#ff8000"), Some(Color32::from_rgb(255, 128, 0)));
+    assert_eq!(
+        parse_color(" #FF800080 "),
+        Some(Color32::from_rgba_unmultiplied(255, 128, 0, 128))
+    );
+    assert_eq!(
+        parse_color("255, 128, 0"),
+        Some(Color32::from_rgb(255, 128, 0))
+    );
+    assert_eq!(
+        parse_color("rgba(64, 32, 0, 128)"),
+        Some(Color32::from_rgba_premultiplied(64, 32, 0, 128))
+    );
+    assert_eq!(parse_color("#ff80"), None);
+    assert_eq!(parse_color("256, 0, 0"), None);
+    assert_eq!(parse_color("red"), None);
+}
+
+#[cfg(test)]
+#[test]
+fn test_recent_colors() {
+    let ctx = CtxRef::default();
+    for color in [Color32::RED, Color32::GREEN, Color32::RED] {
+        ColorSwatches::add_recent(&ctx, color);
+    }
+    let swatches = ColorSwatches::load(&ctx);
+    assert_eq!(swatches.recent, vec![Color32::RED, Color32::GREEN]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_eyedropper() {
+    use crate::test::Harness;
+
+    let red_rect = Rect::from_min_size(pos2(500.0, 100.0), Vec2::splat(50.0));
+    let mut harness = Harness::with_state(Color32::WHITE, |ctx, color| {
+        CentralPanel::default().show(ctx, |ui| {
+            let painter = ui.painter();
+            painter.rect_filled(red_rect, 4.0, Color32::RED);
+            // Strokes are ignored when picking from the shapes, but not in the pixels:
+            let stroke = Stroke::new(10.0, Color32::BLUE);
+            painter.line_segment([red_rect.left_center(), red_rect.right_center()], stroke);
+            color_picker_color32(ui, color, Alpha::Opaque);
+        });
+    });
+    let active = |harness: &Harness<'_, Color32>| Eyedropper::load(harness.ctx()).is_some();
+
+    harness.click("💧");
+    assert!(active(&harness));
+
+    // Nobody captures the pixels, so the shapes under the pointer are used:
+    harness.push_event(Event::PointerMoved(red_rect.center()));
+    harness.run();
+    harness.run();
+    harness.click_at(red_rect.center());
+    assert!(!active(&harness));
+    assert_eq!(*harness.state(), Color32::RED);
+
+    // The pixels captured by the integration take precedence:
+    harness.click("💧");
+    harness.push_event(Event::PointerMoved(red_rect.center()));
+    harness.run();
+    let request = harness
+        .output()
+        .snapshot_requests
+        .iter()
+        .find(|request| request.id == Eyedropper::snapshot_id())
+        .copied()
+        .unwrap();
+    let image = harness.render();
+    let pos = request.rect.center();
+    let snapshot = WidgetSnapshot {
+        size: [1, 1],
+        pixels_per_point: 1.0,
+        pixels: vec![image.pixels[pos.y as usize * image.width() + pos.x as usize]],
+    };
+    harness.push_event(Event::Snapshot {
+        id: request.id,
+        snapshot: std::sync::Arc::new(snapshot),
+    });
+    harness.click_at(red_rect.center());
+    assert_eq!(*harness.state(), Color32::BLUE);
 }
@@ -6,14 +6,14 @@ use crate::*;
 
 // ----------------------------------------------------------------------------
 
-/// Same state for all [`DragValue`]s.
+/// Same state for all [`DragValue`]s and [`Scrub`]s.
 #[derive(Clone, Debug, Default)]
 pub(crate) struct MonoState {
-    last_dragged_id: Option<Id>,
-    last_dragged_value: Option<f64>,
+    pub(crate) last_dragged_id: Option<Id>,
+    pub(crate) last_dragged_value: Option<f64>,
     /// For temporary edit of a `DragValue` value.
     /// Couples with the current focus id.
-    edit_string: Option<String>,
+    pub(crate) edit_string: Option<String>,
 }
 
 impl MonoState {
@@ -29,18 +29,22 @@ impl MonoState {
 
 /// Combined into one function (rather than two) to make it easier
 /// for the borrow checker.
-type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
+pub(crate) type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
 
-fn get(get_set_value: &mut GetSetValue<'_>) -> f64 {
+pub(crate) fn get(get_set_value: &mut GetSetValue<'_>) -> f64 {
     (get_set_value)(None)
 }
 
-fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
+pub(crate) fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
     (get_set_value)(Some(value));
 }
 
 /// A numeric value that you can change by dragging the number. More compact than a [`Slider`].
 ///
+/// With keyboard focus, the arrow keys change the value by [`Self::speed`] and page up/down by ten times that.
+/// Home/end go to the ends of [`Self::clamp_range`], if it is finite.
+/// The gamepad triggers work like the arrow keys.
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// # let mut my_f32: f32 = 0.0;
@@ -266,12 +270,33 @@ impl<'a> Widget for DragValue<'a> {
                     ui.memory().drag_value = drag_state;
                 }
             } else if response.has_focus() {
-                let change = ui.input().num_presses(Key::ArrowUp) as f64
-                    + ui.input().num_presses(Key::ArrowRight) as f64
-                    - ui.input().num_presses(Key::ArrowDown) as f64
-                    - ui.input().num_presses(Key::ArrowLeft) as f64;
-
-                if change != 0.0 {
+                let (change, home_pressed, end_pressed) = {
+                    let input = ui.input();
+                    let change = input.num_presses(Key::ArrowUp) as f64
+                        + input.num_presses(Key::ArrowRight) as f64
+                        - input.num_presses(Key::ArrowDown) as f64
+                        - input.num_presses(Key::ArrowLeft) as f64
+                        + input.num_gamepad_presses(GamepadButton::RightTrigger) as f64
+                        - input.num_gamepad_presses(GamepadButton::LeftTrigger) as f64
+                        + 10.0 * input.num_presses(Key::PageUp) as f64
+                        - 10.0 * input.num_presses(Key::PageDown) as f64;
+                    (
+                        change,
+                        input.key_pressed(Key::Home),
+                        input.key_pressed(Key::End),
+                    )
+                };
+
+                // Home/End only make sense with a finite clamp range:
+                let (min, max) = (
+                    clamp_range.start().min(*clamp_range.end()),
+                    clamp_range.start().max(*clamp_range.end()),
+                );
+                if home_pressed && min.is_finite() {
+                    set(&mut get_set_value, min);
+                } else if end_pressed && max.is_finite() {
+                    set(&mut get_set_value, max);
+                } else if change != 0.0 {
                     let new_value = value + speed * change;
                     let new_value = emath::round_to_decimals(new_value, auto_decimals);
                     let new_value = clamp_to_range(new_value, clamp_range);
@@ -289,7 +314,7 @@ impl<'a> Widget for DragValue<'a> {
     }
 }
 
-fn clamp_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
+pub(crate) fn clamp_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
     x.clamp(
         range.start().min(*range.end()),
         range.start().max(*range.end()),
@@ -0,0 +1,155 @@
+use epaint::text::cursor::CCursor;
+
+use crate::{
+    widgets::text_edit::{CCursorRange, TextEditState},
+    *,
+};
+
+/// The text being edited, stored while an [`EditableLabel`] is in edit mode.
+#[derive(Clone, Debug)]
+struct EditState {
+    text: String,
+
+    /// Width of the label, so the [`TextEdit`] takes up the same space.
+    width: f32,
+}
+
+/// A label that turns into a single line [`TextEdit`] when double-clicked, e.g. to rename a file or a tab.
+///
+/// The [`TextEdit`] gets the keyboard focus, with all text selected.
+/// Enter or clicking elsewhere commits the new text, Escape cancels the edit.
+///
+/// [`Response::changed`] is only set on the frame the new text is committed,
+/// not while the user is typing.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut file_name = "untitled.txt".to_owned();
+/// if ui.add(egui::EditableLabel::new(&mut file_name)).changed() {
+///     // rename the file
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct EditableLabel<'t> {
+    text: &'t mut String,
+    id_source: Option<Id>,
+}
+
+impl<'t> EditableLabel<'t> {
+    pub fn new(text: &'t mut String) -> Self {
+        Self {
+            text,
+            id_source: None,
+        }
+    }
+
+    /// A source for the unique `Id`, e.g. `.id_source(file_index)`.
+    ///
+    /// Needed if the number of widgets before this one changes while editing.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+}
+
+impl<'t> Widget for EditableLabel<'t> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self { text, id_source } = self;
+
+        let id = match id_source {
+            Some(id_source) => ui.make_persistent_id(id_source).derived_from(id_source),
+            None => ui.next_auto_id(),
+        };
+
+        let edit_state = ui.memory().data_temp.get_temp::<EditState>(id);
+        let mut edit_state = if let Some(edit_state) = edit_state {
+            edit_state
+        } else {
+            let response = ui.add(Label::new(text.as_str()).sense(Sense::click()));
+            if response.double_clicked() {
+                start_editing(ui, id, text, response.rect.width());
+            }
+            return response;
+        };
+
+        let mut response = ui.add(
+            TextEdit::singleline(&mut edit_state.text)
+                .id(id)
+                .desired_width(edit_state.width),
+        );
+        response.changed = false; // Only report the committed text.
+
+        if response.has_focus() {
+            ui.memory().data_temp.insert_temp(id, edit_state);
+        } else {
+            // Enter, Escape, clicking elsewhere, tab etc.
+            // The label comes back next frame:
+            ui.memory().data_temp.remove::<EditState>(id);
+            ui.ctx().request_repaint();
+            let cancelled = ui.input().key_pressed(Key::Escape);
+            if !cancelled && edit_state.text != *text {
+                *text = edit_state.text;
+                response.mark_changed();
+            }
+        }
+
+        response
+    }
+}
+
+fn start_editing(ui: &Ui, id: Id, text: &str, width: f32) {
+    let mut text_edit_state = TextEditState::default();
+    text_edit_state.set_ccursor_range(Some(CCursorRange::two(
+        CCursor::new(0),
+        CCursor::new(text.chars().count()),
+    )));
+    text_edit_state.store(ui.ctx(), id);
+
+    let width = width.at_least(ui.spacing().interact_size.x);
+    let text = text.to_owned();
+    ui.memory()
+        .data_temp
+        .insert_temp(id, EditState { text, width });
+    ui.memory().request_focus(id);
+    ui.ctx().request_repaint();
+}
+
+#[cfg(test)]
+#[test]
+fn test_editable_label() {
+    use crate::test::Harness;
+
+    let mut harness = Harness::with_state(("old".to_owned(), false), |ctx, (name, changed)| {
+        CentralPanel::default().show(ctx, |ui| {
+            *changed = ui.add(EditableLabel::new(name)).changed();
+        });
+    });
+    let double_click = |harness: &mut Harness<'_, (String, bool)>| {
+        harness.click("old");
+        harness.click("old");
+    };
+
+    // Cancel with escape:
+    double_click(&mut harness);
+    harness.type_text("new");
+    assert!(!harness.state().1);
+    harness.press_key(Key::Escape);
+    assert!(!harness.state().1);
+    assert_eq!(harness.state().0, "old");
+    assert!(harness.output().needs_repaint);
+    harness.run();
+    harness.find_label("old");
+
+    // Commit with enter. All text is selected, so typing replaces it:
+    double_click(&mut harness);
+    harness.type_text("new");
+    assert!(!harness.state().1);
+    harness.press_key(Key::Enter);
+    assert!(harness.state().1);
+    assert_eq!(harness.state().0, "new");
+    harness.run();
+    assert!(!harness.state().1);
+    harness.find_label("new");
+}
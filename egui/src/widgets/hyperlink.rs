@@ -2,6 +2,9 @@ use crate::*;
 
 /// A clickable hyperlink, e.g. to `"https://github.com/emilk/egui"`.
 ///
+/// Hovering the link shows the url, and right-clicking it shows a menu for copying the url.
+/// Links that have been clicked are shown in [`Visuals::hyperlink_visited_color`] (see [`Memory::is_url_visited`]).
+///
 /// See also [`Ui::hyperlink`] and [`Ui::hyperlink_to`].
 ///
 /// ```
@@ -19,6 +22,7 @@ use crate::*;
 pub struct Hyperlink {
     url: String,
     text: WidgetText,
+    new_tab: bool,
 }
 
 impl Hyperlink {
@@ -28,6 +32,7 @@ impl Hyperlink {
         Self {
             url: url.clone(),
             text: url.into(),
+            new_tab: false,
         }
     }
 
@@ -36,13 +41,23 @@ impl Hyperlink {
         Self {
             url: url.to_string(),
             text: text.into(),
+            new_tab: false,
         }
     }
+
+    /// Always open this link in a new tab (when in a web browser).
+    ///
+    /// By default, a link opens in the same tab unless a modifier key is held down
+    /// or it is clicked with the middle mouse button.
+    pub fn open_in_new_tab(mut self, new_tab: bool) -> Self {
+        self.new_tab = new_tab;
+        self
+    }
 }
 
 impl Widget for Hyperlink {
     fn ui(self, ui: &mut Ui) -> Response {
-        let Hyperlink { url, text } = self;
+        let Hyperlink { url, text, new_tab } = self;
         let label = Label::new(text).sense(Sense::click());
 
         let (pos, text_galley, response) = label.layout_in_ui(ui);
@@ -51,22 +66,21 @@ impl Widget for Hyperlink {
         if response.hovered() {
             ui.ctx().output().cursor_icon = CursorIcon::PointingHand;
         }
-        if response.clicked() {
-            let modifiers = ui.ctx().input().modifiers;
-            ui.ctx().output().open_url = Some(crate::output::OpenUrl {
-                url: url.clone(),
-                new_tab: modifiers.any(),
-            });
-        }
-        if response.middle_clicked() {
-            ui.ctx().output().open_url = Some(crate::output::OpenUrl {
+        if response.clicked() || response.middle_clicked() {
+            let new_tab = new_tab || response.middle_clicked() || ui.ctx().input().modifiers.any();
+            ui.ctx().output().open_urls.push(crate::output::OpenUrl {
                 url: url.clone(),
-                new_tab: true,
+                new_tab,
             });
+            ui.memory().mark_url_visited(&url);
         }
 
         if ui.is_rect_visible(response.rect) {
-            let color = ui.visuals().hyperlink_color;
+            let color = if ui.memory().is_url_visited(&url) {
+                ui.visuals().hyperlink_visited_color
+            } else {
+                ui.visuals().hyperlink_color
+            };
             let visuals = ui.style().interact(&response);
 
             let underline = if response.hovered() || response.has_focus() {
@@ -84,6 +98,67 @@ impl Widget for Hyperlink {
             });
         }
 
-        response.on_hover_text(url)
+        let context_menu_url = url.clone();
+        response
+            .context_menu(|ui| {
+                if ui.button("Copy link address").clicked() {
+                    ui.output().copy_text(context_menu_url);
+                    ui.close_menu();
+                }
+            })
+            .on_hover_text(url)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_hyperlink_visited_and_context_menu() {
+    use crate::{test::Harness, ClipboardData, Event, Modifiers, PointerButton};
+    use epaint::ClippedShape;
+
+    let url = "https://github.com/emilk/egui";
+    // How many urls were opened, and what was copied:
+    type State = (usize, Option<ClipboardData>);
+    let mut harness = Harness::with_state((0, None), |ctx, (opened, copied): &mut State| {
+        CentralPanel::default().show(ctx, |ui| {
+            ui.hyperlink_to("egui", url);
+        });
+        let output = ctx.output();
+        *opened += output.open_urls.len();
+        if output.copied.is_some() {
+            *copied = output.copied.clone();
+        }
+    });
+    let link_color = |harness: &Harness<'_, State>| {
+        harness
+            .shapes()
+            .iter()
+            .find_map(|ClippedShape(_, shape)| match shape {
+                Shape::Text(text) if text.galley.text() == "egui" => text.override_text_color,
+                _ => None,
+            })
+    };
+
+    let visuals = harness.ctx().style().visuals.clone();
+    assert_eq!(link_color(&harness), Some(visuals.hyperlink_color));
+
+    harness.click("egui");
+    assert_eq!(harness.state().0, 1);
+    assert!(harness.ctx().memory().is_url_visited(url));
+    assert_eq!(link_color(&harness), Some(visuals.hyperlink_visited_color));
+
+    // Right-click to open the context menu, then click "Copy link address":
+    let pos = harness.find_label("egui").rect.center();
+    for pressed in [true, false] {
+        harness.push_event(Event::PointerButton {
+            pos,
+            button: PointerButton::Secondary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        });
+        harness.run();
     }
+    harness.click("Copy link address");
+    assert!(matches!(&harness.state().1, Some(ClipboardData::Text(text)) if text == url));
+    assert_eq!(harness.state().0, 1, "copying doesn't open the link");
 }
@@ -0,0 +1,155 @@
+use crate::*;
+
+/// What a [`Kbd`] shows.
+#[derive(Clone, Debug)]
+enum Keys {
+    Key(String),
+    Shortcut(KeyboardShortcut),
+}
+
+/// Keyboard keys shown as key caps, e.g. in help texts and menus.
+///
+/// [`Kbd::shortcut`] shows a whole [`KeyboardShortcut`] the way it is written on the current OS
+/// (see [`Context::os`]): `Ctrl` + `S`, or `⌘` `S` on Mac.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Kbd, Key, KeyboardShortcut, Modifiers};
+/// ui.horizontal(|ui| {
+///     ui.label("Press");
+///     ui.add(Kbd::new("Esc"));
+///     ui.label("to close, or");
+///     ui.add(Kbd::shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::S)));
+///     ui.label("to save.");
+/// });
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Clone, Debug)]
+pub struct Kbd {
+    keys: Keys,
+}
+
+impl Kbd {
+    /// A single key, e.g. `"Esc"` or `"F1"`.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            keys: Keys::Key(key.into()),
+        }
+    }
+
+    /// One key cap per key of the shortcut, see [`KeyboardShortcut::keys`].
+    pub fn shortcut(shortcut: KeyboardShortcut) -> Self {
+        Self {
+            keys: Keys::Shortcut(shortcut),
+        }
+    }
+}
+
+impl Widget for Kbd {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let is_mac = ui.ctx().os().is_mac();
+        let (keys, text) = match &self.keys {
+            Keys::Key(key) => (vec![key.as_str()], key.clone()),
+            Keys::Shortcut(shortcut) => (shortcut.keys(is_mac), shortcut.format(is_mac)),
+        };
+
+        let text_color = ui.visuals().text_color();
+        let padding = vec2(4.0, 1.0);
+        // The bottom edge of a key cap is a bit thicker, as if the key had some depth:
+        let depth = 2.0;
+
+        let caps: Vec<_> = keys
+            .into_iter()
+            .map(|key| {
+                let galley =
+                    ui.fonts()
+                        .layout_no_wrap(key.to_owned(), TextStyle::Monospace, text_color);
+                let size = galley.size() + 2.0 * padding;
+                // Single characters get square key caps:
+                (galley, vec2(size.x.at_least(size.y), size.y))
+            })
+            .collect();
+        // Mac shortcuts are written without separators:
+        let separator = (!is_mac && caps.len() > 1).then(|| {
+            ui.fonts()
+                .layout_no_wrap("+".to_owned(), TextStyle::Body, text_color)
+        });
+        let spacing = separator
+            .as_ref()
+            .map_or(2.0, |galley| galley.size().x + 4.0);
+
+        let height = caps.iter().map(|(_, size)| size.y).fold(0.0, f32::max) + depth;
+        let width = caps.iter().map(|(_, size)| size.x).sum::<f32>()
+            + spacing * caps.len().saturating_sub(1) as f32;
+        let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, &text));
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let fill = visuals.code_bg_color;
+            let stroke = visuals.widgets.noninteractive.bg_stroke;
+            let corner_radius = 3.0;
+
+            let mut left = rect.left();
+            for (index, (galley, size)) in caps.into_iter().enumerate() {
+                if index > 0 {
+                    if let Some(separator) = &separator {
+                        let pos =
+                            pos2(left + 2.0, rect.top() + (size.y - separator.size().y) / 2.0);
+                        ui.painter().galley(pos, separator.clone());
+                    }
+                    left += spacing;
+                }
+
+                let cap_rect = Rect::from_min_size(pos2(left, rect.top()), size);
+                left += size.x;
+
+                ui.painter().rect(
+                    cap_rect.translate(vec2(0.0, depth)),
+                    corner_radius,
+                    stroke.color,
+                    stroke,
+                );
+                ui.painter().rect(cap_rect, corner_radius, fill, stroke);
+                let text_pos = Align2::CENTER_CENTER
+                    .align_size_within_rect(galley.size(), cap_rect)
+                    .min;
+                ui.painter().galley(text_pos, galley);
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_kbd() {
+    use crate::{Key, Modifiers};
+
+    let shortcut = KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::S);
+    let mut ctx = CtxRef::default();
+    let mut widths = |os| {
+        ctx.set_os(os);
+        let mut widths = vec![];
+        let _ = ctx.run(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                widths.push(ui.add(Kbd::new("S")).rect.width());
+                widths.push(ui.add(Kbd::shortcut(shortcut)).rect.width());
+            });
+        });
+        widths
+    };
+
+    let windows = widths(OperatingSystem::Windows);
+    let mac = widths(OperatingSystem::Mac);
+    assert!(
+        windows[0] < mac[1],
+        "a single key is narrower than a shortcut"
+    );
+    assert!(
+        mac[1] < windows[1],
+        "the mac symbols are shorter than `Ctrl` + `Shift`"
+    );
+}
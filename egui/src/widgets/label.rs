@@ -1,4 +1,8 @@
-use crate::{widget_text::WidgetTextGalley, *};
+use crate::{
+    widget_text::{WidgetTextGalley, WidgetTextJob},
+    *,
+};
+use epaint::text::{Fonts, Galley, LayoutJob};
 
 /// Static text.
 ///
@@ -8,12 +12,14 @@ use crate::{widget_text::WidgetTextGalley, *};
 /// ui.add(egui::Label::new("Equivalent"));
 /// ui.add(egui::Label::new("With Options").wrap(false));
 /// ui.label(egui::RichText::new("With formatting").underline());
+/// ui.add(egui::Label::new("A long text that is cut off if it doesn't fit").truncate(true));
 /// # });
 /// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Label {
     text: WidgetText,
     wrap: Option<bool>,
+    truncate: bool,
     sense: Sense,
 }
 
@@ -22,6 +28,7 @@ impl Label {
         Self {
             text: text.into(),
             wrap: None,
+            truncate: false,
             sense: Sense::focusable_noninteractive(),
         }
     }
@@ -43,6 +50,17 @@ impl Label {
         self
     }
 
+    /// If `true`, text that doesn't fit on the current row is cut off with "…"
+    /// instead of wrapping or making the parent wider.
+    /// The full text is then shown when hovering the label.
+    ///
+    /// The label is always a single row, so this overrides [`Self::wrap`].
+    #[inline]
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
     /// Make the label respond to clicks and/or drags.
     ///
     /// By default, a label is inert and does not respond to click or drags.
@@ -68,23 +86,27 @@ impl Label {
     pub fn layout_in_ui(self, ui: &mut Ui) -> (Pos2, WidgetTextGalley, Response) {
         if let WidgetText::Galley(galley) = self.text {
             // If the user said "use this specific galley", then just use it:
-            let (rect, response) = ui.allocate_exact_size(galley.size(), self.sense);
-            let pos = match galley.job.halign {
-                Align::LEFT => rect.left_top(),
-                Align::Center => rect.center_top(),
-                Align::RIGHT => rect.right_top(),
-            };
             let text_galley = WidgetTextGalley {
                 galley,
                 galley_has_color: true,
             };
+            let (rect, response) = ui.allocate_exact_size_with_baseline(
+                text_galley.size(),
+                text_galley.baseline(ui.fonts()),
+                self.sense,
+            );
+            let pos = match text_galley.galley.job.halign {
+                Align::LEFT => rect.left_top(),
+                Align::Center => rect.center_top(),
+                Align::RIGHT => rect.right_top(),
+            };
             return (pos, text_galley, response);
         }
 
         let valign = ui.layout().vertical_align();
         let mut text_job = self.text.into_text_job(ui.style(), TextStyle::Body, valign);
 
-        let should_wrap = self.wrap.unwrap_or_else(|| ui.wrap_text());
+        let should_wrap = !self.truncate && self.wrap.unwrap_or_else(|| ui.wrap_text());
         let available_width = ui.available_width();
 
         if should_wrap
@@ -139,8 +161,28 @@ impl Label {
                 text_job.job.justify = ui.layout().horizontal_justify();
             };
 
-            let text_galley = text_job.into_galley(ui.fonts());
-            let (rect, response) = ui.allocate_exact_size(text_galley.size(), self.sense);
+            let full_job = self
+                .truncate
+                .then(|| (text_job.job.clone(), text_job.job_has_color));
+            let mut text_galley = text_job.into_galley(ui.fonts());
+            let mut full_text = None;
+            if let Some((full_job, job_has_color)) = full_job {
+                let max_width = ui.available_size_before_wrap().x;
+                if let Some(job) = elide_job(&full_job, &text_galley.galley, ui.fonts(), max_width)
+                {
+                    full_text = Some(full_job.text);
+                    text_galley = WidgetTextJob { job, job_has_color }.into_galley(ui.fonts());
+                }
+            }
+
+            let (rect, mut response) = ui.allocate_exact_size_with_baseline(
+                text_galley.size(),
+                text_galley.baseline(ui.fonts()),
+                self.sense,
+            );
+            if let Some(full_text) = full_text {
+                response = response.on_hover_text(full_text);
+            }
             let pos = match text_galley.galley.job.halign {
                 Align::LEFT => rect.left_top(),
                 Align::Center => rect.center_top(),
@@ -151,6 +193,62 @@ impl Label {
     }
 }
 
+/// Cut off the text of a single-row `galley` so that it fits within `max_width`, ending with "…".
+/// Only the first row is kept.
+///
+/// Returns `None` if the text already fits.
+fn elide_job(job: &LayoutJob, galley: &Galley, fonts: &Fonts, max_width: f32) -> Option<LayoutJob> {
+    const ELLIPSIS: char = '…';
+
+    if !max_width.is_finite() || (galley.rows.len() <= 1 && galley.size().x <= max_width) {
+        return None;
+    }
+
+    let first_row = &galley.rows[0];
+    let ellipsis_width = |section_index: usize| {
+        job.sections.get(section_index).map_or(0.0, |section| {
+            fonts.glyph_width(section.format.style, ELLIPSIS)
+        })
+    };
+    let num_chars = first_row
+        .glyphs
+        .iter()
+        .take_while(|glyph| {
+            glyph.max_x() + ellipsis_width(glyph.section_index as usize) <= max_width
+        })
+        .count();
+    let end = job
+        .text
+        .char_indices()
+        .nth(num_chars)
+        .map_or(job.text.len(), |(index, _)| index);
+    let kept = job.text[..end].trim_end();
+    let end = kept.len();
+
+    let mut elided = LayoutJob {
+        text: format!("{}{}", kept, ELLIPSIS),
+        sections: job
+            .sections
+            .iter()
+            .enumerate()
+            .filter(|(index, section)| *index == 0 || section.byte_range.start < end)
+            .map(|(_, section)| {
+                let mut section = section.clone();
+                section.byte_range =
+                    section.byte_range.start.min(end)..section.byte_range.end.min(end);
+                section
+            })
+            .collect(),
+        wrap_width: f32::INFINITY,
+        ..job.clone()
+    };
+    // The ellipsis gets the format of the text before it:
+    if let Some(last) = elided.sections.last_mut() {
+        last.byte_range.end = elided.text.len();
+    }
+    Some(elided)
+}
+
 impl Widget for Label {
     fn ui(self, ui: &mut Ui) -> Response {
         let (pos, text_galley, response) = self.layout_in_ui(ui);
@@ -183,3 +281,37 @@ impl Widget for Label {
         response
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_label_truncate() {
+    use epaint::ClippedShape;
+
+    let text = "A long text that doesn't fit in the panel at all";
+    let mut ctx = CtxRef::default();
+    let mut widths = vec![];
+    let (_, shapes) = ctx.run(Default::default(), |ctx| {
+        SidePanel::left("panel")
+            .resizable(false)
+            .default_width(100.0)
+            .show(ctx, |ui| {
+                widths.push(ui.available_width());
+                widths.push(ui.add(Label::new(text).truncate(true)).rect.width());
+                widths.push(ui.add(Label::new("Short").truncate(true)).rect.width());
+                widths.push(ui.min_rect().width());
+            });
+    });
+
+    let texts: Vec<&str> = shapes
+        .iter()
+        .filter_map(|ClippedShape(_, shape)| match shape {
+            Shape::Text(text) => Some(text.galley.text()),
+            _ => None,
+        })
+        .collect();
+    let elided = texts.iter().find(|t| t.ends_with('…')).unwrap();
+    assert!(text.starts_with(elided.trim_end_matches('…')));
+    assert!(texts.contains(&"Short"));
+    assert!(widths[1] <= widths[0], "the truncated label fits");
+    assert!(widths[3] <= widths[0] + 0.5, "the panel doesn't grow");
+}
@@ -6,26 +6,38 @@
 
 use crate::*;
 
+mod badge;
 mod button;
 pub mod color_picker;
 pub(crate) mod drag_value;
+mod editable_label;
 mod hyperlink;
 mod image;
+mod kbd;
 mod label;
 pub mod plot;
 mod progress_bar;
+mod rich_label;
+mod scrub;
+mod segmented_control;
 mod selected_label;
 mod separator;
 mod slider;
 mod spinner;
 pub mod text_edit;
 
+pub use badge::Badge;
 pub use button::*;
 pub use drag_value::DragValue;
+pub use editable_label::EditableLabel;
 pub use hyperlink::*;
 pub use image::Image;
+pub use kbd::Kbd;
 pub use label::*;
 pub use progress_bar::ProgressBar;
+pub use rich_label::{RichLabel, RichLabelResponse};
+pub use scrub::Scrub;
+pub use segmented_control::SegmentedControl;
 pub use selected_label::SelectableLabel;
 pub use separator::Separator;
 pub use slider::*;
@@ -13,6 +13,7 @@ use values::*;
 
 pub use bar::Bar;
 pub use box_elem::{BoxElem, BoxSpread};
+pub(super) use values::PlotGeometry;
 pub use values::{LineStyle, MarkerShape, Value, Values};
 
 mod bar;
@@ -321,10 +322,45 @@ pub struct Line {
     pub(super) stroke: Stroke,
     pub(super) name: String,
     pub(super) highlight: bool,
-    pub(super) fill: Option<f32>,
+    pub(super) fill: Option<FillTarget>,
+    pub(super) fill_alpha: f32,
     pub(super) style: LineStyle,
 }
 
+/// What to fill the area under a [`Line`] down (or up) to, see [`Line::fill_to`].
+pub enum FillTarget {
+    /// A horizontal line at the given y value.
+    Y(f64),
+
+    /// Another series of values, e.g. for a confidence band.
+    /// Both the line and these values should be sorted by x.
+    Values(Values),
+}
+
+impl From<f64> for FillTarget {
+    fn from(y: f64) -> Self {
+        Self::Y(y)
+    }
+}
+
+impl From<f32> for FillTarget {
+    fn from(y: f32) -> Self {
+        Self::Y(y as f64)
+    }
+}
+
+impl From<Values> for FillTarget {
+    fn from(values: Values) -> Self {
+        Self::Values(values)
+    }
+}
+
+impl From<Line> for FillTarget {
+    fn from(line: Line) -> Self {
+        Self::Values(line.series)
+    }
+}
+
 impl Line {
     pub fn new(series: Values) -> Self {
         Self {
@@ -333,6 +369,7 @@ impl Line {
             name: Default::default(),
             highlight: false,
             fill: None,
+            fill_alpha: DEFAULT_FILL_ALPHA,
             style: LineStyle::Solid,
         }
     }
@@ -362,8 +399,33 @@ impl Line {
     }
 
     /// Fill the area between this line and a given horizontal reference line.
-    pub fn fill(mut self, y_reference: impl Into<f32>) -> Self {
-        self.fill = Some(y_reference.into());
+    ///
+    /// Same as [`Self::fill_to`] with a y value.
+    pub fn fill(self, y_reference: impl Into<f32>) -> Self {
+        self.fill_to(y_reference.into())
+    }
+
+    /// Fill the area between this line and either a horizontal line at the given y value,
+    /// or another series of values, e.g. for area charts and confidence bands.
+    ///
+    /// The area is filled with the line color, see [`Self::fill_alpha`] for its transparency.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Line, Plot, Values};
+    /// let upper = Values::from_explicit_callback(|x| x.sin() + 0.2, .., 100);
+    /// let lower = Values::from_explicit_callback(|x| x.sin() - 0.2, .., 100);
+    /// Plot::new("confidence_band").show(ui, |plot_ui| plot_ui.line(Line::new(upper).fill_to(lower)));
+    /// # });
+    /// ```
+    pub fn fill_to(mut self, target: impl Into<FillTarget>) -> Self {
+        self.fill = Some(target.into());
+        self
+    }
+
+    /// Alpha of the filled area, see [`Self::fill_to`].
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
         self
     }
 
@@ -386,74 +448,173 @@ impl Line {
     }
 }
 
-/// Returns the x-coordinate of a possible intersection between a line segment from `p1` to `p2` and
-/// a horizontal line at the given y-coordinate.
-fn y_intersection(p1: &Pos2, p2: &Pos2, y: f32) -> Option<f32> {
-    ((p1.y > y && p2.y < y) || (p1.y < y && p2.y > y))
-        .then(|| ((y * (p1.x - p2.x)) - (p1.x * p2.y - p1.y * p2.x)) / (p1.y - p2.y))
+/// The y coordinate of the polyline through `points` (sorted by x) at the given x coordinate.
+fn y_at(points: &[Pos2], x: f32) -> f32 {
+    let i = points
+        .partition_point(|point| point.x < x)
+        .clamp(1, points.len() - 1);
+    let (p0, p1) = (points[i - 1], points[i]);
+    if p0.x == p1.x {
+        p1.y
+    } else {
+        lerp(p0.y..=p1.y, (x - p0.x) / (p1.x - p0.x))
+    }
+}
+
+/// Keeps the first, lowest, highest and last point in every `column_width` wide column of a polyline sorted by x,
+/// which looks the same but is much faster to draw if there are many points per column.
+fn decimate_min_max(points: impl Iterator<Item = Pos2>, column_width: f32) -> Vec<Pos2> {
+    fn flush(decimated: &mut Vec<Pos2>, mut kept: [(usize, Pos2); 4]) {
+        kept.sort_by_key(|(index, _)| *index);
+        for (i, &(index, point)) in kept.iter().enumerate() {
+            if i == 0 || kept[i - 1].0 != index {
+                decimated.push(point);
+            }
+        }
+    }
+
+    let mut decimated = Vec::new();
+    let mut column: Option<(i64, [(usize, Pos2); 4])> = None;
+    for (index, point) in points.enumerate() {
+        let column_index = (point.x / column_width).floor() as i64;
+        match &mut column {
+            Some((current, [_, lowest, highest, last])) if *current == column_index => {
+                if point.y < lowest.1.y {
+                    *lowest = (index, point);
+                }
+                if point.y > highest.1.y {
+                    *highest = (index, point);
+                }
+                *last = (index, point);
+            }
+            _ => {
+                if let Some((_, kept)) = column.take() {
+                    flush(&mut decimated, kept);
+                }
+                column = Some((column_index, [(index, point); 4]));
+            }
+        }
+    }
+    if let Some((_, kept)) = column {
+        flush(&mut decimated, kept);
+    }
+    decimated
+}
+
+/// A mesh filling the area between two polylines, both sorted by x, where they overlap in x.
+fn fill_between(a: &[Pos2], b: &[Pos2], color: Color32) -> Mesh {
+    let mut mesh = Mesh::default();
+    if a.len() < 2 || b.len() < 2 {
+        return mesh;
+    }
+    let x_min = a[0].x.max(b[0].x);
+    let x_max = a[a.len() - 1].x.min(b[b.len() - 1].x);
+    let mut xs: Vec<f32> = a
+        .iter()
+        .chain(b)
+        .map(|point| point.x)
+        .filter(|x| (x_min..=x_max).contains(x))
+        .collect();
+    xs.sort_by_key(|&x| x.ord());
+    xs.dedup();
+
+    let mut add = |points: &[Pos2]| {
+        let i = mesh.vertices.len() as u32;
+        for &point in points {
+            mesh.colored_vertex(point, color);
+        }
+        mesh.add_triangle(i, i + 1, i + 2);
+        if points.len() == 4 {
+            mesh.add_triangle(i + 1, i + 2, i + 3);
+        }
+    };
+    for w in xs.windows(2) {
+        let (x0, x1) = (w[0], w[1]);
+        let (a0, b0) = (pos2(x0, y_at(a, x0)), pos2(x0, y_at(b, x0)));
+        let (a1, b1) = (pos2(x1, y_at(a, x1)), pos2(x1, y_at(b, x1)));
+        let (d0, d1) = (a0.y - b0.y, a1.y - b1.y);
+        if d0 * d1 < 0.0 {
+            // The lines cross.
+            let crossing = a0 + (a1 - a0) * (d0 / (d0 - d1));
+            add(&[a0, b0, crossing]);
+            add(&[crossing, a1, b1]);
+        } else {
+            add(&[a0, b0, a1, b1]);
+        }
+    }
+    mesh
 }
 
 impl PlotItem for Line {
-    fn get_shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+    fn get_shapes(&self, ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
         let Self {
             series,
             stroke,
             highlight,
-            mut fill,
+            fill,
+            fill_alpha,
             style,
             ..
         } = self;
 
-        let values_tf: Vec<_> = series
-            .values
-            .iter()
-            .map(|v| transform.position_from_value(v))
-            .collect();
-        let n_values = values_tf.len();
+        // Only transform the visible values, and downsample lines with many points per pixel.
+        let values_tf: Vec<_> = match series.sorted_values_in_x_range(&transform.bounds().range_x())
+        {
+            Some(visible) => {
+                let points = visible.iter().map(|v| transform.position_from_value(v));
+                let pixel_width = 1.0 / ui.ctx().pixels_per_point();
+                if visible.len() as f32 > 4.0 * transform.frame().width() / pixel_width {
+                    decimate_min_max(points, pixel_width)
+                } else {
+                    points.collect()
+                }
+            }
+            None => series
+                .values
+                .iter()
+                .map(|v| transform.position_from_value(v))
+                .collect(),
+        };
 
-        // Fill the area between the line and a reference line, if required.
-        if n_values < 2 {
-            fill = None;
-        }
-        if let Some(y_reference) = fill {
-            let mut fill_alpha = DEFAULT_FILL_ALPHA;
+        // Fill the area between the line and the fill target, if required.
+        if let Some(fill) = fill {
+            let target_tf: Vec<_> = match fill {
+                FillTarget::Y(y) => values_tf
+                    .first()
+                    .into_iter()
+                    .chain(values_tf.last())
+                    .map(|point| {
+                        pos2(
+                            point.x,
+                            transform.position_from_value(&Value::new(0.0, *y)).y,
+                        )
+                    })
+                    .collect(),
+                FillTarget::Values(values) => values
+                    .values
+                    .iter()
+                    .map(|v| transform.position_from_value(v))
+                    .collect(),
+            };
+            let mut fill_alpha = *fill_alpha;
             if *highlight {
                 fill_alpha = (2.0 * fill_alpha).at_most(1.0);
             }
-            let y = transform
-                .position_from_value(&Value::new(0.0, y_reference))
-                .y;
             let fill_color = Rgba::from(stroke.color)
                 .to_opaque()
                 .multiply(fill_alpha)
                 .into();
-            let mut mesh = Mesh::default();
-            let expected_intersections = 20;
-            mesh.reserve_triangles((n_values - 1) * 2);
-            mesh.reserve_vertices(n_values * 2 + expected_intersections);
-            values_tf[0..n_values - 1].windows(2).for_each(|w| {
-                let i = mesh.vertices.len() as u32;
-                mesh.colored_vertex(w[0], fill_color);
-                mesh.colored_vertex(pos2(w[0].x, y), fill_color);
-                if let Some(x) = y_intersection(&w[0], &w[1], y) {
-                    let point = pos2(x, y);
-                    mesh.colored_vertex(point, fill_color);
-                    mesh.add_triangle(i, i + 1, i + 2);
-                    mesh.add_triangle(i + 2, i + 3, i + 4);
-                } else {
-                    mesh.add_triangle(i, i + 1, i + 2);
-                    mesh.add_triangle(i + 1, i + 2, i + 3);
-                }
-            });
-            let last = values_tf[n_values - 1];
-            mesh.colored_vertex(last, fill_color);
-            mesh.colored_vertex(pos2(last.x, y), fill_color);
-            shapes.push(Shape::Mesh(mesh));
+            shapes.push(Shape::Mesh(fill_between(
+                &values_tf, &target_tf, fill_color,
+            )));
         }
         style.style_line(values_tf, *stroke, *highlight, shapes);
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        if let Some(FillTarget::Values(values)) = &mut self.fill {
+            values.generate_points(x_range.clone());
+        }
         self.series.generate_points(x_range);
     }
 
@@ -478,7 +639,11 @@ impl PlotItem for Line {
     }
 
     fn get_bounds(&self) -> PlotBounds {
-        self.series.get_bounds()
+        let mut bounds = self.series.get_bounds();
+        if let Some(FillTarget::Values(values)) = &self.fill {
+            bounds.merge(&values.get_bounds());
+        }
+        bounds
     }
 }
 
@@ -1326,6 +1491,23 @@ impl BarChart {
         }
         self
     }
+
+    /// Places the bars side by side with the bars of other charts at the same argument,
+    /// instead of on top of them.
+    ///
+    /// This is chart number `index` (starting at zero) of `count` grouped charts.
+    /// Together the bars of a group take up the width of a single bar,
+    /// so call this after [`Self::width`].
+    pub fn group(mut self, index: usize, count: usize) -> Self {
+        crate::egui_assert!(index < count);
+        let count = count.max(1) as f64;
+        for bar in &mut self.bars {
+            let group_width = bar.bar_width;
+            bar.bar_width = group_width / count;
+            bar.argument += (index as f64 + 0.5) * bar.bar_width - group_width / 2.0;
+        }
+        self
+    }
 }
 
 impl PlotItem for BarChart {
@@ -1702,3 +1884,88 @@ where
         })
         .min_by_key(|e| e.dist_sq.ord())
 }
+
+#[cfg(test)]
+#[test]
+fn test_bar_chart_group_and_stack() {
+    let chart = |values: &[f64]| {
+        BarChart::new(
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, &value)| Bar::new(i as f64, value))
+                .collect(),
+        )
+        .width(0.6)
+    };
+
+    let first = chart(&[1.0, 2.0]).group(0, 3);
+    let last = chart(&[1.0, 2.0]).group(2, 3);
+    assert!((first.bars[1].argument - 0.8).abs() < 1e-9);
+    assert!((last.bars[1].argument - 1.2).abs() < 1e-9);
+    assert!((first.bars[1].bar_width - 0.2).abs() < 1e-9);
+
+    let bottom = chart(&[1.0, -2.0]);
+    let top = chart(&[3.0, -1.0]).stack_on(&[&bottom]);
+    assert_eq!(top.bars[0].lower(), 1.0);
+    assert_eq!(top.bars[0].upper(), 4.0);
+    assert_eq!(top.bars[1].upper(), -2.0);
+    assert_eq!(top.bars[1].lower(), -3.0);
+}
+
+#[cfg(test)]
+#[test]
+fn test_fill_between() {
+    let area = |mesh: &Mesh| -> f32 {
+        mesh.indices
+            .chunks(3)
+            .map(|triangle| {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize].pos);
+                0.5 * ((b - a).x * (c - a).y - (b - a).y * (c - a).x).abs()
+            })
+            .sum()
+    };
+    let color = Color32::RED;
+
+    // A peak above a baseline:
+    let peak = [pos2(0.0, 0.0), pos2(5.0, 10.0), pos2(10.0, 0.0)];
+    let baseline = [pos2(0.0, 0.0), pos2(10.0, 0.0)];
+    assert_eq!(area(&fill_between(&peak, &baseline, color)), 50.0);
+
+    // Two crossing lines, only overlapping in 0..=10:
+    let rising = [pos2(0.0, -10.0), pos2(20.0, 30.0)];
+    let falling = [pos2(-10.0, 30.0), pos2(10.0, -10.0)];
+    assert_eq!(area(&fill_between(&rising, &falling, color)), 100.0);
+
+    assert!(fill_between(&peak[..1], &baseline, color).is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn test_decimate_min_max() {
+    // 100 points per column, zigzagging between -1 and 1 with a spike in the middle column:
+    let points: Vec<Pos2> = (0..1000)
+        .map(|i| {
+            let y = if i == 550 {
+                10.0
+            } else {
+                (i % 2) as f32 * 2.0 - 1.0
+            };
+            pos2(i as f32 / 100.0, y)
+        })
+        .collect();
+    let decimated = decimate_min_max(points.iter().copied(), 1.0);
+    assert!(decimated.len() <= 4 * 10, "{}", decimated.len());
+    assert!(decimated.windows(2).all(|w| w[0].x <= w[1].x));
+    assert_eq!(decimated.first(), points.first());
+    assert_eq!(decimated.last(), points.last());
+    assert!(decimated.contains(&pos2(5.5, 10.0)));
+    assert_eq!(decimated.iter().filter(|p| p.y == -1.0).count(), 10);
+
+    let values = Values::from_values((0..10).map(|i| Value::new(i, i)).collect());
+    let visible = values.sorted_values_in_x_range(&(2.5..=4.0)).unwrap();
+    assert_eq!(visible.first().unwrap().x, 2.0);
+    assert_eq!(visible.last().unwrap().x, 5.0);
+    let unsorted = Values::from_values(vec![Value::new(1.0, 0.0), Value::new(0.0, 0.0)]);
+    assert!(unsorted.sorted_values_in_x_range(&(0.0..=1.0)).is_none());
+}
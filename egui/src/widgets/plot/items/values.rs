@@ -260,6 +260,24 @@ impl Values {
         }
     }
 
+    /// The values within the given x range, plus one on either side so that lines leaving the range are drawn.
+    ///
+    /// `None` if the values are not sorted by x.
+    pub(super) fn sorted_values_in_x_range(
+        &self,
+        x_range: &RangeInclusive<f64>,
+    ) -> Option<&[Value]> {
+        let values = &self.values;
+        if !values.windows(2).all(|w| w[0].x <= w[1].x) {
+            return None;
+        }
+        let start = values
+            .partition_point(|value| value.x < *x_range.start())
+            .saturating_sub(1);
+        let end = (values.partition_point(|value| value.x <= *x_range.end()) + 1).min(values.len());
+        Some(&values[start..end.max(start)])
+    }
+
     /// Returns the intersection of two ranges if they intersect.
     fn range_intersection(
         range1: &RangeInclusive<f64>,
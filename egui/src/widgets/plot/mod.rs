@@ -1,26 +1,49 @@
 //! Simple plotting library.
 
+use std::ops::RangeInclusive;
+
 use crate::*;
 use epaint::ahash::AHashSet;
 use epaint::color::Hsva;
 use epaint::util::FloatOrd;
 use items::PlotItem;
 use legend::LegendWidget;
-use transform::{PlotBounds, ScreenTransform};
+use transform::ScreenTransform;
 
 pub use items::{
-    Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, HLine, Line, LineStyle, MarkerShape,
-    PlotImage, Points, Polygon, Text, VLine, Value, Values,
+    Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, FillTarget, HLine, Line, LineStyle,
+    MarkerShape, PlotImage, Points, Polygon, Text, VLine, Value, Values,
 };
 pub use legend::{Corner, Legend};
+pub use time::format_unix_timestamp;
+pub use transform::{AxisScale, PlotBounds};
 
 mod items;
 mod legend;
+mod time;
 mod transform;
 
 type CustomLabelFunc = dyn Fn(&str, &Value) -> String;
 type CustomLabelFuncRef = Option<Box<CustomLabelFunc>>;
 
+type AxisFormatterFn = dyn Fn(f64, &RangeInclusive<f64>) -> String;
+type AxisFormatter = Option<Box<AxisFormatterFn>>;
+
+type HoverUiFunc = dyn Fn(&mut Ui, &HoveredElement<'_>);
+
+/// The point, bar or box closest to the pointer, see [`Plot::hover_ui`].
+#[derive(Clone, Copy, Debug)]
+pub struct HoveredElement<'a> {
+    /// The name of the hovered item, e.g. of the [`Line`].
+    pub item_name: &'a str,
+
+    /// Which point, bar or box of the item is hovered.
+    pub index: usize,
+
+    /// The hovered point. For bars and boxes, where the pointer is, in plot coordinates.
+    pub value: Value,
+}
+
 // ----------------------------------------------------------------------------
 
 /// Information about the plot that has to persist between frames.
@@ -36,14 +59,22 @@ struct PlotMemory {
 
 impl PlotMemory {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
-        ctx.memory().data.get_persisted(id)
+        ctx.memory().data_persisted.get_persisted(id)
     }
 
     pub fn store(self, ctx: &Context, id: Id) {
-        ctx.memory().data.insert_persisted(id, self);
+        ctx.memory().data_persisted.insert_persisted(id, self);
     }
 }
 
+/// Which of the [`PlotUi::draggable_points`] the user is dragging.
+#[derive(Clone, Copy)]
+struct DraggedPoint(usize);
+
+/// The bounds last shown by any plot in a group of plots with linked axes, see [`Plot::link_axis`].
+#[derive(Clone)]
+struct LinkedBounds(PlotBounds);
+
 // ----------------------------------------------------------------------------
 
 /// A 2D plot, e.g. a graph of a function.
@@ -68,8 +99,10 @@ pub struct Plot {
     center_y_axis: bool,
     allow_zoom: bool,
     allow_drag: bool,
+    boxed_zoom_modifiers: Option<Modifiers>,
     min_auto_bounds: PlotBounds,
     margin_fraction: Vec2,
+    linked_axes: Option<(Id, [bool; 2])>,
 
     min_size: Vec2,
     width: Option<f32>,
@@ -80,13 +113,17 @@ pub struct Plot {
     show_x: bool,
     show_y: bool,
     custom_label_func: CustomLabelFuncRef,
+    hover_ui: Option<Box<HoverUiFunc>>,
+    axis_formatters: [AxisFormatter; 2],
     legend_config: Option<Legend>,
     show_background: bool,
     show_axes: [bool; 2],
+    axis_scales: [AxisScale; 2],
 }
 
 impl Plot {
     /// Give a unique id for each plot within the same `Ui`.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn new(id_source: impl std::hash::Hash) -> Self {
         Self {
             id_source: Id::new(id_source),
@@ -95,8 +132,10 @@ impl Plot {
             center_y_axis: false,
             allow_zoom: true,
             allow_drag: true,
+            boxed_zoom_modifiers: Some(Modifiers::COMMAND),
             min_auto_bounds: PlotBounds::NOTHING,
             margin_fraction: Vec2::splat(0.05),
+            linked_axes: None,
 
             min_size: Vec2::splat(64.0),
             width: None,
@@ -107,9 +146,12 @@ impl Plot {
             show_x: true,
             show_y: true,
             custom_label_func: None,
+            hover_ui: None,
+            axis_formatters: [None, None],
             legend_config: None,
             show_background: true,
             show_axes: [true; 2],
+            axis_scales: Default::default(),
         }
     }
 
@@ -187,6 +229,30 @@ impl Plot {
         self
     }
 
+    /// Which modifiers to hold while dragging to select a rectangle to zoom into,
+    /// or `None` to disable boxed zoom.
+    ///
+    /// Boxed zoom is also disabled by [`Self::allow_zoom`]. Default: `Some(Modifiers::COMMAND)` (Ctrl, or ⌘ on Mac).
+    pub fn boxed_zoom_modifiers(mut self, modifiers: Option<Modifiers>) -> Self {
+        self.boxed_zoom_modifiers = modifiers;
+        self
+    }
+
+    /// Link the x and/or y axis of all plots with the same `group_id`,
+    /// so that panning or zooming one of them does the same to the others.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::Plot;
+    /// Plot::new("top").link_axis("time", true, false).show(ui, |plot_ui| ());
+    /// Plot::new("bottom").link_axis("time", true, false).show(ui, |plot_ui| ());
+    /// # });
+    /// ```
+    pub fn link_axis(mut self, group_id: impl std::hash::Hash, link_x: bool, link_y: bool) -> Self {
+        self.linked_axes = Some((Id::new(group_id), [link_x, link_y]));
+        self
+    }
+
     /// Provide a function to customize the on-hovel label for the x and y axis
     ///
     /// ```
@@ -216,6 +282,60 @@ impl Plot {
         self
     }
 
+    /// Show a tooltip with custom contents when hovering a point, bar or box of an item,
+    /// e.g. with more information about a data point.
+    ///
+    /// The default hover labels are still shown, unless turned off with [`Self::show_x`] and [`Self::show_y`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Plot, Points, Values};
+    /// let points = Points::new(Values::from_ys_f32(&[3.0, 1.0, 4.0])).name("samples");
+    /// Plot::new("my_plot")
+    ///     .show_x(false)
+    ///     .show_y(false)
+    ///     .hover_ui(|ui, hovered| {
+    ///         ui.label(format!("{} #{}", hovered.item_name, hovered.index));
+    ///     })
+    ///     .show(ui, |plot_ui| plot_ui.points(points));
+    /// # });
+    /// ```
+    pub fn hover_ui(mut self, hover_ui: impl Fn(&mut Ui, &HoveredElement<'_>) + 'static) -> Self {
+        self.hover_ui = Some(Box::new(hover_ui));
+        self
+    }
+
+    /// Provide a function to format the x axis tick labels,
+    /// given the value of the tick and the range of the x axis that is shown.
+    ///
+    /// For instance, use [`format_unix_timestamp`] for time series.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::Plot;
+    /// Plot::new("my_plot")
+    ///     .x_axis_formatter(|x, _range| format!("{}°", x))
+    ///     .show(ui, |plot_ui| ());
+    /// # });
+    /// ```
+    pub fn x_axis_formatter(
+        mut self,
+        fmt: impl Fn(f64, &RangeInclusive<f64>) -> String + 'static,
+    ) -> Self {
+        self.axis_formatters[0] = Some(Box::new(fmt));
+        self
+    }
+
+    /// Provide a function to format the y axis tick labels,
+    /// given the value of the tick and the range of the y axis that is shown.
+    pub fn y_axis_formatter(
+        mut self,
+        fmt: impl Fn(f64, &RangeInclusive<f64>) -> String + 'static,
+    ) -> Self {
+        self.axis_formatters[1] = Some(Box::new(fmt));
+        self
+    }
+
     /// Expand bounds to include the given x value.
     /// For instance, to always show the y axis, call `plot.include_x(0.0)`.
     pub fn include_x(mut self, x: impl Into<f64>) -> Self {
@@ -252,6 +372,32 @@ impl Plot {
         self
     }
 
+    /// How x values are mapped to the screen, e.g. [`AxisScale::Log10`]. Default: [`AxisScale::Linear`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{AxisScale, Line, Plot, Value, Values};
+    /// let line = Line::new(Values::from_values_iter((1..100).map(|i| {
+    ///     let frequency = 10.0 * i as f64;
+    ///     Value::new(frequency, 1.0 / frequency)
+    /// })));
+    /// Plot::new("bode_plot")
+    ///     .x_axis_scale(AxisScale::Log10)
+    ///     .y_axis_scale(AxisScale::Log10)
+    ///     .show(ui, |plot_ui| plot_ui.line(line));
+    /// # });
+    /// ```
+    pub fn x_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[0] = scale.sanitized();
+        self
+    }
+
+    /// How y values are mapped to the screen, e.g. [`AxisScale::Log10`]. Default: [`AxisScale::Linear`].
+    pub fn y_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[1] = scale.sanitized();
+        self
+    }
+
     /// Interact with and add items to the plot and finally draw it.
     pub fn show<R>(self, ui: &mut Ui, build_fn: impl FnOnce(&mut PlotUi) -> R) -> InnerResponse<R> {
         let Self {
@@ -260,8 +406,10 @@ impl Plot {
             center_y_axis,
             allow_zoom,
             allow_drag,
+            boxed_zoom_modifiers,
             min_auto_bounds,
             margin_fraction,
+            linked_axes,
             width,
             height,
             min_size,
@@ -270,9 +418,12 @@ impl Plot {
             mut show_x,
             mut show_y,
             custom_label_func,
+            mut hover_ui,
+            axis_formatters,
             legend_config,
             show_background,
             show_axes,
+            axis_scales,
         } = self;
 
         // Determine the size of the plot in the UI
@@ -303,7 +454,7 @@ impl Plot {
         let (rect, response) = ui.allocate_exact_size(size, Sense::drag());
 
         // Load or initialize the memory.
-        let plot_id = ui.make_persistent_id(id_source);
+        let plot_id = ui.make_persistent_id(id_source).derived_from(id_source);
         let mut memory = PlotMemory::load(ui.ctx(), plot_id).unwrap_or_else(|| PlotMemory {
             auto_bounds: !min_auto_bounds.is_valid(),
             hovered_entry: None,
@@ -312,6 +463,7 @@ impl Plot {
             last_screen_transform: ScreenTransform::new(
                 rect,
                 min_auto_bounds,
+                axis_scales,
                 center_x_axis,
                 center_y_axis,
             ),
@@ -341,6 +493,9 @@ impl Plot {
             items: Vec::new(),
             next_auto_color_idx: 0,
             last_screen_transform,
+            new_bounds: None,
+            point_hovered: false,
+            point_dragged: false,
             response,
             ctx: ui.ctx().clone(),
         };
@@ -349,6 +504,9 @@ impl Plot {
             mut items,
             mut response,
             last_screen_transform,
+            new_bounds,
+            point_hovered,
+            point_dragged,
             ..
         } = plot_ui;
 
@@ -369,6 +527,7 @@ impl Plot {
         if hovered_entry.is_some() {
             show_x = false;
             show_y = false;
+            hover_ui = None;
         }
         // Remove the deselected items.
         items.retain(|item| !hidden_items.contains(item.name()));
@@ -385,27 +544,93 @@ impl Plot {
         // --- Bound computation ---
         let mut bounds = *last_screen_transform.bounds();
 
+        // Bounds set with `PlotUi::set_plot_bounds`.
+        if let Some(new_bounds) = new_bounds {
+            bounds = new_bounds;
+            auto_bounds = false;
+        }
+
         // Allow double clicking to reset to automatic bounds.
-        auto_bounds |= response.double_clicked_by(PointerButton::Primary);
+        let reset = response.double_clicked_by(PointerButton::Primary);
+        auto_bounds |= reset;
 
-        // Set bounds automatically based on content.
-        if auto_bounds || !bounds.is_valid() {
+        // Set bounds automatically based on content, but keep them still while a point is dragged.
+        if (auto_bounds && !point_dragged) || !bounds.is_valid() {
             bounds = min_auto_bounds;
             items
                 .iter()
                 .for_each(|item| bounds.merge(&item.get_bounds()));
-            bounds.add_relative_margin(margin_fraction);
+            let mut scaled = bounds.scaled(&axis_scales);
+            scaled.add_relative_margin(margin_fraction);
+            bounds = scaled.unscaled(&axis_scales);
+        }
+
+        // Follow the other plots in the link group if one of them changed the linked axes,
+        // unless this plot was reset or given new bounds.
+        if let Some((group_id, linked)) = linked_axes.filter(|_| !reset && new_bounds.is_none()) {
+            let group_bounds = ui.memory().data_temp.get_temp::<LinkedBounds>(group_id);
+            if let Some(LinkedBounds(group_bounds)) = group_bounds {
+                let last_bounds = last_screen_transform.bounds();
+                for (axis, &linked) in linked.iter().enumerate() {
+                    let range = |bounds: &PlotBounds| (bounds.min[axis], bounds.max[axis]);
+                    if linked && range(&group_bounds) != range(last_bounds) {
+                        bounds.min[axis] = group_bounds.min[axis];
+                        bounds.max[axis] = group_bounds.max[axis];
+                        auto_bounds = false;
+                    }
+                }
+            }
         }
 
-        let mut transform = ScreenTransform::new(rect, bounds, center_x_axis, center_y_axis);
+        let mut transform =
+            ScreenTransform::new(rect, bounds, axis_scales, center_x_axis, center_y_axis);
 
         // Enforce equal aspect ratio.
         if let Some(data_aspect) = data_aspect {
             transform.set_aspect(data_aspect as f64);
         }
 
+        // Boxed zoom: select a rectangle to zoom into.
+        let boxed_zoom = allow_zoom
+            && !point_dragged
+            && boxed_zoom_modifiers
+                .map_or(false, |modifiers| ui.input().modifiers.matches(modifiers));
+        let boxed_zoom_rect = if boxed_zoom {
+            let pointer_pos = ui.input().pointer.interact_pos();
+            response
+                .drag_origin()
+                .zip(pointer_pos)
+                .map(|(origin, pointer_pos)| Rect::from_two_pos(origin, pointer_pos))
+        } else {
+            None
+        };
+        if let Some(box_rect) = boxed_zoom_rect {
+            if response.drag_released() {
+                if box_rect.width() >= 1.0 && box_rect.height() >= 1.0 {
+                    let mut new_bounds = PlotBounds::NOTHING;
+                    new_bounds.extend_with(&transform.value_from_position(box_rect.min));
+                    new_bounds.extend_with(&transform.value_from_position(box_rect.max));
+                    transform = ScreenTransform::new(
+                        rect,
+                        new_bounds,
+                        axis_scales,
+                        center_x_axis,
+                        center_y_axis,
+                    );
+                    if let Some(data_aspect) = data_aspect {
+                        transform.set_aspect(data_aspect as f64);
+                    }
+                    auto_bounds = false;
+                }
+            } else {
+                response = response.on_hover_cursor(CursorIcon::ZoomIn);
+            }
+        }
+
         // Dragging
-        if allow_drag && response.dragged_by(PointerButton::Primary) {
+        if boxed_zoom_rect.is_some() || point_dragged {
+            // Don't pan while selecting a rectangle to zoom into, or moving a point.
+        } else if allow_drag && response.dragged_by(PointerButton::Primary) {
             response = response.on_hover_cursor(CursorIcon::Grabbing);
             transform.translate_bounds(-response.drag_delta());
             auto_bounds = false;
@@ -415,9 +640,9 @@ impl Plot {
         if allow_zoom {
             if let Some(hover_pos) = response.hover_pos() {
                 let zoom_factor = if data_aspect.is_some() {
-                    Vec2::splat(ui.input().zoom_delta())
+                    Vec2::splat(response.pinch_zoom_delta())
                 } else {
-                    ui.input().zoom_delta_2d()
+                    response.pinch_zoom_delta_2d()
                 };
                 if zoom_factor != Vec2::splat(1.0) {
                     transform.zoom(zoom_factor, hover_pos);
@@ -442,11 +667,29 @@ impl Plot {
             show_x,
             show_y,
             custom_label_func,
+            hover_ui,
+            axis_formatters,
             show_axes,
             transform: transform.clone(),
         };
         prepared.ui(ui, &response);
 
+        if let Some(box_rect) = boxed_zoom_rect {
+            let selection = ui.visuals().selection;
+            ui.painter().sub_region(rect).rect(
+                box_rect,
+                0.0,
+                selection.bg_fill.linear_multiply(0.3),
+                selection.stroke,
+            );
+        }
+
+        if let Some((group_id, _)) = linked_axes {
+            ui.memory()
+                .data_temp
+                .insert_temp(group_id, LinkedBounds(*transform.bounds()));
+        }
+
         if let Some(mut legend) = legend {
             ui.add(&mut legend);
             hidden_items = legend.get_hidden_items();
@@ -462,7 +705,11 @@ impl Plot {
         };
         memory.store(ui.ctx(), plot_id);
 
-        let response = if show_x || show_y {
+        let response = if point_dragged {
+            response.on_hover_cursor(CursorIcon::Grabbing)
+        } else if point_hovered {
+            response.on_hover_cursor(CursorIcon::Grab)
+        } else if show_x || show_y {
             response.on_hover_cursor(CursorIcon::Crosshair)
         } else {
             response
@@ -478,6 +725,9 @@ pub struct PlotUi {
     items: Vec<Box<dyn PlotItem>>,
     next_auto_color_idx: usize,
     last_screen_transform: ScreenTransform,
+    new_bounds: Option<PlotBounds>,
+    point_hovered: bool,
+    point_dragged: bool,
     response: Response,
     ctx: CtxRef,
 }
@@ -502,6 +752,13 @@ impl PlotUi {
         *self.last_screen_transform.bounds()
     }
 
+    /// Show the given bounds from this frame on, instead of the automatic bounds.
+    ///
+    /// Double-click the plot to go back to the automatic bounds.
+    pub fn set_plot_bounds(&mut self, plot_bounds: PlotBounds) {
+        self.new_bounds = Some(plot_bounds);
+    }
+
     /// Returns `true` if the plot area is currently hovered.
     pub fn plot_hovered(&self) -> bool {
         self.response.hovered()
@@ -567,6 +824,82 @@ impl PlotUi {
         self.items.push(Box::new(text));
     }
 
+    /// Add points that the user can drag around, e.g. the control points of an envelope editor
+    /// or annotation handles.
+    ///
+    /// The points are shown as [`Points`] with the given name.
+    /// Returns the index of the point that was moved this frame, if any. Its new value is already in `values`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut envelope = vec![egui::plot::Value::new(0.0, 0.0), egui::plot::Value::new(1.0, 1.0)];
+    /// use egui::plot::{Line, Plot, Values};
+    /// Plot::new("envelope").show(ui, |plot_ui| {
+    ///     if let Some(index) = plot_ui.draggable_points("envelope", &mut envelope) {
+    ///         // envelope[index] was moved
+    ///     }
+    ///     plot_ui.line(Line::new(Values::from_values(envelope.clone())));
+    /// });
+    /// # });
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn draggable_points(&mut self, name: impl ToString, values: &mut [Value]) -> Option<usize> {
+        let name = name.to_string();
+        let radius = 5.0;
+        let id = self.response.id.with(&name);
+        let grab_radius_sq = (radius + 4.0_f32).powi(2);
+        let closest_point = |plot_ui: &Self, pos: Pos2| {
+            values
+                .iter()
+                .map(|value| plot_ui.screen_from_plot(*value).distance_sq(pos))
+                .enumerate()
+                .filter(|(_, dist_sq)| *dist_sq <= grab_radius_sq)
+                .min_by_key(|(_, dist_sq)| dist_sq.ord())
+                .map(|(index, _)| index)
+        };
+
+        let response = &self.response;
+        if response.drag_started() {
+            let grabbed = response
+                .drag_origin()
+                .and_then(|origin| closest_point(self, origin));
+            let mut memory = self.ctx.memory();
+            match grabbed {
+                Some(index) => memory.data_temp.insert_temp(id, DraggedPoint(index)),
+                None => memory.data_temp.remove::<DraggedPoint>(id),
+            }
+        }
+
+        let dragged = self.ctx.memory().data_temp.get_temp::<DraggedPoint>(id);
+        let mut moved = None;
+        if let Some(DraggedPoint(index)) = dragged {
+            let response = &self.response;
+            if response.drag_released() || !response.dragged() {
+                self.ctx.memory().data_temp.remove::<DraggedPoint>(id);
+            }
+            if response.dragged() || response.drag_released() {
+                let delta = response.drag_delta();
+                if let Some(value) = values.get_mut(index).filter(|_| delta != Vec2::ZERO) {
+                    let pos = self.last_screen_transform.position_from_value(value) + delta;
+                    *value = self.last_screen_transform.value_from_position(pos);
+                    moved = Some(index);
+                }
+                self.point_dragged = true;
+            }
+        } else if let Some(hover_pos) = self.response.hover_pos() {
+            self.point_hovered |= closest_point(self, hover_pos).is_some();
+        }
+
+        self.points(
+            Points::new(Values::from_values(values.to_vec()))
+                .name(name)
+                .shape(MarkerShape::Circle)
+                .filled(true)
+                .radius(radius),
+        );
+        moved
+    }
+
     /// Add data points.
     pub fn points(&mut self, mut points: Points) {
         if points.series.is_empty() {
@@ -650,6 +983,8 @@ struct PreparedPlot {
     show_x: bool,
     show_y: bool,
     custom_label_func: CustomLabelFuncRef,
+    hover_ui: Option<Box<HoverUiFunc>>,
+    axis_formatters: [AxisFormatter; 2],
     show_axes: [bool; 2],
     transform: ScreenTransform,
 }
@@ -673,35 +1008,34 @@ impl PreparedPlot {
         }
 
         if let Some(pointer) = response.hover_pos() {
-            self.hover(ui, pointer, &mut shapes);
+            self.hover(ui, response.id, pointer, &mut shapes);
         }
 
         ui.painter().sub_region(*transform.frame()).extend(shapes);
     }
 
     fn paint_axis(&self, ui: &Ui, axis: usize, shapes: &mut Vec<Shape>) {
-        let Self { transform, .. } = self;
+        let Self {
+            transform,
+            axis_formatters,
+            ..
+        } = self;
 
         let bounds = transform.bounds();
+        let range = bounds.min[axis]..=bounds.max[axis];
         let text_style = TextStyle::Body;
 
-        let base: i64 = 10;
-        let basef = base as f64;
-
         let min_line_spacing_in_points = 6.0; // TODO: large enough for a wide label
-        let step_size = transform.dvalue_dpos()[axis] * min_line_spacing_in_points;
-        let step_size = basef.powi(step_size.abs().log(basef).ceil() as i32);
-
-        let step_size_in_points = (transform.dpos_dvalue()[axis] * step_size).abs() as f32;
 
         // Where on the cross-dimension to show the label values
         let value_cross = 0.0_f64.clamp(bounds.min[1 - axis], bounds.max[1 - axis]);
 
-        for i in 0.. {
-            let value_main = step_size * (bounds.min[axis] / step_size + i as f64).floor();
-            if value_main > bounds.max[axis] {
-                break;
-            }
+        for mark in transform.grid_marks(axis, min_line_spacing_in_points) {
+            let transform::GridMark {
+                value: value_main,
+                spacing_in_points,
+                label,
+            } = mark;
 
             let value = if axis == 0 {
                 Value::new(value_main, value_cross)
@@ -710,15 +1044,6 @@ impl PreparedPlot {
             };
             let pos_in_gui = transform.position_from_value(&value);
 
-            let n = (value_main / step_size).round() as i64;
-            let spacing_in_points = if n % (base * base) == 0 {
-                step_size_in_points * (basef * basef) as f32 // think line (multiple of 100)
-            } else if n % base == 0 {
-                step_size_in_points * basef as f32 // medium line (multiple of 10)
-            } else {
-                step_size_in_points // thin line
-            };
-
             let line_alpha = remap_clamp(
                 spacing_in_points,
                 (min_line_spacing_in_points as f32)..=300.0,
@@ -739,9 +1064,11 @@ impl PreparedPlot {
 
             if text_alpha > 0.0 {
                 let color = color_from_alpha(ui, text_alpha);
-                let text = emath::round_to_decimals(value_main, 5).to_string(); // hack
-
-                let galley = ui.painter().layout_no_wrap(text, text_style, color);
+                let label = match &axis_formatters[axis] {
+                    Some(fmt) => fmt(value_main, &range),
+                    None => label,
+                };
+                let galley = ui.painter().layout_no_wrap(label, text_style, color);
 
                 let mut text_pos = pos_in_gui + vec2(1.0, -galley.size().y);
 
@@ -763,17 +1090,18 @@ impl PreparedPlot {
         }
     }
 
-    fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) {
+    fn hover(&self, ui: &Ui, plot_id: Id, pointer: Pos2, shapes: &mut Vec<Shape>) {
         let Self {
             transform,
             show_x,
             show_y,
             custom_label_func,
+            hover_ui,
             items,
             ..
         } = self;
 
-        if !show_x && !show_y {
+        if !show_x && !show_y && hover_ui.is_none() {
             return;
         }
 
@@ -797,6 +1125,24 @@ impl PreparedPlot {
             show_y: *show_y,
         };
 
+        if let (Some(hover_ui), Some((item, elem))) = (hover_ui, &closest) {
+            let value = match item.geometry() {
+                items::PlotGeometry::Points(points) => points[elem.index],
+                _ => transform.value_from_position(pointer),
+            };
+            let hovered = HoveredElement {
+                item_name: item.name(),
+                index: elem.index,
+                value,
+            };
+            show_tooltip_at_pointer(ui.ctx(), plot_id.with("hover_ui"), |ui| {
+                hover_ui(ui, &hovered);
+            });
+        }
+
+        if !show_x && !show_y {
+            return;
+        }
         if let Some((item, elem)) = closest {
             item.on_hover(elem, shapes, &plot, custom_label_func);
         } else {
@@ -805,3 +1151,158 @@ impl PreparedPlot {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_boxed_zoom_and_linked_axes() {
+    let mut ctx = CtxRef::default();
+    let mut frame = |events: Vec<Event>, bounds: &mut Vec<PlotBounds>| {
+        let input = RawInput {
+            events,
+            modifiers: Modifiers::COMMAND,
+            ..Default::default()
+        };
+        let mut corners = [Pos2::ZERO; 2];
+        bounds.clear();
+        let _ = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                for name in ["a", "b"] {
+                    let line = Line::new(Values::from_values(vec![
+                        Value::new(0.0, 0.0),
+                        Value::new(10.0, 10.0),
+                    ]));
+                    Plot::new(name)
+                        .width(200.0)
+                        .height(200.0)
+                        .link_axis("group", true, false)
+                        .show(ui, |plot_ui| {
+                            if name == "a" {
+                                corners = [
+                                    plot_ui.screen_from_plot(Value::new(2.0, 2.0)),
+                                    plot_ui.screen_from_plot(Value::new(4.0, 6.0)),
+                                ];
+                            }
+                            bounds.push(plot_ui.plot_bounds());
+                            plot_ui.line(line);
+                        });
+                }
+            });
+        });
+        corners
+    };
+    let button = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::COMMAND,
+    };
+
+    let mut bounds = vec![];
+    frame(vec![], &mut bounds);
+    let [from, to] = frame(vec![], &mut bounds);
+    frame(
+        vec![Event::PointerMoved(from), button(from, true)],
+        &mut bounds,
+    );
+    frame(vec![Event::PointerMoved(to)], &mut bounds);
+    frame(vec![button(to, false)], &mut bounds);
+    frame(vec![], &mut bounds);
+
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-3;
+    let a = bounds[0];
+    assert!(close(a.min()[0], 2.0) && close(a.max()[0], 4.0), "{:?}", a);
+    assert!(close(a.min()[1], 2.0) && close(a.max()[1], 6.0), "{:?}", a);
+    let b = bounds[1];
+    assert!(close(b.min()[0], 2.0) && close(b.max()[0], 4.0), "{:?}", b);
+    assert!(b.max()[1] > 9.0, "the y axis is not linked: {:?}", b);
+}
+
+#[cfg(test)]
+#[test]
+fn test_hover_ui() {
+    use std::{cell::Cell, rc::Rc};
+
+    let mut ctx = CtxRef::default();
+    let hovered = Rc::new(Cell::new(None));
+    let mut frame = |events: Vec<Event>| {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let mut screen_pos = Pos2::ZERO;
+        let hovered = hovered.clone();
+        let _ = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let points = Points::new(Values::from_ys_f32(&[3.0, 1.0, 4.0])).name("samples");
+                Plot::new("plot")
+                    .width(200.0)
+                    .height(200.0)
+                    .hover_ui(move |_ui, element| {
+                        assert_eq!(element.item_name, "samples");
+                        hovered.set(Some((element.index, element.value)));
+                    })
+                    .show(ui, |plot_ui| {
+                        screen_pos = plot_ui.screen_from_plot(Value::new(1.0, 1.0));
+                        plot_ui.points(points);
+                    });
+            });
+        });
+        screen_pos
+    };
+
+    frame(vec![]);
+    let screen_pos = frame(vec![]);
+    assert_eq!(hovered.get(), None);
+    frame(vec![Event::PointerMoved(screen_pos + vec2(2.0, 2.0))]);
+    assert_eq!(hovered.get(), Some((1, Value::new(1.0, 1.0))));
+}
+
+#[cfg(test)]
+#[test]
+fn test_draggable_points() {
+    let mut ctx = CtxRef::default();
+    let mut values = vec![Value::new(0.0, 0.0), Value::new(1.0, 1.0)];
+    let mut frame = |events: Vec<Event>, values: &mut Vec<Value>| {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let mut result = (None, Pos2::ZERO, PlotBounds::NOTHING);
+        let _ = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                Plot::new("plot")
+                    .width(200.0)
+                    .height(200.0)
+                    .show(ui, |plot_ui| {
+                        let screen_pos = plot_ui.screen_from_plot(Value::new(1.0, 1.0));
+                        let moved = plot_ui.draggable_points("handles", values);
+                        result = (moved, screen_pos, plot_ui.plot_bounds());
+                    });
+            });
+        });
+        result
+    };
+    let button = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+
+    frame(vec![], &mut values);
+    let (_, pos, bounds) = frame(vec![], &mut values);
+    let grab = pos + vec2(2.0, 0.0);
+    frame(
+        vec![Event::PointerMoved(grab), button(grab, true)],
+        &mut values,
+    );
+    let drop = grab + vec2(0.0, 50.0);
+    let (moved, _, _) = frame(vec![Event::PointerMoved(drop)], &mut values);
+    assert_eq!(moved, Some(1));
+    assert_eq!(values[0], Value::new(0.0, 0.0));
+    assert!((values[1].x - 1.0).abs() < 1e-3, "{:?}", values[1]);
+    assert!(values[1].y < 0.9, "{:?}", values[1]);
+    let (moved, _, bounds_while_dragging) = frame(vec![button(drop, false)], &mut values);
+    assert_eq!(moved, None);
+    assert_eq!(bounds_while_dragging, bounds, "the plot should not pan");
+}
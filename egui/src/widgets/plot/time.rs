@@ -0,0 +1,85 @@
+//! Formatting of time stamps on the plot axes.
+
+use std::ops::RangeInclusive;
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+const YEAR: f64 = 365.25 * DAY;
+
+/// Formats seconds since the Unix epoch (1970-01-01 00:00:00) as an UTC date and/or time of day,
+/// with a precision that suits the `range` shown on the axis:
+/// `2021-12-31` when showing months, `23:59` when showing hours, `23:59:59.250` when showing seconds etc.
+///
+/// Pass it to [`super::Plot::x_axis_formatter`] for time series:
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::plot::{format_unix_timestamp, Plot};
+/// Plot::new("time_series")
+///     .x_axis_formatter(format_unix_timestamp)
+///     .show(ui, |plot_ui| ());
+/// # });
+/// ```
+pub fn format_unix_timestamp(seconds: f64, range: &RangeInclusive<f64>) -> String {
+    if !seconds.is_finite() || seconds.abs() > 1e6 * YEAR {
+        return seconds.to_string();
+    }
+
+    let millis = (seconds * 1000.0).round() as i64;
+    let (days, millis_of_day) = (millis.div_euclid(86_400_000), millis.rem_euclid(86_400_000));
+    let (year, month, day) = date_from_days(days);
+    let (hour, minute) = (millis_of_day / 3_600_000, millis_of_day / 60_000 % 60);
+    let (second, milli) = (millis_of_day / 1000 % 60, millis_of_day % 1000);
+
+    let span = (range.end() - range.start()).abs();
+    if span < 10.0 {
+        format!("{:02}:{:02}:{:02}.{:03}", hour, minute, second, milli)
+    } else if span < HOUR {
+        format!("{:02}:{:02}:{:02}", hour, minute, second)
+    } else if span < DAY {
+        format!("{:02}:{:02}", hour, minute)
+    } else if span < 10.0 * DAY {
+        format!("{:02}-{:02} {:02}:{:02}", month, day, hour, minute)
+    } else if span < 3.0 * YEAR {
+        format!("{}-{:02}-{:02}", year, month, day)
+    } else {
+        format!("{}-{:02}", year, month)
+    }
+}
+
+/// The (year, month, day) of the given number of days since 1970-01-01, in the proleptic Gregorian calendar.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn date_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468; // Days since 0000-03-01
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+#[cfg(test)]
+#[test]
+fn test_format_unix_timestamp() {
+    let format = |seconds: f64, span: f64| format_unix_timestamp(seconds, &(0.0..=span));
+    assert_eq!(format(0.0, YEAR), "1970-01-01");
+    assert_eq!(format(951_782_400.0, YEAR), "2000-02-29");
+    assert_eq!(format(-DAY, YEAR), "1969-12-31");
+
+    let new_years_eve = 1_640_995_199.25;
+    assert_eq!(format(new_years_eve, 5.0), "23:59:59.250");
+    assert_eq!(format(new_years_eve, MINUTE), "23:59:59");
+    assert_eq!(format(new_years_eve, HOUR), "23:59");
+    assert_eq!(format(new_years_eve, DAY), "12-31 23:59");
+    assert_eq!(format(new_years_eve, 10.0 * YEAR), "2021-12");
+}
@@ -3,6 +3,123 @@ use std::ops::RangeInclusive;
 use super::items::Value;
 use crate::*;
 
+/// How the values on an axis are mapped to positions on the screen.
+///
+/// See [`super::Plot::x_axis_scale`] and [`super::Plot::y_axis_scale`].
+#[derive(Clone, Copy, Debug)]
+pub enum AxisScale {
+    /// Equal distances between values take up equal space. The default.
+    Linear,
+
+    /// Every power of ten takes up the same space. Only positive values can be shown.
+    Log10,
+
+    /// Logarithmic far from zero, and close to linear in `-linear_threshold..=linear_threshold`.
+    ///
+    /// Useful for values of both signs that span several orders of magnitude.
+    /// `linear_threshold` must be positive.
+    SymLog { linear_threshold: f64 },
+
+    /// A custom strictly increasing function, together with its inverse.
+    Custom {
+        forward: fn(f64) -> f64,
+        inverse: fn(f64) -> f64,
+    },
+}
+
+impl Default for AxisScale {
+    fn default() -> Self {
+        Self::Linear
+    }
+}
+
+impl AxisScale {
+    /// From a value to where it is on the (linear) screen axis, up to an offset and a factor.
+    pub fn forward(&self, value: f64) -> f64 {
+        match *self {
+            Self::Linear => value,
+            Self::Log10 => value.max(f64::MIN_POSITIVE).log10(),
+            Self::SymLog { linear_threshold } => {
+                value.signum() * (value.abs() / linear_threshold).ln_1p() / std::f64::consts::LN_10
+            }
+            Self::Custom { forward, .. } => forward(value),
+        }
+    }
+
+    /// The inverse of [`Self::forward`].
+    pub fn inverse(&self, scaled: f64) -> f64 {
+        match *self {
+            Self::Linear => scaled,
+            Self::Log10 => 10_f64.powf(scaled),
+            Self::SymLog { linear_threshold } => {
+                scaled.signum() * linear_threshold * (10_f64.powf(scaled.abs()) - 1.0)
+            }
+            Self::Custom { inverse, .. } => inverse(scaled),
+        }
+    }
+
+    /// A [`Self::SymLog`] threshold that is not positive would divide by zero, so it is clamped.
+    pub(crate) fn sanitized(self) -> Self {
+        match self {
+            Self::SymLog { linear_threshold } => Self::SymLog {
+                linear_threshold: if linear_threshold > 0.0 {
+                    linear_threshold
+                } else {
+                    f64::MIN_POSITIVE
+                },
+            },
+            scale => scale,
+        }
+    }
+
+    /// Make sure the range can be shown on this scale, i.e. is positive for [`Self::Log10`].
+    fn sanitize(&self, min: &mut f64, max: &mut f64) {
+        if matches!(self, Self::Log10) {
+            if *max <= 0.0 {
+                *min = 1.0;
+                *max = 10.0;
+            } else if *min <= 0.0 {
+                *min = *max / 1000.0;
+            }
+        }
+    }
+
+    /// The text of a grid mark label.
+    fn format_grid_mark(&self, value: f64) -> String {
+        match self {
+            Self::Linear => emath::round_to_decimals(value, 5).to_string(), // hack
+            Self::Log10 | Self::SymLog { .. } => {
+                let exponent = value.abs().log10().floor();
+                if value == 0.0 || (-5.0..6.0).contains(&exponent) {
+                    emath::round_to_decimals(value, 5).to_string()
+                } else {
+                    let mantissa = (value / 10_f64.powf(exponent)).round();
+                    format!("{}e{}", mantissa, exponent)
+                }
+            }
+            Self::Custom { .. } => {
+                if value == 0.0 {
+                    return "0".to_owned();
+                }
+                // Three significant digits:
+                let decimals = (2.0 - value.abs().log10().floor()).clamp(0.0, 15.0) as usize;
+                emath::round_to_decimals(value, decimals).to_string()
+            }
+        }
+    }
+}
+
+/// Where to draw a grid line (and maybe a label).
+pub(crate) struct GridMark {
+    pub value: f64,
+
+    /// The distance to the neighboring grid marks of the same or a coarser level, in points.
+    /// Decides how visible the line and the label are.
+    pub spacing_in_points: f32,
+
+    pub label: String,
+}
+
 /// 2D bounding box of f64 precision.
 /// The range of data values we show.
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -18,6 +135,11 @@ impl PlotBounds {
         max: [-f64::INFINITY; 2],
     };
 
+    /// The bounds from `min` to `max`, e.g. to set them with [`super::PlotUi::set_plot_bounds`].
+    pub fn from_min_max(min: [f64; 2], max: [f64; 2]) -> Self {
+        Self { min, max }
+    }
+
     pub fn min(&self) -> [f64; 2] {
         self.min
     }
@@ -131,6 +253,27 @@ impl PlotBounds {
         self.min[1] = -y_abs;
         self.max[1] = y_abs;
     }
+
+    /// The bounds in the space where both axes are linear, see [`AxisScale::forward`].
+    pub(crate) fn scaled(&self, scales: &[AxisScale; 2]) -> Self {
+        let mut scaled = *self;
+        for (axis, scale) in scales.iter().enumerate() {
+            scale.sanitize(&mut scaled.min[axis], &mut scaled.max[axis]);
+            scaled.min[axis] = scale.forward(scaled.min[axis]);
+            scaled.max[axis] = scale.forward(scaled.max[axis]);
+        }
+        scaled
+    }
+
+    /// The inverse of [`Self::scaled`].
+    pub(crate) fn unscaled(&self, scales: &[AxisScale; 2]) -> Self {
+        let mut unscaled = *self;
+        for (axis, scale) in scales.iter().enumerate() {
+            unscaled.min[axis] = scale.inverse(self.min[axis]);
+            unscaled.max[axis] = scale.inverse(self.max[axis]);
+        }
+        unscaled
+    }
 }
 
 /// Contains the screen rectangle and the plot bounds and provides methods to transform them.
@@ -141,6 +284,9 @@ pub(crate) struct ScreenTransform {
     frame: Rect,
     /// The plot bounds.
     bounds: PlotBounds,
+    /// How values are mapped to the screen on each axis.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scales: [AxisScale; 2],
     /// Whether to always center the x-range of the bounds.
     x_centered: bool,
     /// Whether to always center the y-range of the bounds.
@@ -148,7 +294,13 @@ pub(crate) struct ScreenTransform {
 }
 
 impl ScreenTransform {
-    pub fn new(frame: Rect, mut bounds: PlotBounds, x_centered: bool, y_centered: bool) -> Self {
+    pub fn new(
+        frame: Rect,
+        mut bounds: PlotBounds,
+        scales: [AxisScale; 2],
+        x_centered: bool,
+        y_centered: bool,
+    ) -> Self {
         // Make sure they are not empty.
         if !bounds.is_valid() {
             bounds = PlotBounds::new_symmetrical(1.0);
@@ -162,9 +314,14 @@ impl ScreenTransform {
             bounds.make_y_symmetrical();
         };
 
+        for (axis, scale) in scales.iter().enumerate() {
+            scale.sanitize(&mut bounds.min[axis], &mut bounds.max[axis]);
+        }
+
         Self {
             frame,
             bounds,
+            scales,
             x_centered,
             y_centered,
         }
@@ -178,6 +335,11 @@ impl ScreenTransform {
         &self.bounds
     }
 
+    /// The bounds in the space where both axes are linear.
+    fn scaled_bounds(&self) -> PlotBounds {
+        self.bounds.scaled(&self.scales)
+    }
+
     pub fn translate_bounds(&mut self, mut delta_pos: Vec2) {
         if self.x_centered {
             delta_pos.x = 0.;
@@ -185,20 +347,30 @@ impl ScreenTransform {
         if self.y_centered {
             delta_pos.y = 0.;
         }
-        delta_pos.x *= self.dvalue_dpos()[0] as f32;
-        delta_pos.y *= self.dvalue_dpos()[1] as f32;
-        self.bounds.translate(delta_pos);
+        let mut scaled = self.scaled_bounds();
+        delta_pos.x *= (scaled.width() / self.frame.width() as f64) as f32;
+        delta_pos.y *= (-scaled.height() / self.frame.height() as f64) as f32; // negated y axis!
+        scaled.translate(delta_pos);
+        self.bounds = scaled.unscaled(&self.scales);
     }
 
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
         let center = self.value_from_position(center);
-
-        let mut new_bounds = self.bounds;
-        new_bounds.min[0] = center.x + (new_bounds.min[0] - center.x) / (zoom_factor.x as f64);
-        new_bounds.max[0] = center.x + (new_bounds.max[0] - center.x) / (zoom_factor.x as f64);
-        new_bounds.min[1] = center.y + (new_bounds.min[1] - center.y) / (zoom_factor.y as f64);
-        new_bounds.max[1] = center.y + (new_bounds.max[1] - center.y) / (zoom_factor.y as f64);
+        let center = [
+            self.scales[0].forward(center.x),
+            self.scales[1].forward(center.y),
+        ];
+        let zoom_factor = [zoom_factor.x as f64, zoom_factor.y as f64];
+
+        let mut new_bounds = self.scaled_bounds();
+        for axis in 0..2 {
+            new_bounds.min[axis] =
+                center[axis] + (new_bounds.min[axis] - center[axis]) / zoom_factor[axis];
+            new_bounds.max[axis] =
+                center[axis] + (new_bounds.max[axis] - center[axis]) / zoom_factor[axis];
+        }
+        let new_bounds = new_bounds.unscaled(&self.scales);
 
         if new_bounds.is_valid() {
             self.bounds = new_bounds;
@@ -206,31 +378,33 @@ impl ScreenTransform {
     }
 
     pub fn position_from_value(&self, value: &Value) -> Pos2 {
+        let bounds = self.scaled_bounds();
         let x = remap(
-            value.x,
-            self.bounds.min[0]..=self.bounds.max[0],
+            self.scales[0].forward(value.x),
+            bounds.min[0]..=bounds.max[0],
             (self.frame.left() as f64)..=(self.frame.right() as f64),
         );
         let y = remap(
-            value.y,
-            self.bounds.min[1]..=self.bounds.max[1],
+            self.scales[1].forward(value.y),
+            bounds.min[1]..=bounds.max[1],
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
         );
         pos2(x as f32, y as f32)
     }
 
     pub fn value_from_position(&self, pos: Pos2) -> Value {
+        let bounds = self.scaled_bounds();
         let x = remap(
             pos.x as f64,
             (self.frame.left() as f64)..=(self.frame.right() as f64),
-            self.bounds.min[0]..=self.bounds.max[0],
+            bounds.min[0]..=bounds.max[0],
         );
         let y = remap(
             pos.y as f64,
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
-            self.bounds.min[1]..=self.bounds.max[1],
+            bounds.min[1]..=bounds.max[1],
         );
-        Value::new(x, y)
+        Value::new(self.scales[0].inverse(x), self.scales[1].inverse(y))
     }
 
     /// Transform a rectangle of plot values to a screen-coordinate rectangle.
@@ -270,18 +444,203 @@ impl ScreenTransform {
     pub fn get_aspect(&self) -> f64 {
         let rw = self.frame.width() as f64;
         let rh = self.frame.height() as f64;
-        (self.bounds.width() / rw) / (self.bounds.height() / rh)
+        let bounds = self.scaled_bounds();
+        (bounds.width() / rw) / (bounds.height() / rh)
     }
 
     pub fn set_aspect(&mut self, aspect: f64) {
         let epsilon = 1e-5;
         let current_aspect = self.get_aspect();
+        let mut bounds = self.scaled_bounds();
         if current_aspect < aspect - epsilon {
-            self.bounds
-                .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
+            bounds.expand_x((aspect / current_aspect - 1.0) * bounds.width() * 0.5);
         } else if current_aspect > aspect + epsilon {
-            self.bounds
-                .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
+            bounds.expand_y((current_aspect / aspect - 1.0) * bounds.height() * 0.5);
+        }
+        self.bounds = bounds.unscaled(&self.scales);
+    }
+
+    /// Where to draw the grid lines of the given axis.
+    ///
+    /// Grid marks closer than `min_spacing_in_points` to their neighbors are left out.
+    pub(crate) fn grid_marks(&self, axis: usize, min_spacing_in_points: f64) -> Vec<GridMark> {
+        match self.scales[axis] {
+            AxisScale::Linear | AxisScale::Custom { .. } => {
+                self.linear_grid_marks(axis, min_spacing_in_points)
+            }
+            AxisScale::Log10 | AxisScale::SymLog { .. } => {
+                self.decade_grid_marks(axis, min_spacing_in_points)
+            }
+        }
+    }
+
+    /// Evenly spaced grid marks in the scaled space, at multiples of a power of ten.
+    fn linear_grid_marks(&self, axis: usize, min_spacing_in_points: f64) -> Vec<GridMark> {
+        let scale = self.scales[axis];
+        let bounds = self.scaled_bounds();
+
+        let base: i64 = 10;
+        let basef = base as f64;
+
+        let dvalue_dpos = (bounds.max[axis] - bounds.min[axis]) / self.frame.size()[axis] as f64;
+        let step_size = dvalue_dpos * min_spacing_in_points;
+        let step_size = basef.powi(step_size.abs().log(basef).ceil() as i32);
+
+        let step_size_in_points = (step_size / dvalue_dpos).abs() as f32;
+
+        let mut marks = vec![];
+        for i in 0.. {
+            let value_main = step_size * (bounds.min[axis] / step_size + i as f64).floor();
+            if value_main > bounds.max[axis] {
+                break;
+            }
+
+            let n = (value_main / step_size).round() as i64;
+            let spacing_in_points = if n % (base * base) == 0 {
+                step_size_in_points * (basef * basef) as f32 // think line (multiple of 100)
+            } else if n % base == 0 {
+                step_size_in_points * basef as f32 // medium line (multiple of 10)
+            } else {
+                step_size_in_points // thin line
+            };
+
+            let value = scale.inverse(value_main);
+            marks.push(GridMark {
+                value,
+                spacing_in_points,
+                label: scale.format_grid_mark(value),
+            });
+        }
+        marks
+    }
+
+    /// Grid marks at the powers of ten, and at their multiples when there is room.
+    fn decade_grid_marks(&self, axis: usize, min_spacing_in_points: f64) -> Vec<GridMark> {
+        let scale = self.scales[axis];
+        let bounds = self.scaled_bounds();
+        let (min, max) = (self.bounds.min[axis], self.bounds.max[axis]);
+
+        let points_per_scaled =
+            self.frame.size()[axis] as f64 / (bounds.max[axis] - bounds.min[axis]);
+        let spacing =
+            |a: f64, b: f64| (scale.forward(b) - scale.forward(a)).abs() * points_per_scaled;
+
+        let mut marks = vec![];
+        let mut add = |value: f64, spacing_in_points: f64| {
+            if (min..=max).contains(&value) && spacing_in_points >= min_spacing_in_points {
+                marks.push(GridMark {
+                    value,
+                    spacing_in_points: spacing_in_points as f32,
+                    label: scale.format_grid_mark(value),
+                });
+            }
+        };
+
+        let mut add_decades = |sign: f64, exponents: RangeInclusive<i32>| {
+            for exponent in exponents {
+                let decade = sign * 10_f64.powi(exponent);
+                let decade_spacing = spacing(decade, 10.0 * decade);
+                let emphasis = if exponent % 10 == 0 {
+                    10.0 // stand out when there are many decades
+                } else {
+                    1.0
+                };
+                add(decade, emphasis * decade_spacing);
+
+                if decade_spacing >= min_spacing_in_points {
+                    for m in 2..=9 {
+                        let m = m as f64;
+                        add(m * decade, spacing(m * decade, (m + 1.0) * decade));
+                    }
+                }
+            }
+        };
+
+        let max_exponent = |value: f64| value.abs().log10().ceil().min(308.0) as i32;
+        if let AxisScale::SymLog { linear_threshold } = scale {
+            let min_exponent = linear_threshold.log10().floor() as i32;
+            if max > 0.0 {
+                add_decades(1.0, min_exponent..=max_exponent(max));
+            }
+            if min < 0.0 {
+                add_decades(-1.0, min_exponent..=max_exponent(min));
+            }
+            add(0.0, spacing(0.0, 10.0 * linear_threshold));
+        } else {
+            let min_exponent = min.log10().floor().max(-308.0) as i32;
+            add_decades(1.0, min_exponent..=max_exponent(max));
         }
+        marks
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_log_axis() {
+    let frame = Rect::from_min_size(pos2(0.0, 0.0), vec2(400.0, 400.0));
+    let bounds = PlotBounds {
+        min: [1.0, -100.0],
+        max: [10_000.0, 100.0],
+    };
+    let scales = [
+        AxisScale::Log10,
+        AxisScale::SymLog {
+            linear_threshold: 1.0,
+        },
+    ];
+    let transform = ScreenTransform::new(frame, bounds, scales, false, false);
+
+    // Every decade takes up a quarter of the width:
+    for (value, x) in [(1.0, 0.0), (10.0, 100.0), (100.0, 200.0), (10_000.0, 400.0)] {
+        let pos = transform.position_from_value(&Value::new(value, 0.0));
+        assert!((pos.x - x).abs() < 1e-3, "{} at {}", value, pos.x);
+        let back = transform.value_from_position(pos);
+        assert!((back.x - value).abs() < 1e-6 * value);
+    }
+    assert!((transform.position_from_value(&Value::new(1.0, 0.0)).y - 200.0).abs() < 1e-3);
+
+    let marks = transform.grid_marks(0, 6.0);
+    let labels: Vec<&str> = marks.iter().map(|mark| mark.label.as_str()).collect();
+    for label in ["1", "2", "6", "10", "20", "1000", "10000"] {
+        assert!(labels.contains(&label), "{:?}", labels);
+    }
+
+    let marks = transform.grid_marks(1, 6.0);
+    let values: Vec<f64> = marks.iter().map(|mark| mark.value).collect();
+    for value in [-100.0, -10.0, 0.0, 10.0, 100.0] {
+        assert!(values.contains(&value), "{:?}", values);
+    }
+
+    assert_eq!(AxisScale::Log10.format_grid_mark(3e-9), "3e-9");
+    assert_eq!(AxisScale::Log10.format_grid_mark(2e7), "2e7");
+    assert_eq!(AxisScale::Log10.format_grid_mark(0.05), "0.05");
+}
+
+#[cfg(test)]
+#[test]
+fn test_custom_axis_through_zero() {
+    let frame = Rect::from_min_size(pos2(0.0, 0.0), vec2(400.0, 400.0));
+    let bounds = PlotBounds::new_symmetrical(1.0);
+    let scales = [
+        AxisScale::Custom {
+            forward: f64::cbrt,
+            inverse: |x| x * x * x,
+        },
+        AxisScale::Linear,
+    ];
+    let transform = ScreenTransform::new(frame, bounds, scales, false, false);
+
+    let marks = transform.grid_marks(0, 6.0);
+    let labels: Vec<&str> = marks.iter().map(|mark| mark.label.as_str()).collect();
+    assert!(labels.contains(&"0"), "{:?}", labels);
+
+    assert_eq!(scales[0].format_grid_mark(0.0), "0");
+    assert_eq!(scales[0].format_grid_mark(0.123_456), "0.123");
+    assert_eq!(scales[0].format_grid_mark(1e-300), "0");
+
+    let symlog = AxisScale::SymLog {
+        linear_threshold: 0.0,
+    }
+    .sanitized();
+    assert!(symlog.forward(1.0).is_finite());
+}
@@ -0,0 +1,306 @@
+use crate::*;
+use epaint::text::LayoutJob;
+
+/// What a span of a [`RichLabel`] does when interacted with.
+enum SpanKind {
+    Text,
+    Link { url: String },
+    Clickable,
+    Tooltip(WidgetText),
+}
+
+/// A label made of spans of differently styled text, some of which can be
+/// inline links, clickable words, or have their own tooltips.
+///
+/// Unlike putting several widgets in a [`Ui::horizontal_wrapped`], all spans are laid out together,
+/// so the text wraps just like a single [`Label`].
+///
+/// Each link, clickable and tooltip span gets its own [`Response`], see [`RichLabelResponse::spans`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # fn show_docs() {}
+/// let response = egui::RichLabel::new()
+///     .text("Read the ")
+///     .clickable("docs")
+///     .text(" or visit ")
+///     .link(egui::RichText::new("the repository").strong(), "https://github.com/emilk/egui")
+///     .text(" for ")
+///     .tooltip("more info", "Examples, guides and the changelog")
+///     .text(".")
+///     .show(ui);
+/// if response.spans[0].clicked() {
+///     show_docs();
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Default)]
+pub struct RichLabel {
+    spans: Vec<(RichText, SpanKind)>,
+    wrap: Option<bool>,
+}
+
+/// The [`Response`]s of a [`RichLabel`].
+pub struct RichLabelResponse {
+    /// The whole label.
+    pub response: Response,
+
+    /// One per span added with [`RichLabel::link`], [`RichLabel::clickable`] or [`RichLabel::tooltip`],
+    /// in the order they were added.
+    /// A span that wraps over several rows covers all of them.
+    pub spans: Vec<Response>,
+}
+
+impl RichLabel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Plain text, styled as given.
+    pub fn text(mut self, text: impl Into<RichText>) -> Self {
+        self.spans.push((text.into(), SpanKind::Text));
+        self
+    }
+
+    /// A link to a url, like a [`Hyperlink`]: it opens the url when clicked,
+    /// and is shown in [`Visuals::hyperlink_color`] unless the text has a color of its own.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn link(mut self, text: impl Into<RichText>, url: impl ToString) -> Self {
+        let url = url.to_string();
+        self.spans.push((text.into(), SpanKind::Link { url }));
+        self
+    }
+
+    /// Text that looks like a link, but that you handle yourself with [`Response::clicked`].
+    pub fn clickable(mut self, text: impl Into<RichText>) -> Self {
+        self.spans.push((text.into(), SpanKind::Clickable));
+        self
+    }
+
+    /// Text with a dashed underline that shows a tooltip when hovered.
+    pub fn tooltip(mut self, text: impl Into<RichText>, tooltip: impl Into<WidgetText>) -> Self {
+        self.spans
+            .push((text.into(), SpanKind::Tooltip(tooltip.into())));
+        self
+    }
+
+    /// Same as [`Label::wrap`].
+    #[inline]
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> RichLabelResponse {
+        let Self { spans, wrap } = self;
+
+        let valign = ui.layout().vertical_align();
+        let mut job = LayoutJob::default();
+        // The span each section of the job belongs to:
+        let mut section_spans = vec![];
+        // The interactive spans, with their text and color:
+        let mut interactive = vec![];
+        for (span_index, (text, kind)) in spans.into_iter().enumerate() {
+            let default_color = match &kind {
+                SpanKind::Link { url } if ui.memory().is_url_visited(url) => {
+                    ui.visuals().hyperlink_visited_color
+                }
+                SpanKind::Link { .. } | SpanKind::Clickable => ui.visuals().hyperlink_color,
+                SpanKind::Text | SpanKind::Tooltip(_) => ui.visuals().text_color(),
+            };
+            let span_job = text.into_text_job(ui.style(), TextStyle::Body, valign);
+            let offset = job.text.len();
+            job.text += &span_job.job.text;
+            let mut color = default_color;
+            for mut section in span_job.job.sections {
+                section.byte_range =
+                    section.byte_range.start + offset..section.byte_range.end + offset;
+                if span_job.job_has_color {
+                    color = section.format.color;
+                } else {
+                    section.format.color = default_color;
+                }
+                job.sections.push(section);
+                section_spans.push(span_index);
+            }
+            if !matches!(kind, SpanKind::Text) {
+                interactive.push((span_index, kind, span_job.job.text, color));
+            }
+        }
+
+        let mut label = Label::new(job);
+        if let Some(wrap) = wrap {
+            label = label.wrap(wrap);
+        }
+        let (pos, text_galley, response) = label.layout_in_ui(ui);
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, text_galley.text()));
+
+        let mut span_responses = vec![];
+        let mut underlines: Vec<Shape> = vec![];
+        for (span_index, kind, text, color) in interactive {
+            // One rect per row that the span is on:
+            let row_rects: Vec<Rect> = text_galley
+                .galley
+                .rows
+                .iter()
+                .filter_map(|row| {
+                    let mut glyphs = row
+                        .glyphs
+                        .iter()
+                        .filter(|glyph| section_spans[glyph.section_index as usize] == span_index);
+                    let first = glyphs.next()?;
+                    let max_x = glyphs.next_back().unwrap_or(first).max_x();
+                    let rect = Rect::from_x_y_ranges(first.pos.x..=max_x, row.rect.y_range());
+                    Some(rect.translate(pos.to_vec2()))
+                })
+                .collect();
+
+            let id = response.id.with(span_index);
+            let mut span_response = ui.interact(
+                row_rects
+                    .first()
+                    .copied()
+                    .unwrap_or_else(|| Rect::from_min_size(pos, Vec2::ZERO)),
+                id,
+                Sense::click(),
+            );
+            for (row_index, rect) in row_rects.iter().enumerate().skip(1) {
+                span_response |= ui.interact(*rect, id.with(row_index), Sense::click());
+            }
+
+            let underline_points: Vec<[Pos2; 2]> = row_rects
+                .iter()
+                .map(|rect| {
+                    let y = rect.bottom() - 1.0;
+                    [pos2(rect.left(), y), pos2(rect.right(), y)]
+                })
+                .collect();
+            let underline = |stroke: Stroke| {
+                underline_points
+                    .iter()
+                    .map(move |&points| Shape::line_segment(points, stroke))
+            };
+
+            let span_response = match kind {
+                SpanKind::Text => continue,
+                SpanKind::Link { url } => {
+                    span_response.widget_info(|| WidgetInfo::labeled(WidgetType::Hyperlink, &text));
+                    if span_response.clicked() || span_response.middle_clicked() {
+                        let new_tab =
+                            span_response.middle_clicked() || ui.ctx().input().modifiers.any();
+                        ui.ctx().output().open_urls.push(crate::output::OpenUrl {
+                            url: url.clone(),
+                            new_tab,
+                        });
+                        ui.memory().mark_url_visited(&url);
+                    }
+                    if span_response.hovered() {
+                        ui.ctx().output().cursor_icon = CursorIcon::PointingHand;
+                        underlines.extend(underline(Stroke::new(1.0, color)));
+                    }
+                    span_response.on_hover_text(url)
+                }
+                SpanKind::Clickable => {
+                    span_response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, &text));
+                    if span_response.hovered() {
+                        ui.ctx().output().cursor_icon = CursorIcon::PointingHand;
+                        underlines.extend(underline(Stroke::new(1.0, color)));
+                    }
+                    span_response
+                }
+                SpanKind::Tooltip(tooltip) => {
+                    let stroke = Stroke::new(1.0, ui.visuals().weak_text_color());
+                    for points in &underline_points {
+                        underlines.extend(Shape::dashed_line(points, stroke, 2.0, 2.0));
+                    }
+                    span_response.on_hover_text(tooltip)
+                }
+            };
+            span_responses.push(span_response);
+        }
+
+        if ui.is_rect_visible(response.rect) {
+            ui.painter().add(epaint::TextShape {
+                pos,
+                galley: text_galley.galley,
+                override_text_color: None,
+                underline: Stroke::none(),
+                angle: 0.0,
+            });
+            ui.painter().extend(underlines);
+        }
+
+        RichLabelResponse {
+            response,
+            spans: span_responses,
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_rich_label_spans() {
+    use crate::test::Harness;
+
+    #[derive(Default)]
+    struct State {
+        width: f32,
+        /// The whole label and its spans, last frame.
+        rects: Vec<Rect>,
+        clicks: [usize; 3],
+        urls: Vec<String>,
+    }
+
+    let url = "https://github.com/emilk/egui";
+    let state = State {
+        width: 400.0,
+        ..Default::default()
+    };
+    let mut harness = Harness::with_state(state, |ctx, state| {
+        CentralPanel::default().show(ctx, |ui| {
+            ui.allocate_ui(vec2(state.width, 200.0), |ui| {
+                let response = RichLabel::new()
+                    .text("Read the ")
+                    .clickable("documentation of egui")
+                    .text(" or ")
+                    .link("the repository", url)
+                    .text(" for ")
+                    .tooltip("more info", "Examples and guides")
+                    .show(ui);
+                state.rects = vec![response.response.rect];
+                for (index, span) in response.spans.iter().enumerate() {
+                    state.rects.push(span.rect);
+                    state.clicks[index] += span.clicked() as usize;
+                }
+            });
+        });
+        let open_urls = ctx.output().open_urls.clone();
+        state
+            .urls
+            .extend(open_urls.into_iter().map(|open_url| open_url.url));
+    });
+
+    let rects = harness.state().rects.clone();
+    assert_eq!(rects.len(), 4, "one response per interactive span");
+    assert_eq!(rects[1].height(), rects[0].height(), "a single row");
+    assert!(rects[1].left() > rects[0].left() && rects[3].right() <= rects[0].right());
+
+    harness.click("documentation of egui");
+    assert_eq!(harness.state().clicks, [1, 0, 0]);
+    assert!(harness.state().urls.is_empty());
+
+    harness.click("the repository");
+    assert_eq!(harness.state().clicks, [1, 1, 0]);
+    assert_eq!(harness.state().urls, [url]);
+
+    // When wrapping, the spans are still part of the same text:
+    harness.state_mut().width = 120.0;
+    harness.run();
+    let rects = &harness.state().rects;
+    assert!(rects[0].width() <= 120.0);
+    assert!(rects[0].height() > 2.0 * rects[2].height());
+    for span_rect in &rects[1..] {
+        assert!(rects[0].expand(0.5).contains_rect(*span_rect));
+    }
+}
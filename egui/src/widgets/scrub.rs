@@ -0,0 +1,208 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::ops::RangeInclusive;
+
+use crate::widgets::drag_value::{clamp_to_range, get, set, GetSetValue};
+use crate::*;
+
+/// A label showing a number, which you can drag horizontally to change the number.
+///
+/// This is a more compact alternative to [`DragValue`], for dense inspectors.
+///
+/// The label text is given by a format string, where `{}` is replaced by the value.
+/// Use `{:.N}` to show the value with `N` decimals.
+///
+/// Hold down shift while dragging for finer control, or the command/ctrl key for coarser control.
+/// Double-click the label to type in an exact value.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut speed: f32 = 0.0;
+/// ui.add(egui::Scrub::new(&mut speed, "Speed: {:.1}").speed(0.1));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Scrub<'a> {
+    get_set_value: GetSetValue<'a>,
+    format: String,
+    speed: f64,
+    clamp_range: RangeInclusive<f64>,
+}
+
+impl<'a> Scrub<'a> {
+    pub fn new<Num: emath::Numeric>(value: &'a mut Num, format: impl ToString) -> Self {
+        let slf = Self::from_get_set(
+            move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *value = Num::from_f64(v);
+                }
+                value.to_f64()
+            },
+            format,
+        );
+
+        if Num::INTEGRAL {
+            slf.clamp_range(Num::MIN..=Num::MAX).speed(0.25)
+        } else {
+            slf
+        }
+    }
+
+    pub fn from_get_set(
+        get_set_value: impl 'a + FnMut(Option<f64>) -> f64,
+        format: impl ToString,
+    ) -> Self {
+        Self {
+            get_set_value: Box::new(get_set_value),
+            format: format.to_string(),
+            speed: 1.0,
+            clamp_range: f64::NEG_INFINITY..=f64::INFINITY,
+        }
+    }
+
+    /// How much the value changes when dragged one point (logical pixel).
+    pub fn speed(mut self, speed: impl Into<f64>) -> Self {
+        self.speed = speed.into();
+        self
+    }
+
+    /// Clamp incoming and outgoing values to this range.
+    pub fn clamp_range<Num: emath::Numeric>(mut self, clamp_range: RangeInclusive<Num>) -> Self {
+        self.clamp_range = clamp_range.start().to_f64()..=clamp_range.end().to_f64();
+        self
+    }
+}
+
+impl<'a> Widget for Scrub<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            mut get_set_value,
+            format,
+            speed,
+            clamp_range,
+        } = self;
+
+        let old_value = get(&mut get_set_value);
+        let value = clamp_to_range(old_value, clamp_range.clone());
+        if old_value != value {
+            set(&mut get_set_value, value);
+        }
+
+        let kb_edit_id = ui.auto_id_with("edit");
+        let is_kb_editing = ui.memory().has_focus(kb_edit_id);
+
+        let mut response = if is_kb_editing {
+            let mut value_text = ui
+                .memory()
+                .drag_value
+                .edit_string
+                .take()
+                .unwrap_or_else(|| value.to_string());
+            let response = ui.add(
+                TextEdit::singleline(&mut value_text)
+                    .id(kb_edit_id)
+                    .desired_width(ui.spacing().interact_size.x)
+                    .text_style(TextStyle::Monospace),
+            );
+            if let Ok(parsed_value) = value_text.parse() {
+                set(
+                    &mut get_set_value,
+                    clamp_to_range(parsed_value, clamp_range),
+                );
+            }
+            if ui.input().key_pressed(Key::Enter) {
+                ui.memory().surrender_focus(kb_edit_id);
+                ui.memory().drag_value.edit_string = None;
+            } else {
+                ui.memory().drag_value.edit_string = Some(value_text);
+            }
+            response
+        } else {
+            let response = ui
+                .add(Label::new(format_value(&format, value)).sense(Sense::click_and_drag()))
+                .on_hover_cursor(CursorIcon::ResizeHorizontal);
+
+            if response.double_clicked() {
+                ui.memory().request_focus(kb_edit_id);
+                ui.memory().drag_value.edit_string = None; // Filled in next frame
+            } else if response.dragged() {
+                ui.output().cursor_icon = CursorIcon::ResizeHorizontal;
+
+                let modifiers = ui.input().modifiers;
+                let speed = if modifiers.shift {
+                    speed / 10.0
+                } else if modifiers.command {
+                    speed * 10.0
+                } else {
+                    speed
+                };
+                let delta_value = response.drag_delta().x as f64 * speed;
+
+                if delta_value != 0.0 {
+                    let mut drag_state = std::mem::take(&mut ui.memory().drag_value);
+
+                    // The value may be rounded (e.g. for integers), so we need to store the full precision value in memory:
+                    let stored_value = (drag_state.last_dragged_id == Some(response.id))
+                        .then(|| drag_state.last_dragged_value)
+                        .flatten();
+                    let stored_value = stored_value.unwrap_or(value) + delta_value;
+                    set(
+                        &mut get_set_value,
+                        clamp_to_range(stored_value, clamp_range),
+                    );
+
+                    drag_state.last_dragged_id = Some(response.id);
+                    drag_state.last_dragged_value = Some(stored_value);
+                    ui.memory().drag_value = drag_state;
+                }
+            }
+
+            response
+        };
+
+        response.changed = get(&mut get_set_value) != old_value;
+
+        response.widget_info(|| WidgetInfo::drag_value(value));
+        response
+    }
+}
+
+/// Replace the first `{}` or `{:.N}` in `format` with `value`.
+///
+/// If there is no such placeholder, the value is put at the end.
+fn format_value(format: &str, value: f64) -> String {
+    let placeholder = format.find('{').and_then(|start| {
+        let end = start + format[start..].find('}')?;
+        let spec = &format[start + 1..end];
+        let decimals = if spec.is_empty() {
+            None
+        } else {
+            Some(spec.strip_prefix(":.")?.parse::<usize>().ok()?)
+        };
+        Some((start, end, decimals))
+    });
+
+    let value_text = |decimals: Option<usize>| match decimals {
+        Some(decimals) => format!("{:.*}", decimals, value),
+        None => value.to_string(),
+    };
+
+    match placeholder {
+        Some((start, end, decimals)) => format!(
+            "{}{}{}",
+            &format[..start],
+            value_text(decimals),
+            &format[end + 1..]
+        ),
+        None => format!("{}{}", format, value_text(None)),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_format_value() {
+    assert_eq!(format_value("Speed: {:.1}", 1.24), "Speed: 1.2");
+    assert_eq!(format_value("{} m", 3.0), "3 m");
+    assert_eq!(format_value("x = {:.2}!", 0.5), "x = 0.50!");
+    assert_eq!(format_value("Count: ", 7.0), "Count: 7");
+}
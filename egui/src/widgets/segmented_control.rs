@@ -0,0 +1,238 @@
+use crate::*;
+
+/// Which segments of a [`SegmentedControl`] are selected.
+enum Selection<'a> {
+    /// Exactly one, by index.
+    One(&'a mut usize),
+
+    /// One `bool` per segment.
+    Many(&'a mut [bool]),
+}
+
+impl<'a> Selection<'a> {
+    fn is_selected(&self, index: usize) -> bool {
+        match self {
+            Self::One(selected) => **selected == index,
+            Self::Many(selected) => selected.get(index).copied().unwrap_or(false),
+        }
+    }
+
+    /// Returns `true` on change.
+    fn click(&mut self, index: usize) -> bool {
+        match self {
+            Self::One(selected) => {
+                let changed = **selected != index;
+                **selected = index;
+                changed
+            }
+            Self::Many(selected) => {
+                if let Some(selected) = selected.get_mut(index) {
+                    *selected = !*selected;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+}
+
+/// A row of connected buttons, where either exactly one is selected (like radio buttons),
+/// or any number of them (like checkboxes).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut view = 0;
+/// ui.add(egui::SegmentedControl::new(&mut view, ["Day", "Week", "Month"]));
+///
+/// let mut bold_italic_underline = [true, false, false];
+/// ui.add(egui::SegmentedControl::multiple(&mut bold_italic_underline, ["B", "I", "U"]).equal_width(true));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct SegmentedControl<'a> {
+    selection: Selection<'a>,
+    segments: Vec<WidgetText>,
+    equal_width: bool,
+}
+
+impl<'a> SegmentedControl<'a> {
+    /// Exactly one segment is selected: the one with index `*selected`.
+    pub fn new(
+        selected: &'a mut usize,
+        segments: impl IntoIterator<Item = impl Into<WidgetText>>,
+    ) -> Self {
+        Self::from_selection(Selection::One(selected), segments)
+    }
+
+    /// Any number of segments are selected, one `bool` per segment.
+    /// Clicking a segment toggles it.
+    pub fn multiple(
+        selected: &'a mut [bool],
+        segments: impl IntoIterator<Item = impl Into<WidgetText>>,
+    ) -> Self {
+        Self::from_selection(Selection::Many(selected), segments)
+    }
+
+    fn from_selection(
+        selection: Selection<'a>,
+        segments: impl IntoIterator<Item = impl Into<WidgetText>>,
+    ) -> Self {
+        Self {
+            selection,
+            segments: segments.into_iter().map(Into::into).collect(),
+            equal_width: false,
+        }
+    }
+
+    /// Make all segments as wide as the widest one.
+    /// Default: `false`, each segment is as wide as its text.
+    pub fn equal_width(mut self, equal_width: bool) -> Self {
+        self.equal_width = equal_width;
+        self
+    }
+}
+
+impl<'a> Widget for SegmentedControl<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            mut selection,
+            segments,
+            equal_width,
+        } = self;
+
+        let button_padding = ui.spacing().button_padding;
+        let galleys: Vec<_> = segments
+            .into_iter()
+            .map(|text| text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button))
+            .collect();
+
+        let mut widths: Vec<f32> = galleys
+            .iter()
+            .map(|galley| galley.size().x + 2.0 * button_padding.x)
+            .collect();
+        if equal_width {
+            let max_width = widths.iter().copied().fold(0.0, f32::max);
+            widths.fill(max_width);
+        }
+        let text_height = galleys
+            .iter()
+            .map(|galley| galley.size().y)
+            .fold(0.0, f32::max);
+        let height = (text_height + 2.0 * button_padding.y).at_least(ui.spacing().interact_size.y);
+
+        let desired_size = vec2(widths.iter().sum(), height);
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        let corner_radius = ui.visuals().widgets.inactive.corner_radius;
+        let separator_stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+        let num_segments = galleys.len();
+        let mut left = rect.left();
+        let mut changed = false;
+
+        for (index, (galley, width)) in galleys.into_iter().zip(widths).enumerate() {
+            let segment_rect = Rect::from_x_y_ranges(left..=left + width, rect.y_range());
+            left += width;
+
+            let segment_response =
+                ui.interact(segment_rect, response.id.with(index), Sense::click());
+            if segment_response.clicked() {
+                changed |= selection.click(index);
+            }
+            let selected = selection.is_selected(index);
+            segment_response.widget_info(|| {
+                WidgetInfo::selected(WidgetType::SelectableLabel, selected, galley.text())
+            });
+
+            if ui.is_rect_visible(segment_rect) {
+                let visuals = ui.style().interact_selectable(&segment_response, selected);
+
+                // Only the outer corners of the group are rounded:
+                let left_radius = if index == 0 { corner_radius } else { 0.0 };
+                let right_radius = if index + 1 == num_segments {
+                    corner_radius
+                } else {
+                    0.0
+                };
+                let mut path = Vec::new();
+                epaint::tessellator::path::rounded_rectangle_corners(
+                    &mut path,
+                    segment_rect,
+                    [left_radius, right_radius, left_radius, right_radius],
+                );
+                ui.painter().add(Shape::convex_polygon(
+                    path,
+                    visuals.bg_fill,
+                    visuals.bg_stroke,
+                ));
+
+                if index > 0 {
+                    ui.painter().line_segment(
+                        [segment_rect.left_top(), segment_rect.left_bottom()],
+                        separator_stroke,
+                    );
+                }
+
+                let text_pos = Align2::CENTER_CENTER
+                    .align_size_within_rect(galley.size(), segment_rect)
+                    .min;
+                galley.paint_with_visuals(ui.painter(), text_pos, &visuals);
+            }
+
+            response |= segment_response;
+        }
+
+        if ui.is_rect_visible(rect) {
+            ui.painter()
+                .rect_stroke(rect, corner_radius, separator_stroke);
+        }
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_segmented_control() {
+    use crate::test::Harness;
+
+    type State = (usize, [bool; 2]);
+    let mut harness = Harness::with_state((0, [false, false]), |ctx, (one, many): &mut State| {
+        CentralPanel::default().show(ctx, |ui| {
+            let segments = ["Short", "A bit longer"];
+            ui.add(SegmentedControl::new(one, segments));
+            ui.add(SegmentedControl::multiple(many, segments).equal_width(true));
+        });
+    });
+    // Both controls have the same segments, so pick them by control index:
+    let segment = |harness: &Harness<'_, State>, control: usize, label: &str| {
+        harness
+            .nodes()
+            .iter()
+            .filter(|node| node.info.label.as_deref() == Some(label))
+            .nth(control)
+            .unwrap()
+            .rect
+    };
+    let click = |harness: &mut Harness<'_, State>, control: usize, label: &str| {
+        let pos = segment(harness, control, label).center();
+        harness.click_at(pos);
+    };
+
+    assert!(
+        segment(&harness, 0, "Short").width() < segment(&harness, 1, "Short").width(),
+        "equal width segments are as wide as the widest one"
+    );
+
+    click(&mut harness, 0, "A bit longer");
+    assert_eq!(harness.state().0, 1);
+
+    click(&mut harness, 1, "Short");
+    click(&mut harness, 1, "A bit longer");
+    assert_eq!(harness.state().1, [true, true]);
+    click(&mut harness, 1, "Short");
+    assert_eq!(harness.state().1, [false, true]);
+}
@@ -9,12 +9,18 @@ use crate::*;
 /// // These are equivalent:
 /// ui.separator();
 /// ui.add(egui::Separator::default());
+///
+/// // A horizontal line with text in the middle:
+/// ui.separator_with_label("Advanced");
+/// ui.add(egui::Separator::default().text("Advanced").text_align(egui::Align::Min));
 /// # });
 /// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Separator {
     spacing: f32,
     is_horizontal_line: Option<bool>,
+    text: Option<WidgetText>,
+    text_align: Align,
 }
 
 impl Default for Separator {
@@ -22,6 +28,8 @@ impl Default for Separator {
         Self {
             spacing: 6.0,
             is_horizontal_line: None,
+            text: None,
+            text_align: Align::Center,
         }
     }
 }
@@ -48,6 +56,20 @@ impl Separator {
         self.is_horizontal_line = Some(false);
         self
     }
+
+    /// Show some text on the line, e.g. the title of the next section of a form.
+    ///
+    /// A separator with text is always a horizontal line.
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Where on the line to put the [`Self::text`]. Default: [`Align::Center`].
+    pub fn text_align(mut self, text_align: Align) -> Self {
+        self.text_align = text_align;
+        self
+    }
 }
 
 impl Widget for Separator {
@@ -55,8 +77,14 @@ impl Widget for Separator {
         let Separator {
             spacing,
             is_horizontal_line,
+            text,
+            text_align,
         } = self;
 
+        if let Some(text) = text {
+            return labeled_separator_ui(ui, spacing, text, text_align);
+        }
+
         let is_horizontal_line = is_horizontal_line
             .unwrap_or_else(|| ui.is_grid() || !ui.layout().main_dir().is_horizontal());
 
@@ -89,3 +117,81 @@ impl Widget for Separator {
         response
     }
 }
+
+/// A horizontal line with the text on it, at `text_align`.
+fn labeled_separator_ui(
+    ui: &mut Ui,
+    spacing: f32,
+    text: WidgetText,
+    text_align: Align,
+) -> Response {
+    let available_width = ui.available_size_before_wrap().x;
+    // Space between the text and the line:
+    let gap = ui.spacing().item_spacing.x;
+    let galley = text.into_galley(ui, Some(false), available_width, TextStyle::Body);
+
+    let size = vec2(available_width, galley.size().y.at_least(spacing));
+    let (rect, response) = ui.allocate_at_least(size, Sense::hover());
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, galley.text()));
+
+    if ui.is_rect_visible(rect) {
+        let text_rect =
+            Align2([text_align, Align::Center]).align_size_within_rect(galley.size(), rect);
+        let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+        let y = rect.center().y;
+        if text_rect.left() - gap > rect.left() {
+            ui.painter().line_segment(
+                [pos2(rect.left(), y), pos2(text_rect.left() - gap, y)],
+                stroke,
+            );
+        }
+        if text_rect.right() + gap < rect.right() {
+            ui.painter().line_segment(
+                [pos2(text_rect.right() + gap, y), pos2(rect.right(), y)],
+                stroke,
+            );
+        }
+        galley.paint_with_fallback_color(
+            ui.painter(),
+            text_rect.min,
+            ui.visuals().weak_text_color(),
+        );
+    }
+
+    response
+}
+
+#[cfg(test)]
+#[test]
+fn test_labeled_separator() {
+    use epaint::ClippedShape;
+
+    let mut ctx = CtxRef::default();
+    let mut rect = Rect::NOTHING;
+    let (_, shapes) = ctx.run(Default::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            rect = ui.separator_with_label("Advanced").rect;
+            ui.add(Separator::default().text("Left").text_align(Align::Min));
+        });
+    });
+
+    let lines: Vec<[Pos2; 2]> = shapes
+        .iter()
+        .filter_map(|ClippedShape(_, shape)| match shape {
+            Shape::LineSegment { points, .. } => Some(*points),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        lines.len(),
+        3,
+        "two lines around the centered text, one after the left text"
+    );
+    assert_eq!(lines[0][0].x, rect.left());
+    assert_eq!(lines[1][1].x, rect.right());
+    assert!(
+        (rect.center().x - lines[0][1].x - (lines[1][0].x - rect.center().x)).abs() < 1.0,
+        "the text is centered"
+    );
+    assert!(lines[2][0].x > rect.left());
+}
@@ -50,6 +50,10 @@ pub enum SliderOrientation {
 /// The slider consists of three parts: a slider, a value display, and an optional text.
 /// The user can click the value display to edit its value. It can be turned off with `.show_value(false)`.
 ///
+/// With keyboard focus, the arrow keys and page up/down move the slider (see [`Slider::step_by`]),
+/// and home/end go to the start/end of the range.
+/// The gamepad triggers work like the arrow keys.
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// # let mut my_f32: f32 = 0.0;
@@ -65,6 +69,7 @@ pub struct Slider<'a> {
     spec: SliderSpec,
     clamp_to_range: bool,
     smart_aim: bool,
+    step: Option<f64>,
     show_value: bool,
     orientation: SliderOrientation,
     prefix: String,
@@ -107,6 +112,7 @@ impl<'a> Slider<'a> {
             },
             clamp_to_range: true,
             smart_aim: true,
+            step: None,
             show_value: true,
             orientation: SliderOrientation::Horizontal,
             prefix: Default::default(),
@@ -199,6 +205,18 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Only allow values that are a multiple of `step` away from the start of the range.
+    ///
+    /// This is also how much the arrow keys change the value when the slider has keyboard focus
+    /// (and ten times as much for page up/down).
+    /// Without a step, the arrow keys move the slider one point.
+    ///
+    /// A `step` of zero or less is ignored.
+    pub fn step_by(mut self, step: f64) -> Self {
+        self.step = (step > 0.0).then(|| step);
+        self
+    }
+
     // TODO: we should also have a "min precision".
     /// Set a minimum number of decimals to display.
     /// Normally you don't need to pick a precision, as the slider will intelligently pick a precision for you.
@@ -247,6 +265,10 @@ impl<'a> Slider<'a> {
     }
 
     fn set_value(&mut self, mut value: f64) {
+        if let Some(step) = self.step {
+            let start = *self.range.start();
+            value = start + ((value - start) / step).round() * step;
+        }
         if self.clamp_to_range {
             let start = *self.range.start();
             let end = *self.range.end();
@@ -317,19 +339,44 @@ impl<'a> Slider<'a> {
         if response.has_focus() {
             let (dec_key, inc_key) = match self.orientation {
                 SliderOrientation::Horizontal => (Key::ArrowLeft, Key::ArrowRight),
-                // Note that this is for moving the slider position,
-                // so up = decrement y coordinate:
-                SliderOrientation::Vertical => (Key::ArrowUp, Key::ArrowDown),
+                SliderOrientation::Vertical => (Key::ArrowDown, Key::ArrowUp),
+            };
+
+            // Steps towards the end of the range:
+            let (kb_steps, kb_page_steps, home_pressed, end_pressed) = {
+                let input = ui.input();
+                (
+                    input.num_presses(inc_key) as f64 - input.num_presses(dec_key) as f64
+                        + input.num_gamepad_presses(GamepadButton::RightTrigger) as f64
+                        - input.num_gamepad_presses(GamepadButton::LeftTrigger) as f64,
+                    input.num_presses(Key::PageUp) as f64 - input.num_presses(Key::PageDown) as f64,
+                    input.key_pressed(Key::Home),
+                    input.key_pressed(Key::End),
+                )
             };
 
-            let decrement = ui.input().num_presses(dec_key);
-            let increment = ui.input().num_presses(inc_key);
-            let kb_step = increment as f32 - decrement as f32;
+            if home_pressed {
+                self.set_value(*self.range.start());
+            } else if end_pressed {
+                self.set_value(*self.range.end());
+            } else if let Some(step) = self.step {
+                if kb_steps != 0.0 || kb_page_steps != 0.0 {
+                    let step = if self.range.start() <= self.range.end() {
+                        step
+                    } else {
+                        -step
+                    };
+                    let prev_value = self.get_value();
+                    self.set_value(prev_value + step * (kb_steps + 10.0 * kb_page_steps));
+                }
+            } else if kb_steps != 0.0 || kb_page_steps != 0.0 {
+                // One point per arrow key press, and a tenth of the slider per page:
+                let (start, end) = (*position_range.start(), *position_range.end());
+                let kb_step = kb_steps as f32 + 0.1 * (end - start).abs() * kb_page_steps as f32;
 
-            if kb_step != 0.0 {
                 let prev_value = self.get_value();
                 let prev_position = self.position_from_value(prev_value, position_range.clone());
-                let new_position = prev_position + kb_step;
+                let new_position = prev_position + (end - start).signum() * kb_step;
                 let new_value = if self.smart_aim {
                     let aim_radius = ui.input().aim_radius();
                     emath::smart_aim::best_in_range_f64(
@@ -123,6 +123,7 @@ impl<'t> TextEdit<'t> {
     }
 
     /// A source for the unique `Id`, e.g. `.id_source("second_text_edit_field")` or `.id_source(loop_index)`.
+    #[cfg_attr(feature = "id_source_locations", track_caller)]
     pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
         self.id_source = Some(Id::new(id_source));
         self
@@ -256,7 +257,7 @@ impl<'t> TextEdit<'t> {
         let frame_rect = output.response.rect.expand2(margin);
         ui.allocate_space(frame_rect.size());
         if interactive {
-            output.response |= ui.interact(frame_rect, id, Sense::click());
+            output.response |= ui.interact_without_focus_ring(frame_rect, id, Sense::click(), None);
         }
         if output.response.clicked() && !output.response.lost_focus() {
             ui.memory().request_focus(output.response.id);
@@ -363,12 +364,13 @@ impl<'t> TextEdit<'t> {
 
         let id = id.unwrap_or_else(|| {
             if let Some(id_source) = id_source {
-                ui.make_persistent_id(id_source)
+                ui.make_persistent_id(id_source).derived_from(id_source)
             } else {
                 auto_id // Since we are only storing the cursor a persistent Id is not super important
             }
         });
         let mut state = TextEditState::load(ui.ctx(), id).unwrap_or_default();
+        let prev_ccursor_range = state.ccursor_range();
 
         // On touch screens (e.g. mobile in egui_web), should
         // dragging select text, or scroll the enclosing `ScrollArea` (if any)?
@@ -385,7 +387,7 @@ impl<'t> TextEdit<'t> {
         } else {
             Sense::hover()
         };
-        let mut response = ui.interact(rect, id, sense);
+        let mut response = ui.interact_without_focus_ring(rect, id, sense, None);
         let painter = ui.painter_at(rect);
 
         if interactive {
@@ -510,6 +512,10 @@ impl<'t> TextEdit<'t> {
             text_draw_pos -= vec2(offset_x, 0.0);
         }
 
+        if response.gained_focus() || state.ccursor_range() != prev_ccursor_range {
+            state.last_cursor_move_time = ui.input().time;
+        }
+
         if ui.is_rect_visible(rect) {
             painter.galley(text_draw_pos, galley.clone());
 
@@ -528,24 +534,30 @@ impl<'t> TextEdit<'t> {
                     // We paint the cursor on top of the text, in case
                     // the text galley has backgrounds (as e.g. `code` snippets in markup do).
                     paint_cursor_selection(ui, &painter, text_draw_pos, &galley, &cursor_range);
-                    paint_cursor_end(
-                        ui,
-                        row_height,
-                        &painter,
-                        text_draw_pos,
-                        &galley,
-                        &cursor_range.primary,
-                    );
+                    if is_cursor_blinked_on(ui, state.last_cursor_move_time) {
+                        paint_cursor_end(
+                            ui,
+                            row_height,
+                            &painter,
+                            text_draw_pos,
+                            &galley,
+                            &cursor_range.primary,
+                        );
+                    }
 
                     if interactive && text.is_mutable() {
                         // egui_web uses `text_cursor_pos` when showing IME,
                         // so only set it when text is editable and visible!
-                        ui.ctx().output().text_cursor_pos = Some(
-                            galley
-                                .pos_from_cursor(&cursor_range.primary)
-                                .translate(response.rect.min.to_vec2())
-                                .left_top(),
-                        );
+                        let cursor_rect = galley
+                            .pos_from_cursor(&cursor_range.primary)
+                            .translate(response.rect.min.to_vec2());
+                        let mut output = ui.ctx().output();
+                        output.text_cursor_pos = Some(cursor_rect.left_top());
+                        output.text_input = Some(crate::output::TextInputState {
+                            rect: response.rect,
+                            cursor_rect,
+                            gained_focus: response.gained_focus(),
+                        });
                     }
                 }
             }
@@ -639,8 +651,8 @@ fn events(
     );
 
     let copy_if_not_password = |ui: &Ui, text: String| {
-        if !password {
-            ui.ctx().output().copied_text = text;
+        if !password && !text.is_empty() {
+            ui.ctx().output().copy_text(text);
         }
     };
 
@@ -835,6 +847,29 @@ fn paint_cursor_selection(
     }
 }
 
+/// Should the blinking text cursor be shown right now?
+///
+/// Also asks for a repaint for when that changes, so that we don't have to repaint continuously.
+fn is_cursor_blinked_on(ui: &Ui, last_cursor_move_time: f64) -> bool {
+    if !ui.visuals().text_cursor_blink {
+        return true;
+    }
+
+    const ON_DURATION: f64 = 0.5;
+    const OFF_DURATION: f64 = 0.5;
+
+    let time = ui.input().time;
+    let phase = (time - last_cursor_move_time).max(0.0) % (ON_DURATION + OFF_DURATION);
+    let is_on = phase < ON_DURATION;
+    let next_change = if is_on {
+        ON_DURATION - phase
+    } else {
+        ON_DURATION + OFF_DURATION - phase
+    };
+    ui.ctx().request_repaint_at(time + next_change);
+    is_on
+}
+
 fn paint_cursor_end(
     ui: &mut Ui,
     row_height: f32,
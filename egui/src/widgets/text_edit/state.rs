@@ -30,15 +30,19 @@ pub struct TextEditState {
     // Visual offset when editing singleline text bigger than the width.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) singleline_offset: f32,
+
+    // When the cursor last moved. The cursor only blinks after it has been still for a moment.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) last_cursor_move_time: f64,
 }
 
 impl TextEditState {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
-        ctx.memory().data.get_persisted(id)
+        ctx.memory().data_persisted.get_persisted(id)
     }
 
     pub fn store(self, ctx: &Context, id: Id) {
-        ctx.memory().data.insert_persisted(id, self);
+        ctx.memory().data_persisted.insert_persisted(id, self);
     }
 
     /// The the currently selected range of characters.
@@ -227,9 +227,23 @@ fn show_menu_bar(ui: &mut Ui) {
     trace!(ui);
     use egui::*;
 
+    const ORGANIZE_SHORTCUT: KeyboardShortcut =
+        KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::O);
+
+    // Handled out here, so that it also works while the menu is closed:
+    ui.ctx()
+        .register_shortcut(ORGANIZE_SHORTCUT, "Windows", "Organize windows");
+    if ui.ctx().consume_shortcut(&ORGANIZE_SHORTCUT) {
+        ui.ctx().memory().reset_areas();
+    }
+
     menu::bar(ui, |ui| {
-        ui.menu_button("File", |ui| {
-            if ui.button("Organize windows").clicked() {
+        ui.menu_button("&File", |ui| {
+            let shortcut_text = ui.ctx().format_shortcut(&ORGANIZE_SHORTCUT);
+            if ui
+                .add(Button::new("Organize windows").shortcut_text(shortcut_text))
+                .clicked()
+            {
                 ui.ctx().memory().reset_areas();
                 ui.close_menu();
             }
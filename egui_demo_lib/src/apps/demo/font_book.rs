@@ -98,7 +98,7 @@ impl super::View for FontBook {
                         };
 
                         if ui.add(button).on_hover_ui(tooltip_ui).clicked() {
-                            ui.output().copied_text = chr.to_string();
+                            ui.output().copy_text(chr.to_string());
                         }
                     }
                 }
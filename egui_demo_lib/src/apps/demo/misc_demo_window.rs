@@ -66,17 +66,33 @@ impl View for MiscDemoWindow {
             .default_open(false)
             .show(ui, |ui| self.tree.ui(ui));
 
-        ui.collapsing("Columns", |ui| {
-            ui.add(Slider::new(&mut self.num_columns, 1..=10).text("Columns"));
-            ui.columns(self.num_columns, |cols| {
-                for (i, col) in cols.iter_mut().enumerate() {
-                    col.label(format!("Column {} out of {}", i + 1, self.num_columns));
-                    if i + 1 == self.num_columns && col.button("Delete this").clicked() {
-                        self.num_columns -= 1;
+        let mut add_column = false;
+        CollapsingHeader::new("Columns").show_with_header(
+            ui,
+            |ui| {
+                ui.label("Columns");
+                ui.with_layout(Layout::right_to_left(), |ui| {
+                    add_column = ui
+                        .small_button("➕")
+                        .on_hover_text("Add a column")
+                        .clicked();
+                });
+            },
+            |ui| {
+                ui.add(Slider::new(&mut self.num_columns, 1..=10).text("Columns"));
+                ui.columns(self.num_columns, |cols| {
+                    for (i, col) in cols.iter_mut().enumerate() {
+                        col.label(format!("Column {} out of {}", i + 1, self.num_columns));
+                        if i + 1 == self.num_columns && col.button("Delete this").clicked() {
+                            self.num_columns -= 1;
+                        }
                     }
-                }
-            });
-        });
+                });
+            },
+        );
+        if add_column {
+            self.num_columns = (self.num_columns + 1).min(10);
+        }
 
         CollapsingHeader::new("Test box rendering")
             .default_open(false)
@@ -3,7 +3,7 @@ use std::f64::consts::TAU;
 use egui::*;
 use plot::{
     Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, Corner, HLine, Legend, Line, LineStyle,
-    MarkerShape, Plot, PlotImage, Points, Polygon, Text, VLine, Value, Values,
+    MarkerShape, Plot, PlotBounds, PlotImage, Points, Polygon, Text, VLine, Value, Values,
 };
 
 #[derive(PartialEq)]
@@ -14,6 +14,7 @@ struct LineDemo {
     circle_center: Pos2,
     square: bool,
     proportional: bool,
+    fill_wave: bool,
     line_style: LineStyle,
 }
 
@@ -26,6 +27,7 @@ impl Default for LineDemo {
             circle_center: Pos2::new(0.0, 0.0),
             square: false,
             proportional: true,
+            fill_wave: false,
             line_style: LineStyle::Solid,
         }
     }
@@ -40,6 +42,7 @@ impl LineDemo {
             circle_center,
             square,
             proportional,
+            fill_wave,
             line_style,
             ..
         } = self;
@@ -76,6 +79,8 @@ impl LineDemo {
                     .on_hover_text("Always keep the viewport square.");
                 ui.checkbox(proportional, "Proportional data axes")
                     .on_hover_text("Tick are the same size on both axes.");
+                ui.checkbox(fill_wave, "Fill wave")
+                    .on_hover_text("Fill the area between the wave and a shifted copy of it.");
 
                 ComboBox::from_label("Line style")
                     .selected_text(line_style.to_string())
@@ -114,14 +119,21 @@ impl LineDemo {
 
     fn sin(&self) -> Line {
         let time = self.time;
-        Line::new(Values::from_explicit_callback(
-            move |x| 0.5 * (2.0 * x).sin() * time.sin(),
-            ..,
-            512,
-        ))
-        .color(Color32::from_rgb(200, 100, 100))
-        .style(self.line_style)
-        .name("wave")
+        let wave = move |x: f64| 0.5 * (2.0 * x).sin() * time.sin();
+        let line = Line::new(Values::from_explicit_callback(wave, .., 512))
+            .color(Color32::from_rgb(200, 100, 100))
+            .style(self.line_style)
+            .name("wave");
+        if self.fill_wave {
+            line.fill_to(Values::from_explicit_callback(
+                move |x| wave(x) - 0.25,
+                ..,
+                512,
+            ))
+            .fill_alpha(0.2)
+        } else {
+            line
+        }
     }
 
     fn thingy(&self) -> Line {
@@ -226,7 +238,10 @@ impl Widget for &mut MarkerDemo {
 
         let markers_plot = Plot::new("markers_demo")
             .data_aspect(1.0)
-            .legend(Legend::default());
+            .legend(Legend::default())
+            .hover_ui(|ui, hovered| {
+                ui.label(format!("{} marker #{}", hovered.item_name, hovered.index));
+            });
         markers_plot
             .show(ui, |plot_ui| {
                 for marker in self.markers() {
@@ -377,11 +392,19 @@ impl Widget for &mut ItemsDemo {
 }
 
 #[derive(PartialEq)]
-struct InteractionDemo {}
+struct InteractionDemo {
+    handles: Vec<Value>,
+}
 
 impl Default for InteractionDemo {
     fn default() -> Self {
-        Self {}
+        Self {
+            handles: vec![
+                Value::new(-1.0, 0.0),
+                Value::new(0.0, 1.0),
+                Value::new(1.0, 0.0),
+            ],
+        }
     }
 }
 
@@ -393,6 +416,8 @@ impl Widget for &mut InteractionDemo {
             response,
             inner: (screen_pos, pointer_coordinate, pointer_coordinate_drag_delta, bounds, hovered),
         } = plot.show(ui, |plot_ui| {
+            plot_ui.draggable_points("handles", &mut self.handles);
+            plot_ui.line(Line::new(Values::from_values(self.handles.clone())));
             (
                 plot_ui.screen_from_plot(Value::new(0.0, 0.0)),
                 plot_ui.pointer_coordinate(),
@@ -431,10 +456,69 @@ impl Widget for &mut InteractionDemo {
     }
 }
 
+#[derive(PartialEq)]
+struct LinkedAxisDemo {
+    link_x: bool,
+    link_y: bool,
+}
+
+impl Default for LinkedAxisDemo {
+    fn default() -> Self {
+        Self {
+            link_x: true,
+            link_y: false,
+        }
+    }
+}
+
+impl LinkedAxisDemo {
+    fn sin() -> Line {
+        Line::new(Values::from_explicit_callback(move |x| x.sin(), .., 100)).name("sin")
+    }
+    fn cos() -> Line {
+        Line::new(Values::from_explicit_callback(
+            move |x| 2.0 * x.cos(),
+            ..,
+            100,
+        ))
+        .name("cos")
+    }
+}
+
+impl Widget for &mut LinkedAxisDemo {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let mut reset = false;
+        ui.horizontal(|ui| {
+            ui.label("Linked axes:");
+            ui.checkbox(&mut self.link_x, "X");
+            ui.checkbox(&mut self.link_y, "Y");
+            reset = ui.button("Show one period").clicked();
+        });
+
+        let link_group = Id::new("linked_demo");
+        let (link_x, link_y) = (self.link_x, self.link_y);
+        let plot = |name: &str| {
+            Plot::new(name)
+                .height(150.0)
+                .link_axis(link_group, link_x, link_y)
+        };
+        plot("linked_sin").show(ui, |plot_ui| {
+            if reset {
+                plot_ui.set_plot_bounds(PlotBounds::from_min_max([0.0, -2.0], [TAU, 2.0]));
+            }
+            plot_ui.line(LinkedAxisDemo::sin());
+        });
+        plot("linked_cos")
+            .show(ui, |plot_ui| plot_ui.line(LinkedAxisDemo::cos()))
+            .response
+    }
+}
+
 #[derive(PartialEq, Eq)]
 enum Chart {
     GaussBars,
     StackedBars,
+    GroupedBars,
     BoxPlot,
 }
 
@@ -551,6 +635,47 @@ impl ChartsDemo {
             .response
     }
 
+    fn bar_grouped(&self, ui: &mut Ui) -> Response {
+        let days = ["Mon", "Tue", "Wed", "Thu", "Fri"];
+        let sets: [(&str, [f64; 5]); 3] = [
+            ("Set 1", [1.0, 3.0, 1.0, 2.0, 4.0]),
+            ("Set 2", [1.0, 1.5, -0.5, 0.7, 0.8]),
+            ("Set 3", [2.5, 1.0, 0.5, -1.0, 0.3]),
+        ];
+
+        let charts: Vec<BarChart> = sets
+            .iter()
+            .enumerate()
+            .map(|(index, (name, values))| {
+                let bars = days
+                    .iter()
+                    .zip(values)
+                    .enumerate()
+                    .map(|(day, (day_name, &value))| Bar::new(day as f64, value).name(day_name))
+                    .collect();
+                let chart = BarChart::new(bars)
+                    .width(0.8)
+                    .group(index, sets.len())
+                    .name(name);
+                if self.vertical {
+                    chart
+                } else {
+                    chart.horizontal()
+                }
+            })
+            .collect();
+
+        Plot::new("Grouped Bar Chart Demo")
+            .legend(Legend::default())
+            .data_aspect(1.0)
+            .show(ui, |plot_ui| {
+                for chart in charts {
+                    plot_ui.bar_chart(chart);
+                }
+            })
+            .response
+    }
+
     fn box_plot(&self, ui: &mut Ui) -> Response {
         let yellow = Color32::from_rgb(248, 252, 168);
         let mut box1 = BoxPlot::new(vec![
@@ -600,6 +725,7 @@ impl Widget for &mut ChartsDemo {
         ui.horizontal(|ui| {
             ui.selectable_value(&mut self.chart, Chart::GaussBars, "Histogram");
             ui.selectable_value(&mut self.chart, Chart::StackedBars, "Stacked Bar Chart");
+            ui.selectable_value(&mut self.chart, Chart::GroupedBars, "Grouped Bar Chart");
             ui.selectable_value(&mut self.chart, Chart::BoxPlot, "Box Plot");
         });
         ui.label("Orientation:");
@@ -610,6 +736,7 @@ impl Widget for &mut ChartsDemo {
         match self.chart {
             Chart::GaussBars => self.bar_gauss(ui),
             Chart::StackedBars => self.bar_stacked(ui),
+            Chart::GroupedBars => self.bar_grouped(ui),
             Chart::BoxPlot => self.box_plot(ui),
         }
     }
@@ -623,6 +750,7 @@ enum Panel {
     Charts,
     Items,
     Interaction,
+    LinkedAxes,
 }
 
 impl Default for Panel {
@@ -639,6 +767,7 @@ pub struct PlotDemo {
     charts_demo: ChartsDemo,
     items_demo: ItemsDemo,
     interaction_demo: InteractionDemo,
+    linked_axis_demo: LinkedAxisDemo,
     open_panel: Panel,
 }
 
@@ -670,6 +799,7 @@ impl super::View for PlotDemo {
                 } else {
                     ui.label("Zoom with ctrl + scroll.");
                 }
+                ui.label("Zoom into a rectangle by dragging with ctrl / ⌘.");
                 ui.label("Reset view with double-click.");
                 ui.add(crate::__egui_github_link_file!());
             });
@@ -682,6 +812,7 @@ impl super::View for PlotDemo {
             ui.selectable_value(&mut self.open_panel, Panel::Charts, "Charts");
             ui.selectable_value(&mut self.open_panel, Panel::Items, "Items");
             ui.selectable_value(&mut self.open_panel, Panel::Interaction, "Interaction");
+            ui.selectable_value(&mut self.open_panel, Panel::LinkedAxes, "Linked Axes");
         });
         ui.separator();
 
@@ -704,6 +835,9 @@ impl super::View for PlotDemo {
             Panel::Interaction => {
                 ui.add(&mut self.interaction_demo);
             }
+            Panel::LinkedAxes => {
+                ui.add(&mut self.linked_axis_demo);
+            }
         }
     }
 }
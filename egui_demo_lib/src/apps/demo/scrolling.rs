@@ -210,7 +210,7 @@ impl super::View for ScrollTo {
         }
 
         ui.separator();
-        let (current_scroll, max_scroll) = scroll_area.show(ui, |ui| {
+        let scroll_area_output = scroll_area.show(ui, |ui| {
             if scroll_top {
                 ui.scroll_to_cursor(Align::TOP);
             }
@@ -231,16 +231,13 @@ impl super::View for ScrollTo {
             }
 
             let margin = ui.visuals().clip_rect_margin;
-
-            let current_scroll = ui.clip_rect().top() - ui.min_rect().top() + margin;
-            let max_scroll = ui.min_rect().height() - ui.clip_rect().height() + 2.0 * margin;
-            (current_scroll, max_scroll)
+            ui.min_rect().height() - ui.clip_rect().height() + 2.0 * margin // max scroll
         });
         ui.separator();
 
         ui.label(format!(
             "Scroll offset: {:.0}/{:.0} px",
-            current_scroll, max_scroll
+            scroll_area_output.offset.y, scroll_area_output.inner
         ));
 
         ui.separator();
@@ -11,6 +11,7 @@ pub struct Sliders {
     pub logarithmic: bool,
     pub clamp_to_range: bool,
     pub smart_aim: bool,
+    pub step: f64,
     pub integer: bool,
     pub vertical: bool,
     pub value: f64,
@@ -24,6 +25,7 @@ impl Default for Sliders {
             logarithmic: true,
             clamp_to_range: false,
             smart_aim: true,
+            step: 0.0,
             integer: false,
             vertical: false,
             value: 10.0,
@@ -55,6 +57,7 @@ impl super::View for Sliders {
             logarithmic,
             clamp_to_range,
             smart_aim,
+            step,
             integer,
             vertical,
             value,
@@ -86,6 +89,7 @@ impl super::View for Sliders {
                     .logarithmic(*logarithmic)
                     .clamp_to_range(*clamp_to_range)
                     .smart_aim(*smart_aim)
+                    .step_by(*step)
                     .orientation(orientation)
                     .text("i32 demo slider"),
             );
@@ -96,6 +100,7 @@ impl super::View for Sliders {
                     .logarithmic(*logarithmic)
                     .clamp_to_range(*clamp_to_range)
                     .smart_aim(*smart_aim)
+                    .step_by(*step)
                     .orientation(orientation)
                     .text("f64 demo slider"),
             );
@@ -157,6 +162,17 @@ impl super::View for Sliders {
         ui.label("Smart Aim will guide you towards round values when you drag the slider so you you are more likely to hit 250 than 247.23");
         ui.add_space(8.0);
 
+        ui.horizontal(|ui| {
+            ui.label("Step:");
+            ui.add(
+                DragValue::new(step)
+                    .speed(0.1)
+                    .clamp_range(0.0..=f64::INFINITY),
+            );
+        });
+        ui.label("If non-zero, the slider only allows multiples of this step. The arrow keys also move the slider by this step when it has keyboard focus.");
+        ui.add_space(8.0);
+
         ui.vertical_centered(|ui| {
             egui::reset_button(ui, self);
             ui.add(crate::__egui_github_link_file!());
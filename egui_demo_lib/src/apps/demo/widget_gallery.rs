@@ -13,6 +13,7 @@ pub struct WidgetGallery {
     visible: bool,
     boolean: bool,
     radio: Enum,
+    segment: usize,
     scalar: f32,
     string: String,
     color: egui::Color32,
@@ -26,6 +27,7 @@ impl Default for WidgetGallery {
             visible: true,
             boolean: false,
             radio: Enum::First,
+            segment: 0,
             scalar: 42.0,
             string: Default::default(),
             color: egui::Color32::LIGHT_BLUE.linear_multiply(0.5),
@@ -95,6 +97,7 @@ impl WidgetGallery {
             visible: _,
             boolean,
             radio,
+            segment,
             scalar,
             string,
             color,
@@ -105,6 +108,27 @@ impl WidgetGallery {
         ui.label("Welcome to the widget gallery!");
         ui.end_row();
 
+        ui.add(doc_link_label("RichLabel", "RichLabel"));
+        egui::RichLabel::new()
+            .text("Mix ")
+            .text(egui::RichText::new("styles").strong())
+            .text(", ")
+            .link("links", "https://github.com/emilk/egui")
+            .text(" and ")
+            .tooltip("tooltips", "In one label that wraps like any other")
+            .show(ui);
+        ui.end_row();
+
+        ui.add(doc_link_label("Kbd", "Kbd"));
+        ui.horizontal(|ui| {
+            ui.add(egui::Kbd::shortcut(egui::KeyboardShortcut::new(
+                egui::Modifiers::COMMAND,
+                egui::Key::S,
+            )));
+            ui.label("to save");
+        });
+        ui.end_row();
+
         ui.add(doc_link_label("Hyperlink", "Hyperlink"));
         use egui::special_emojis::GITHUB;
         ui.hyperlink_to(
@@ -123,6 +147,27 @@ impl WidgetGallery {
         }
         ui.end_row();
 
+        ui.add(doc_link_label("Badge", "Badge"));
+        let response = ui.button("Inbox");
+        egui::Badge::count(3).show(ui, &response);
+        ui.end_row();
+
+        #[cfg(feature = "icons")]
+        {
+            ui.add(doc_link_label("Icon", "Icon"));
+            ui.horizontal(|ui| {
+                for icon in [
+                    egui::Icon::Save,
+                    egui::Icon::Search,
+                    egui::Icon::Settings,
+                    egui::Icon::Warning,
+                ] {
+                    ui.icon(icon).on_hover_text(format!("{:?}", icon));
+                }
+            });
+            ui.end_row();
+        }
+
         ui.add(doc_link_label("Checkbox", "checkbox"));
         ui.checkbox(boolean, "Checkbox");
         ui.end_row();
@@ -146,6 +191,13 @@ impl WidgetGallery {
         });
         ui.end_row();
 
+        ui.add(doc_link_label("SegmentedControl", "SegmentedControl"));
+        ui.add(egui::SegmentedControl::new(
+            segment,
+            ["Day", "Week", "Month"],
+        ));
+        ui.end_row();
+
         ui.add(doc_link_label("ComboBox", "ComboBox"));
 
         egui::ComboBox::from_label("Take your pick")
@@ -199,6 +251,13 @@ impl WidgetGallery {
         ui.separator();
         ui.end_row();
 
+        ui.add(doc_link_label(
+            "Separator with label",
+            "separator_with_label",
+        ));
+        ui.separator_with_label("Advanced");
+        ui.end_row();
+
         ui.add(doc_link_label("CollapsingHeader", "collapsing"));
         ui.collapsing("Click to see what is hidden!", |ui| {
             ui.horizontal_wrapped(|ui| {
@@ -117,7 +117,7 @@ impl epi::App for HttpApp {
                     Err(error) => {
                         // This should only happen if the fetch API isn't available or something similar.
                         ui.colored_label(
-                            egui::Color32::RED,
+                            ui.visuals().error_fg_color,
                             if error.is_empty() { "Error" } else { error },
                         );
                     }
@@ -206,7 +206,7 @@ fn ui_resource(ui: &mut egui::Ui, frame: &epi::Frame, tex_mngr: &mut TexMngr, re
             if let Some(text) = &text {
                 let tooltip = "Click to copy the response body";
                 if ui.button("📋").on_hover_text(tooltip).clicked() {
-                    ui.output().copied_text = text.clone();
+                    ui.output().copy_text(text.clone());
                 }
                 ui.separator();
             }
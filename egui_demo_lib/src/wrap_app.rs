@@ -44,10 +44,13 @@ impl epi::App for WrapApp {
 
     fn setup(
         &mut self,
-        _ctx: &egui::CtxRef,
+        ctx: &egui::CtxRef,
         _frame: &epi::Frame,
         _storage: Option<&dyn epi::Storage>,
     ) {
+        // Hold F1 to see all keyboard shortcuts:
+        ctx.add_plugin(egui::ShortcutCheatSheet::default());
+
         #[cfg(feature = "persistence")]
         if let Some(storage) = _storage {
             *self = epi::get_value(storage, epi::APP_KEY).unwrap_or_default();
@@ -66,7 +66,7 @@ pub fn run(app: Box<dyn epi::App>, native_options: &epi::NativeOptions) -> ! {
                 std::thread::sleep(std::time::Duration::from_millis(10));
             }
 
-            let (needs_repaint, mut tex_allocation_data, shapes) =
+            let (repaint_after, mut tex_allocation_data, shapes) =
                 integration.update(display.gl_window().window());
             let clipped_meshes = integration.egui_ctx.tessellate(shapes);
 
@@ -85,9 +85,10 @@ pub fn run(app: Box<dyn epi::App>, native_options: &epi::NativeOptions) -> ! {
                     &display,
                     &mut target,
                     integration.egui_ctx.pixels_per_point(),
-                    clipped_meshes,
+                    &clipped_meshes,
                     &integration.egui_ctx.font_image(),
                 );
+                integration.egui_ctx.recycle_meshes(clipped_meshes);
 
                 target.finish().unwrap();
             }
@@ -99,9 +100,15 @@ pub fn run(app: Box<dyn epi::App>, native_options: &epi::NativeOptions) -> ! {
             {
                 *control_flow = if integration.should_quit() {
                     glutin::event_loop::ControlFlow::Exit
-                } else if needs_repaint {
-                    display.gl_window().window().request_redraw();
-                    glutin::event_loop::ControlFlow::Poll
+                } else if let Some(repaint_after) = repaint_after {
+                    if repaint_after.is_zero() {
+                        display.gl_window().window().request_redraw();
+                        glutin::event_loop::ControlFlow::Poll
+                    } else {
+                        // e.g. a blinking text cursor, or throttled repaints in power save mode:
+                        let repaint_at = std::time::Instant::now() + repaint_after;
+                        glutin::event_loop::ControlFlow::WaitUntil(repaint_at)
+                    }
                 } else {
                     glutin::event_loop::ControlFlow::Wait
                 };
@@ -132,6 +139,11 @@ pub fn run(app: Box<dyn epi::App>, native_options: &epi::NativeOptions) -> ! {
             glutin::event::Event::LoopDestroyed => {
                 integration.on_exit(display.gl_window().window());
             }
+            glutin::event::Event::NewEvents(glutin::event::StartCause::ResumeTimeReached {
+                ..
+            }) => {
+                display.gl_window().window().request_redraw();
+            }
             glutin::event::Event::UserEvent(RequestRepaintEvent) => {
                 display.gl_window().window().request_redraw();
             }
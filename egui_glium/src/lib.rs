@@ -152,8 +152,9 @@ impl EguiGlium {
             display,
             target,
             self.egui_ctx.pixels_per_point(),
-            clipped_meshes,
+            &clipped_meshes,
             &self.egui_ctx.font_image(),
         );
+        self.egui_ctx.recycle_meshes(clipped_meshes);
     }
 }
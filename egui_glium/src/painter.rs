@@ -96,13 +96,13 @@ impl Painter {
         display: &glium::Display,
         target: &mut T,
         pixels_per_point: f32,
-        cipped_meshes: Vec<egui::ClippedMesh>,
+        cipped_meshes: &[egui::ClippedMesh],
         font_image: &egui::FontImage,
     ) {
         self.upload_egui_texture(display, font_image);
 
         for egui::ClippedMesh(clip_rect, mesh) in cipped_meshes {
-            self.paint_mesh(target, display, pixels_per_point, clip_rect, &mesh);
+            self.paint_mesh(target, display, pixels_per_point, *clip_rect, mesh);
         }
     }
 
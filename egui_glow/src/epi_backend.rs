@@ -82,7 +82,7 @@ pub fn run(app: Box<dyn epi::App>, native_options: &epi::NativeOptions) -> ! {
                 std::thread::sleep(std::time::Duration::from_millis(10));
             }
 
-            let (needs_repaint, mut tex_allocation_data, shapes) =
+            let (repaint_after, mut tex_allocation_data, shapes) =
                 integration.update(gl_window.window());
             let clipped_meshes = integration.egui_ctx.tessellate(shapes);
 
@@ -104,8 +104,19 @@ pub fn run(app: Box<dyn epi::App>, native_options: &epi::NativeOptions) -> ! {
                     &gl,
                     gl_window.window().inner_size().into(),
                     integration.egui_ctx.pixels_per_point(),
-                    clipped_meshes,
+                    &clipped_meshes,
                 );
+                integration.egui_ctx.recycle_meshes(clipped_meshes);
+
+                for request in integration.take_snapshot_requests() {
+                    let snapshot = painter.read_snapshot(
+                        &gl,
+                        gl_window.window().inner_size().into(),
+                        integration.egui_ctx.pixels_per_point(),
+                        request.rect,
+                    );
+                    integration.on_snapshot(request.id, snapshot);
+                }
 
                 gl_window.swap_buffers().unwrap();
             }
@@ -117,9 +128,15 @@ pub fn run(app: Box<dyn epi::App>, native_options: &epi::NativeOptions) -> ! {
             {
                 *control_flow = if integration.should_quit() {
                     glutin::event_loop::ControlFlow::Exit
-                } else if needs_repaint {
-                    gl_window.window().request_redraw();
-                    glutin::event_loop::ControlFlow::Poll
+                } else if let Some(repaint_after) = repaint_after {
+                    if repaint_after.is_zero() {
+                        gl_window.window().request_redraw();
+                        glutin::event_loop::ControlFlow::Poll
+                    } else {
+                        // e.g. a blinking text cursor, or throttled repaints in power save mode:
+                        let repaint_at = std::time::Instant::now() + repaint_after;
+                        glutin::event_loop::ControlFlow::WaitUntil(repaint_at)
+                    }
                 } else {
                     glutin::event_loop::ControlFlow::Wait
                 };
@@ -155,6 +172,11 @@ pub fn run(app: Box<dyn epi::App>, native_options: &epi::NativeOptions) -> ! {
                 integration.on_exit(gl_window.window());
                 painter.destroy(&gl);
             }
+            glutin::event::Event::NewEvents(glutin::event::StartCause::ResumeTimeReached {
+                ..
+            }) => {
+                gl_window.window().request_redraw();
+            }
             glutin::event::Event::UserEvent(RequestRepaintEvent) => {
                 gl_window.window().request_redraw();
             }
@@ -169,8 +169,9 @@ impl EguiGlow {
             gl,
             dimensions,
             self.egui_ctx.pixels_per_point(),
-            clipped_meshes,
+            &clipped_meshes,
         );
+        self.egui_ctx.recycle_meshes(clipped_meshes);
     }
 
     /// Call to release the allocated graphics resources.
@@ -298,7 +298,7 @@ impl Painter {
         gl: &glow::Context,
         inner_size: [u32; 2],
         pixels_per_point: f32,
-        clipped_meshes: Vec<egui::ClippedMesh>,
+        clipped_meshes: &[egui::ClippedMesh],
     ) {
         self.assert_not_destroyed();
 
@@ -309,7 +309,7 @@ impl Painter {
         }
         let size_in_pixels = unsafe { self.prepare_painting(inner_size, gl, pixels_per_point) };
         for egui::ClippedMesh(clip_rect, mesh) in clipped_meshes {
-            self.paint_mesh(gl, size_in_pixels, pixels_per_point, clip_rect, &mesh);
+            self.paint_mesh(gl, size_in_pixels, pixels_per_point, *clip_rect, mesh);
         }
         unsafe {
             self.vertex_array.unbind_vertex_array(gl);
@@ -389,6 +389,59 @@ impl Painter {
 
     // ------------------------------------------------------------------------
 
+    /// Read back the pixels of `rect` (in points) from the frame buffer,
+    /// to answer a [`egui::output::SnapshotRequest`].
+    ///
+    /// Call this after [`Self::paint_meshes`] and before swapping buffers.
+    pub fn read_snapshot(
+        &self,
+        gl: &glow::Context,
+        inner_size: [u32; 2],
+        pixels_per_point: f32,
+        rect: Rect,
+    ) -> egui::WidgetSnapshot {
+        self.assert_not_destroyed();
+
+        let [width_in_pixels, height_in_pixels] = inner_size;
+        let min_x = (pixels_per_point * rect.min.x).round().max(0.0) as u32;
+        let min_y = (pixels_per_point * rect.min.y).round().max(0.0) as u32;
+        let max_x = ((pixels_per_point * rect.max.x).round() as u32).min(width_in_pixels);
+        let max_y = ((pixels_per_point * rect.max.y).round() as u32).min(height_in_pixels);
+        let width = max_x.saturating_sub(min_x) as usize;
+        let height = max_y.saturating_sub(min_y) as usize;
+
+        let mut bytes = vec![0_u8; 4 * width * height];
+        if width > 0 && height > 0 {
+            unsafe {
+                gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+                // OpenGL has the origin in the bottom left corner:
+                gl.read_pixels(
+                    min_x as i32,
+                    (height_in_pixels - max_y) as i32,
+                    width as i32,
+                    height as i32,
+                    glow::RGBA,
+                    glow::UNSIGNED_BYTE,
+                    glow::PixelPackData::Slice(&mut bytes),
+                );
+            }
+        }
+
+        // Flip the rows so we start at the top left:
+        let pixels = bytes
+            .chunks_exact(4 * width.max(1))
+            .rev()
+            .flat_map(|row| row.chunks_exact(4))
+            .map(|p| Color32::from_rgba_premultiplied(p[0], p[1], p[2], p[3]))
+            .collect();
+
+        egui::WidgetSnapshot {
+            size: [width, height],
+            pixels_per_point,
+            pixels,
+        }
+    }
+
     #[cfg(feature = "epi")]
     pub fn set_texture(&mut self, gl: &glow::Context, tex_id: u64, image: &epi::Image) {
         self.assert_not_destroyed();
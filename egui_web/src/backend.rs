@@ -87,12 +87,18 @@ pub struct AppRunner {
     pub(crate) input: WebInput,
     app: Box<dyn epi::App>,
     pub(crate) needs_repaint: std::sync::Arc<NeedRepaint>,
+    /// When to repaint next, from [`egui::Output::repaint_after`] (in the time of [`now_sec`]).
+    pub(crate) repaint_at: Option<f64>,
     storage: LocalStorage,
     last_save_time: f64,
     screen_reader: crate::screen_reader::ScreenReader,
     pub(crate) text_cursor_pos: Option<egui::Pos2>,
     pub(crate) mutable_text_under_cursor: bool,
+    pub(crate) text_input: Option<egui::output::TextInputState>,
     pending_texture_destructions: Vec<u64>,
+    /// CSS `url()`s of the user textures that are small enough to be shown as cursors,
+    /// for [`egui::CursorIcon::Custom`].
+    cursor_images: std::collections::HashMap<u64, String>,
 }
 
 impl AppRunner {
@@ -119,10 +125,13 @@ impl AppRunner {
 
         let egui_ctx = egui::CtxRef::default();
         load_memory(&egui_ctx);
-        if prefer_dark_mode == Some(true) {
-            egui_ctx.set_visuals(egui::Visuals::dark());
-        } else {
-            egui_ctx.set_visuals(egui::Visuals::light());
+        // The browser already zooms with Ctrl+Plus/Minus/0, and its zoom is in `native_pixels_per_point`:
+        egui_ctx.memory().options.zoom_with_keyboard = false;
+        // Note that this is intentionally different from what we use in egui-winit:
+        egui_ctx.memory().options.line_scroll_speed = 8.0;
+        egui_ctx.set_theme_preference(egui::ThemePreference::FollowSystem);
+        if let Some(user_agent) = user_agent() {
+            egui_ctx.set_os(egui::OperatingSystem::from_user_agent(&user_agent));
         }
 
         let storage = LocalStorage::default();
@@ -131,15 +140,26 @@ impl AppRunner {
             frame,
             egui_ctx,
             painter,
-            input: Default::default(),
+            input: WebInput {
+                // Changes after this are reported by `install_media_query_events`:
+                raw: egui::RawInput {
+                    system_theme: system_theme(),
+                    prefers_reduced_motion: prefers_reduced_motion(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
             app,
             needs_repaint,
+            repaint_at: None,
             storage,
             last_save_time: now_sec(),
             screen_reader: Default::default(),
             text_cursor_pos: None,
             mutable_text_under_cursor: false,
+            text_input: None,
             pending_texture_destructions: Default::default(),
+            cursor_images: Default::default(),
         };
 
         {
@@ -209,8 +229,14 @@ impl AppRunner {
             } = app_output;
 
             for (id, image) in tex_allocation_data.creations {
+                if let Some(url) = cursor_image_url(&image) {
+                    self.cursor_images.insert(id, url);
+                }
                 self.painter.set_texture(id, image);
             }
+            for id in &tex_allocation_data.destructions {
+                self.cursor_images.remove(id);
+            }
             self.pending_texture_destructions = tex_allocation_data.destructions;
         }
 
@@ -223,7 +249,8 @@ impl AppRunner {
             .upload_egui_texture(&self.egui_ctx.font_image());
         self.painter.clear(self.app.clear_color());
         self.painter
-            .paint_meshes(clipped_meshes, self.egui_ctx.pixels_per_point())?;
+            .paint_meshes(&clipped_meshes, self.egui_ctx.pixels_per_point())?;
+        self.egui_ctx.recycle_meshes(clipped_meshes);
         for id in self.pending_texture_destructions.drain(..) {
             self.painter.free_texture(id);
         }
@@ -237,28 +264,55 @@ impl AppRunner {
 
         let egui::Output {
             cursor_icon,
-            open_url,
-            copied_text,
-            needs_repaint: _, // handled elsewhere
+            open_urls,
+            copied,
+            needs_repaint: _, // handled in `paint_and_schedule`
+            repaint_after: _, // handled in `paint_and_schedule`
             events: _,        // already handled
+            snapshot_requests: _,
+            ui_events: _,
+            hit_regions: _,
+            accessibility_nodes: _,
+            unconsumed_events: _,
+            window_commands: _, // a web page can't control the browser window
             mutable_text_under_cursor,
             text_cursor_pos,
+            text_input,
         } = output;
 
-        set_cursor_icon(*cursor_icon);
-        if let Some(open) = open_url {
+        match *cursor_icon {
+            egui::CursorIcon::Custom(egui::TextureId::User(id), hotspot)
+                if self.cursor_images.contains_key(&id) =>
+            {
+                let url = &self.cursor_images[&id];
+                let hotspot = hotspot.round();
+                set_cursor(&format!("{} {} {}, default", url, hotspot.x, hotspot.y));
+            }
+            cursor_icon => {
+                set_cursor_icon(cursor_icon);
+            }
+        }
+        for open in open_urls {
             crate::open_url(&open.url, open.new_tab);
         }
 
+        // Text and images are copied, but HTML is copied as text:
         #[cfg(web_sys_unstable_apis)]
-        if !copied_text.is_empty() {
-            set_clipboard_text(copied_text);
+        match copied {
+            Some(egui::ClipboardData::Image(image)) => set_clipboard_image(image),
+            Some(copied) => {
+                if let Some(text) = copied.text() {
+                    set_clipboard_text(text);
+                }
+            }
+            None => {}
         }
 
         #[cfg(not(web_sys_unstable_apis))]
-        let _ = copied_text;
+        let _ = copied;
 
         self.mutable_text_under_cursor = *mutable_text_under_cursor;
+        self.text_input = *text_input;
 
         if &self.text_cursor_pos != text_cursor_pos {
             move_text_cursor(text_cursor_pos, self.canvas_id());
@@ -281,6 +335,7 @@ fn start_runner(app_runner: AppRunner) -> Result<AppRunnerRef, JsValue> {
     let runner_ref = AppRunnerRef(Arc::new(Mutex::new(app_runner)));
     install_canvas_events(&runner_ref)?;
     install_document_events(&runner_ref)?;
+    install_media_query_events(&runner_ref)?;
     install_text_agent(&runner_ref)?;
     repaint_every_ms(&runner_ref, 1000)?; // just in case. TODO: make it a parameter
     paint_and_schedule(runner_ref.clone())?;
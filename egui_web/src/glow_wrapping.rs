@@ -97,7 +97,7 @@ impl crate::Painter for WrappedGlowPainter {
 
     fn paint_meshes(
         &mut self,
-        clipped_meshes: Vec<ClippedMesh>,
+        clipped_meshes: &[ClippedMesh],
         pixels_per_point: f32,
     ) -> Result<(), JsValue> {
         let canvas_dimension = [self.canvas.width(), self.canvas.height()];
@@ -93,6 +93,25 @@ pub fn prefer_dark_mode() -> Option<bool> {
     )
 }
 
+pub fn system_theme() -> Option<egui::Theme> {
+    prefer_dark_mode().map(|dark| {
+        if dark {
+            egui::Theme::Dark
+        } else {
+            egui::Theme::Light
+        }
+    })
+}
+
+pub fn prefers_reduced_motion() -> Option<bool> {
+    Some(
+        web_sys::window()?
+            .match_media("(prefers-reduced-motion: reduce)")
+            .ok()??
+            .matches(),
+    )
+}
+
 pub fn canvas_element(canvas_id: &str) -> Option<web_sys::HtmlCanvasElement> {
     use wasm_bindgen::JsCast;
     let document = web_sys::window()?.document()?;
@@ -119,6 +138,8 @@ pub fn button_from_mouse_event(event: &web_sys::MouseEvent) -> Option<egui::Poin
         0 => Some(egui::PointerButton::Primary),
         1 => Some(egui::PointerButton::Middle),
         2 => Some(egui::PointerButton::Secondary),
+        3 => Some(egui::PointerButton::Extra1),
+        4 => Some(egui::PointerButton::Extra2),
         _ => None,
     }
 }
@@ -298,15 +319,172 @@ impl epi::Storage for LocalStorage {
 
 // ----------------------------------------------------------------------------
 
+/// Show one of the standard cursors. [`egui::CursorIcon::Custom`] shows the default cursor.
 pub fn set_cursor_icon(cursor: egui::CursorIcon) -> Option<()> {
+    set_cursor(cursor_web_name(cursor))
+}
+
+/// Set the CSS `cursor` property of the page.
+fn set_cursor(css_cursor: &str) -> Option<()> {
     let document = web_sys::window()?.document()?;
     document
         .body()?
         .style()
-        .set_property("cursor", cursor_web_name(cursor))
+        .set_property("cursor", css_cursor)
         .ok()
 }
 
+/// A CSS `url()` showing the image, for using it as a cursor.
+///
+/// Browsers ignore cursor images larger than 128x128 pixels, so for those this returns `None`.
+fn cursor_image_url(image: &epi::Image) -> Option<String> {
+    let [width, height] = image.size;
+    if width == 0 || height == 0 || width > 128 || height > 128 {
+        return None;
+    }
+
+    let rgba: Vec<u8> = image
+        .pixels
+        .iter()
+        .flat_map(|color| color.to_srgba_unmultiplied())
+        .collect();
+    let canvas = canvas_with_image(image.size, &rgba).ok()?;
+    Some(format!("url({})", canvas.to_data_url().ok()?))
+}
+
+/// A new (detached) canvas of the given size, and its 2D context.
+fn new_canvas(
+    width: u32,
+    height: u32,
+) -> Result<
+    (
+        web_sys::HtmlCanvasElement,
+        web_sys::CanvasRenderingContext2d,
+    ),
+    JsValue,
+> {
+    let document = web_sys::window()
+        .ok_or("no window")?
+        .document()
+        .ok_or("no document")?;
+    let canvas = document
+        .create_element("canvas")?
+        .dyn_into::<web_sys::HtmlCanvasElement>()?;
+    canvas.set_width(width);
+    canvas.set_height(height);
+    let context = canvas
+        .get_context("2d")?
+        .ok_or("no 2d context")?
+        .dyn_into::<web_sys::CanvasRenderingContext2d>()?;
+    Ok((canvas, context))
+}
+
+/// A new canvas showing the given unmultiplied RGBA pixels.
+fn canvas_with_image(
+    [width, height]: [usize; 2],
+    rgba: &[u8],
+) -> Result<web_sys::HtmlCanvasElement, JsValue> {
+    let (canvas, context) = new_canvas(width as u32, height as u32)?;
+    let image_data = web_sys::ImageData::new_with_u8_clamped_array_and_sh(
+        wasm_bindgen::Clamped(rgba),
+        width as u32,
+        height as u32,
+    )?;
+    context.put_image_data(&image_data, 0.0, 0.0)?;
+    Ok(canvas)
+}
+
+/// Put an image in the clipboard, as a PNG.
+#[cfg(web_sys_unstable_apis)]
+pub fn set_clipboard_image(image: &egui::ClipboardImage) {
+    let canvas = match canvas_with_image(image.size, &image.rgba) {
+        Ok(canvas) => canvas,
+        Err(err) => {
+            console_error(format!("Failed to copy image: {:?}", err));
+            return;
+        }
+    };
+    let on_blob = Closure::once_into_js(move |blob: JsValue| {
+        if let Err(err) = write_clipboard_blob(&blob) {
+            console_error(format!("Failed to copy image: {:?}", err));
+        }
+    });
+    if let Err(err) = canvas.to_blob(on_blob.unchecked_ref()) {
+        console_error(format!("Failed to copy image: {:?}", err));
+    }
+}
+
+/// Write a PNG blob to the clipboard.
+#[cfg(web_sys_unstable_apis)]
+fn write_clipboard_blob(blob: &JsValue) -> Result<(), JsValue> {
+    let window = web_sys::window().ok_or("no window")?;
+    let clipboard = window.navigator().clipboard().ok_or("no clipboard")?;
+
+    // `ClipboardItem` and `Clipboard::write` with it are not in `web_sys` yet:
+    let clipboard_item = js_sys::Reflect::get(&window, &JsValue::from_str("ClipboardItem"))?
+        .dyn_into::<js_sys::Function>()?;
+    let items = js_sys::Object::new();
+    js_sys::Reflect::set(&items, &JsValue::from_str("image/png"), blob)?;
+    let item = js_sys::Reflect::construct(&clipboard_item, &js_sys::Array::of1(&items))?;
+    let write = js_sys::Reflect::get(&clipboard, &JsValue::from_str("write"))?
+        .dyn_into::<js_sys::Function>()?;
+    let promise = write
+        .call1(&clipboard, &js_sys::Array::of1(&item))?
+        .dyn_into::<js_sys::Promise>()?;
+
+    let future = wasm_bindgen_futures::JsFuture::from(promise);
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Err(err) = future.await {
+            console_error(format!("Copy action denied: {:?}", err));
+        }
+    });
+    Ok(())
+}
+
+/// The first image file in pasted data, if any.
+#[cfg(web_sys_unstable_apis)]
+fn pasted_image_file(data: &web_sys::DataTransfer) -> Option<web_sys::File> {
+    let files = data.files()?;
+    (0..files.length())
+        .filter_map(|i| files.get(i))
+        .find(|file| file.type_().starts_with("image/"))
+}
+
+/// Decode a pasted image file, and send it as [`egui::Event::Paste`] once that is done.
+#[cfg(web_sys_unstable_apis)]
+fn paste_image(runner_ref: AppRunnerRef, file: web_sys::File) {
+    spawn_future(async move {
+        match decode_image(&file).await {
+            Ok(image) => {
+                let mut runner_lock = runner_ref.0.lock();
+                runner_lock
+                    .input
+                    .raw
+                    .events
+                    .push(egui::Event::Paste(egui::ClipboardData::Image(image)));
+                runner_lock.needs_repaint.set_true();
+            }
+            Err(err) => console_error(format!("Failed to paste image: {:?}", err)),
+        }
+    });
+}
+
+#[cfg(web_sys_unstable_apis)]
+async fn decode_image(blob: &web_sys::Blob) -> Result<egui::ClipboardImage, JsValue> {
+    let window = web_sys::window().ok_or("no window")?;
+    let bitmap = wasm_bindgen_futures::JsFuture::from(window.create_image_bitmap_with_blob(blob)?)
+        .await?
+        .dyn_into::<web_sys::ImageBitmap>()?;
+    let (width, height) = (bitmap.width(), bitmap.height());
+    let (_canvas, context) = new_canvas(width, height)?;
+    context.draw_image_with_image_bitmap(&bitmap, 0.0, 0.0)?;
+    let image_data = context.get_image_data(0.0, 0.0, width as f64, height as f64)?;
+    Ok(egui::ClipboardImage {
+        size: [width as usize, height as usize],
+        rgba: image_data.data().0,
+    })
+}
+
 #[cfg(web_sys_unstable_apis)]
 pub fn set_clipboard_text(s: &str) {
     if let Some(window) = web_sys::window() {
@@ -352,11 +530,24 @@ fn cursor_web_name(cursor: egui::CursorIcon) -> &'static str {
         egui::CursorIcon::ResizeNeSw => "nesw-resize",
         egui::CursorIcon::ResizeNwSe => "nwse-resize",
         egui::CursorIcon::ResizeVertical => "ns-resize",
+        egui::CursorIcon::ResizeEast => "e-resize",
+        egui::CursorIcon::ResizeSouthEast => "se-resize",
+        egui::CursorIcon::ResizeSouth => "s-resize",
+        egui::CursorIcon::ResizeSouthWest => "sw-resize",
+        egui::CursorIcon::ResizeWest => "w-resize",
+        egui::CursorIcon::ResizeNorthWest => "nw-resize",
+        egui::CursorIcon::ResizeNorth => "n-resize",
+        egui::CursorIcon::ResizeNorthEast => "ne-resize",
+        egui::CursorIcon::ResizeColumn => "col-resize",
+        egui::CursorIcon::ResizeRow => "row-resize",
         egui::CursorIcon::Text => "text",
         egui::CursorIcon::VerticalText => "vertical-text",
         egui::CursorIcon::Wait => "wait",
         egui::CursorIcon::ZoomIn => "zoom-in",
         egui::CursorIcon::ZoomOut => "zoom-out",
+
+        // Handled by `AppRunner`, which knows the texture:
+        egui::CursorIcon::Custom(..) => "default",
     }
 }
 
@@ -431,6 +622,9 @@ pub fn translate_key(key: &str) -> Option<egui::Key> {
         "PageUp" => Some(egui::Key::PageUp),
         "PageDown" => Some(egui::Key::PageDown),
 
+        "-" => Some(egui::Key::Minus),
+        "+" | "=" => Some(egui::Key::PlusEquals),
+
         "0" => Some(egui::Key::Num0),
         "1" => Some(egui::Key::Num1),
         "2" => Some(egui::Key::Num2),
@@ -469,6 +663,19 @@ pub fn translate_key(key: &str) -> Option<egui::Key> {
         "y" | "Y" => Some(egui::Key::Y),
         "z" | "Z" => Some(egui::Key::Z),
 
+        "F1" => Some(egui::Key::F1),
+        "F2" => Some(egui::Key::F2),
+        "F3" => Some(egui::Key::F3),
+        "F4" => Some(egui::Key::F4),
+        "F5" => Some(egui::Key::F5),
+        "F6" => Some(egui::Key::F6),
+        "F7" => Some(egui::Key::F7),
+        "F8" => Some(egui::Key::F8),
+        "F9" => Some(egui::Key::F9),
+        "F10" => Some(egui::Key::F10),
+        "F11" => Some(egui::Key::F11),
+        "F12" => Some(egui::Key::F12),
+
         _ => None,
     }
 }
@@ -481,11 +688,22 @@ pub struct AppRunnerRef(Arc<Mutex<AppRunner>>);
 fn paint_and_schedule(runner_ref: AppRunnerRef) -> Result<(), JsValue> {
     fn paint_if_needed(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
         let mut runner_lock = runner_ref.0.lock();
+        if runner_lock
+            .repaint_at
+            .map_or(false, |repaint_at| now_sec() >= repaint_at)
+        {
+            runner_lock.needs_repaint.set_true();
+        }
         if runner_lock.needs_repaint.fetch_and_clear() {
             let (output, clipped_meshes) = runner_lock.logic()?;
             runner_lock.paint(clipped_meshes)?;
+            runner_lock.repaint_at = None;
             if output.needs_repaint {
-                runner_lock.needs_repaint.set_true();
+                if output.repaint_after > 0.0 {
+                    runner_lock.repaint_at = Some(now_sec() + output.repaint_after as f64);
+                } else {
+                    runner_lock.needs_repaint.set_true();
+                }
             }
             runner_lock.auto_save();
         }
@@ -557,10 +775,18 @@ fn install_document_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
 
             let egui_wants_keyboard = runner_lock.egui_ctx().wants_keyboard_input();
 
+            let zoom_with_keyboard = runner_lock.egui_ctx().memory().options.zoom_with_keyboard;
+
             let prevent_default = if matches!(event.key().as_str(), "Tab") {
                 // Always prevent moving cursor to url bar.
                 // egui wants to use tab to move to the next text field.
                 true
+            } else if zoom_with_keyboard
+                && (modifiers.ctrl || modifiers.command)
+                && matches!(event.key().as_str(), "+" | "=" | "-" | "0")
+            {
+                // egui zooms, so the browser shouldn't zoom too.
+                true
             } else if egui_wants_keyboard {
                 matches!(
                     event.key().as_str(),
@@ -616,13 +842,24 @@ fn install_document_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
         let runner_ref = runner_ref.clone();
         let closure = Closure::wrap(Box::new(move |event: web_sys::ClipboardEvent| {
             if let Some(data) = event.clipboard_data() {
-                if let Ok(text) = data.get_data("text") {
+                if let Some(file) = pasted_image_file(&data) {
+                    paste_image(runner_ref.clone(), file);
+                    event.stop_propagation();
+                    event.prevent_default();
+                } else if let Ok(text) = data.get_data("text") {
+                    let text = text.replace("\r\n", "\n");
+                    let pasted = match data.get_data("text/html") {
+                        Ok(html) if !html.is_empty() => egui::ClipboardData::Html {
+                            html,
+                            text: text.clone(),
+                        },
+                        _ => egui::ClipboardData::Text(text.clone()),
+                    };
                     let mut runner_lock = runner_ref.0.lock();
-                    runner_lock
-                        .input
-                        .raw
-                        .events
-                        .push(egui::Event::Text(text.replace("\r\n", "\n")));
+                    let events = &mut runner_lock.input.raw.events;
+                    events.push(egui::Event::Paste(pasted));
+                    // For widgets that don't know about `Event::Paste` yet:
+                    events.push(egui::Event::Text(text));
                     runner_lock.needs_repaint.set_true();
                     event.stop_propagation();
                     event.prevent_default();
@@ -671,6 +908,38 @@ fn install_document_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
     Ok(())
 }
 
+/// Report changes to the `prefers-color-scheme` and `prefers-reduced-motion` of the browser.
+fn install_media_query_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
+    use wasm_bindgen::JsCast;
+    let window = web_sys::window().unwrap();
+
+    if let Some(media_query_list) = window.match_media("(prefers-color-scheme: dark)")? {
+        let runner_ref = runner_ref.clone();
+        let closure = Closure::wrap(Box::new(move || {
+            let mut runner_lock = runner_ref.0.lock();
+            runner_lock.input.raw.system_theme = system_theme();
+            runner_lock.needs_repaint.set_true();
+        }) as Box<dyn FnMut()>);
+        media_query_list
+            .add_event_listener_with_callback("change", closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
+    if let Some(media_query_list) = window.match_media("(prefers-reduced-motion: reduce)")? {
+        let runner_ref = runner_ref.clone();
+        let closure = Closure::wrap(Box::new(move || {
+            let mut runner_lock = runner_ref.0.lock();
+            runner_lock.input.raw.prefers_reduced_motion = prefers_reduced_motion();
+            runner_lock.needs_repaint.set_true();
+        }) as Box<dyn FnMut()>);
+        media_query_list
+            .add_event_listener_with_callback("change", closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
+    Ok(())
+}
+
 /// Repaint at least every `ms` milliseconds.
 fn repaint_every_ms(runner_ref: &AppRunnerRef, milliseconds: i32) -> Result<(), JsValue> {
     assert!(milliseconds >= 0);
@@ -1013,33 +1282,38 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
         let closure = Closure::wrap(Box::new(move |event: web_sys::WheelEvent| {
             let mut runner_lock = runner_ref.0.lock();
 
-            let scroll_multiplier = match event.delta_mode() {
-                web_sys::WheelEvent::DOM_DELTA_PAGE => {
-                    canvas_size_in_points(runner_ref.0.lock().canvas_id()).y
-                }
-                web_sys::WheelEvent::DOM_DELTA_LINE => {
-                    #[allow(clippy::let_and_return)]
-                    let points_per_scroll_line = 8.0; // Note that this is intentionally different from what we use in egui_glium / winit.
-                    points_per_scroll_line
-                }
-                _ => 1.0,
+            let unit = match event.delta_mode() {
+                web_sys::WheelEvent::DOM_DELTA_PAGE => egui::MouseWheelUnit::Page,
+                web_sys::WheelEvent::DOM_DELTA_LINE => egui::MouseWheelUnit::Line,
+                _ => egui::MouseWheelUnit::Point,
             };
 
-            let delta = -scroll_multiplier
-                * egui::Vec2::new(event.delta_x() as f32, event.delta_y() as f32);
+            let delta = -egui::Vec2::new(event.delta_x() as f32, event.delta_y() as f32);
 
             // Report a zoom event in case CTRL (on Windows or Linux) or CMD (on Mac) is pressed.
             // This if-statement is equivalent to how `Modifiers.command` is determined in
             // `modifiers_from_event()`, but we cannot directly use that fn for a `WheelEvent`.
             if event.ctrl_key() || event.meta_key() {
-                let factor = (delta.y / 200.0).exp();
+                let scroll_multiplier = match unit {
+                    egui::MouseWheelUnit::Page => {
+                        canvas_size_in_points(runner_ref.0.lock().canvas_id()).y
+                    }
+                    egui::MouseWheelUnit::Line => {
+                        #[allow(clippy::let_and_return)]
+                        let points_per_scroll_line = 8.0; // Note that this is intentionally different from what we use in egui_glium / winit.
+                        points_per_scroll_line
+                    }
+                    egui::MouseWheelUnit::Point => 1.0,
+                };
+                let factor = (scroll_multiplier * delta.y / 200.0).exp();
                 runner_lock.input.raw.events.push(egui::Event::Zoom(factor));
             } else {
-                runner_lock
-                    .input
-                    .raw
-                    .events
-                    .push(egui::Event::Scroll(delta));
+                let modifiers = runner_lock.input.raw.modifiers;
+                runner_lock.input.raw.events.push(egui::Event::MouseWheel {
+                    unit,
+                    delta,
+                    modifiers,
+                });
             }
 
             runner_lock.needs_repaint.set_true();
@@ -1170,8 +1444,12 @@ fn update_text_agent(runner: &AppRunner) -> Option<()> {
             // Move up canvas so that text edit is shown at ~30% of screen height.
             // Only on touch screens, when keyboard popups.
             if let Some(latest_touch_pos) = runner.input.latest_touch_pos {
+                // Prefer the position of the focused text field over where the user touched:
+                let text_y = runner
+                    .text_input
+                    .map_or(latest_touch_pos.y, |text_input| text_input.rect.bottom());
                 let window_height = window.inner_height().ok()?.as_f64()? as f32;
-                let current_rel = latest_touch_pos.y / window_height;
+                let current_rel = text_y / window_height;
 
                 // estimated amount of screen covered by keyboard
                 let keyboard_fraction = 0.5;
@@ -1202,10 +1480,14 @@ fn update_text_agent(runner: &AppRunner) -> Option<()> {
     Some(())
 }
 
+pub fn user_agent() -> Option<String> {
+    web_sys::window()?.navigator().user_agent().ok()
+}
+
 const MOBILE_DEVICE: [&str; 6] = ["Android", "iPhone", "iPad", "iPod", "webOS", "BlackBerry"];
 /// If context is running under mobile device?
 fn is_mobile() -> Option<bool> {
-    let user_agent = web_sys::window()?.navigator().user_agent().ok()?;
+    let user_agent = user_agent()?;
     let is_mobile = MOBILE_DEVICE.iter().any(|&name| user_agent.contains(name));
     Some(is_mobile)
 }
@@ -16,7 +16,7 @@ pub trait Painter {
 
     fn paint_meshes(
         &mut self,
-        clipped_meshes: Vec<egui::ClippedMesh>,
+        clipped_meshes: &[egui::ClippedMesh],
         pixels_per_point: f32,
     ) -> Result<(), JsValue>;
 
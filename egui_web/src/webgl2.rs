@@ -99,13 +99,13 @@ impl WebGl2Painter {
         }
     }
 
-    fn paint_mesh(&self, mesh: &egui::epaint::Mesh16) -> Result<(), JsValue> {
+    fn paint_mesh(&self, mesh: &egui::epaint::Mesh16Slice<'_>) -> Result<(), JsValue> {
         debug_assert!(mesh.is_valid());
 
         let mut positions: Vec<f32> = Vec::with_capacity(2 * mesh.vertices.len());
         let mut tex_coords: Vec<f32> = Vec::with_capacity(2 * mesh.vertices.len());
         let mut colors: Vec<u8> = Vec::with_capacity(4 * mesh.vertices.len());
-        for v in &mesh.vertices {
+        for v in mesh.vertices {
             positions.push(v.pos.x);
             positions.push(v.pos.y);
             tex_coords.push(v.uv.x);
@@ -366,7 +366,7 @@ impl crate::Painter for WebGl2Painter {
 
     fn paint_meshes(
         &mut self,
-        clipped_meshes: Vec<egui::ClippedMesh>,
+        clipped_meshes: &[egui::ClippedMesh],
         pixels_per_point: f32,
     ) -> Result<(), JsValue> {
         let gl = &self.gl;
@@ -420,7 +420,8 @@ impl crate::Painter for WebGl2Painter {
                     clip_max_y - clip_min_y,
                 );
 
-                for mesh in mesh.split_to_u16() {
+                // WebGL needs `u16` indices:
+                for mesh in mesh.split_to_u16_slices() {
                     self.paint_mesh(&mesh)?;
                 }
             } else {
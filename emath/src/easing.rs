@@ -0,0 +1,99 @@
+//! Easing curves for animations, see [`Easing`].
+
+/// How an animation moves from its start to its end over time.
+///
+/// ```
+/// use emath::Easing;
+/// assert_eq!(Easing::Linear.ease(0.25), 0.25);
+/// assert!(Easing::CubicOut.ease(0.25) > 0.25, "starts fast");
+/// assert_eq!(Easing::CubicOut.ease(1.0), 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Starts slow and speeds up.
+    CubicIn,
+
+    /// Starts fast and slows down.
+    CubicOut,
+
+    /// Starts slow, speeds up, and slows down again.
+    CubicInOut,
+
+    /// Starts very fast and slows down quickly.
+    ExponentialOut,
+
+    /// Goes a bit past the end before settling back.
+    BackOut,
+
+    /// Swings back and forth around the end before settling, like a spring.
+    ElasticOut,
+}
+
+impl Easing {
+    pub const ALL: [Easing; 7] = [
+        Easing::Linear,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::ExponentialOut,
+        Easing::BackOut,
+        Easing::ElasticOut,
+    ];
+
+    /// How far along the animation is (`0.0` at the start, `1.0` at the end)
+    /// after the fraction `t` of its time has passed.
+    ///
+    /// `t` is clamped to `[0, 1]`.
+    /// [`Self::BackOut`] and [`Self::ElasticOut`] return values above `1.0` along the way.
+    pub fn ease(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::CubicIn => t * t * t,
+            Self::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Self::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+                }
+            }
+            Self::ExponentialOut => {
+                if t == 1.0 {
+                    1.0
+                } else {
+                    1.0 - 2_f32.powf(-10.0 * t)
+                }
+            }
+            Self::BackOut => {
+                const OVERSHOOT: f32 = 1.70158;
+                let t = t - 1.0;
+                1.0 + (OVERSHOOT + 1.0) * t * t * t + OVERSHOOT * t * t
+            }
+            Self::ElasticOut => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    let period = std::f32::consts::TAU / 3.0;
+                    2_f32.powf(-10.0 * t) * ((10.0 * t - 0.75) * period).sin() + 1.0
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_easing() {
+    for easing in Easing::ALL {
+        assert_eq!(easing.ease(0.0), 0.0, "{:?}", easing);
+        assert!((easing.ease(1.0) - 1.0).abs() < 1e-6, "{:?}", easing);
+        assert_eq!(easing.ease(2.0), easing.ease(1.0), "{:?}", easing);
+    }
+    assert!(Easing::CubicIn.ease(0.5) < 0.5);
+    assert!((Easing::CubicInOut.ease(0.5) - 0.5).abs() < 1e-6);
+    assert!(Easing::BackOut.ease(0.8) > 1.0, "overshoots");
+}
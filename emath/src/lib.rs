@@ -96,6 +96,7 @@ use std::ops::{Add, Div, Mul, RangeInclusive, Sub};
 // ----------------------------------------------------------------------------
 
 pub mod align;
+mod easing;
 mod numeric;
 mod pos2;
 mod rect;
@@ -106,6 +107,7 @@ mod vec2;
 
 pub use {
     align::{Align, Align2},
+    easing::Easing,
     numeric::*,
     pos2::*,
     rect::*,
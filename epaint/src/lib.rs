@@ -93,6 +93,8 @@ pub mod mutex;
 mod shadow;
 mod shape;
 pub mod shape_transform;
+#[cfg(feature = "software_rasterizer")]
+pub mod software_rasterizer;
 pub mod stats;
 mod stroke;
 pub mod tessellator;
@@ -102,12 +104,14 @@ pub mod util;
 
 pub use {
     color::{Color32, Rgba},
-    mesh::{Mesh, Mesh16, Vertex},
+    mesh::{Mesh, Mesh16, Mesh16Slice, Vertex},
     shadow::Shadow,
     shape::{CircleShape, PathShape, RectShape, Shape, TextShape},
     stats::PaintStats,
     stroke::Stroke,
-    tessellator::{tessellate_shapes, TessellationOptions, Tessellator},
+    tessellator::{
+        tessellate_shapes, tessellate_shapes_recycling, TessellationOptions, Tessellator,
+    },
     text::{Fonts, Galley, TextStyle},
     texture_atlas::{FontImage, TextureAtlas},
 };
@@ -154,6 +158,15 @@ pub struct ClippedShape(
     pub Shape,
 );
 
+impl std::hash::Hash for ClippedShape {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self(clip_rect, shape) = self;
+        rect_hash(state, *clip_rect);
+        shape.hash(state);
+    }
+}
+
 /// A [`Mesh`] within a clip rectangle.
 ///
 /// Everything is using logical points.
@@ -197,6 +210,18 @@ pub(crate) fn f32_hash<H: std::hash::Hasher>(state: &mut H, f: f32) {
     }
 }
 
+#[inline(always)]
+pub(crate) fn pos2_hash<H: std::hash::Hasher>(state: &mut H, pos: emath::Pos2) {
+    f32_hash(state, pos.x);
+    f32_hash(state, pos.y);
+}
+
+#[inline(always)]
+pub(crate) fn rect_hash<H: std::hash::Hasher>(state: &mut H, rect: emath::Rect) {
+    pos2_hash(state, rect.min);
+    pos2_hash(state, rect.max);
+}
+
 #[inline(always)]
 pub(crate) fn f64_hash<H: std::hash::Hasher>(state: &mut H, f: f64) {
     if f == 0.0 {
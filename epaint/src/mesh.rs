@@ -22,6 +22,16 @@ pub struct Vertex {
     pub color: Color32, // 32 bit
 }
 
+impl std::hash::Hash for Vertex {
+    #[inline(always)]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self { pos, uv, color } = *self;
+        pos2_hash(state, pos);
+        pos2_hash(state, uv);
+        color.hash(state);
+    }
+}
+
 /// Textured triangles in two dimensions.
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -41,6 +51,19 @@ pub struct Mesh {
     // TODO: bounding rectangle
 }
 
+impl std::hash::Hash for Mesh {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self {
+            indices,
+            vertices,
+            texture_id,
+        } = self;
+        indices.hash(state);
+        vertices.hash(state);
+        texture_id.hash(state);
+    }
+}
+
 impl Mesh {
     pub fn with_texture(texture_id: TextureId) -> Self {
         Self {
@@ -49,11 +72,11 @@ impl Mesh {
         }
     }
 
-    /// Restore to default state, but without freeing memory.
+    /// Restore to default state (including [`Self::texture_id`]), but without freeing memory.
     pub fn clear(&mut self) {
         self.indices.clear();
         self.vertices.clear();
-        self.vertices = Default::default();
+        self.texture_id = Default::default();
     }
 
     pub fn bytes_used(&self) -> usize {
@@ -177,19 +200,74 @@ impl Mesh {
     /// Splits this mesh into many smaller meshes (if needed)
     /// where the smaller meshes have 16-bit indices.
     pub fn split_to_u16(self) -> Vec<Mesh16> {
-        crate::epaint_assert!(self.is_valid());
+        self.split_to_max_vertices(1 << 16)
+            .into_iter()
+            .map(|mesh| Mesh16 {
+                indices: mesh.indices.iter().map(|&i| i as u16).collect(),
+                vertices: mesh.vertices,
+                texture_id: mesh.texture_id,
+            })
+            .collect()
+    }
 
-        const MAX_SIZE: u32 = 1 << 16;
+    /// Like [`Self::split_to_u16`], but the smaller meshes borrow their vertices from this one,
+    /// so that only the indices are copied.
+    pub fn split_to_u16_slices(&self) -> Vec<Mesh16Slice<'_>> {
+        self.spans(1 << 16)
+            .into_iter()
+            .map(|(indices, vertices)| Mesh16Slice {
+                indices: self.indices[indices]
+                    .iter()
+                    .map(|&i| (i as usize - vertices.start) as u16)
+                    .collect(),
+                vertices: &self.vertices[vertices],
+                texture_id: self.texture_id,
+            })
+            .collect()
+    }
 
-        if self.vertices.len() < MAX_SIZE as usize {
+    /// Splits this mesh into many smaller meshes (if needed)
+    /// with at most `max_vertices` vertices each.
+    ///
+    /// With `max_vertices = 1 << 16` all indices fit in a `u16`.
+    ///
+    /// Panics if a single triangle spans more than `max_vertices` vertices,
+    /// which never happens for meshes from the [`crate::Tessellator`] unless `max_vertices` is tiny.
+    pub fn split_to_max_vertices(self, max_vertices: usize) -> Vec<Mesh> {
+        if self.vertices.len() <= max_vertices {
             // Common-case optimization:
-            return vec![Mesh16 {
-                indices: self.indices.iter().map(|&i| i as u16).collect(),
-                vertices: self.vertices,
-                texture_id: self.texture_id,
-            }];
+            crate::epaint_assert!(self.is_valid());
+            return vec![self];
+        }
+
+        self.spans(max_vertices)
+            .into_iter()
+            .map(|(indices, vertices)| {
+                let mesh = Mesh {
+                    indices: self.indices[indices]
+                        .iter()
+                        .map(|&i| i - vertices.start as u32)
+                        .collect(),
+                    vertices: self.vertices[vertices].to_vec(),
+                    texture_id: self.texture_id,
+                };
+                crate::epaint_assert!(mesh.is_valid());
+                mesh
+            })
+            .collect()
+    }
+
+    /// The ranges of indices to split this mesh at for [`Self::split_to_max_vertices`],
+    /// each with the range of vertices it uses.
+    fn spans(&self, max_vertices: usize) -> Vec<(std::ops::Range<usize>, std::ops::Range<usize>)> {
+        crate::epaint_assert!(self.is_valid());
+
+        if self.vertices.len() <= max_vertices {
+            // Common-case optimization:
+            return vec![(0..self.indices.len(), 0..self.vertices.len())];
         }
 
+        let max_size = max_vertices.min(u32::MAX as usize) as u32;
         let mut output = vec![];
         let mut index_cursor = 0;
 
@@ -206,7 +284,7 @@ impl Mesh {
                     new_max = new_max.max(idx);
                 }
 
-                if new_max - new_min < MAX_SIZE {
+                if new_max - new_min < max_size {
                     // Triangle fits
                     min_vindex = new_min;
                     max_vindex = new_max;
@@ -219,19 +297,13 @@ impl Mesh {
             assert!(
                 index_cursor > span_start,
                 "One triangle spanned more than {} vertices",
-                MAX_SIZE
+                max_size
             );
 
-            let mesh = Mesh16 {
-                indices: self.indices[span_start..index_cursor]
-                    .iter()
-                    .map(|vi| u16::try_from(vi - min_vindex).unwrap())
-                    .collect(),
-                vertices: self.vertices[(min_vindex as usize)..=(max_vindex as usize)].to_vec(),
-                texture_id: self.texture_id,
-            };
-            crate::epaint_assert!(mesh.is_valid());
-            output.push(mesh);
+            output.push((
+                span_start..index_cursor,
+                min_vindex as usize..max_vindex as usize + 1,
+            ));
         }
         output
     }
@@ -272,3 +344,48 @@ impl Mesh16 {
         }
     }
 }
+
+/// Like [`Mesh16`], but borrowing the vertices of the [`Mesh`] it is a part of.
+///
+/// This is produced by [`Mesh::split_to_u16_slices`].
+pub struct Mesh16Slice<'a> {
+    /// Draw as triangles (i.e. the length is always multiple of three).
+    ///
+    /// egui is NOT consistent with what winding order it uses, so turn off backface culling.
+    pub indices: Vec<u16>,
+
+    /// The vertex data indexed by `indices`.
+    pub vertices: &'a [Vertex],
+
+    /// The texture to use when drawing these triangles.
+    pub texture_id: TextureId,
+}
+
+impl<'a> Mesh16Slice<'a> {
+    /// Are all indices within the bounds of the contained vertices?
+    pub fn is_valid(&self) -> bool {
+        self.indices
+            .iter()
+            .all(|&i| (i as usize) < self.vertices.len())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_split_to_u16_slices() {
+    let mut mesh = Mesh::default();
+    for i in 0..30_000 {
+        let rect = Rect::from_min_size(pos2(i as f32, 0.0), vec2(1.0, 1.0));
+        mesh.add_colored_rect(rect, Color32::WHITE);
+    }
+
+    let slices = mesh.split_to_u16_slices();
+    let meshes = mesh.clone().split_to_u16();
+    assert!(slices.len() > 1);
+    assert_eq!(slices.len(), meshes.len());
+    for (slice, mesh) in slices.iter().zip(&meshes) {
+        assert!(slice.is_valid());
+        assert_eq!(slice.indices, mesh.indices);
+        assert_eq!(slice.vertices, mesh.vertices.as_slice());
+    }
+}
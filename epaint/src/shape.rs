@@ -1,8 +1,10 @@
 use crate::{
+    f32_hash, pos2_hash, rect_hash,
     text::{Fonts, Galley, TextStyle},
     Color32, Mesh, Stroke,
 };
 use emath::*;
+use std::hash::{Hash, Hasher};
 
 /// A paint primitive such as a circle or a piece of text.
 /// Coordinates are all screen space points (not physical pixels).
@@ -25,6 +27,31 @@ pub enum Shape {
     Mesh(Mesh),
 }
 
+/// Hashes everything that affects the tessellation of the shape,
+/// so that the resulting mesh can be cached.
+///
+/// Text is hashed by its [`crate::text::LayoutJob`] and layout size rather than by its glyphs,
+/// so the hash does not take the font into account.
+impl Hash for Shape {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Shape::Noop => {}
+            Shape::Vec(shapes) => shapes.hash(state),
+            Shape::Circle(circle_shape) => circle_shape.hash(state),
+            Shape::LineSegment { points, stroke } => {
+                pos2_hash(state, points[0]);
+                pos2_hash(state, points[1]);
+                stroke.hash(state);
+            }
+            Shape::Path(path_shape) => path_shape.hash(state),
+            Shape::Rect(rect_shape) => rect_shape.hash(state),
+            Shape::Text(text_shape) => text_shape.hash(state),
+            Shape::Mesh(mesh) => mesh.hash(state),
+        }
+    }
+}
+
 /// ## Constructors
 impl Shape {
     /// A line between two points.
@@ -141,6 +168,30 @@ impl Shape {
         }
     }
 
+    /// A conservative screen-space rectangle that contains everything this shape will paint.
+    ///
+    /// Shapes that paint nothing return [`Rect::NOTHING`].
+    pub fn visual_bounding_rect(&self) -> Rect {
+        match self {
+            Shape::Noop => Rect::NOTHING,
+            Shape::Vec(shapes) => shapes.iter().fold(Rect::NOTHING, |rect, shape| {
+                rect.union(shape.visual_bounding_rect())
+            }),
+            Shape::Circle(circle_shape) => circle_shape.visual_bounding_rect(),
+            Shape::LineSegment { points, stroke } => {
+                if stroke.is_empty() {
+                    Rect::NOTHING
+                } else {
+                    Rect::from_two_pos(points[0], points[1]).expand(stroke.width)
+                }
+            }
+            Shape::Path(path_shape) => path_shape.bounding_rect(),
+            Shape::Rect(rect_shape) => rect_shape.bounding_rect(),
+            Shape::Text(text_shape) => text_shape.visual_bounding_rect(),
+            Shape::Mesh(mesh) => mesh.calc_bounds(),
+        }
+    }
+
     /// Move the shape by this many points, in-place.
     pub fn translate(&mut self, delta: Vec2) {
         match self {
@@ -188,6 +239,22 @@ pub struct CircleShape {
     pub stroke: Stroke,
 }
 
+impl Hash for CircleShape {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self {
+            center,
+            radius,
+            fill,
+            stroke,
+        } = *self;
+        pos2_hash(state, center);
+        f32_hash(state, radius);
+        fill.hash(state);
+        stroke.hash(state);
+    }
+}
+
 impl CircleShape {
     #[inline]
     pub fn filled(center: Pos2, radius: f32, fill_color: impl Into<Color32>) -> Self {
@@ -208,6 +275,17 @@ impl CircleShape {
             stroke: stroke.into(),
         }
     }
+
+    /// Screen-space bounding rectangle.
+    #[inline]
+    pub fn visual_bounding_rect(&self) -> Rect {
+        if self.radius <= 0.0 {
+            Rect::NOTHING
+        } else {
+            Rect::from_center_size(self.center, Vec2::splat(2.0 * self.radius))
+                .expand(self.stroke.width)
+        }
+    }
 }
 
 impl From<CircleShape> for Shape {
@@ -232,6 +310,24 @@ pub struct PathShape {
     pub stroke: Stroke,
 }
 
+impl Hash for PathShape {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self {
+            points,
+            closed,
+            fill,
+            stroke,
+        } = self;
+        state.write_usize(points.len());
+        for &point in points {
+            pos2_hash(state, point);
+        }
+        closed.hash(state);
+        fill.hash(state);
+        stroke.hash(state);
+    }
+}
+
 impl PathShape {
     /// A line through many points.
     ///
@@ -299,6 +395,22 @@ pub struct RectShape {
     pub stroke: Stroke,
 }
 
+impl Hash for RectShape {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self {
+            rect,
+            corner_radius,
+            fill,
+            stroke,
+        } = *self;
+        rect_hash(state, rect);
+        f32_hash(state, corner_radius);
+        fill.hash(state);
+        stroke.hash(state);
+    }
+}
+
 impl RectShape {
     #[inline]
     pub fn filled(rect: Rect, corner_radius: f32, fill_color: impl Into<Color32>) -> Self {
@@ -359,6 +471,24 @@ pub struct TextShape {
     pub angle: f32,
 }
 
+impl Hash for TextShape {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self {
+            pos,
+            galley,
+            underline,
+            override_text_color,
+            angle,
+        } = self;
+        pos2_hash(state, *pos);
+        galley.job.hash(state);
+        rect_hash(state, galley.rect);
+        underline.hash(state);
+        override_text_color.hash(state);
+        f32_hash(state, *angle);
+    }
+}
+
 impl TextShape {
     #[inline]
     pub fn new(pos: Pos2, galley: std::sync::Arc<Galley>) -> Self {
@@ -376,6 +506,15 @@ impl TextShape {
     pub fn bounding_rect(&self) -> Rect {
         self.galley.mesh_bounds.translate(self.pos.to_vec2())
     }
+
+    /// Screen-space bounding rectangle, taking [`Self::angle`] and [`Self::underline`] into account.
+    pub fn visual_bounding_rect(&self) -> Rect {
+        let mut rect = self.galley.mesh_bounds.expand(self.underline.width);
+        if self.angle != 0.0 {
+            rect = rect.rotate_bb(Rot2::from_angle(self.angle));
+        }
+        rect.translate(self.pos.to_vec2())
+    }
 }
 
 impl From<TextShape> for Shape {
@@ -0,0 +1,397 @@
+//! A simple and deterministic CPU rasterizer for [`ClippedMesh`]es.
+//!
+//! This is not meant for painting egui in real applications (it is slow),
+//! but for snapshot testing: render a frame to a [`ColorImage`] and compare it
+//! against a golden image with [`ColorImage::assert_matches_golden`].
+//!
+//! Only available with the `software_rasterizer` feature.
+
+use crate::{ClippedMesh, Color32, FontImage, Mesh, Pos2, Rect, TextureId};
+
+/// An image of premultiplied sRGBA pixels, row by row, top to bottom.
+#[derive(Clone, PartialEq)]
+pub struct ColorImage {
+    /// width, height.
+    pub size: [usize; 2],
+    pub pixels: Vec<Color32>,
+}
+
+impl std::fmt::Debug for ColorImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColorImage")
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+/// How different two [`ColorImage`]s are, see [`ColorImage::diff`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ImageDiff {
+    /// Number of pixels where any channel differs by more than the tolerance.
+    pub num_different_pixels: usize,
+
+    /// The largest difference in any channel of any pixel.
+    pub max_difference: u8,
+}
+
+impl ColorImage {
+    pub fn new(size: [usize; 2], color: Color32) -> Self {
+        Self {
+            size,
+            pixels: vec![color; size[0] * size[1]],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.size[0]
+    }
+
+    pub fn height(&self) -> usize {
+        self.size[1]
+    }
+
+    /// The pixels as `[r, g, b, a]` bytes, row by row.
+    pub fn as_rgba_bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|c| c.to_array()).collect()
+    }
+
+    /// Compare two images.
+    ///
+    /// Images of different sizes differ in all pixels.
+    pub fn diff(&self, other: &Self, tolerance: u8) -> ImageDiff {
+        if self.size != other.size {
+            return ImageDiff {
+                num_different_pixels: self.pixels.len().max(other.pixels.len()),
+                max_difference: u8::MAX,
+            };
+        }
+
+        let mut diff = ImageDiff::default();
+        for (a, b) in self.pixels.iter().zip(&other.pixels) {
+            let max_difference = a
+                .to_array()
+                .iter()
+                .zip(b.to_array())
+                .map(|(a, b)| a.max(&b) - a.min(&b))
+                .max()
+                .unwrap_or_default();
+            if max_difference > tolerance {
+                diff.num_different_pixels += 1;
+            }
+            diff.max_difference = diff.max_difference.max(max_difference);
+        }
+        diff
+    }
+
+    /// Encode as a [PAM](https://netpbm.sourceforge.net/doc/pam.html) file,
+    /// which is a very simple uncompressed image format.
+    pub fn to_pam(&self) -> Vec<u8> {
+        let mut pam = format!(
+            "P7\nWIDTH {}\nHEIGHT {}\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n",
+            self.width(),
+            self.height()
+        )
+        .into_bytes();
+        pam.extend(self.as_rgba_bytes());
+        pam
+    }
+
+    /// Decode an image written with [`Self::to_pam`].
+    ///
+    /// Returns `None` if this is not an 8-bit RGBA PAM file.
+    pub fn from_pam(pam: &[u8]) -> Option<Self> {
+        const END: &[u8] = b"ENDHDR\n";
+        let header_len = pam.windows(END.len()).position(|w| w == END)? + END.len();
+        let header = std::str::from_utf8(&pam[..header_len]).ok()?;
+
+        let mut lines = header.lines();
+        if lines.next()? != "P7" {
+            return None;
+        }
+        let (mut width, mut height) = (None, None);
+        for line in lines {
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next()) {
+                (Some("WIDTH"), Some(value)) => width = value.parse().ok(),
+                (Some("HEIGHT"), Some(value)) => height = value.parse().ok(),
+                (Some("DEPTH"), Some(value)) if value != "4" => return None,
+                (Some("MAXVAL"), Some(value)) if value != "255" => return None,
+                _ => {}
+            }
+        }
+        let size = [width?, height?];
+
+        let bytes = &pam[header_len..];
+        if bytes.len() != 4 * size[0] * size[1] {
+            return None;
+        }
+        let pixels = bytes
+            .chunks_exact(4)
+            .map(|p| Color32::from_rgba_premultiplied(p[0], p[1], p[2], p[3]))
+            .collect();
+        Some(Self { size, pixels })
+    }
+
+    /// Compare this image to the golden image at `path` (a `.pam` file), and panic if they differ.
+    ///
+    /// If the golden image does not exist, or if the `UPDATE_GOLDEN` environment variable is set,
+    /// the golden image is (re)written instead.
+    /// A missing golden image still fails the test, so that it is not forgotten in version control.
+    ///
+    /// On failure, the new image is written next to the golden one with a `.new.pam` extension.
+    ///
+    /// `tolerance` is the largest difference in any channel that is not considered a difference.
+    pub fn assert_matches_golden(&self, path: impl AsRef<std::path::Path>, tolerance: u8) {
+        let path = path.as_ref();
+        let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+
+        match std::fs::read(path) {
+            Ok(bytes) if !update => {
+                let golden = Self::from_pam(&bytes)
+                    .unwrap_or_else(|| panic!("Failed to decode golden image {:?}", path));
+                let diff = self.diff(&golden, tolerance);
+                if diff.num_different_pixels > 0 {
+                    let new_path = path.with_extension("new.pam");
+                    let _ = std::fs::write(&new_path, self.to_pam());
+                    panic!(
+                        "Image differs from golden image {:?}: {:?}. Wrote new image to {:?}. \
+                         Run with UPDATE_GOLDEN=1 to accept it.",
+                        path, diff, new_path
+                    );
+                }
+            }
+            result => {
+                if let Some(dir) = path.parent() {
+                    let _ = std::fs::create_dir_all(dir);
+                }
+                std::fs::write(path, self.to_pam())
+                    .unwrap_or_else(|err| panic!("Failed to write {:?}: {}", path, err));
+                if result.is_err() && !update {
+                    panic!(
+                        "Golden image {:?} was missing; it has now been written",
+                        path
+                    );
+                }
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Rasterize the given meshes, in order, into an image of `size_in_pixels` cleared to `clear_color`.
+///
+/// Blending is done with premultiplied alpha in gamma (sRGB) space, and textures are sampled bilinearly.
+/// Meshes using [`TextureId::User`] are painted as if the texture was white.
+pub fn rasterize(
+    clipped_meshes: &[ClippedMesh],
+    font_image: &FontImage,
+    pixels_per_point: f32,
+    size_in_pixels: [usize; 2],
+    clear_color: Color32,
+) -> ColorImage {
+    let mut image = ColorImage::new(size_in_pixels, clear_color);
+    let font_texture: Vec<Color32> = font_image.srgba_pixels(1.0).collect();
+    let font_texture = Texture {
+        size: font_image.size(),
+        pixels: &font_texture,
+    };
+
+    for ClippedMesh(clip_rect, mesh) in clipped_meshes {
+        // Same rounding as the integrations use for their scissor rectangles:
+        let clip_rect = Rect::from_min_max(
+            (clip_rect.min.to_vec2() * pixels_per_point)
+                .round()
+                .to_pos2(),
+            (clip_rect.max.to_vec2() * pixels_per_point)
+                .round()
+                .to_pos2(),
+        );
+        let texture = match mesh.texture_id {
+            TextureId::Egui => Some(&font_texture),
+            TextureId::User(_) => None,
+        };
+        paint_mesh(&mut image, mesh, texture, clip_rect, pixels_per_point);
+    }
+
+    image
+}
+
+struct Texture<'a> {
+    size: [usize; 2],
+    pixels: &'a [Color32],
+}
+
+impl<'a> Texture<'a> {
+    /// Bilinear sampling, clamped to the edges.
+    fn sample(&self, uv: Pos2) -> [f32; 4] {
+        let [w, h] = self.size;
+        if w == 0 || h == 0 {
+            return [255.0; 4];
+        }
+        let x = (uv.x * w as f32 - 0.5).clamp(0.0, (w - 1) as f32);
+        let y = (uv.y * h as f32 - 0.5).clamp(0.0, (h - 1) as f32);
+        let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+        let (x1, y1) = ((x0 + 1).min(w - 1), (y0 + 1).min(h - 1));
+        let (tx, ty) = (x - x0 as f32, y - y0 as f32);
+
+        let texel = |x: usize, y: usize| self.pixels[y * w + x].to_array();
+        let (c00, c10, c01, c11) = (texel(x0, y0), texel(x1, y0), texel(x0, y1), texel(x1, y1));
+        let mut out = [0.0; 4];
+        for i in 0..4 {
+            let top = c00[i] as f32 * (1.0 - tx) + c10[i] as f32 * tx;
+            let bottom = c01[i] as f32 * (1.0 - tx) + c11[i] as f32 * tx;
+            out[i] = top * (1.0 - ty) + bottom * ty;
+        }
+        out
+    }
+}
+
+fn paint_mesh(
+    image: &mut ColorImage,
+    mesh: &Mesh,
+    texture: Option<&Texture<'_>>,
+    clip_rect: Rect,
+    pixels_per_point: f32,
+) {
+    let clip_rect = clip_rect.intersect(Rect::from_min_max(
+        Pos2::ZERO,
+        Pos2::new(image.width() as f32, image.height() as f32),
+    ));
+    if !clip_rect.is_positive() {
+        return;
+    }
+
+    for triangle in mesh.indices.chunks_exact(3) {
+        let mut v = [
+            mesh.vertices[triangle[0] as usize],
+            mesh.vertices[triangle[1] as usize],
+            mesh.vertices[triangle[2] as usize],
+        ];
+        for vertex in &mut v {
+            vertex.pos = (vertex.pos.to_vec2() * pixels_per_point).to_pos2();
+        }
+
+        let area = edge_function(v[0].pos, v[1].pos, v[2].pos);
+        if area == 0.0 {
+            continue;
+        }
+        if area < 0.0 {
+            v.swap(1, 2);
+        }
+        let area = area.abs();
+
+        let bounds = Rect::from_points(&[v[0].pos, v[1].pos, v[2].pos]).intersect(clip_rect);
+        if !bounds.is_positive() {
+            continue;
+        }
+
+        let edges = [(1, 2), (2, 0), (0, 1)]; // edge opposite vertex i
+        let owns_edge = edges.map(|(a, b)| is_owner_of_edge(v[a].pos, v[b].pos));
+
+        let (x_min, x_max) = (bounds.min.x.floor() as usize, bounds.max.x.ceil() as usize);
+        let (y_min, y_max) = (bounds.min.y.floor() as usize, bounds.max.y.ceil() as usize);
+        for y in y_min..y_max.min(image.height()) {
+            for x in x_min..x_max.min(image.width()) {
+                let p = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+                if !clip_rect.contains(p) {
+                    continue;
+                }
+
+                let mut weights = [0.0; 3];
+                let mut inside = true;
+                for (i, &(a, b)) in edges.iter().enumerate() {
+                    let w = edge_function(v[a].pos, v[b].pos, p);
+                    // Pixels exactly on a shared edge are only painted by one of the triangles:
+                    inside &= w > 0.0 || (w == 0.0 && owns_edge[i]);
+                    weights[i] = w / area;
+                }
+                if !inside {
+                    continue;
+                }
+
+                let mut color = [0.0_f32; 4];
+                let mut uv = Pos2::ZERO;
+                for (vertex, weight) in v.iter().zip(weights) {
+                    for (c, vc) in color.iter_mut().zip(vertex.color.to_array()) {
+                        *c += vc as f32 * weight;
+                    }
+                    uv += vertex.uv.to_vec2() * weight;
+                }
+                if let Some(texture) = texture {
+                    let texel = texture.sample(uv);
+                    for (c, t) in color.iter_mut().zip(texel) {
+                        *c *= t / 255.0;
+                    }
+                }
+
+                let dst = &mut image.pixels[y * image.size[0] + x];
+                *dst = blend(color, *dst);
+            }
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a, b, p`.
+fn edge_function(a: Pos2, b: Pos2, p: Pos2) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+/// For any edge shared by two triangles, exactly one of them owns it.
+fn is_owner_of_edge(a: Pos2, b: Pos2) -> bool {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    dy > 0.0 || (dy == 0.0 && dx > 0.0)
+}
+
+/// Premultiplied "over" blending.
+fn blend(src: [f32; 4], dst: Color32) -> Color32 {
+    let src_alpha = src[3] / 255.0;
+    let dst = dst.to_array();
+    let channel = |i: usize| {
+        (src[i] + dst[i] as f32 * (1.0 - src_alpha))
+            .round()
+            .clamp(0.0, 255.0) as u8
+    };
+    Color32::from_rgba_premultiplied(channel(0), channel(1), channel(2), channel(3))
+}
+
+#[cfg(test)]
+#[test]
+fn test_rasterize_rect() {
+    let mut mesh = Mesh::default();
+    mesh.add_colored_rect(
+        Rect::from_min_max(Pos2::new(1.0, 1.0), Pos2::new(3.0, 2.0)),
+        Color32::RED,
+    );
+    let clip_rect = Rect::EVERYTHING;
+    let font_image = FontImage {
+        version: 0,
+        width: 1,
+        height: 1,
+        pixels: vec![255],
+    };
+    let image = rasterize(
+        &[ClippedMesh(clip_rect, mesh)],
+        &font_image,
+        2.0,
+        [8, 8],
+        Color32::BLACK,
+    );
+    for y in 0..8 {
+        for x in 0..8 {
+            let inside = (2..6).contains(&x) && (2..4).contains(&y);
+            let expected = if inside { Color32::RED } else { Color32::BLACK };
+            assert_eq!(image.pixels[y * 8 + x], expected, "pixel {} {}", x, y);
+        }
+    }
+
+    let decoded = ColorImage::from_pam(&image.to_pam()).unwrap();
+    assert_eq!(decoded, image);
+    assert_eq!(image.diff(&decoded, 0), ImageDiff::default());
+    assert_eq!(
+        image
+            .diff(&ColorImage::new([8, 8], Color32::BLACK), 0)
+            .num_different_pixels,
+        8
+    );
+}
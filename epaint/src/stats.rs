@@ -166,6 +166,13 @@ pub struct PaintStats {
     pub text_shape_vertices: AllocInfo,
     pub text_shape_indices: AllocInfo,
 
+    /// Number of top-level shapes that are completely outside of their clip rectangle.
+    /// These are skipped by [`crate::tessellate_shapes`]
+    /// if [`crate::TessellationOptions::coarse_tessellation_culling`] is set.
+    ///
+    /// egui limits the clip rectangles to the screen, so this includes all off-screen shapes.
+    pub culled_shapes: usize,
+
     /// Number of separate clip rectangles
     pub clipped_meshes: AllocInfo,
     pub vertices: AllocInfo,
@@ -179,8 +186,11 @@ impl PaintStats {
         stats.shape_vec.element_size = ElementSize::Heterogenous; // nicer display later
 
         stats.shapes = AllocInfo::from_slice(shapes);
-        for ClippedShape(_, shape) in shapes {
+        for ClippedShape(clip_rect, shape) in shapes {
             stats.add(shape);
+            if !clip_rect.intersects(shape.visual_bounding_rect()) {
+                stats.culled_shapes += 1;
+            }
         }
         stats
     }
@@ -208,6 +208,34 @@ pub mod path {
         }
     }
 
+    /// Like [`rounded_rectangle`], but with a separate radius for each corner:
+    /// `[top_left, top_right, bottom_left, bottom_right]`.
+    ///
+    /// Useful for shapes that share an edge, e.g. a row of connected buttons.
+    ///
+    /// overwrites existing points
+    pub fn rounded_rectangle_corners(path: &mut Vec<Pos2>, rect: Rect, corner_radii: [f32; 4]) {
+        path.clear();
+
+        let min = rect.min;
+        let max = rect.max;
+
+        let max_radius = (rect.width() * 0.5).min(rect.height() * 0.5);
+        let [nw, ne, sw, se] = corner_radii.map(|cr| cr.min(max_radius).max(0.0));
+
+        let mut add_corner = |center: Pos2, cr: f32, quadrant: f32| {
+            if cr > 0.0 {
+                add_circle_quadrant(path, center, cr, quadrant);
+            } else {
+                path.push(center);
+            }
+        };
+        add_corner(pos2(max.x - se, max.y - se), se, 0.0);
+        add_corner(pos2(min.x + sw, max.y - sw), sw, 1.0);
+        add_corner(pos2(min.x + nw, min.y + nw), nw, 2.0);
+        add_corner(pos2(max.x - ne, min.y + ne), ne, 3.0);
+    }
+
     /// Add one quadrant of a circle
     ///
     /// * quadrant 0: right bottom
@@ -286,6 +314,33 @@ pub struct TessellationOptions {
 
     /// If true, no clipping will be done.
     pub debug_ignore_clip_rects: bool,
+
+    /// If `true` (default), long lists of shapes are split into chunks that are tessellated
+    /// in parallel on the [`rayon`](https://docs.rs/rayon) thread pool.
+    /// The output is the same as when tessellating on one thread.
+    ///
+    /// Only has an effect if the `rayon` feature of `epaint` is enabled.
+    pub parallel_tessellation: bool,
+
+    /// If set, [`tessellate_shapes`] splits meshes so that none has more than this many vertices,
+    /// by emitting several [`ClippedMesh`]es for the same clip rectangle.
+    ///
+    /// Set to `Some(1 << 16)` if your renderer only supports 16-bit indices (e.g. GLES2 and `WebGL1`),
+    /// so that [`Mesh::split_to_u16`] never needs to split anything.
+    ///
+    /// Default: `None`.
+    pub max_vertices_per_mesh: Option<usize>,
+
+    /// If `true`, [`Vertex::color`] is premultiplied *linear* RGBA instead of premultiplied sRGBA.
+    ///
+    /// Use this if your renderer blends in linear space (e.g. into an sRGB framebuffer with wgpu),
+    /// together with [`crate::FontImage::linear_rgba_pixels`] for the font texture
+    /// (uploaded as a non-sRGB texture).
+    /// The colors of the anti-aliasing feathering are then also interpolated in linear space,
+    /// which avoids the washed-out edges you get when blending sRGB colors in linear space.
+    ///
+    /// Default: `false`.
+    pub linear_vertex_colors: bool,
 }
 
 impl Default for TessellationOptions {
@@ -299,10 +354,42 @@ impl Default for TessellationOptions {
             debug_paint_text_rects: false,
             debug_paint_clip_rects: false,
             debug_ignore_clip_rects: false,
+            parallel_tessellation: true,
+            max_vertices_per_mesh: None,
+            linear_vertex_colors: false,
         }
     }
 }
 
+impl std::hash::Hash for TessellationOptions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self {
+            pixels_per_point,
+            aa_size,
+            anti_alias,
+            coarse_tessellation_culling,
+            round_text_to_pixels,
+            debug_paint_clip_rects,
+            debug_paint_text_rects,
+            debug_ignore_clip_rects,
+            parallel_tessellation,
+            max_vertices_per_mesh,
+            linear_vertex_colors,
+        } = *self;
+        crate::f32_hash(state, pixels_per_point);
+        crate::f32_hash(state, aa_size);
+        anti_alias.hash(state);
+        coarse_tessellation_culling.hash(state);
+        round_text_to_pixels.hash(state);
+        debug_paint_clip_rects.hash(state);
+        debug_paint_text_rects.hash(state);
+        debug_ignore_clip_rects.hash(state);
+        parallel_tessellation.hash(state);
+        max_vertices_per_mesh.hash(state);
+        linear_vertex_colors.hash(state);
+    }
+}
+
 impl TessellationOptions {
     pub fn from_pixels_per_point(pixels_per_point: f32) -> Self {
         Self {
@@ -882,11 +969,16 @@ impl Tessellator {
 /// The given shapes will tessellated in the same order as they are given.
 /// They will be batched together by clip rectangle.
 ///
+/// If [`TessellationOptions::coarse_tessellation_culling`] is set, shapes whose
+/// [`Shape::visual_bounding_rect`] is outside of their clip rectangle are skipped.
+///
 /// * `shapes`: what to tessellate
 /// * `options`: tessellation quality
 /// * `tex_size`: size of the font texture (required to normalize glyph uv rectangles)
 ///
 /// The implementation uses a [`Tessellator`].
+/// With the `rayon` feature and [`TessellationOptions::parallel_tessellation`],
+/// long lists of shapes are tessellated in parallel.
 ///
 /// ## Returns
 /// A list of clip rectangles with matching [`Mesh`].
@@ -894,6 +986,112 @@ pub fn tessellate_shapes(
     shapes: Vec<ClippedShape>,
     options: TessellationOptions,
     tex_size: [usize; 2],
+) -> Vec<ClippedMesh> {
+    tessellate_shapes_recycling(shapes, options, tex_size, &mut Vec::new())
+}
+
+/// Like [`tessellate_shapes`], but tessellates into the meshes of `recycled_meshes`
+/// (e.g. the meshes of last frame) before allocating new ones.
+///
+/// The recycled meshes are cleared first, but keep their capacity,
+/// so that the vertex and index buffers don't have to grow from scratch every frame.
+/// Meshes are taken from the end of `recycled_meshes`, and the ones not needed are left there.
+///
+/// Meshes are not recycled when a long list of shapes is tessellated in parallel.
+///
+/// The shapes can come from any iterator, e.g. a part of a [`Vec::drain`],
+/// so that they don't need to be collected first.
+pub fn tessellate_shapes_recycling(
+    shapes: impl IntoIterator<Item = ClippedShape>,
+    options: TessellationOptions,
+    tex_size: [usize; 2],
+    recycled_meshes: &mut Vec<Mesh>,
+) -> Vec<ClippedMesh> {
+    #[cfg(feature = "rayon")]
+    let mut clipped_meshes = if options.parallel_tessellation {
+        let shapes = shapes.into_iter().collect();
+        tessellate_shapes_in_parallel(shapes, options, tex_size, recycled_meshes)
+    } else {
+        tessellate_shapes_in_order(shapes, options, tex_size, recycled_meshes)
+    };
+    #[cfg(not(feature = "rayon"))]
+    let mut clipped_meshes = tessellate_shapes_in_order(shapes, options, tex_size, recycled_meshes);
+
+    let mut tessellator = Tessellator::from_options(options);
+
+    if options.debug_paint_clip_rects {
+        for ClippedMesh(clip_rect, mesh) in &mut clipped_meshes {
+            tessellator.clip_rect = Rect::EVERYTHING;
+            tessellator.tessellate_shape(
+                tex_size,
+                Shape::rect_stroke(
+                    *clip_rect,
+                    0.0,
+                    Stroke::new(2.0, Color32::from_rgb(150, 255, 150)),
+                ),
+                mesh,
+            );
+        }
+    }
+
+    if let Some(max_vertices) = options.max_vertices_per_mesh {
+        if clipped_meshes
+            .iter()
+            .any(|ClippedMesh(_, mesh)| mesh.vertices.len() > max_vertices)
+        {
+            clipped_meshes = clipped_meshes
+                .into_iter()
+                .flat_map(|ClippedMesh(clip_rect, mesh)| {
+                    mesh.split_to_max_vertices(max_vertices)
+                        .into_iter()
+                        .map(move |mesh| ClippedMesh(clip_rect, mesh))
+                })
+                .collect();
+        }
+    }
+
+    if options.linear_vertex_colors {
+        convert_to_linear_colors(&mut clipped_meshes);
+    }
+
+    if options.debug_ignore_clip_rects {
+        for ClippedMesh(clip_rect, _) in &mut clipped_meshes {
+            *clip_rect = Rect::EVERYTHING;
+        }
+    }
+
+    for ClippedMesh(_, mesh) in &clipped_meshes {
+        crate::epaint_assert!(mesh.is_valid(), "Tessellator generated invalid Mesh");
+    }
+
+    clipped_meshes
+}
+
+/// Convert the vertex colors from premultiplied sRGBA to premultiplied linear RGBA.
+fn convert_to_linear_colors(clipped_meshes: &mut [ClippedMesh]) {
+    use crate::color::{linear_f32_from_gamma_u8, linear_u8_from_linear_f32};
+    let linear_from_gamma: Vec<u8> = (0..=255)
+        .map(|c| linear_u8_from_linear_f32(linear_f32_from_gamma_u8(c)))
+        .collect();
+    for ClippedMesh(_, mesh) in clipped_meshes {
+        for vertex in &mut mesh.vertices {
+            let [r, g, b, a] = vertex.color.to_array();
+            vertex.color = Color32::from_rgba_premultiplied(
+                linear_from_gamma[r as usize],
+                linear_from_gamma[g as usize],
+                linear_from_gamma[b as usize],
+                a,
+            );
+        }
+    }
+}
+
+/// Tessellate the shapes one by one, batching them by clip rectangle and texture.
+fn tessellate_shapes_in_order(
+    shapes: impl IntoIterator<Item = ClippedShape>,
+    options: TessellationOptions,
+    tex_size: [usize; 2],
+    recycled_meshes: &mut Vec<Mesh>,
 ) -> Vec<ClippedMesh> {
     let mut tessellator = Tessellator::from_options(options);
 
@@ -904,13 +1102,21 @@ pub fn tessellate_shapes(
             continue; // skip empty clip rectangles
         }
 
+        if options.coarse_tessellation_culling
+            && !clip_rect.intersects(shape.visual_bounding_rect())
+        {
+            continue; // skip shapes that are completely clipped out
+        }
+
         let start_new_mesh = match clipped_meshes.last() {
             None => true,
             Some(cm) => cm.0 != clip_rect || cm.1.texture_id != shape.texture_id(),
         };
 
         if start_new_mesh {
-            clipped_meshes.push(ClippedMesh(clip_rect, Mesh::default()));
+            let mut mesh = recycled_meshes.pop().unwrap_or_default();
+            mesh.clear();
+            clipped_meshes.push(ClippedMesh(clip_rect, mesh));
         }
 
         let out = &mut clipped_meshes.last_mut().unwrap().1;
@@ -918,30 +1124,129 @@ pub fn tessellate_shapes(
         tessellator.tessellate_shape(tex_size, shape, out);
     }
 
-    if options.debug_paint_clip_rects {
-        for ClippedMesh(clip_rect, mesh) in &mut clipped_meshes {
-            tessellator.clip_rect = Rect::EVERYTHING;
-            tessellator.tessellate_shape(
-                tex_size,
-                Shape::rect_stroke(
-                    *clip_rect,
-                    0.0,
-                    Stroke::new(2.0, Color32::from_rgb(150, 255, 150)),
-                ),
-                mesh,
-            );
+    clipped_meshes
+}
+
+/// Shapes are only tessellated in parallel in chunks of at least this many.
+#[cfg(feature = "rayon")]
+const MIN_SHAPES_PER_CHUNK: usize = 256;
+
+/// Split the shapes into one chunk per thread, tessellate the chunks in parallel,
+/// and stitch the result together so that it is the same as [`tessellate_shapes_in_order`].
+#[cfg(feature = "rayon")]
+fn tessellate_shapes_in_parallel(
+    shapes: Vec<ClippedShape>,
+    options: TessellationOptions,
+    tex_size: [usize; 2],
+    recycled_meshes: &mut Vec<Mesh>,
+) -> Vec<ClippedMesh> {
+    use rayon::prelude::*;
+
+    let chunk_size = (shapes.len() / rayon::current_num_threads()).max(MIN_SHAPES_PER_CHUNK);
+    if shapes.len() <= chunk_size {
+        return tessellate_shapes_in_order(shapes, options, tex_size, recycled_meshes);
+    }
+
+    let mut chunks = Vec::new();
+    let mut shapes = shapes.into_iter();
+    loop {
+        let chunk: Vec<ClippedShape> = shapes.by_ref().take(chunk_size).collect();
+        if chunk.is_empty() {
+            break;
         }
+        chunks.push(chunk);
     }
 
-    if options.debug_ignore_clip_rects {
-        for ClippedMesh(clip_rect, _) in &mut clipped_meshes {
-            *clip_rect = Rect::EVERYTHING;
+    let chunks: Vec<Vec<ClippedMesh>> = chunks
+        .into_par_iter()
+        .map(|chunk| tessellate_shapes_in_order(chunk, options, tex_size, &mut Vec::new()))
+        .collect();
+
+    // Merge meshes across chunk boundaries, just like they would have been merged within a chunk:
+    let mut clipped_meshes: Vec<ClippedMesh> = Vec::new();
+    for clipped_mesh in chunks.into_iter().flatten() {
+        match clipped_meshes.last_mut() {
+            Some(last)
+                if last.0 == clipped_mesh.0 && last.1.texture_id == clipped_mesh.1.texture_id =>
+            {
+                last.1.append(clipped_mesh.1);
+            }
+            _ => clipped_meshes.push(clipped_mesh),
         }
     }
+    clipped_meshes
+}
 
-    for ClippedMesh(_, mesh) in &clipped_meshes {
-        crate::epaint_assert!(mesh.is_valid(), "Tessellator generated invalid Mesh");
+#[cfg(all(test, feature = "rayon"))]
+#[test]
+fn test_parallel_tessellation() {
+    let clip_rects = [
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0)),
+        Rect::from_min_max(pos2(50.0, 50.0), pos2(200.0, 200.0)),
+    ];
+    let shapes: Vec<ClippedShape> = (0..10_000)
+        .map(|i| {
+            let center = pos2((i % 200) as f32, (i / 50) as f32);
+            let shape = Shape::circle_filled(center, 2.0, Color32::from_gray(i as u8));
+            ClippedShape(clip_rects[i / 3000 % 2], shape)
+        })
+        .collect();
+
+    let options = TessellationOptions::default();
+    let in_order = tessellate_shapes_in_order(shapes.clone(), options, [1, 1], &mut Vec::new());
+    let in_parallel = tessellate_shapes_in_parallel(shapes, options, [1, 1], &mut Vec::new());
+    assert_eq!(in_order.len(), in_parallel.len());
+    for (a, b) in in_order.iter().zip(&in_parallel) {
+        assert_eq!(a.0, b.0);
+        assert_eq!(a.1, b.1);
     }
+}
 
-    clipped_meshes
+#[cfg(test)]
+#[test]
+fn test_max_vertices_per_mesh() {
+    let clip_rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+    let shapes: Vec<ClippedShape> = (0..1000)
+        .map(|i| {
+            let center = pos2((i % 100) as f32, (i / 10) as f32);
+            ClippedShape(clip_rect, Shape::circle_filled(center, 2.0, Color32::RED))
+        })
+        .collect();
+
+    let options = TessellationOptions::default();
+    let unsplit = tessellate_shapes(shapes.clone(), options, [1, 1]);
+    assert_eq!(unsplit.len(), 1);
+
+    let options = TessellationOptions {
+        max_vertices_per_mesh: Some(1000),
+        ..options
+    };
+    let split = tessellate_shapes(shapes, options, [1, 1]);
+    assert!(split.len() > 1);
+    assert!(split
+        .iter()
+        .all(|m| m.0 == clip_rect && m.1.vertices.len() <= 1000));
+    let num_indices =
+        |meshes: &[ClippedMesh]| meshes.iter().map(|m| m.1.indices.len()).sum::<usize>();
+    assert_eq!(num_indices(&split), num_indices(&unsplit));
+}
+
+#[cfg(test)]
+#[test]
+fn test_linear_vertex_colors() {
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0));
+    let shapes = vec![ClippedShape(
+        Rect::EVERYTHING,
+        Shape::rect_filled(rect, 0.0, Color32::from_gray(128)),
+    )];
+    let options = TessellationOptions {
+        linear_vertex_colors: true,
+        ..Default::default()
+    };
+    let meshes = tessellate_shapes(shapes, options, [1, 1]);
+    let colors: Vec<Color32> = meshes[0].1.vertices.iter().map(|v| v.color).collect();
+    // sRGB 128 is about 22% linear:
+    assert!(colors.contains(&Color32::from_gray(55)));
+    // The anti-aliasing feathering fades out to transparent:
+    assert!(colors.contains(&Color32::TRANSPARENT));
 }
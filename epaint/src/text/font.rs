@@ -149,6 +149,21 @@ impl FontImpl {
             }
         }
 
+        #[cfg(feature = "icons")]
+        if glyph_id.0 == 0 {
+            if let Some(icon) = super::Icon::from_char(c) {
+                let glyph_info = allocate_icon(
+                    &mut self.atlas.lock(),
+                    icon,
+                    self.scale_in_pixels,
+                    self.y_offset,
+                    self.pixels_per_point,
+                );
+                self.glyph_info_cache.write().insert(c, glyph_info);
+                return Some(glyph_info);
+            }
+        }
+
         if glyph_id.0 == 0 {
             if invisible_char(c) {
                 // hack
@@ -192,6 +207,13 @@ impl FontImpl {
         self.height_in_points
     }
 
+    /// Distance from the top of a row of text to the baseline, where the glyphs are placed. In points.
+    #[inline(always)]
+    pub fn ascent(&self) -> f32 {
+        // The glyphs are rasterized with the baseline one font size down, see `allocate_glyph`:
+        self.height_in_points + self.y_offset
+    }
+
     #[inline(always)]
     pub fn pixels_per_point(&self) -> f32 {
         self.pixels_per_point
@@ -273,6 +295,8 @@ impl Font {
             for font in &self.fonts {
                 characters.extend(font.characters());
             }
+            #[cfg(feature = "icons")]
+            characters.extend(super::Icon::ALL.iter().map(|icon| icon.char()));
             self.characters.write().replace(characters);
         }
         self.characters.read().clone().unwrap()
@@ -294,6 +318,13 @@ impl Font {
         self.row_height
     }
 
+    /// Distance from the top of a row of text to the baseline of the primary font. In points.
+    pub fn ascent(&self) -> f32 {
+        self.fonts
+            .first()
+            .map_or(self.row_height, |font| font.ascent())
+    }
+
     pub fn uv_rect(&self, c: char) -> UvRect {
         self.glyph_info_cache
             .read()
@@ -410,3 +441,40 @@ fn allocate_glyph(
         uv_rect,
     }
 }
+
+/// Icons are drawn a bit smaller than the font size, and centered on the capital letters, like emojis.
+#[cfg(feature = "icons")]
+fn allocate_icon(
+    atlas: &mut TextureAtlas,
+    icon: super::Icon,
+    scale_in_pixels: f32,
+    y_offset: f32,
+    pixels_per_point: f32,
+) -> GlyphInfo {
+    let icon_size = (0.8 * scale_in_pixels).round().max(1.0) as usize;
+    let icon_pos = atlas.allocate((icon_size, icon_size));
+
+    let texture = atlas.image_mut();
+    for (i, coverage) in icon.rasterize(icon_size).into_iter().enumerate() {
+        texture[(icon_pos.0 + i % icon_size, icon_pos.1 + i / icon_size)] = coverage;
+    }
+
+    let bearing_in_pixels = (0.07 * scale_in_pixels).round();
+    let offset_in_pixels = vec2(
+        bearing_in_pixels,
+        (0.46 * scale_in_pixels - 0.5 * icon_size as f32).round(),
+    );
+    GlyphInfo {
+        id: ab_glyph::GlyphId(0),
+        advance_width: (icon_size as f32 + 2.0 * bearing_in_pixels) / pixels_per_point,
+        uv_rect: UvRect {
+            offset: offset_in_pixels / pixels_per_point + y_offset * Vec2::Y,
+            size: Vec2::splat(icon_size as f32) / pixels_per_point,
+            min: [icon_pos.0 as u16, icon_pos.1 as u16],
+            max: [
+                (icon_pos.0 + icon_size) as u16,
+                (icon_pos.1 + icon_size) as u16,
+            ],
+        },
+    }
+}
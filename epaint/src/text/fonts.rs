@@ -340,6 +340,11 @@ impl Fonts {
         self.fonts[&text_style].row_height()
     }
 
+    /// Distance from the top of a row of text to its baseline. In points
+    pub fn ascent(&self, text_style: TextStyle) -> f32 {
+        self.fonts[&text_style].ascent()
+    }
+
     /// Layout some text.
     /// This is the most advanced layout function.
     /// See also [`Self::layout`], [`Self::layout_no_wrap`] and
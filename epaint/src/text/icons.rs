@@ -0,0 +1,370 @@
+//! A small set of built-in icons, drawn as vector shapes instead of coming from a font file.
+//!
+//! Only available with the `icons` feature.
+
+/// A point in the 16×16 design grid of the icons, with y pointing down.
+type Point = (f32, f32);
+
+/// The width of the lines of the icons, in units of the 16×16 design grid.
+const LINE_WIDTH: f32 = 1.5;
+
+/// The building blocks of an icon, in the 16×16 design grid.
+#[derive(Clone, Copy, Debug)]
+enum Part {
+    /// Line segments between consecutive points.
+    Line(&'static [Point]),
+
+    /// Like [`Self::Line`], but also connecting the last point to the first.
+    Loop(&'static [Point]),
+
+    /// A filled polygon (even-odd rule).
+    Fill(&'static [Point]),
+
+    /// The outline of a circle: center and radius.
+    Circle(Point, f32),
+
+    /// A filled circle: center and radius.
+    Disc(Point, f32),
+
+    /// Part of the outline of a circle: center, radius,
+    /// and the angles (in degrees, clockwise from the right) to go between.
+    Arc(Point, f32, [f32; 2]),
+}
+
+/// A built-in icon that can be used in any text, since each icon is a `char` in the Unicode Private Use Area.
+///
+/// The icons are drawn into the font texture on demand, in the size and color of the text around them,
+/// so they look the same with any font (unlike emojis, which depend on the fonts that happen to be available).
+///
+/// ```
+/// use epaint::text::Icon;
+/// let button_text = format!("{} Save", Icon::Save);
+/// assert_eq!(Icon::from_char(button_text.chars().next().unwrap()), Some(Icon::Save));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Icon {
+    Save,
+    Open,
+    New,
+    Close,
+    Check,
+    Add,
+    Remove,
+    Search,
+    Settings,
+    Home,
+    Delete,
+    Edit,
+    Copy,
+    Info,
+    Warning,
+    Error,
+    ArrowUp,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
+    Play,
+    Pause,
+    Stop,
+    Refresh,
+    Menu,
+    Star,
+}
+
+impl Icon {
+    /// All the icons, in the order of their `char`s.
+    pub const ALL: [Icon; 26] = [
+        Icon::Save,
+        Icon::Open,
+        Icon::New,
+        Icon::Close,
+        Icon::Check,
+        Icon::Add,
+        Icon::Remove,
+        Icon::Search,
+        Icon::Settings,
+        Icon::Home,
+        Icon::Delete,
+        Icon::Edit,
+        Icon::Copy,
+        Icon::Info,
+        Icon::Warning,
+        Icon::Error,
+        Icon::ArrowUp,
+        Icon::ArrowDown,
+        Icon::ArrowLeft,
+        Icon::ArrowRight,
+        Icon::Play,
+        Icon::Pause,
+        Icon::Stop,
+        Icon::Refresh,
+        Icon::Menu,
+        Icon::Star,
+    ];
+
+    /// The `char` of the first icon. The range after it is not used by the default fonts.
+    const FIRST_CHAR: u32 = 0xEA00;
+
+    /// The `char` that is drawn as this icon.
+    pub fn char(self) -> char {
+        char::from_u32(Self::FIRST_CHAR + self as u32).unwrap()
+    }
+
+    /// The icon drawn for this `char`, if any.
+    pub fn from_char(c: char) -> Option<Self> {
+        let index = (c as u32).checked_sub(Self::FIRST_CHAR)?;
+        Self::ALL.get(index as usize).copied()
+    }
+
+    fn parts(self) -> &'static [Part] {
+        use Part::{Arc, Circle, Disc, Fill, Line, Loop};
+        match self {
+            Icon::Save => &[
+                Loop(&[
+                    (2.0, 2.0),
+                    (12.0, 2.0),
+                    (14.0, 4.0),
+                    (14.0, 14.0),
+                    (2.0, 14.0),
+                ]),
+                Loop(&[(5.0, 2.0), (11.0, 2.0), (11.0, 6.0), (5.0, 6.0)]),
+                Loop(&[(4.5, 9.0), (11.5, 9.0), (11.5, 14.0), (4.5, 14.0)]),
+            ],
+            Icon::Open => &[Loop(&[
+                (1.5, 3.0),
+                (6.0, 3.0),
+                (7.5, 5.0),
+                (14.5, 5.0),
+                (14.5, 13.0),
+                (1.5, 13.0),
+            ])],
+            Icon::New => &[
+                Loop(&[
+                    (3.0, 1.5),
+                    (9.5, 1.5),
+                    (13.0, 5.0),
+                    (13.0, 14.5),
+                    (3.0, 14.5),
+                ]),
+                Line(&[(9.5, 1.5), (9.5, 5.0), (13.0, 5.0)]),
+            ],
+            Icon::Close => &[
+                Line(&[(3.0, 3.0), (13.0, 13.0)]),
+                Line(&[(13.0, 3.0), (3.0, 13.0)]),
+            ],
+            Icon::Check => &[Line(&[(2.5, 8.5), (6.0, 12.0), (13.5, 4.0)])],
+            Icon::Add => &[
+                Line(&[(8.0, 2.5), (8.0, 13.5)]),
+                Line(&[(2.5, 8.0), (13.5, 8.0)]),
+            ],
+            Icon::Remove => &[Line(&[(2.5, 8.0), (13.5, 8.0)])],
+            Icon::Search => &[Circle((6.5, 6.5), 4.5), Line(&[(10.0, 10.0), (14.0, 14.0)])],
+            Icon::Settings => &[
+                Circle((8.0, 8.0), 2.0),
+                Circle((8.0, 8.0), 5.0),
+                Line(&[(8.0, 1.0), (8.0, 3.0)]),
+                Line(&[(8.0, 13.0), (8.0, 15.0)]),
+                Line(&[(1.0, 8.0), (3.0, 8.0)]),
+                Line(&[(13.0, 8.0), (15.0, 8.0)]),
+                Line(&[(3.0, 3.0), (4.5, 4.5)]),
+                Line(&[(13.0, 13.0), (11.5, 11.5)]),
+                Line(&[(13.0, 3.0), (11.5, 4.5)]),
+                Line(&[(3.0, 13.0), (4.5, 11.5)]),
+            ],
+            Icon::Home => &[
+                Line(&[(1.5, 8.0), (8.0, 2.0), (14.5, 8.0)]),
+                Line(&[(3.5, 6.5), (3.5, 14.0), (12.5, 14.0), (12.5, 6.5)]),
+                Line(&[(6.5, 14.0), (6.5, 10.0), (9.5, 10.0), (9.5, 14.0)]),
+            ],
+            Icon::Delete => &[
+                Line(&[(2.0, 4.0), (14.0, 4.0)]),
+                Line(&[(6.0, 4.0), (6.0, 2.0), (10.0, 2.0), (10.0, 4.0)]),
+                Line(&[(3.5, 4.0), (4.5, 14.5), (11.5, 14.5), (12.5, 4.0)]),
+                Line(&[(6.5, 7.0), (6.5, 12.0)]),
+                Line(&[(9.5, 7.0), (9.5, 12.0)]),
+            ],
+            Icon::Edit => &[
+                Loop(&[
+                    (11.0, 2.0),
+                    (14.0, 5.0),
+                    (5.5, 13.5),
+                    (2.0, 14.0),
+                    (2.5, 10.5),
+                ]),
+                Line(&[(9.5, 3.5), (12.5, 6.5)]),
+            ],
+            Icon::Copy => &[
+                Loop(&[(5.5, 5.5), (14.0, 5.5), (14.0, 14.0), (5.5, 14.0)]),
+                Line(&[(2.0, 11.0), (2.0, 2.0), (11.0, 2.0)]),
+            ],
+            Icon::Info => &[
+                Circle((8.0, 8.0), 6.5),
+                Line(&[(8.0, 7.0), (8.0, 11.5)]),
+                Disc((8.0, 4.5), 1.0),
+            ],
+            Icon::Warning => &[
+                Loop(&[(8.0, 1.5), (15.0, 14.5), (1.0, 14.5)]),
+                Line(&[(8.0, 6.0), (8.0, 10.0)]),
+                Disc((8.0, 12.5), 1.0),
+            ],
+            Icon::Error => &[
+                Circle((8.0, 8.0), 6.5),
+                Line(&[(5.5, 5.5), (10.5, 10.5)]),
+                Line(&[(10.5, 5.5), (5.5, 10.5)]),
+            ],
+            Icon::ArrowUp => &[
+                Line(&[(8.0, 14.0), (8.0, 2.0)]),
+                Line(&[(3.0, 7.0), (8.0, 2.0), (13.0, 7.0)]),
+            ],
+            Icon::ArrowDown => &[
+                Line(&[(8.0, 2.0), (8.0, 14.0)]),
+                Line(&[(3.0, 9.0), (8.0, 14.0), (13.0, 9.0)]),
+            ],
+            Icon::ArrowLeft => &[
+                Line(&[(14.0, 8.0), (2.0, 8.0)]),
+                Line(&[(7.0, 3.0), (2.0, 8.0), (7.0, 13.0)]),
+            ],
+            Icon::ArrowRight => &[
+                Line(&[(2.0, 8.0), (14.0, 8.0)]),
+                Line(&[(9.0, 3.0), (14.0, 8.0), (9.0, 13.0)]),
+            ],
+            Icon::Play => &[Fill(&[(4.0, 2.0), (14.0, 8.0), (4.0, 14.0)])],
+            Icon::Pause => &[
+                Fill(&[(3.5, 2.0), (6.5, 2.0), (6.5, 14.0), (3.5, 14.0)]),
+                Fill(&[(9.5, 2.0), (12.5, 2.0), (12.5, 14.0), (9.5, 14.0)]),
+            ],
+            Icon::Stop => &[Fill(&[(3.0, 3.0), (13.0, 3.0), (13.0, 13.0), (3.0, 13.0)])],
+            Icon::Refresh => &[
+                Arc((8.0, 8.0), 5.5, [0.0, 300.0]),
+                Fill(&[(11.0, 8.5), (16.0, 8.5), (13.5, 4.5)]),
+            ],
+            Icon::Menu => &[
+                Line(&[(2.0, 4.0), (14.0, 4.0)]),
+                Line(&[(2.0, 8.0), (14.0, 8.0)]),
+                Line(&[(2.0, 12.0), (14.0, 12.0)]),
+            ],
+            Icon::Star => &[Fill(&[
+                (8.0, 1.5),
+                (9.76, 6.07),
+                (14.66, 6.34),
+                (10.85, 9.43),
+                (12.11, 14.16),
+                (8.0, 11.5),
+                (3.89, 14.16),
+                (5.15, 9.43),
+                (1.34, 6.34),
+                (6.24, 6.07),
+            ])],
+        }
+    }
+
+    /// Is this point of the 16×16 design grid covered by the icon?
+    fn covers(self, p: Point) -> bool {
+        let half_width = 0.5 * LINE_WIDTH;
+        self.parts().iter().any(|part| match *part {
+            Part::Line(points) => points
+                .windows(2)
+                .any(|w| distance_to_segment(p, w[0], w[1]) <= half_width),
+            Part::Loop(points) => {
+                let closing = [points[points.len() - 1], points[0]];
+                points
+                    .windows(2)
+                    .chain(std::iter::once(&closing[..]))
+                    .any(|w| distance_to_segment(p, w[0], w[1]) <= half_width)
+            }
+            Part::Fill(points) => polygon_contains(points, p),
+            Part::Circle(center, radius) => (distance(p, center) - radius).abs() <= half_width,
+            Part::Disc(center, radius) => distance(p, center) <= radius,
+            Part::Arc(center, radius, [start, end]) => {
+                let angle = (p.1 - center.1).atan2(p.0 - center.0).to_degrees();
+                let angle = (angle - start).rem_euclid(360.0) + start;
+                (distance(p, center) - radius).abs() <= half_width && angle <= end
+            }
+        })
+    }
+
+    /// The coverage (0-255) of a `size`×`size` pixel image of the icon, row by row.
+    pub(crate) fn rasterize(self, size: usize) -> Vec<u8> {
+        /// Samples per pixel in each direction, for anti-aliasing.
+        const SAMPLES: usize = 4;
+
+        let scale = 16.0 / size as f32;
+        let mut pixels = Vec::with_capacity(size * size);
+        for y in 0..size {
+            for x in 0..size {
+                let mut covered = 0;
+                for sy in 0..SAMPLES {
+                    for sx in 0..SAMPLES {
+                        let sample = (
+                            (x as f32 + (sx as f32 + 0.5) / SAMPLES as f32) * scale,
+                            (y as f32 + (sy as f32 + 0.5) / SAMPLES as f32) * scale,
+                        );
+                        covered += self.covers(sample) as usize;
+                    }
+                }
+                pixels.push((covered * 255 / (SAMPLES * SAMPLES)) as u8);
+            }
+        }
+        pixels
+    }
+}
+
+impl std::fmt::Display for Icon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use std::fmt::Write as _;
+        f.write_char(self.char())
+    }
+}
+
+fn distance(a: Point, b: Point) -> f32 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+fn distance_to_segment(p: Point, a: Point, b: Point) -> f32 {
+    let (abx, aby) = (b.0 - a.0, b.1 - a.1);
+    let length_sq = abx * abx + aby * aby;
+    let t = if length_sq > 0.0 {
+        (((p.0 - a.0) * abx + (p.1 - a.1) * aby) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    distance(p, (a.0 + t * abx, a.1 + t * aby))
+}
+
+/// Even-odd rule: count the edges crossed by a ray going right from `p`.
+fn polygon_contains(points: &[Point], p: Point) -> bool {
+    let mut inside = false;
+    let mut prev = points[points.len() - 1];
+    for &point in points {
+        if (point.1 > p.1) != (prev.1 > p.1) {
+            let x = point.0 + (p.1 - point.1) / (prev.1 - point.1) * (prev.0 - point.0);
+            if p.0 < x {
+                inside = !inside;
+            }
+        }
+        prev = point;
+    }
+    inside
+}
+
+#[test]
+fn test_icon_chars() {
+    for icon in Icon::ALL {
+        assert_eq!(Icon::from_char(icon.char()), Some(icon));
+    }
+    assert_eq!(Icon::from_char('a'), None);
+    assert_eq!(Icon::from_char('\u{EA00}'), Some(Icon::ALL[0]));
+    assert_eq!(Icon::from_char('\u{EAFF}'), None);
+}
+
+#[test]
+fn test_rasterize_icon() {
+    let size = 16;
+    let pixels = Icon::Stop.rasterize(size);
+    assert_eq!(pixels[8 * size + 8], 255, "center is covered");
+    assert_eq!(pixels[0], 0, "corner is empty");
+    let pixels = Icon::Search.rasterize(size);
+    assert_eq!(pixels[6 * size + 6], 0, "inside the magnifying glass");
+    assert!(pixels[14 * size + 14] > 0, "the handle");
+}
@@ -3,12 +3,17 @@
 pub mod cursor;
 mod font;
 mod fonts;
+#[cfg(feature = "icons")]
+mod icons;
 mod text_layout;
 mod text_layout_types;
 
 /// One `\t` character is this many spaces wide.
 pub const TAB_SIZE: usize = 4;
 
+#[cfg(feature = "icons")]
+pub use icons::Icon;
+
 pub use {
     fonts::{FontData, FontDefinitions, FontFamily, Fonts, TextStyle},
     text_layout::layout,
@@ -33,6 +33,25 @@ impl FontImage {
             .iter()
             .map(move |&l| srgba_from_luminance_lut[l as usize])
     }
+
+    /// Returns the textures as premultiplied *linear* RGBA pixels, row by row, top to bottom.
+    ///
+    /// Use this instead of [`Self::srgba_pixels`] for a non-sRGB texture
+    /// when [`crate::TessellationOptions::linear_vertex_colors`] is set.
+    pub fn linear_rgba_pixels(&'_ self, gamma: f32) -> impl Iterator<Item = super::Color32> + '_ {
+        use super::Color32;
+
+        let linear_from_luminance_lut: Vec<Color32> = (0..=255)
+            .map(|a| {
+                let a = super::color::linear_f32_from_linear_u8(a).powf(gamma);
+                let a = super::color::linear_u8_from_linear_f32(a);
+                Color32::from_rgba_premultiplied(a, a, a, a)
+            })
+            .collect();
+        self.pixels
+            .iter()
+            .map(move |&l| linear_from_luminance_lut[l as usize])
+    }
 }
 
 impl std::ops::Index<(usize, usize)> for FontImage {